@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// ID generation strategy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// Random UUID v4
+    Uuid,
+    /// Monotonic counter with an optional prefix
+    Sequential {
+        /// ID prefix, joined to the counter with `-`
+        prefix: Option<String>,
+    },
+}
+
+/// ID generator
+///
+/// Sequential IDs come from a monotonic counter that never goes back, so an
+/// ID is never reissued after the entity holding it is removed. Clones share
+/// the counter. To stay unique across restarts, either use UUIDs or persist
+/// `last_issued()` and resume with `starting_after()`.
+#[derive(Debug, Clone)]
+pub struct IdGenerator {
+    /// Generation strategy
+    pub strategy: IdStrategy,
+    /// Last issued counter value
+    counter: Arc<AtomicU64>,
+}
+
+impl IdGenerator {
+    /// Create a UUID generator
+    pub fn uuid() -> Self {
+        Self {
+            strategy: IdStrategy::Uuid,
+            counter: Arc::new(AtomicU64::new(0)),
+        }
+    }
+    
+    /// Create a sequential generator, an empty prefix yields bare numbers
+    pub fn sequential(prefix: &str) -> Self {
+        Self::starting_after(prefix, 0)
+    }
+    
+    /// Create a sequential generator resuming after a previously issued counter value
+    pub fn starting_after(prefix: &str, last_issued: u64) -> Self {
+        Self {
+            strategy: IdStrategy::Sequential {
+                prefix: if prefix.is_empty() { None } else { Some(prefix.to_string()) },
+            },
+            counter: Arc::new(AtomicU64::new(last_issued)),
+        }
+    }
+    
+    /// Generate the next ID
+    pub fn next_id(&self) -> String {
+        match &self.strategy {
+            IdStrategy::Uuid => uuid::Uuid::new_v4().to_string(),
+            IdStrategy::Sequential { prefix } => {
+                let value = self.counter.fetch_add(1, Ordering::SeqCst) + 1;
+                match prefix {
+                    Some(prefix) => format!("{}-{}", prefix, value),
+                    None => value.to_string(),
+                }
+            },
+        }
+    }
+    
+    /// Get the last issued counter value, 0 if none or for UUIDs
+    pub fn last_issued(&self) -> u64 {
+        self.counter.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for IdGenerator {
+    fn default() -> Self {
+        Self::uuid()
+    }
+}
@@ -1,5 +1,6 @@
 pub mod config;
 pub mod context;
+pub mod id;
 pub mod integration;
 pub mod lifecycle;
 
@@ -1,7 +1,8 @@
 use std::error::Error;
 use std::fmt;
-use std::path::Path;
+use std::path::{Component, Path};
 use std::process::Command;
+use std::str::FromStr;
 use std::fs::{self, File};
 use std::io::{self, Write};
 use std::sync::{Arc, Mutex};
@@ -11,6 +12,14 @@ use crate::gui::input_method::InputMethodManager;
 use crate::gui::theme::ThemeManager;
 use crate::gui::keyboard_input::KeyboardInputManager;
 use crate::gui::mouse_input::MouseInputManager;
+use crate::system::ByteSize;
+use sha2::{Digest, Sha256};
+
+/// 包完整性清单文件名，格式与 `sha256sum` 相同
+pub const MANIFEST_FILE: &str = "manifest.sha256";
+
+/// 计入完整性清单的子目录
+const MANIFEST_DIRS: [&str; 3] = ["bin", "config", "resources"];
 
 /// 部署错误类型
 #[derive(Debug)]
@@ -72,6 +81,111 @@ impl VirtualMachineType {
             VirtualMachineType::HyperV => "Hyper-V",
         }
     }
+    
+    /// 解析虚拟机类型，不区分大小写，支持常用别名
+    pub fn parse_from_str(s: &str) -> Result<Self, DeploymentError> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "vmware" => Ok(VirtualMachineType::VMware),
+            "virtualbox" | "vbox" => Ok(VirtualMachineType::VirtualBox),
+            "qemu" | "kvm" | "qemu/kvm" => Ok(VirtualMachineType::QEMU),
+            "hyperv" | "hyper-v" => Ok(VirtualMachineType::HyperV),
+            _ => Err(DeploymentError::Other(format!(
+                "未知的虚拟机类型: {} (可选: vmware, virtualbox, qemu, hyperv)",
+                s
+            ))),
+        }
+    }
+}
+
+impl FromStr for VirtualMachineType {
+    type Err = DeploymentError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_from_str(s)
+    }
+}
+
+/// 计算SHA-256摘要，返回十六进制字符串
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    to_hex(&Sha256::digest(bytes))
+}
+
+/// 流式计算文件的SHA-256摘要，不把整个文件读入内存
+pub fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(to_hex(&hasher.finalize()))
+}
+
+/// 把摘要字节转换为十六进制字符串
+fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 检查清单中的路径是否为包目录内的相对路径，拒绝绝对路径和 `..`
+fn is_package_relative(file: &str) -> bool {
+    let path = Path::new(file);
+    !file.is_empty() && path.components().all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// 递归收集目录下的文件，返回相对包目录的路径
+fn collect_package_files(package_dir: &Path, dir: &Path, files: &mut Vec<String>) -> io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_package_files(package_dir, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(package_dir) {
+            files.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    
+    Ok(())
+}
+
+/// 部署状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentStatus {
+    /// 已部署
+    Deployed,
+    /// 按配置跳过部署
+    Skipped(String),
+    /// 当前环境不支持该虚拟机类型的自动部署，未实际部署
+    Unsupported(String),
+}
+
+impl DeploymentStatus {
+    /// 是否已实际部署
+    pub fn is_deployed(&self) -> bool {
+        *self == DeploymentStatus::Deployed
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeploymentStatus::Deployed => write!(f, "已部署"),
+            DeploymentStatus::Skipped(reason) => write!(f, "已跳过: {}", reason),
+            DeploymentStatus::Unsupported(reason) => write!(f, "未部署(不支持): {}", reason),
+        }
+    }
+}
+
+/// 构建计划
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildPlan {
+    /// cargo 参数
+    pub cargo_args: Vec<String>,
+    /// 构建产物路径
+    pub source_executable: String,
+    /// 输出目录中的可执行文件路径
+    pub target_executable: String,
+    /// 可执行文件名
+    pub executable_name: String,
 }
 
 /// 部署配置
@@ -90,6 +204,12 @@ pub struct DeploymentConfig {
     enable_4k_resolution: bool,
     /// 是否启用输入设备直通
     enable_input_passthrough: bool,
+    /// 虚拟机内存大小
+    vm_memory: ByteSize,
+    /// 交叉编译目标三元组，如 aarch64-unknown-linux-gnu
+    target_triple: Option<String>,
+    /// 演练模式：只记录命令，不实际执行
+    dry_run: bool,
 }
 
 impl DeploymentConfig {
@@ -102,6 +222,9 @@ impl DeploymentConfig {
             enable_hardware_acceleration: true,
             enable_4k_resolution: true,
             enable_input_passthrough: true,
+            vm_memory: ByteSize::gb(4),
+            target_triple: None,
+            dry_run: false,
         }
     }
     
@@ -129,6 +252,39 @@ impl DeploymentConfig {
     pub fn set_input_passthrough(&mut self, enable: bool) {
         self.enable_input_passthrough = enable;
     }
+    
+    /// 设置虚拟机内存大小
+    pub fn set_vm_memory(&mut self, size: ByteSize) {
+        self.vm_memory = size;
+    }
+    
+    /// 获取虚拟机内存大小
+    pub fn vm_memory(&self) -> ByteSize {
+        self.vm_memory
+    }
+    
+    /// 设置交叉编译目标三元组
+    pub fn set_target_triple(&mut self, triple: &str) {
+        self.target_triple = Some(triple.to_string());
+    }
+    
+    /// 获取交叉编译目标三元组
+    pub fn target_triple(&self) -> Option<&str> {
+        self.target_triple.as_deref()
+    }
+    
+    /// 设置演练模式
+    ///
+    /// 演练模式下外部命令只记录到计划命令列表，不实际执行；假定虚拟机工具
+    /// 均可用，跳过等待虚拟机启动和平台检查，但仍会在输出目录生成脚本和配置文件。
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+    
+    /// 是否为演练模式
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
 }
 
 /// 部署管理器
@@ -137,6 +293,8 @@ pub struct DeploymentManager {
     config: DeploymentConfig,
     /// 日志
     logs: Vec<String>,
+    /// 演练模式下记录的命令
+    planned_commands: Vec<String>,
 }
 
 impl DeploymentManager {
@@ -144,6 +302,47 @@ impl DeploymentManager {
         Self {
             config,
             logs: Vec::new(),
+            planned_commands: Vec::new(),
+        }
+    }
+    
+    /// 获取演练模式下记录的命令
+    pub fn get_planned_commands(&self) -> &[String] {
+        &self.planned_commands
+    }
+    
+    /// 运行外部命令，返回是否成功；演练模式下只记录命令
+    fn run_command(&mut self, program: &str, args: &[&str], dir: Option<&str>) -> Result<bool, DeploymentError> {
+        if self.config.dry_run {
+            let command_line = std::iter::once(program)
+                .chain(args.iter().copied())
+                .map(|part| if part.contains(char::is_whitespace) { format!("\"{}\"", part) } else { part.to_string() })
+                .collect::<Vec<_>>()
+                .join(" ");
+            self.planned_commands.push(command_line);
+            return Ok(true);
+        }
+        
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = dir {
+            command.current_dir(dir);
+        }
+        Ok(command.status()?.success())
+    }
+    
+    /// 检查外部工具是否可用；演练模式下假定可用
+    fn tool_available(&self, tool: &str) -> bool {
+        self.config.dry_run || Command::new("which")
+            .arg(tool)
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+    
+    /// 等待虚拟机启动；演练模式下不等待
+    fn wait_for_vm_boot(&self) {
+        if !self.config.dry_run {
+            std::thread::sleep(std::time::Duration::from_secs(30));
         }
     }
     
@@ -174,6 +373,34 @@ impl DeploymentManager {
         Ok(())
     }
     
+    /// 生成构建计划
+    ///
+    /// 设置了目标三元组时向 cargo 传入 `--target`，构建产物位于
+    /// `target/<triple>/release`，否则位于 `target/release`。
+    pub fn build_plan(&self, source_dir: &str) -> BuildPlan {
+        let mut cargo_args = vec!["build".to_string(), "--release".to_string()];
+        let target_dir = match &self.config.target_triple {
+            Some(triple) => {
+                cargo_args.push("--target".to_string());
+                cargo_args.push(triple.clone());
+                format!("{}/target/{}/release", source_dir, triple)
+            }
+            None => format!("{}/target/release", source_dir),
+        };
+        
+        let executable_name = Path::new(source_dir)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("rust_agi_os");
+        
+        BuildPlan {
+            cargo_args,
+            source_executable: format!("{}/{}", target_dir, executable_name),
+            target_executable: format!("{}/bin/{}", self.config.output_dir, executable_name),
+            executable_name: executable_name.to_string(),
+        }
+    }
+    
     /// 打包应用
     pub fn package_application(&mut self, source_dir: &str) -> Result<String, DeploymentError> {
         self.log(&format!("开始打包应用: {}", source_dir));
@@ -182,20 +409,23 @@ impl DeploymentManager {
         self.create_output_directory()?;
         
         // 构建应用
-        self.log("构建应用...");
-        let status = Command::new("cargo")
-            .args(&["build", "--release"])
-            .current_dir(source_dir)
-            .status()?;
-            
-        if !status.success() {
+        let plan = self.build_plan(source_dir);
+        self.log(&format!("构建应用: cargo {}", plan.cargo_args.join(" ")));
+        let cargo_args: Vec<&str> = plan.cargo_args.iter().map(String::as_str).collect();
+        if !self.run_command("cargo", &cargo_args, Some(source_dir))? {
             return Err(DeploymentError::PackagingError("构建应用失败".to_string()));
         }
         
+        if !self.config.dry_run && !Path::new(&plan.source_executable).is_file() {
+            return Err(DeploymentError::PackagingError(format!(
+                "构建产物不存在: {}",
+                plan.source_executable
+            )));
+        }
+        
         self.log("应用构建成功");
         
         // 复制构建产物到输出目录
-        let target_dir = format!("{}/target/release", source_dir);
         let output_bin_dir = format!("{}/bin", self.config.output_dir);
         
         fs::create_dir_all(&output_bin_dir)?;
@@ -203,15 +433,11 @@ impl DeploymentManager {
         self.log(&format!("复制构建产物到: {}", output_bin_dir));
         
         // 复制可执行文件
-        let executable_name = Path::new(source_dir)
-            .file_name()
-            .and_then(|name| name.to_str())
-            .unwrap_or("rust_agi_os");
-            
-        let source_executable = format!("{}/{}", target_dir, executable_name);
-        let target_executable = format!("{}/{}", output_bin_dir, executable_name);
-        
-        fs::copy(&source_executable, &target_executable)?;
+        if self.config.dry_run {
+            self.planned_commands.push(format!("cp {} {}", plan.source_executable, plan.target_executable));
+        } else {
+            fs::copy(&plan.source_executable, &plan.target_executable)?;
+        }
         
         // 复制配置文件和资源
         let output_config_dir = format!("{}/config", self.config.output_dir);
@@ -241,14 +467,10 @@ impl DeploymentManager {
             writeln!(file, "export RUST_AGI_OS_RESOLUTION=1920x1080")?;
         }
         
-        writeln!(file, "bin/{} \"$@\"", executable_name)?;
+        writeln!(file, "bin/{} \"$@\"", plan.executable_name)?;
         
         // 设置脚本为可执行
-        let status = Command::new("chmod")
-            .args(&["+x", &startup_script])
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("chmod", &["+x", &startup_script], None)? {
             return Err(DeploymentError::PackagingError("设置启动脚本为可执行失败".to_string()));
         }
         
@@ -257,11 +479,82 @@ impl DeploymentManager {
         // 创建虚拟机配置文件
         self.create_vm_config()?;
         
+        // 生成完整性清单；演练模式下未复制可执行文件，不生成
+        if !self.config.dry_run {
+            let output_dir = self.config.output_dir.clone();
+            self.write_manifest(&output_dir)?;
+        }
+        
         self.log("应用打包完成");
         
         Ok(self.config.output_dir.clone())
     }
     
+    /// 生成完整性清单
+    ///
+    /// 清单列出可执行文件、启动脚本以及配置和资源文件的SHA-256，写入包目录下的
+    /// `manifest.sha256`，返回清单路径。
+    pub fn write_manifest(&mut self, package_dir: &str) -> Result<String, DeploymentError> {
+        let root = Path::new(package_dir);
+        let mut files = vec!["start.sh".to_string()];
+        for dir in MANIFEST_DIRS {
+            collect_package_files(root, &root.join(dir), &mut files)?;
+        }
+        files.sort();
+        
+        let mut manifest = String::new();
+        for file in &files {
+            manifest.push_str(&format!("{}  {}\n", sha256_file(&root.join(file))?, file));
+        }
+        
+        let manifest_path = root.join(MANIFEST_FILE);
+        fs::write(&manifest_path, manifest)?;
+        
+        let manifest_path = manifest_path.to_string_lossy().into_owned();
+        self.log(&format!("完整性清单已生成: {} ({}个文件)", manifest_path, files.len()));
+        
+        Ok(manifest_path)
+    }
+    
+    /// 校验包完整性
+    ///
+    /// 重新计算清单中每个文件的SHA-256，哈希不一致、文件缺失或路径指向包目录之外时
+    /// 返回列出这些文件的 `PackagingError`。
+    pub fn verify_package(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+        let root = Path::new(package_dir);
+        let manifest = fs::read_to_string(root.join(MANIFEST_FILE)).map_err(|e| {
+            DeploymentError::PackagingError(format!("无法读取完整性清单 {}: {}", MANIFEST_FILE, e))
+        })?;
+        
+        let mut failures = Vec::new();
+        for line in manifest.lines().filter(|line| !line.trim().is_empty()) {
+            let (expected, file) = line.split_once("  ").ok_or_else(|| {
+                DeploymentError::PackagingError(format!("完整性清单格式错误: {}", line))
+            })?;
+            
+            if !is_package_relative(file) {
+                failures.push(format!("{} (路径不在包目录内)", file));
+                continue;
+            }
+            
+            match sha256_file(&root.join(file)) {
+                Ok(actual) if actual == expected => {},
+                Ok(_) => failures.push(format!("{} (哈希不一致)", file)),
+                Err(_) => failures.push(format!("{} (文件缺失)", file)),
+            }
+        }
+        
+        if !failures.is_empty() {
+            return Err(DeploymentError::PackagingError(format!(
+                "包校验失败: {}",
+                failures.join(", ")
+            )));
+        }
+        
+        self.log(&format!("包校验通过: {}", package_dir));
+        Ok(())
+    }
+    
     /// 创建虚拟机配置文件
     fn create_vm_config(&mut self) -> Result<(), DeploymentError> {
         self.log("创建虚拟机配置文件...");
@@ -279,7 +572,7 @@ impl DeploymentManager {
                 writeln!(file, "virtualHW.version = \"18\"")?;
                 writeln!(file, "displayName = \"Rust AGI OS GUI\"")?;
                 writeln!(file, "guestOS = \"ubuntu-64\"")?;
-                writeln!(file, "memsize = \"4096\"")?;
+                writeln!(file, "memsize = \"{}\"", self.config.vm_memory.as_mb())?;
                 writeln!(file, "numvcpus = \"4\"")?;
                 
                 if self.config.enable_hardware_acceleration {
@@ -317,7 +610,7 @@ impl DeploymentManager {
                 writeln!(file, "  <Machine name=\"Rust AGI OS GUI\" OSType=\"Ubuntu_64\" snapshotFolder=\"Snapshots\">")?;
                 writeln!(file, "    <Hardware>")?;
                 writeln!(file, "      <CPU count=\"4\"/>")?;
-                writeln!(file, "      <Memory RAMSize=\"4096\"/>")?;
+                writeln!(file, "      <Memory RAMSize=\"{}\"/>", self.config.vm_memory.as_mb())?;
                 writeln!(file, "      <Display VRAMSize=\"128\" accelerate3D=\"{}\" accelerate2DVideo=\"{}\"/>",
                     self.config.enable_hardware_acceleration, self.config.enable_hardware_acceleration)?;
                 
@@ -341,7 +634,7 @@ impl DeploymentManager {
                 
                 let mut qemu_args = vec![
                     "-name \"Rust AGI OS GUI\"".to_string(),
-                    "-m 4G".to_string(),
+                    format!("-m {}M", self.config.vm_memory.as_mb()),
                     "-smp 4".to_string(),
                 ];
                 
@@ -368,11 +661,7 @@ impl DeploymentManager {
                 writeln!(file, "  {}", qemu_args.last().unwrap())?;
                 
                 // 设置脚本为可执行
-                let status = Command::new("chmod")
-                    .args(&["+x", &qemu_script])
-                    .status()?;
-                    
-                if !status.success() {
+                if !self.run_command("chmod", &["+x", &qemu_script], None)? {
                     return Err(DeploymentError::PackagingError("设置QEMU启动脚本为可执行失败".to_string()));
                 }
                 
@@ -383,7 +672,7 @@ impl DeploymentManager {
                 let mut file = File::create(&hyperv_script)?;
                 
                 writeln!(file, "# Hyper-V创建脚本")?;
-                writeln!(file, "New-VM -Name \"Rust AGI OS GUI\" -MemoryStartupBytes 4GB -Generation 2")?;
+                writeln!(file, "New-VM -Name \"Rust AGI OS GUI\" -MemoryStartupBytes {} -Generation 2", self.config.vm_memory.as_bytes())?;
                 writeln!(file, "Set-VMProcessor -VMName \"Rust AGI OS GUI\" -Count 4")?;
                 
                 if let Some(disk_path) = &self.config.vm_disk_path {
@@ -404,239 +693,191 @@ impl DeploymentManager {
     }
     
     /// 部署到虚拟机
-    pub fn deploy_to_vm(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+    ///
+    /// 只有实际完成部署时才返回`Deployed`；缺少虚拟机工具或平台不支持时返回
+    /// `Unsupported`，而不是假装部署成功。
+    pub fn deploy_to_vm(&mut self, package_dir: &str) -> Result<DeploymentStatus, DeploymentError> {
         self.log(&format!("开始部署到{}虚拟机...", self.config.vm_type.as_str()));
         
         // 检查虚拟机磁盘路径
         if self.config.vm_disk_path.is_none() {
             self.log("警告: 未指定虚拟机磁盘路径，跳过部署到虚拟机");
-            return Ok(());
+            return Ok(DeploymentStatus::Skipped("未指定虚拟机磁盘路径".to_string()));
         }
         
-        match self.config.vm_type {
-            VirtualMachineType::VMware => {
-                self.deploy_to_vmware(package_dir)?;
-            },
-            VirtualMachineType::VirtualBox => {
-                self.deploy_to_virtualbox(package_dir)?;
-            },
-            VirtualMachineType::QEMU => {
-                self.deploy_to_qemu(package_dir)?;
-            },
-            VirtualMachineType::HyperV => {
-                self.deploy_to_hyperv(package_dir)?;
-            },
-        }
+        let status = match self.config.vm_type {
+            VirtualMachineType::VMware => self.deploy_to_vmware(package_dir)?,
+            VirtualMachineType::VirtualBox => self.deploy_to_virtualbox(package_dir)?,
+            VirtualMachineType::QEMU => self.deploy_to_qemu(package_dir)?,
+            VirtualMachineType::HyperV => self.deploy_to_hyperv(package_dir)?,
+        };
         
-        self.log("部署到虚拟机完成");
+        self.log(&format!("部署到虚拟机结束: {}", status));
         
-        Ok(())
+        Ok(status)
+    }
+    
+    /// 记录不支持的部署
+    fn unsupported(&mut self, reason: &str) -> Result<DeploymentStatus, DeploymentError> {
+        self.log(&format!("警告: {}", reason));
+        Ok(DeploymentStatus::Unsupported(reason.to_string()))
     }
     
     /// 部署到VMware
-    fn deploy_to_vmware(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+    fn deploy_to_vmware(&mut self, package_dir: &str) -> Result<DeploymentStatus, DeploymentError> {
         self.log("部署到VMware虚拟机...");
         
         // 检查VMware工具是否可用
-        let vmrun_status = Command::new("which")
-            .arg("vmrun")
-            .status();
-            
-        if vmrun_status.is_err() || !vmrun_status.unwrap().success() {
-            self.log("警告: VMware工具(vmrun)不可用，无法自动部署");
-            return Ok(());
+        if !self.tool_available("vmrun") {
+            return self.unsupported("VMware工具(vmrun)不可用，无法自动部署");
         }
         
         let vm_config_path = format!("{}/vm/rust_agi_os.vmx", self.config.output_dir);
         
         // 启动虚拟机
         self.log("启动虚拟机...");
-        let status = Command::new("vmrun")
-            .args(&["start", &vm_config_path])
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("vmrun", &["start", &vm_config_path], None)? {
             return Err(DeploymentError::VirtualMachineError("启动VMware虚拟机失败".to_string()));
         }
         
         // 等待虚拟机启动
-        std::thread::sleep(std::time::Duration::from_secs(30));
+        self.wait_for_vm_boot();
         
         // 复制文件到虚拟机
         self.log("复制文件到虚拟机...");
-        let status = Command::new("vmrun")
-            .args(&[
-                "copyFileFromHostToGuest",
-                &vm_config_path,
-                package_dir,
-                "/opt/rust_agi_os",
-            ])
-            .status()?;
+        let copied = self.run_command("vmrun", &[
+            "copyFileFromHostToGuest",
+            &vm_config_path,
+            package_dir,
+            "/opt/rust_agi_os",
+        ], None)?;
             
-        if !status.success() {
+        if !copied {
             return Err(DeploymentError::VirtualMachineError("复制文件到VMware虚拟机失败".to_string()));
         }
         
         // 在虚拟机中运行启动脚本
         self.log("在虚拟机中运行启动脚本...");
-        let status = Command::new("vmrun")
-            .args(&[
-                "runProgramInGuest",
-                &vm_config_path,
-                "/bin/bash",
-                "/opt/rust_agi_os/start.sh",
-            ])
-            .status()?;
+        let started = self.run_command("vmrun", &[
+            "runProgramInGuest",
+            &vm_config_path,
+            "/bin/bash",
+            "/opt/rust_agi_os/start.sh",
+        ], None)?;
             
-        if !status.success() {
+        if !started {
             return Err(DeploymentError::VirtualMachineError("在VMware虚拟机中运行启动脚本失败".to_string()));
         }
         
         self.log("VMware虚拟机部署完成");
         
-        Ok(())
+        Ok(DeploymentStatus::Deployed)
     }
     
     /// 部署到VirtualBox
-    fn deploy_to_virtualbox(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+    fn deploy_to_virtualbox(&mut self, package_dir: &str) -> Result<DeploymentStatus, DeploymentError> {
         self.log("部署到VirtualBox虚拟机...");
         
         // 检查VirtualBox工具是否可用
-        let vboxmanage_status = Command::new("which")
-            .arg("VBoxManage")
-            .status();
-            
-        if vboxmanage_status.is_err() || !vboxmanage_status.unwrap().success() {
-            self.log("警告: VirtualBox工具(VBoxManage)不可用，无法自动部署");
-            return Ok(());
+        if !self.tool_available("VBoxManage") {
+            return self.unsupported("VirtualBox工具(VBoxManage)不可用，无法自动部署");
         }
         
         // 导入虚拟机
         self.log("导入虚拟机...");
         let vbox_path = format!("{}/vm/rust_agi_os.vbox", self.config.output_dir);
-        let status = Command::new("VBoxManage")
-            .args(&["import", &vbox_path])
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("VBoxManage", &["import", &vbox_path], None)? {
             return Err(DeploymentError::VirtualMachineError("导入VirtualBox虚拟机失败".to_string()));
         }
         
         // 启动虚拟机
         self.log("启动虚拟机...");
-        let status = Command::new("VBoxManage")
-            .args(&["startvm", "Rust AGI OS GUI"])
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("VBoxManage", &["startvm", "Rust AGI OS GUI"], None)? {
             return Err(DeploymentError::VirtualMachineError("启动VirtualBox虚拟机失败".to_string()));
         }
         
         // 等待虚拟机启动
-        std::thread::sleep(std::time::Duration::from_secs(30));
+        self.wait_for_vm_boot();
         
         // 复制文件到虚拟机
         self.log("复制文件到虚拟机...");
-        let status = Command::new("VBoxManage")
-            .args(&[
-                "guestcontrol",
-                "Rust AGI OS GUI",
-                "copyto",
-                "--target-directory", "/opt/rust_agi_os",
-                package_dir,
-            ])
-            .status()?;
+        let copied = self.run_command("VBoxManage", &[
+            "guestcontrol",
+            "Rust AGI OS GUI",
+            "copyto",
+            "--target-directory", "/opt/rust_agi_os",
+            package_dir,
+        ], None)?;
             
-        if !status.success() {
+        if !copied {
             return Err(DeploymentError::VirtualMachineError("复制文件到VirtualBox虚拟机失败".to_string()));
         }
         
         // 在虚拟机中运行启动脚本
         self.log("在虚拟机中运行启动脚本...");
-        let status = Command::new("VBoxManage")
-            .args(&[
-                "guestcontrol",
-                "Rust AGI OS GUI",
-                "run",
-                "--exe", "/bin/bash",
-                "--",
-                "/opt/rust_agi_os/start.sh",
-            ])
-            .status()?;
+        let started = self.run_command("VBoxManage", &[
+            "guestcontrol",
+            "Rust AGI OS GUI",
+            "run",
+            "--exe", "/bin/bash",
+            "--",
+            "/opt/rust_agi_os/start.sh",
+        ], None)?;
             
-        if !status.success() {
+        if !started {
             return Err(DeploymentError::VirtualMachineError("在VirtualBox虚拟机中运行启动脚本失败".to_string()));
         }
         
         self.log("VirtualBox虚拟机部署完成");
         
-        Ok(())
+        Ok(DeploymentStatus::Deployed)
     }
     
     /// 部署到QEMU
-    fn deploy_to_qemu(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+    fn deploy_to_qemu(&mut self, package_dir: &str) -> Result<DeploymentStatus, DeploymentError> {
         self.log("部署到QEMU虚拟机...");
         
         // 检查QEMU工具是否可用
-        let qemu_status = Command::new("which")
-            .arg("qemu-system-x86_64")
-            .status();
-            
-        if qemu_status.is_err() || !qemu_status.unwrap().success() {
-            self.log("警告: QEMU工具(qemu-system-x86_64)不可用，无法自动部署");
-            return Ok(());
+        if !self.tool_available("qemu-system-x86_64") {
+            return self.unsupported("QEMU工具(qemu-system-x86_64)不可用，无法自动部署");
         }
         
         // 运行QEMU启动脚本
         self.log("运行QEMU启动脚本...");
         let qemu_script = format!("{}/vm/start_qemu.sh", self.config.output_dir);
-        let status = Command::new("bash")
-            .arg(&qemu_script)
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("bash", &[&qemu_script], None)? {
             return Err(DeploymentError::VirtualMachineError("运行QEMU启动脚本失败".to_string()));
         }
         
         self.log("QEMU虚拟机部署完成");
         
-        Ok(())
+        Ok(DeploymentStatus::Deployed)
     }
     
     /// 部署到Hyper-V
-    fn deploy_to_hyperv(&mut self, package_dir: &str) -> Result<(), DeploymentError> {
+    fn deploy_to_hyperv(&mut self, package_dir: &str) -> Result<DeploymentStatus, DeploymentError> {
         self.log("部署到Hyper-V虚拟机...");
         
         // 检查是否在Windows环境
-        if !cfg!(target_os = "windows") {
-            self.log("警告: 不是Windows环境，无法部署到Hyper-V");
-            return Ok(());
+        if !cfg!(target_os = "windows") && !self.config.dry_run {
+            return self.unsupported("不是Windows环境，无法部署到Hyper-V");
         }
         
         // 检查PowerShell是否可用
-        let powershell_status = Command::new("which")
-            .arg("powershell")
-            .status();
-            
-        if powershell_status.is_err() || !powershell_status.unwrap().success() {
-            self.log("警告: PowerShell不可用，无法自动部署到Hyper-V");
-            return Ok(());
+        if !self.tool_available("powershell") {
+            return self.unsupported("PowerShell不可用，无法自动部署到Hyper-V");
         }
         
         // 运行Hyper-V创建脚本
         self.log("运行Hyper-V创建脚本...");
         let hyperv_script = format!("{}/vm/create_hyperv.ps1", self.config.output_dir);
-        let status = Command::new("powershell")
-            .args(&["-ExecutionPolicy", "Bypass", "-File", &hyperv_script])
-            .status()?;
-            
-        if !status.success() {
+        if !self.run_command("powershell", &["-ExecutionPolicy", "Bypass", "-File", &hyperv_script], None)? {
             return Err(DeploymentError::VirtualMachineError("运行Hyper-V创建脚本失败".to_string()));
         }
         
         self.log("Hyper-V虚拟机部署完成");
         
-        Ok(())
+        Ok(DeploymentStatus::Deployed)
     }
     
     /// 生成部署文档
@@ -652,7 +893,7 @@ impl DeploymentManager {
         writeln!(file)?;
         writeln!(file, "- 操作系统: Linux (推荐 Ubuntu 20.04 或更高版本)")?;
         writeln!(file, "- CPU: 4核心或更多")?;
-        writeln!(file, "- 内存: 4GB或更多")?;
+        writeln!(file, "- 内存: {}或更多", self.config.vm_memory)?;
         writeln!(file, "- 显卡: 支持OpenGL 3.3或更高版本")?;
         
         if self.config.enable_4k_resolution {
@@ -807,7 +1048,8 @@ pub fn package_and_deploy(
     let package_dir = manager.package_application(source_dir)?;
     
     // 部署到虚拟机
-    manager.deploy_to_vm(&package_dir)?;
+    let status = manager.deploy_to_vm(&package_dir)?;
+    println!("部署状态: {}", status);
     
     // 生成部署文档
     manager.generate_deployment_documentation()?;
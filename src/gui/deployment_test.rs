@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::deployment::{sha256_file, sha256_hex, BuildPlan, DeploymentConfig, DeploymentError, DeploymentManager, DeploymentStatus, VirtualMachineType};
+
+    #[test]
+    fn test_deploy_without_disk_path_is_skipped() {
+        let config = DeploymentConfig::new("/tmp/rust_agi_os_deploy_test", VirtualMachineType::QEMU);
+        let mut manager = DeploymentManager::new(config);
+
+        let status = manager.deploy_to_vm("/tmp/rust_agi_os_deploy_test/package").unwrap();
+        assert!(matches!(status, DeploymentStatus::Skipped(_)));
+        assert!(!status.is_deployed());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_hyperv_deploy_off_windows_is_unsupported() {
+        let mut config = DeploymentConfig::new("/tmp/rust_agi_os_deploy_test", VirtualMachineType::HyperV);
+        config.set_vm_disk_path("/tmp/rust_agi_os_deploy_test/disk.vhdx");
+        let mut manager = DeploymentManager::new(config);
+
+        let status = manager.deploy_to_vm("/tmp/rust_agi_os_deploy_test/package").unwrap();
+        assert!(matches!(status, DeploymentStatus::Unsupported(_)));
+        assert!(!status.is_deployed());
+    }
+
+    #[test]
+    fn test_vm_type_parses_aliases() {
+        let cases = [
+            ("vmware", VirtualMachineType::VMware),
+            ("VMware", VirtualMachineType::VMware),
+            ("virtualbox", VirtualMachineType::VirtualBox),
+            ("VBox", VirtualMachineType::VirtualBox),
+            ("qemu", VirtualMachineType::QEMU),
+            ("KVM", VirtualMachineType::QEMU),
+            ("hyperv", VirtualMachineType::HyperV),
+            ("Hyper-V", VirtualMachineType::HyperV),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(VirtualMachineType::parse_from_str(input).unwrap(), expected, "{}", input);
+            assert_eq!(input.parse::<VirtualMachineType>().unwrap(), expected, "{}", input);
+        }
+
+        // as_str round-trips
+        for vm_type in [VirtualMachineType::VMware, VirtualMachineType::VirtualBox, VirtualMachineType::QEMU, VirtualMachineType::HyperV] {
+            assert_eq!(vm_type.as_str().parse::<VirtualMachineType>().unwrap(), vm_type);
+        }
+    }
+
+    #[test]
+    fn test_vm_type_rejects_unknown() {
+        assert!(matches!(VirtualMachineType::parse_from_str("xen"), Err(DeploymentError::Other(_))));
+        assert!("".parse::<VirtualMachineType>().is_err());
+    }
+
+    #[test]
+    fn test_build_plan_uses_target_triple() {
+        let mut config = DeploymentConfig::new("/tmp/rust_agi_os_deploy_test", VirtualMachineType::QEMU);
+        config.set_target_triple("aarch64-unknown-linux-gnu");
+        let manager = DeploymentManager::new(config);
+
+        assert_eq!(manager.build_plan("/src/rust_agi_os"), BuildPlan {
+            cargo_args: vec![
+                "build".to_string(),
+                "--release".to_string(),
+                "--target".to_string(),
+                "aarch64-unknown-linux-gnu".to_string(),
+            ],
+            source_executable: "/src/rust_agi_os/target/aarch64-unknown-linux-gnu/release/rust_agi_os".to_string(),
+            target_executable: "/tmp/rust_agi_os_deploy_test/bin/rust_agi_os".to_string(),
+            executable_name: "rust_agi_os".to_string(),
+        });
+
+        let native = DeploymentManager::new(DeploymentConfig::new("/tmp/out", VirtualMachineType::QEMU));
+        let plan = native.build_plan("/src/rust_agi_os");
+        assert_eq!(plan.cargo_args, vec!["build".to_string(), "--release".to_string()]);
+        assert_eq!(plan.source_executable, "/src/rust_agi_os/target/release/rust_agi_os");
+    }
+
+    fn dry_run_deploy(vm_type: VirtualMachineType) -> (DeploymentStatus, Vec<String>) {
+        let mut config = DeploymentConfig::new("/tmp/agi_dry", vm_type);
+        config.set_vm_disk_path("/tmp/agi_dry/disk.img");
+        config.set_dry_run(true);
+        let mut manager = DeploymentManager::new(config);
+
+        let status = manager.deploy_to_vm("/tmp/agi_dry/package").unwrap();
+        (status, manager.get_planned_commands().to_vec())
+    }
+
+    #[test]
+    fn test_dry_run_plans_vmware_commands() {
+        let (status, commands) = dry_run_deploy(VirtualMachineType::VMware);
+        assert_eq!(status, DeploymentStatus::Deployed);
+        assert_eq!(commands, vec![
+            "vmrun start /tmp/agi_dry/vm/rust_agi_os.vmx",
+            "vmrun copyFileFromHostToGuest /tmp/agi_dry/vm/rust_agi_os.vmx /tmp/agi_dry/package /opt/rust_agi_os",
+            "vmrun runProgramInGuest /tmp/agi_dry/vm/rust_agi_os.vmx /bin/bash /opt/rust_agi_os/start.sh",
+        ]);
+    }
+
+    #[test]
+    fn test_dry_run_plans_virtualbox_commands() {
+        let (status, commands) = dry_run_deploy(VirtualMachineType::VirtualBox);
+        assert_eq!(status, DeploymentStatus::Deployed);
+        assert_eq!(commands, vec![
+            "VBoxManage import /tmp/agi_dry/vm/rust_agi_os.vbox",
+            "VBoxManage startvm \"Rust AGI OS GUI\"",
+            "VBoxManage guestcontrol \"Rust AGI OS GUI\" copyto --target-directory /opt/rust_agi_os /tmp/agi_dry/package",
+            "VBoxManage guestcontrol \"Rust AGI OS GUI\" run --exe /bin/bash -- /opt/rust_agi_os/start.sh",
+        ]);
+    }
+
+    #[test]
+    fn test_dry_run_plans_qemu_commands() {
+        let (status, commands) = dry_run_deploy(VirtualMachineType::QEMU);
+        assert_eq!(status, DeploymentStatus::Deployed);
+        assert_eq!(commands, vec!["bash /tmp/agi_dry/vm/start_qemu.sh"]);
+    }
+
+    #[test]
+    fn test_dry_run_plans_hyperv_commands() {
+        // Planned regardless of the host platform
+        let (status, commands) = dry_run_deploy(VirtualMachineType::HyperV);
+        assert_eq!(status, DeploymentStatus::Deployed);
+        assert_eq!(commands, vec!["powershell -ExecutionPolicy Bypass -File /tmp/agi_dry/vm/create_hyperv.ps1"]);
+    }
+
+    #[test]
+    fn test_sha256_known_vectors() {
+        assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(
+            sha256_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_verify_package_flags_corrupted_file() {
+        let dir = std::env::temp_dir().join(format!("agi_package_{}", uuid::Uuid::new_v4()));
+        for sub in ["bin", "config", "resources/icons"] {
+            std::fs::create_dir_all(dir.join(sub)).unwrap();
+        }
+        std::fs::write(dir.join("bin/rust_agi_os"), b"\x7fELF fake binary").unwrap();
+        std::fs::write(dir.join("start.sh"), "#!/bin/bash\nbin/rust_agi_os\n").unwrap();
+        std::fs::write(dir.join("config/system.json"), r#"{"debug": false}"#).unwrap();
+        std::fs::write(dir.join("resources/icons/app.png"), b"png").unwrap();
+
+        let package_dir = dir.to_str().unwrap();
+        let mut manager = DeploymentManager::new(DeploymentConfig::new(package_dir, VirtualMachineType::QEMU));
+        manager.write_manifest(package_dir).unwrap();
+        let manifest = std::fs::read_to_string(dir.join("manifest.sha256")).unwrap();
+        assert_eq!(manifest.lines().count(), 4);
+        assert!(manager.verify_package(package_dir).is_ok());
+
+        std::fs::write(dir.join("config/system.json"), r#"{"debug": true}"#).unwrap();
+        let result = manager.verify_package(package_dir);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        match result {
+            Err(DeploymentError::PackagingError(msg)) => {
+                assert!(msg.ends_with("config/system.json (哈希不一致)"), "{}", msg);
+                assert!(!msg.contains("start.sh") && !msg.contains("rust_agi_os (") && !msg.contains("app.png"), "{}", msg);
+            },
+            other => panic!("expected packaging error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_verify_package_rejects_paths_outside_package() {
+        let dir = std::env::temp_dir().join(format!("agi_package_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(dir.join("package")).unwrap();
+        std::fs::write(dir.join("secret.txt"), b"outside").unwrap();
+        std::fs::write(dir.join("package/start.sh"), b"#!/bin/bash\n").unwrap();
+
+        let outside = sha256_hex(b"outside");
+        let manifest = format!(
+            "{}  start.sh\n{}  ../secret.txt\n{}  {}\n",
+            sha256_hex(b"#!/bin/bash\n"),
+            outside,
+            outside,
+            dir.join("secret.txt").display()
+        );
+        std::fs::write(dir.join("package/manifest.sha256"), manifest).unwrap();
+        assert_eq!(sha256_file(&dir.join("secret.txt")).unwrap(), outside);
+
+        let package_dir = dir.join("package");
+        let package_dir = package_dir.to_str().unwrap();
+        let mut manager = DeploymentManager::new(DeploymentConfig::new(package_dir, VirtualMachineType::QEMU));
+        let result = manager.verify_package(package_dir);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        match result {
+            Err(DeploymentError::PackagingError(msg)) => {
+                assert!(msg.contains("../secret.txt (路径不在包目录内)"), "{}", msg);
+                assert_eq!(msg.matches("路径不在包目录内").count(), 2, "{}", msg);
+                assert!(!msg.contains("start.sh"), "{}", msg);
+            },
+            other => panic!("expected packaging error, got {:?}", other),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::Path;
+use std::collections::BTreeMap;
 
 /// 用户反馈收集错误类型
 #[derive(Debug)]
@@ -16,6 +17,8 @@ pub enum FeedbackError {
     AnalysisError(String),
     /// IO错误
     IoError(io::Error),
+    /// 校验错误
+    ValidationError(Vec<FieldError>),
     /// 其他错误
     Other(String),
 }
@@ -29,6 +32,10 @@ impl fmt::Display for FeedbackError {
             FeedbackError::CollectionError(msg) => write!(f, "用户反馈收集错误: {}", msg),
             FeedbackError::AnalysisError(msg) => write!(f, "用户反馈分析错误: {}", msg),
             FeedbackError::IoError(err) => write!(f, "IO错误: {}", err),
+            FeedbackError::ValidationError(errors) => {
+                let errors = errors.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                write!(f, "用户反馈校验错误: {}", errors.join("; "))
+            },
             FeedbackError::Other(msg) => write!(f, "其他用户反馈错误: {}", msg),
         }
     }
@@ -40,6 +47,33 @@ impl From<io::Error> for FeedbackError {
     }
 }
 
+/// 字段校验错误
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// 字段名
+    pub field: String,
+    /// 错误信息
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "字段 {}: {}", self.field, self.message)
+    }
+}
+
+/// 反馈时间戳格式
+pub const FEEDBACK_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
 /// 用户反馈项
 #[derive(Debug, Clone)]
 pub struct FeedbackItem {
@@ -55,6 +89,8 @@ pub struct FeedbackItem {
     pub module: String,
     /// 时间戳
     pub timestamp: String,
+    /// 解析后的时间戳，无法解析时为空
+    pub recorded_at: Option<chrono::NaiveDateTime>,
 }
 
 /// 反馈类型
@@ -92,6 +128,18 @@ impl FeedbackType {
             _ => FeedbackType::Other,
         }
     }
+    
+    /// 严格解析，未知值返回 None
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "错误报告" | "bug" | "bug report" => Some(FeedbackType::BugReport),
+            "功能建议" | "feature" | "feature request" => Some(FeedbackType::FeatureRequest),
+            "性能问题" | "performance" | "performance issue" => Some(FeedbackType::PerformanceIssue),
+            "用户体验" | "ux" | "user experience" => Some(FeedbackType::UserExperience),
+            "其他" | "other" => Some(FeedbackType::Other),
+            _ => None,
+        }
+    }
 }
 
 /// 反馈严重程度
@@ -129,6 +177,258 @@ impl FeedbackSeverity {
             _ => FeedbackSeverity::Suggestion,
         }
     }
+    
+    /// 严格解析，未知值返回 None
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "关键" | "critical" => Some(FeedbackSeverity::Critical),
+            "高" | "high" => Some(FeedbackSeverity::High),
+            "中" | "medium" => Some(FeedbackSeverity::Medium),
+            "低" | "low" => Some(FeedbackSeverity::Low),
+            "建议" | "suggestion" => Some(FeedbackSeverity::Suggestion),
+            _ => None,
+        }
+    }
+}
+
+/// 反馈文件头部的必填字段
+pub const FEEDBACK_HEADER_KEYS: [&str; 3] = ["type", "severity", "module"];
+
+/// 反馈文件头部的可选时间戳字段，格式为 `FEEDBACK_TIMESTAMP_FORMAT`
+pub const FEEDBACK_TIMESTAMP_KEY: &str = "timestamp";
+
+/// 反馈文件格式校验规则
+///
+/// 反馈文件以 `key: value` 头部开始，包含 `type`、`severity` 和 `module`，
+/// 可选包含 `timestamp`，头部之后（可用空行分隔）为内容（可多行）。没有头部字段时按旧的按行格式解析：
+/// 依次为类型、严重程度、模块、内容（可多行）。
+#[derive(Debug, Clone)]
+pub struct FeedbackSchema {
+    /// 内容最小长度（字符）
+    pub min_content_length: usize,
+    /// 内容最大长度（字符）
+    pub max_content_length: usize,
+    /// 模块名最大长度（字符）
+    pub max_module_length: usize,
+}
+
+impl Default for FeedbackSchema {
+    fn default() -> Self {
+        Self {
+            min_content_length: 1,
+            max_content_length: 4096,
+            max_module_length: 64,
+        }
+    }
+}
+
+impl FeedbackSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// 校验并解析反馈文件内容，返回所有字段错误
+    ///
+    /// 头部格式缺少必填字段时返回 `CollectionError`。头部未给出 `timestamp` 时使用 `timestamp`。
+    pub fn parse(&self, id: &str, content: &str, timestamp: &str) -> Result<FeedbackItem, FeedbackError> {
+        match Self::parse_header(content) {
+            Some((header, body)) => {
+                let missing = FEEDBACK_HEADER_KEYS
+                    .iter()
+                    .filter(|key| !header.iter().any(|(k, _)| k == *key))
+                    .copied()
+                    .collect::<Vec<_>>();
+                if !missing.is_empty() {
+                    return Err(FeedbackError::CollectionError(format!(
+                        "缺少必填字段: {}", missing.join(", ")
+                    )));
+                }
+                
+                let field = |key: &str| {
+                    header.iter().find(|(k, _)| k == key).map(|(_, v)| *v)
+                };
+                let timestamp = field(FEEDBACK_TIMESTAMP_KEY).unwrap_or(timestamp);
+                self.validate(
+                    id,
+                    field("type").unwrap_or(""),
+                    field("severity").unwrap_or(""),
+                    field("module").unwrap_or(""),
+                    body,
+                    timestamp,
+                )
+            },
+            None => {
+                let lines = content.lines().collect::<Vec<_>>();
+                let field = |index: usize| lines.get(index).map(|line| line.trim()).unwrap_or("");
+                let body = if lines.len() > 3 { lines[3..].join("\n") } else { String::new() };
+                self.validate(id, field(0), field(1), field(2), body, timestamp)
+            },
+        }
+    }
+    
+    /// 解析 `key: value` 头部，返回头部字段和内容；没有头部字段时返回 `None`
+    fn parse_header(content: &str) -> Option<(Vec<(String, &str)>, String)> {
+        let mut header = Vec::new();
+        let mut lines = content.lines().peekable();
+        
+        while let Some(line) = lines.peek() {
+            let entry = line.split_once(':').and_then(|(key, value)| {
+                let key = key.trim().to_lowercase();
+                let known = FEEDBACK_HEADER_KEYS.contains(&key.as_str()) || key == FEEDBACK_TIMESTAMP_KEY;
+                known.then(|| (key, value.trim()))
+            });
+            match entry {
+                Some(entry) => {
+                    header.push(entry);
+                    lines.next();
+                },
+                None => break,
+            }
+        }
+        
+        if header.is_empty() {
+            return None;
+        }
+        
+        // 跳过头部与内容之间的空行
+        while lines.peek().is_some_and(|line| line.trim().is_empty()) {
+            lines.next();
+        }
+        
+        Some((header, lines.collect::<Vec<_>>().join("\n")))
+    }
+    
+    /// 校验各字段，返回所有字段错误
+    fn validate(
+        &self,
+        id: &str,
+        feedback_type: &str,
+        severity: &str,
+        module: &str,
+        body: String,
+        timestamp: &str,
+    ) -> Result<FeedbackItem, FeedbackError> {
+        let mut errors = Vec::new();
+        
+        let feedback_type = match feedback_type {
+            "" => {
+                errors.push(FieldError::new("type", "缺少必填字段"));
+                None
+            },
+            value => {
+                let parsed = FeedbackType::parse(value);
+                if parsed.is_none() {
+                    errors.push(FieldError::new("type", &format!("不支持的反馈类型: {}", value)));
+                }
+                parsed
+            },
+        };
+        
+        let severity = match severity {
+            "" => {
+                errors.push(FieldError::new("severity", "缺少必填字段"));
+                None
+            },
+            value => {
+                let parsed = FeedbackSeverity::parse(value);
+                if parsed.is_none() {
+                    errors.push(FieldError::new("severity", &format!("不支持的严重程度: {}", value)));
+                }
+                parsed
+            },
+        };
+        
+        if module.is_empty() {
+            errors.push(FieldError::new("module", "缺少必填字段"));
+        } else if module.chars().count() > self.max_module_length {
+            errors.push(FieldError::new("module", &format!(
+                "长度超过上限 {}", self.max_module_length
+            )));
+        }
+        
+        let body_length = body.trim().chars().count();
+        if body_length == 0 {
+            errors.push(FieldError::new("content", "缺少必填字段"));
+        } else if body_length < self.min_content_length {
+            errors.push(FieldError::new("content", &format!(
+                "长度不足 {}", self.min_content_length
+            )));
+        } else if body_length > self.max_content_length {
+            errors.push(FieldError::new("content", &format!(
+                "长度超过上限 {}", self.max_content_length
+            )));
+        }
+        
+        match (feedback_type, severity) {
+            (Some(feedback_type), Some(severity)) if errors.is_empty() => Ok(FeedbackItem {
+                id: id.to_string(),
+                feedback_type,
+                content: body,
+                severity,
+                module: module.to_string(),
+                timestamp: timestamp.to_string(),
+                recorded_at: chrono::NaiveDateTime::parse_from_str(timestamp.trim(), FEEDBACK_TIMESTAMP_FORMAT).ok(),
+            }),
+            _ => Err(FeedbackError::ValidationError(errors)),
+        }
+    }
+}
+
+/// 分析反馈时聚类相似反馈的默认相似度阈值
+pub const DEFAULT_CLUSTER_THRESHOLD: f32 = 0.5;
+
+/// 将反馈内容规范化为词集合
+///
+/// 忽略大小写和标点，连续的ASCII字母数字组成一个词，其他文字（如中文）每个字符为一个词。
+fn content_tokens(content: &str) -> std::collections::HashSet<String> {
+    let mut tokens = std::collections::HashSet::new();
+    let mut word = String::new();
+    
+    for c in content.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.insert(std::mem::take(&mut word));
+        }
+        if c.is_alphanumeric() {
+            tokens.insert(c.to_string());
+        }
+    }
+    if !word.is_empty() {
+        tokens.insert(word);
+    }
+    
+    tokens
+}
+
+/// 计算两个词集合的Jaccard相似度
+fn jaccard_similarity(a: &std::collections::HashSet<String>, b: &std::collections::HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// 报告中内容摘要的最大字符数
+pub const REPORT_SNIPPET_CHARS: usize = 80;
+
+/// 按字符边界截断文本，超出部分以省略号代替
+///
+/// 结果（含省略号）不超过 `max_chars` 个字符，不会截断多字节字符。
+pub fn truncate_chars(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+    
+    let mut truncated: String = s.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
 }
 
 /// 用户反馈收集器
@@ -137,8 +437,12 @@ pub struct FeedbackCollector {
     feedback_items: Vec<FeedbackItem>,
     /// 收集路径
     collection_path: String,
+    /// 反馈文件格式校验规则
+    schema: FeedbackSchema,
     /// 日志
     logs: Vec<String>,
+    /// 上次收集时跳过的反馈文件数量
+    skipped_files: usize,
 }
 
 impl FeedbackCollector {
@@ -146,10 +450,17 @@ impl FeedbackCollector {
         Self {
             feedback_items: Vec::new(),
             collection_path: collection_path.to_string(),
+            schema: FeedbackSchema::default(),
             logs: Vec::new(),
+            skipped_files: 0,
         }
     }
     
+    /// 设置反馈文件格式校验规则
+    pub fn set_schema(&mut self, schema: FeedbackSchema) {
+        self.schema = schema;
+    }
+    
     /// 记录日志
     fn log(&mut self, message: &str) {
         println!("[FEEDBACK] {}", message);
@@ -183,6 +494,38 @@ impl FeedbackCollector {
         &self.feedback_items
     }
     
+    /// 获取上次收集时因无法读取或格式不正确而跳过的反馈文件数量
+    pub fn get_skipped_count(&self) -> usize {
+        self.skipped_files
+    }
+    
+    /// 读取并解析反馈文件，错误信息包含文件名
+    ///
+    /// 头部没有 `timestamp` 时使用文件的修改时间，无法获取修改时间时使用当前时间。
+    pub fn parse_feedback_file(&self, path: &Path) -> Result<FeedbackItem, FeedbackError> {
+        let file_name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().to_string(),
+        );
+        
+        let content = fs::read_to_string(path).map_err(|e| {
+            FeedbackError::CollectionError(format!("无法读取反馈文件 {}: {}", file_name, e))
+        })?;
+        
+        let id = path.file_stem().map_or_else(|| file_name.clone(), |stem| stem.to_string_lossy().to_string());
+        let timestamp = match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => chrono::DateTime::<chrono::Local>::from(modified).format(FEEDBACK_TIMESTAMP_FORMAT).to_string(),
+            Err(_) => chrono::Local::now().format(FEEDBACK_TIMESTAMP_FORMAT).to_string(),
+        };
+        
+        self.schema.parse(&id, &content, &timestamp).map_err(|e| match e {
+            FeedbackError::CollectionError(msg) => {
+                FeedbackError::CollectionError(format!("反馈文件 {}: {}", file_name, msg))
+            },
+            e => e,
+        })
+    }
+    
     /// 获取特定类型的反馈项
     pub fn get_feedback_by_type(&self, feedback_type: FeedbackType) -> Vec<&FeedbackItem> {
         self.feedback_items
@@ -207,9 +550,71 @@ impl FeedbackCollector {
             .collect()
     }
     
+    /// 获取时间在 `[start, end)` 范围内的反馈项，时间戳无法解析的反馈项不计入
+    pub fn get_feedback_in_range(&self, start: chrono::NaiveDateTime, end: chrono::NaiveDateTime) -> Vec<&FeedbackItem> {
+        self.feedback_items
+            .iter()
+            .filter(|item| item.recorded_at.is_some_and(|at| at >= start && at < end))
+            .collect()
+    }
+    
+    /// 按日期统计反馈数量，时间戳无法解析的反馈项不计入
+    pub fn count_by_day(&self) -> BTreeMap<chrono::NaiveDate, usize> {
+        let mut counts = BTreeMap::new();
+        for at in self.feedback_items.iter().filter_map(|item| item.recorded_at) {
+            *counts.entry(at.date()).or_insert(0) += 1;
+        }
+        counts
+    }
+    
+    /// 按内容相似度聚类反馈，返回每组反馈的ID
+    ///
+    /// 两条反馈内容的Jaccard相似度超过 `threshold` 时归为一组，分组具有传递性。
+    /// 结果包含只有一条反馈的分组，按分组大小降序排列，大小相同时按首条反馈的顺序排列。
+    pub fn cluster_similar(&self, threshold: f32) -> Vec<Vec<String>> {
+        let tokens = self.feedback_items
+            .iter()
+            .map(|item| content_tokens(&item.content))
+            .collect::<Vec<_>>();
+        
+        // 并查集，每组以最早的反馈为根
+        let mut parent = (0..tokens.len()).collect::<Vec<_>>();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        
+        for i in 0..tokens.len() {
+            for j in (i + 1)..tokens.len() {
+                if jaccard_similarity(&tokens[i], &tokens[j]) > threshold {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[a.max(b)] = a.min(b);
+                }
+            }
+        }
+        
+        let mut clusters: Vec<(usize, Vec<String>)> = Vec::new();
+        for i in 0..tokens.len() {
+            let root = find(&mut parent, i);
+            let id = self.feedback_items[i].id.clone();
+            match clusters.iter_mut().find(|(r, _)| *r == root) {
+                Some((_, ids)) => ids.push(id),
+                None => clusters.push((root, vec![id])),
+            }
+        }
+        
+        // 稳定排序，大小相同的分组保持首条反馈的顺序
+        clusters.sort_by_key(|(_, ids)| std::cmp::Reverse(ids.len()));
+        clusters.into_iter().map(|(_, ids)| ids).collect()
+    }
+    
     /// 收集用户反馈
     pub fn collect_feedback(&mut self) -> Result<(), FeedbackError> {
         self.log("开始收集用户反馈...");
+        self.skipped_files = 0;
         
         // 确保收集路径存在
         if !Path::new(&self.collection_path).exists() {
@@ -235,40 +640,19 @@ impl FeedbackCollector {
         for path in feedback_files {
             self.log(&format!("解析反馈文件: {:?}", path));
             
-            let content = match fs::read_to_string(&path) {
-                Ok(content) => content,
+            match self.parse_feedback_file(&path) {
+                Ok(item) => self.add_feedback(item),
                 Err(err) => {
-                    self.log(&format!("无法读取反馈文件 {:?}: {}", path, err));
-                    continue;
-                }
-            };
-            
-            let lines = content.lines().collect::<Vec<_>>();
-            if lines.len() < 4 {
-                self.log(&format!("反馈文件 {:?} 格式不正确", path));
-                continue;
+                    self.skipped_files += 1;
+                    self.log(&format!("跳过反馈文件 {:?}: {}", path, err));
+                },
             }
-            
-            let id = path.file_stem().unwrap().to_string_lossy().to_string();
-            let feedback_type = FeedbackType::from_str(lines[0]);
-            let severity = FeedbackSeverity::from_str(lines[1]);
-            let module = lines[2].to_string();
-            let content = lines[3..].join("\n");
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-            
-            let item = FeedbackItem {
-                id,
-                feedback_type,
-                content,
-                severity,
-                module,
-                timestamp,
-            };
-            
-            self.add_feedback(item);
         }
         
         self.log(&format!("成功收集 {} 个反馈项", self.feedback_items.len()));
+        if self.skipped_files > 0 {
+            self.log(&format!("跳过 {} 个反馈文件", self.skipped_files));
+        }
         
         Ok(())
     }
@@ -321,6 +705,17 @@ impl FeedbackCollector {
             }
         }
         
+        // 找出相似反馈最多的问题，以最早的反馈作为代表
+        if let Some(cluster) = self.cluster_similar(DEFAULT_CLUSTER_THRESHOLD).into_iter().next() {
+            if cluster.len() > 1 {
+                analysis.largest_cluster_issue = self.feedback_items
+                    .iter()
+                    .find(|item| item.id == cluster[0])
+                    .map(|item| item.content.clone());
+                analysis.largest_cluster_size = cluster.len();
+            }
+        }
+        
         // 找出最需要关注的模块
         if !analysis.module_issues.is_empty() {
             let mut module_vec = analysis.module_issues.clone().into_iter().collect::<Vec<_>>();
@@ -370,11 +765,19 @@ impl FeedbackCollector {
             if let Some(issue) = &analysis.most_common_issue {
                 writeln!(file, "### 最常见问题")?;
                 writeln!(file)?;
-                writeln!(file, "- 问题: {}", issue)?;
+                writeln!(file, "- 问题: {}", truncate_chars(issue, REPORT_SNIPPET_CHARS))?;
                 writeln!(file, "- 出现次数: {}", analysis.most_common_issue_count)?;
                 writeln!(file)?;
             }
             
+            if let Some(issue) = &analysis.largest_cluster_issue {
+                writeln!(file, "### 相似反馈最多的问题")?;
+                writeln!(file)?;
+                writeln!(file, "- 问题: {}", truncate_chars(issue, REPORT_SNIPPET_CHARS))?;
+                writeln!(file, "- 相似反馈数: {}", analysis.largest_cluster_size)?;
+                writeln!(file)?;
+            }
+            
             if let Some(module) = &analysis.most_problematic_module {
                 writeln!(file, "### 最需要关注的模块")?;
                 writeln!(file)?;
@@ -513,6 +916,10 @@ pub struct FeedbackAnalysis {
     pub most_common_issue: Option<String>,
     /// 最常见问题出现次数
     pub most_common_issue_count: usize,
+    /// 相似反馈最多的问题（代表内容）
+    pub largest_cluster_issue: Option<String>,
+    /// 相似反馈最多的问题的反馈数
+    pub largest_cluster_size: usize,
     /// 最需要关注的模块
     pub most_problematic_module: Option<String>,
     /// 最需要关注模块的问题数
@@ -535,6 +942,8 @@ impl FeedbackAnalysis {
             module_issues: std::collections::HashMap::new(),
             most_common_issue: None,
             most_common_issue_count: 0,
+            largest_cluster_issue: None,
+            largest_cluster_size: 0,
             most_problematic_module: None,
             most_problematic_module_issues: 0,
         }
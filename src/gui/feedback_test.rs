@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::feedback::{
+        truncate_chars, FeedbackCollector, FeedbackError, FeedbackItem, FeedbackSchema, FeedbackSeverity,
+        FeedbackType,
+    };
+
+    #[test]
+    fn test_schema_accepts_valid_feedback() {
+        let schema = FeedbackSchema::new();
+        let item = schema
+            .parse("fb-1", "bug\nhigh\nwindow_manager\n窗口拖动时闪烁\n多显示器下更明显", "2024-01-01 00:00:00")
+            .unwrap();
+
+        assert_eq!(item.id, "fb-1");
+        assert_eq!(item.feedback_type, FeedbackType::BugReport);
+        assert_eq!(item.severity, FeedbackSeverity::High);
+        assert_eq!(item.module, "window_manager");
+        assert_eq!(item.content, "窗口拖动时闪烁\n多显示器下更明显");
+    }
+
+    #[test]
+    fn test_schema_rejects_invalid_severity() {
+        let schema = FeedbackSchema::new();
+        let result = schema.parse("fb-2", "feature\nurgent\ndesktop\n增加深色主题", "2024-01-01 00:00:00");
+
+        match result {
+            Err(FeedbackError::ValidationError(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field, "severity");
+                assert!(errors[0].message.contains("urgent"));
+            },
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_schema_reports_every_field_error() {
+        let schema = FeedbackSchema {
+            max_content_length: 8,
+            ..FeedbackSchema::default()
+        };
+        let result = schema.parse("fb-3", "complaint\n\ndesktop\n这条反馈的内容明显超过了上限", "2024-01-01 00:00:00");
+
+        match result {
+            Err(FeedbackError::ValidationError(errors)) => {
+                let fields = errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+                assert_eq!(fields, vec!["type", "severity", "content"]);
+            },
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_truncate_chars_mixed_ascii_cjk() {
+        let text = "Error: 窗口系统无法启动 (code 42)";
+        
+        let truncated = truncate_chars(text, 12);
+        assert_eq!(truncated, "Error: 窗口系统…");
+        assert_eq!(truncated.chars().count(), 12);
+        assert!(std::str::from_utf8(truncated.as_bytes()).is_ok());
+        
+        // 字节长度超过上限但字符数未超过时保持原样
+        assert_eq!(truncate_chars("反馈内容", 4), "反馈内容");
+        assert_eq!(truncate_chars("反馈内容", 3), "反馈…");
+        assert_eq!(truncate_chars("abc", 0), "");
+    }
+
+    #[test]
+    fn test_schema_accepts_header_feedback() {
+        let schema = FeedbackSchema::new();
+        let item = schema
+            .parse(
+                "fb-4",
+                "Severity: medium\ntype: performance\nmodule: renderer\n\n动画卡顿\n切换工作区时明显",
+                "2024-01-01 00:00:00",
+            )
+            .unwrap();
+
+        assert_eq!(item.feedback_type, FeedbackType::PerformanceIssue);
+        assert_eq!(item.severity, FeedbackSeverity::Medium);
+        assert_eq!(item.module, "renderer");
+        assert_eq!(item.content, "动画卡顿\n切换工作区时明显");
+
+        // 头部字段值仍需通过校验
+        let result = schema.parse("fb-5", "type: bug\nseverity: urgent\nmodule: desktop\n无法登录", "2024-01-01 00:00:00");
+        assert!(matches!(result, Err(FeedbackError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_collect_feedback_skips_malformed_files() {
+        let dir = std::env::temp_dir().join(format!("feedback_test_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("header.feedback"), "type: bug\nseverity: high\nmodule: desktop\n\n图标错位").unwrap();
+        fs::write(dir.join("positional.feedback"), "feature\nlow\ndesktop\n增加深色主题").unwrap();
+        fs::write(dir.join("malformed.feedback"), "type: bug\nmodule: desktop\n\n缺少严重程度").unwrap();
+
+        let mut collector = FeedbackCollector::new(dir.to_str().unwrap());
+        collector.collect_feedback().unwrap();
+
+        let mut ids = collector.get_feedback_items().iter().map(|item| item.id.as_str()).collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec!["header", "positional"]);
+        assert_eq!(collector.get_skipped_count(), 1);
+
+        match collector.parse_feedback_file(&dir.join("malformed.feedback")) {
+            Err(FeedbackError::CollectionError(msg)) => {
+                assert!(msg.contains("malformed.feedback"));
+                assert!(msg.contains("severity"));
+            },
+            other => panic!("expected collection error, got {:?}", other),
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_header_timestamp_overrides_fallback() {
+        let schema = FeedbackSchema::new();
+        let item = schema
+            .parse("fb-6", "type: bug\nseverity: low\nmodule: desktop\ntimestamp: 2024-03-02 08:30:00\n\n图标错位", "2024-01-01 00:00:00")
+            .unwrap();
+
+        assert_eq!(item.timestamp, "2024-03-02 08:30:00");
+        let expected = chrono::NaiveDate::from_ymd_opt(2024, 3, 2).unwrap().and_hms_opt(8, 30, 0).unwrap();
+        assert_eq!(item.recorded_at, Some(expected));
+    }
+
+    #[test]
+    fn test_feedback_file_without_timestamp_uses_modification_time() {
+        let dir = std::env::temp_dir().join(format!("feedback_test_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("old.feedback");
+        fs::write(&path, "type: bug\nseverity: low\nmodule: desktop\n\n图标错位").unwrap();
+        let modified = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+
+        let collector = FeedbackCollector::new(dir.to_str().unwrap());
+        let item = collector.parse_feedback_file(&path).unwrap();
+        let expected = chrono::DateTime::<chrono::Local>::from(modified).naive_local();
+        assert_eq!(item.recorded_at, Some(expected));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn feedback_item(id: &str, content: &str) -> FeedbackItem {
+        FeedbackItem {
+            id: id.to_string(),
+            feedback_type: FeedbackType::BugReport,
+            content: content.to_string(),
+            severity: FeedbackSeverity::Medium,
+            module: "window_manager".to_string(),
+            timestamp: "2024-01-01 00:00:00".to_string(),
+            recorded_at: None,
+        }
+    }
+
+    #[test]
+    fn test_cluster_similar_groups_reworded_duplicates() {
+        let mut collector = FeedbackCollector::new("/tmp/feedback");
+        collector.add_feedback(feedback_item("fb-1", "Add a dark theme to the settings panel"));
+        collector.add_feedback(feedback_item("fb-2", "The window flickers when dragging on multiple monitors"));
+        collector.add_feedback(feedback_item("fb-3", "Window flickers when dragging across multiple monitors!"));
+
+        let clusters = collector.cluster_similar(0.5);
+        assert_eq!(clusters, vec![
+            vec!["fb-2".to_string(), "fb-3".to_string()],
+            vec!["fb-1".to_string()],
+        ]);
+
+        let analysis = collector.analyze_feedback().unwrap();
+        assert_eq!(analysis.largest_cluster_size, 2);
+        assert_eq!(
+            analysis.largest_cluster_issue.as_deref(),
+            Some("The window flickers when dragging on multiple monitors")
+        );
+    }
+
+    #[test]
+    fn test_feedback_time_window_and_daily_counts() {
+        let schema = FeedbackSchema::new();
+        let mut collector = FeedbackCollector::new("/tmp/feedback");
+        for (id, timestamp) in [
+            ("fb-1", "2024-03-01 09:00:00"),
+            ("fb-2", "2024-03-01 18:30:00"),
+            ("fb-3", "2024-03-02 12:00:00"),
+            ("fb-4", "2024-03-04 08:15:00"),
+            ("fb-5", "昨天"),
+        ] {
+            let item = schema.parse(id, "bug\nlow\ndesktop\n图标错位", timestamp).unwrap();
+            collector.add_feedback(item);
+        }
+        assert!(collector.get_feedback_items()[4].recorded_at.is_none());
+
+        let date = |day: u32| chrono::NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+        let start = date(1).and_hms_opt(12, 0, 0).unwrap();
+        let end = date(4).and_hms_opt(0, 0, 0).unwrap();
+        let ids = collector.get_feedback_in_range(start, end)
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["fb-2", "fb-3"]);
+
+        let counts = collector.count_by_day();
+        assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![(date(1), 2), (date(2), 1), (date(4), 1)]);
+    }
+}
@@ -78,7 +78,8 @@ pub fn package_and_deploy_to_vm() -> Result<(), Box<dyn Error>> {
     let package_dir = manager.package_application(".")?;
     
     // 部署到虚拟机
-    manager.deploy_to_vm(&package_dir)?;
+    let status = manager.deploy_to_vm(&package_dir)?;
+    println!("部署状态: {}", status);
     
     // 生成部署文档
     let doc_path = manager.generate_deployment_documentation()?;
@@ -99,6 +99,288 @@ impl Theme {
     pub fn get_metadata(&self, key: &str) -> Option<&String> {
         self.metadata.get(key)
     }
+    
+    /// Get the effective theme values, unset properties fall back to the default light theme
+    pub fn spec(&self) -> ThemeSpec {
+        let mut spec = ThemeSpec::light();
+        
+        for (key, slot) in spec.colors.fields_mut() {
+            if let Some(value) = self.colors.get(key) {
+                *slot = value.clone();
+            }
+        }
+        for (key, slot) in spec.fonts.fields_mut() {
+            if let Some(value) = self.fonts.get(key) {
+                *slot = value.clone();
+            }
+        }
+        for (key, slot) in spec.metrics.fields_mut() {
+            if let Some(value) = self.sizes.get(key) {
+                *slot = *value;
+            }
+        }
+        
+        spec
+    }
+}
+
+/// Theme colors
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeColors {
+    /// Background color
+    pub background: String,
+    /// Foreground color
+    pub foreground: String,
+    /// Primary color
+    pub primary: String,
+    /// Secondary color
+    pub secondary: String,
+    /// Accent color
+    pub accent: String,
+    /// Error color
+    pub error: String,
+    /// Warning color
+    pub warning: String,
+    /// Info color
+    pub info: String,
+    /// Success color
+    pub success: String,
+}
+
+impl ThemeColors {
+    /// Get colors by theme key
+    pub fn fields(&self) -> [(&'static str, &String); 9] {
+        [
+            ("background", &self.background),
+            ("foreground", &self.foreground),
+            ("primary", &self.primary),
+            ("secondary", &self.secondary),
+            ("accent", &self.accent),
+            ("error", &self.error),
+            ("warning", &self.warning),
+            ("info", &self.info),
+            ("success", &self.success),
+        ]
+    }
+    
+    /// Get colors by theme key (mutable)
+    pub fn fields_mut(&mut self) -> [(&'static str, &mut String); 9] {
+        [
+            ("background", &mut self.background),
+            ("foreground", &mut self.foreground),
+            ("primary", &mut self.primary),
+            ("secondary", &mut self.secondary),
+            ("accent", &mut self.accent),
+            ("error", &mut self.error),
+            ("warning", &mut self.warning),
+            ("info", &mut self.info),
+            ("success", &mut self.success),
+        ]
+    }
+}
+
+/// Theme fonts
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeFonts {
+    /// Default font
+    pub default: String,
+    /// Heading font
+    pub heading: String,
+    /// Monospace font
+    pub monospace: String,
+}
+
+impl ThemeFonts {
+    /// Get fonts by theme key
+    pub fn fields(&self) -> [(&'static str, &String); 3] {
+        [
+            ("default", &self.default),
+            ("heading", &self.heading),
+            ("monospace", &self.monospace),
+        ]
+    }
+    
+    /// Get fonts by theme key (mutable)
+    pub fn fields_mut(&mut self) -> [(&'static str, &mut String); 3] {
+        [
+            ("default", &mut self.default),
+            ("heading", &mut self.heading),
+            ("monospace", &mut self.monospace),
+        ]
+    }
+}
+
+/// Theme metrics
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeMetrics {
+    /// Small font size
+    pub font_small: f32,
+    /// Medium font size
+    pub font_medium: f32,
+    /// Large font size
+    pub font_large: f32,
+    /// Small padding
+    pub padding_small: f32,
+    /// Medium padding
+    pub padding_medium: f32,
+    /// Large padding
+    pub padding_large: f32,
+    /// Border radius
+    pub border_radius: f32,
+}
+
+impl ThemeMetrics {
+    /// Get metrics by theme key
+    pub fn fields(&self) -> [(&'static str, f32); 7] {
+        [
+            ("font_small", self.font_small),
+            ("font_medium", self.font_medium),
+            ("font_large", self.font_large),
+            ("padding_small", self.padding_small),
+            ("padding_medium", self.padding_medium),
+            ("padding_large", self.padding_large),
+            ("border_radius", self.border_radius),
+        ]
+    }
+    
+    /// Get metrics by theme key (mutable)
+    pub fn fields_mut(&mut self) -> [(&'static str, &mut f32); 7] {
+        [
+            ("font_small", &mut self.font_small),
+            ("font_medium", &mut self.font_medium),
+            ("font_large", &mut self.font_large),
+            ("padding_small", &mut self.padding_small),
+            ("padding_medium", &mut self.padding_medium),
+            ("padding_large", &mut self.padding_large),
+            ("border_radius", &mut self.border_radius),
+        ]
+    }
+}
+
+/// Property that differs between two theme specs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemePropertyDiff {
+    /// Property path, e.g. `colors.background`
+    pub property: String,
+    /// Value in this theme
+    pub expected: String,
+    /// Value in the other theme
+    pub actual: String,
+}
+
+impl fmt::Display for ThemePropertyDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.property, self.expected, self.actual)
+    }
+}
+
+/// Complete structured theme definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSpec {
+    /// Colors
+    pub colors: ThemeColors,
+    /// Fonts
+    pub fonts: ThemeFonts,
+    /// Metrics
+    pub metrics: ThemeMetrics,
+}
+
+impl ThemeSpec {
+    /// Default light theme values
+    pub fn light() -> Self {
+        Self {
+            colors: ThemeColors {
+                background: "#FFFFFF".to_string(),
+                foreground: "#000000".to_string(),
+                primary: "#0078D7".to_string(),
+                secondary: "#E6E6E6".to_string(),
+                accent: "#0078D7".to_string(),
+                error: "#FF0000".to_string(),
+                warning: "#FFCC00".to_string(),
+                info: "#0078D7".to_string(),
+                success: "#00CC00".to_string(),
+            },
+            fonts: ThemeFonts {
+                default: "Helvetica".to_string(),
+                heading: "Helvetica Bold".to_string(),
+                monospace: "Courier New".to_string(),
+            },
+            metrics: ThemeMetrics {
+                font_small: 12.0,
+                font_medium: 14.0,
+                font_large: 18.0,
+                padding_small: 4.0,
+                padding_medium: 8.0,
+                padding_large: 16.0,
+                border_radius: 4.0,
+            },
+        }
+    }
+    
+    /// Default dark theme values
+    pub fn dark() -> Self {
+        let mut spec = Self::light();
+        spec.colors.background = "#1E1E1E".to_string();
+        spec.colors.foreground = "#FFFFFF".to_string();
+        spec.colors.secondary = "#2D2D2D".to_string();
+        spec
+    }
+    
+    /// Create a theme with these values
+    pub fn to_theme(&self, name: &str) -> Theme {
+        let mut theme = Theme::new(name);
+        for (key, value) in self.colors.fields() {
+            theme.set_color(key, value);
+        }
+        for (key, value) in self.fonts.fields() {
+            theme.set_font(key, value);
+        }
+        for (key, value) in self.metrics.fields() {
+            theme.set_size(key, value);
+        }
+        theme
+    }
+    
+    /// Get the properties that differ from another spec
+    pub fn diff(&self, other: &ThemeSpec) -> Vec<ThemePropertyDiff> {
+        let mut diffs = Vec::new();
+        
+        for ((key, expected), (_, actual)) in self.colors.fields().iter().zip(other.colors.fields().iter()) {
+            if expected != actual {
+                diffs.push(ThemePropertyDiff {
+                    property: format!("colors.{}", key),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+        }
+        for ((key, expected), (_, actual)) in self.fonts.fields().iter().zip(other.fonts.fields().iter()) {
+            if expected != actual {
+                diffs.push(ThemePropertyDiff {
+                    property: format!("fonts.{}", key),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+        }
+        for ((key, expected), (_, actual)) in self.metrics.fields().iter().zip(other.metrics.fields().iter()) {
+            if expected != actual {
+                diffs.push(ThemePropertyDiff {
+                    property: format!("metrics.{}", key),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+        }
+        
+        diffs
+    }
+}
+
+impl Default for ThemeSpec {
+    fn default() -> Self {
+        Self::light()
+    }
 }
 
 /// Theme manager
@@ -199,66 +481,12 @@ impl ThemeManager {
 
 /// Create default light theme
 pub fn create_default_light_theme() -> Theme {
-    let mut theme = Theme::new("Default Light");
-    
-    // Set colors
-    theme.set_color("background", "#FFFFFF");
-    theme.set_color("foreground", "#000000");
-    theme.set_color("primary", "#0078D7");
-    theme.set_color("secondary", "#E6E6E6");
-    theme.set_color("accent", "#0078D7");
-    theme.set_color("error", "#FF0000");
-    theme.set_color("warning", "#FFCC00");
-    theme.set_color("info", "#0078D7");
-    theme.set_color("success", "#00CC00");
-    
-    // Set fonts
-    theme.set_font("default", "Helvetica");
-    theme.set_font("heading", "Helvetica Bold");
-    theme.set_font("monospace", "Courier New");
-    
-    // Set sizes
-    theme.set_size("font_small", 12.0);
-    theme.set_size("font_medium", 14.0);
-    theme.set_size("font_large", 18.0);
-    theme.set_size("padding_small", 4.0);
-    theme.set_size("padding_medium", 8.0);
-    theme.set_size("padding_large", 16.0);
-    theme.set_size("border_radius", 4.0);
-    
-    theme
+    ThemeSpec::light().to_theme("Default Light")
 }
 
 /// Create default dark theme
 pub fn create_default_dark_theme() -> Theme {
-    let mut theme = Theme::new("Default Dark");
-    
-    // Set colors
-    theme.set_color("background", "#1E1E1E");
-    theme.set_color("foreground", "#FFFFFF");
-    theme.set_color("primary", "#0078D7");
-    theme.set_color("secondary", "#2D2D2D");
-    theme.set_color("accent", "#0078D7");
-    theme.set_color("error", "#FF0000");
-    theme.set_color("warning", "#FFCC00");
-    theme.set_color("info", "#0078D7");
-    theme.set_color("success", "#00CC00");
-    
-    // Set fonts
-    theme.set_font("default", "Helvetica");
-    theme.set_font("heading", "Helvetica Bold");
-    theme.set_font("monospace", "Courier New");
-    
-    // Set sizes
-    theme.set_size("font_small", 12.0);
-    theme.set_size("font_medium", 14.0);
-    theme.set_size("font_large", 18.0);
-    theme.set_size("padding_small", 4.0);
-    theme.set_size("padding_medium", 8.0);
-    theme.set_size("padding_large", 16.0);
-    theme.set_size("border_radius", 4.0);
-    
-    theme
+    ThemeSpec::dark().to_theme("Default Dark")
 }
 
 /// Initialize theme module
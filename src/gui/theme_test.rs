@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::theme::{create_default_dark_theme, create_default_light_theme, Theme, ThemeSpec};
+
+    #[test]
+    fn test_default_themes_match_specs() {
+        assert_eq!(create_default_light_theme().spec(), ThemeSpec::light());
+        assert_eq!(create_default_dark_theme().spec(), ThemeSpec::dark());
+        assert_eq!(create_default_dark_theme().get_color("background").unwrap(), "#1E1E1E");
+    }
+
+    #[test]
+    fn test_diff_custom_theme_against_default() {
+        let mut custom = create_default_light_theme();
+        custom.set_color("accent", "#FF00FF");
+        custom.set_size("border_radius", 8.0);
+        custom.set_font("monospace", "Courier New");
+
+        let diffs = ThemeSpec::default().diff(&custom.spec());
+
+        let rendered: Vec<String> = diffs.iter().map(|d| d.to_string()).collect();
+        assert_eq!(rendered, vec![
+            "colors.accent: #0078D7 -> #FF00FF".to_string(),
+            "metrics.border_radius: 4 -> 8".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn test_spec_fills_unset_properties_from_default() {
+        let mut sparse = Theme::new("Sparse");
+        sparse.set_color("background", "#101010");
+
+        let spec = sparse.spec();
+        assert_eq!(spec.colors.background, "#101010");
+        assert_eq!(spec.fonts.heading, "Helvetica Bold");
+        assert_eq!(spec.diff(&ThemeSpec::light()).len(), 1);
+    }
+}
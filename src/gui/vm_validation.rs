@@ -5,6 +5,29 @@ use std::process::Command;
 use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::Path;
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+use crate::gui::feedback::truncate_chars;
+
+/// 报告中单条日志的最大字符数
+pub const REPORT_LOG_CHARS: usize = 200;
+
+/// 客户机虚拟机名称
+pub const GUEST_VM_NAME: &str = "Rust AGI OS GUI";
+
+/// Markdown验证报告路径
+pub const MARKDOWN_REPORT_PATH: &str = "/tmp/vm_validation_report.md";
+
+/// JSON验证报告路径
+pub const JSON_REPORT_PATH: &str = "/tmp/vm_validation_report.json";
+
+/// 客户机中的性能测试日志路径
+pub const GUEST_PERFORMANCE_LOG_PATH: &str = "/tmp/performance_test.log";
+
+/// 默认的主机性能测试日志路径，从客户机取回的日志保存在这里
+pub const DEFAULT_PERFORMANCE_LOG_PATH: &str = "/tmp/vm_performance_test.log";
 
 /// 虚拟机验证错误类型
 #[derive(Debug)]
@@ -41,19 +64,142 @@ impl From<io::Error> for VMValidationError {
     }
 }
 
+/// 验证状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationStatus {
+    /// 通过
+    Passed,
+    /// 失败
+    Failed,
+    /// 当前虚拟机类型不支持该验证，未实际执行
+    NotValidated,
+}
+
+impl From<bool> for ValidationStatus {
+    fn from(passed: bool) -> Self {
+        if passed { ValidationStatus::Passed } else { ValidationStatus::Failed }
+    }
+}
+
+impl fmt::Display for ValidationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationStatus::Passed => write!(f, "通过"),
+            ValidationStatus::Failed => write!(f, "失败"),
+            ValidationStatus::NotValidated => write!(f, "未验证(不支持)"),
+        }
+    }
+}
+
 /// 虚拟机验证结果
 #[derive(Debug)]
 pub struct VMValidationResult {
     /// 测试名称
     pub test_name: String,
-    /// 是否通过
-    pub passed: bool,
+    /// 验证状态
+    pub status: ValidationStatus,
     /// 详细信息
     pub details: String,
     /// 性能指标
     pub performance_metrics: Option<Vec<(String, f64)>>,
 }
 
+impl VMValidationResult {
+    /// 是否通过
+    pub fn passed(&self) -> bool {
+        self.status == ValidationStatus::Passed
+    }
+}
+
+/// JSON报告中的结果摘要
+#[derive(Serialize)]
+struct JsonReportSummary {
+    total: usize,
+    passed: usize,
+    failed: usize,
+    not_validated: usize,
+    /// 实际执行的验证中的通过率，没有实际执行的验证时为空
+    pass_rate: Option<f64>,
+}
+
+/// JSON报告中的单项结果
+#[derive(Serialize)]
+struct JsonReportResult<'a> {
+    test_name: &'a str,
+    status: ValidationStatus,
+    passed: bool,
+    details: &'a str,
+    performance_metrics: Option<BTreeMap<&'a str, f64>>,
+}
+
+/// JSON验证报告
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    vm_type: &'a str,
+    vm_config_path: &'a str,
+    summary: JsonReportSummary,
+    results: Vec<JsonReportResult<'a>>,
+}
+
+/// 解析性能测试日志中`metric_name: value`形式的指标
+///
+/// 不符合该形式的行视为普通输出并跳过；没有任何指标时返回错误。
+pub fn parse_performance_log(content: &str) -> Result<Vec<(String, f64)>, VMValidationError> {
+    let metrics: Vec<(String, f64)> = content.lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            let name = name.trim();
+            let is_metric_name = !name.is_empty()
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+            let value = value.trim().parse::<f64>().ok().filter(|v| v.is_finite())?;
+            is_metric_name.then(|| (name.to_string(), value))
+        })
+        .collect();
+    
+    if metrics.is_empty() {
+        return Err(VMValidationError::ValidationError("性能测试日志中没有可解析的指标".to_string()));
+    }
+    
+    Ok(metrics)
+}
+
+/// 在主机上执行的命令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostCommand {
+    /// 程序名
+    pub program: String,
+    /// 参数
+    pub args: Vec<String>,
+    /// 作为标准输入传给命令的主机文件
+    pub stdin_path: Option<String>,
+}
+
+impl HostCommand {
+    fn new(program: &str, args: &[&str]) -> Self {
+        Self {
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            stdin_path: None,
+        }
+    }
+    
+    /// 执行命令，返回是否成功退出
+    pub fn status(&self) -> Result<bool, VMValidationError> {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args);
+        if let Some(path) = &self.stdin_path {
+            command.stdin(File::open(path)?);
+        }
+        Ok(command.status()?.success())
+    }
+}
+
+/// 将字符串转为PowerShell单引号字面量，内部的单引号加倍转义
+pub fn powershell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
 /// 虚拟机验证器
 pub struct VMValidator {
     /// 虚拟机类型
@@ -64,6 +210,12 @@ pub struct VMValidator {
     results: Vec<VMValidationResult>,
     /// 日志
     logs: Vec<String>,
+    /// QEMU客户机的SSH目标，如 user@192.168.122.10
+    guest_ssh_target: Option<String>,
+    /// 性能测试日志路径
+    performance_log_path: String,
+    /// 关键测试，为空时所有测试都是关键测试
+    critical_tests: HashSet<String>,
 }
 
 impl VMValidator {
@@ -73,6 +225,185 @@ impl VMValidator {
             vm_config_path: vm_config_path.to_string(),
             results: Vec::new(),
             logs: Vec::new(),
+            guest_ssh_target: None,
+            performance_log_path: DEFAULT_PERFORMANCE_LOG_PATH.to_string(),
+            critical_tests: HashSet::new(),
+        }
+    }
+    
+    /// 添加关键测试，设置关键测试后只有关键测试失败才会导致整体验证失败
+    pub fn add_critical_test(&mut self, test_name: &str) {
+        self.critical_tests.insert(test_name.to_string());
+    }
+    
+    /// 检查测试是否为关键测试
+    pub fn is_critical_test(&self, test_name: &str) -> bool {
+        self.critical_tests.is_empty() || self.critical_tests.contains(test_name)
+    }
+    
+    /// 设置从客户机取回的性能测试日志在主机上的保存路径
+    pub fn set_performance_log_path(&mut self, path: &str) {
+        self.performance_log_path = path.to_string();
+    }
+    
+    /// 读取并解析性能测试日志
+    pub fn read_performance_metrics(&self) -> Result<Vec<(String, f64)>, VMValidationError> {
+        let content = fs::read_to_string(&self.performance_log_path).map_err(|e| {
+            VMValidationError::ValidationError(format!(
+                "无法读取性能测试日志 {}: {}",
+                self.performance_log_path, e
+            ))
+        })?;
+        parse_performance_log(&content)
+    }
+    
+    /// 从客户机取回性能测试日志到`performance_log_path`
+    ///
+    /// 先删除主机上的旧日志，取回失败时不会读到上一次验证留下的文件。
+    pub fn fetch_performance_log(&self) -> Result<(), VMValidationError> {
+        self.check_guest_access()?;
+        
+        match fs::remove_file(&self.performance_log_path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {},
+        }
+        
+        let command = self.fetch_command(GUEST_PERFORMANCE_LOG_PATH, &self.performance_log_path)?;
+        if !command.status()? {
+            return Err(VMValidationError::ValidationError(format!(
+                "无法从客户机取回性能测试日志 {}", GUEST_PERFORMANCE_LOG_PATH
+            )));
+        }
+        Ok(())
+    }
+    
+    /// 构造把客户机文件复制到主机的命令
+    ///
+    /// QEMU通过`ssh cat`读取文件并把标准输出写入主机文件，Hyper-V通过
+    /// `Invoke-Command`读取文件内容，其余类型使用各自的复制命令。
+    pub fn fetch_command(&self, guest_path: &str, host_path: &str) -> Result<HostCommand, VMValidationError> {
+        let command = match self.vm_type {
+            crate::gui::deployment::VirtualMachineType::VMware => HostCommand::new("vmrun", &[
+                "copyFileFromGuestToHost", &self.vm_config_path, guest_path, host_path,
+            ]),
+            crate::gui::deployment::VirtualMachineType::VirtualBox => HostCommand::new("VBoxManage", &[
+                "guestcontrol", GUEST_VM_NAME, "copyfrom", guest_path, host_path,
+            ]),
+            crate::gui::deployment::VirtualMachineType::QEMU => HostCommand::new("scp", &[
+                "-o", "BatchMode=yes", "-o", "ConnectTimeout=10",
+                &format!("{}:{}", self.guest_ssh_target()?, guest_path), host_path,
+            ]),
+            crate::gui::deployment::VirtualMachineType::HyperV => HostCommand::new("powershell", &["-NoProfile", "-Command", &format!(
+                "Invoke-Command -VMName {} -ScriptBlock {{ param($path) Get-Content -Raw $path }} -ArgumentList {} -ErrorAction Stop | Set-Content -NoNewline -Path {}",
+                powershell_quote(GUEST_VM_NAME), powershell_quote(guest_path), powershell_quote(host_path)
+            )]),
+        };
+        
+        Ok(command)
+    }
+    
+    /// 设置QEMU客户机的SSH目标
+    pub fn set_guest_ssh_target(&mut self, target: &str) {
+        self.guest_ssh_target = Some(target.to_string());
+    }
+    
+    /// 构造在客户机中运行脚本的主机命令序列
+    ///
+    /// 脚本先复制到客户机中的同一路径再运行：VMware使用`vmrun copyFileFromHostToGuest`和
+    /// `runProgramInGuest`，VirtualBox使用`VBoxManage guestcontrol copyto`和`run`，
+    /// Hyper-V使用PowerShell `Copy-VMFile`和`Invoke-Command`；QEMU通过SSH连接到
+    /// `guest_ssh_target`，脚本经标准输入传给`bash -s`，不需要复制。
+    pub fn guest_commands(&self, script_path: &str) -> Result<Vec<HostCommand>, VMValidationError> {
+        let commands = match self.vm_type {
+            crate::gui::deployment::VirtualMachineType::VMware => vec![
+                HostCommand::new("vmrun", &[
+                    "copyFileFromHostToGuest", &self.vm_config_path, script_path, script_path,
+                ]),
+                HostCommand::new("vmrun", &[
+                    "runProgramInGuest", &self.vm_config_path, "/bin/bash", script_path,
+                ]),
+            ],
+            crate::gui::deployment::VirtualMachineType::VirtualBox => vec![
+                HostCommand::new("VBoxManage", &[
+                    "guestcontrol", GUEST_VM_NAME, "copyto", script_path, script_path,
+                ]),
+                HostCommand::new("VBoxManage", &[
+                    "guestcontrol", GUEST_VM_NAME, "run", "--exe", "/bin/bash", "--", "/bin/bash", script_path,
+                ]),
+            ],
+            crate::gui::deployment::VirtualMachineType::QEMU => {
+                let mut command = HostCommand::new("ssh", &[
+                    "-o", "BatchMode=yes", "-o", "ConnectTimeout=10", self.guest_ssh_target()?, "/bin/bash", "-s",
+                ]);
+                command.stdin_path = Some(script_path.to_string());
+                vec![command]
+            },
+            crate::gui::deployment::VirtualMachineType::HyperV => {
+                let vm_name = powershell_quote(GUEST_VM_NAME);
+                let script = powershell_quote(script_path);
+                vec![
+                    HostCommand::new("powershell", &["-NoProfile", "-Command", &format!(
+                        "Copy-VMFile -Name {} -SourcePath {} -DestinationPath {} -FileSource Host -CreateFullPath -Force",
+                        vm_name, script, script
+                    )]),
+                    HostCommand::new("powershell", &["-NoProfile", "-Command", &format!(
+                        "$code = Invoke-Command -VMName {} -ScriptBlock {{ param($script) /bin/bash $script | Out-Null; $LASTEXITCODE }} -ArgumentList {}; exit $code",
+                        vm_name, script
+                    )]),
+                ]
+            },
+        };
+        
+        Ok(commands)
+    }
+    
+    /// 获取QEMU客户机的SSH目标，未设置时返回`CompatibilityError`
+    fn guest_ssh_target(&self) -> Result<&str, VMValidationError> {
+        self.guest_ssh_target.as_deref().ok_or_else(|| {
+            VMValidationError::CompatibilityError("未设置QEMU客户机的SSH目标".to_string())
+        })
+    }
+    
+    /// 检查当前环境能否操作该类型的客户机
+    fn check_guest_access(&self) -> Result<(), VMValidationError> {
+        if self.vm_type == crate::gui::deployment::VirtualMachineType::HyperV && !cfg!(target_os = "windows") {
+            return Err(VMValidationError::CompatibilityError(
+                "不是Windows环境，无法访问Hyper-V虚拟机".to_string()
+            ));
+        }
+        Ok(())
+    }
+    
+    /// 在客户机中运行脚本，返回脚本是否成功退出
+    ///
+    /// 当前环境无法在该类型的客户机中运行脚本时返回`CompatibilityError`，
+    /// 脚本无法复制到客户机时返回`ValidationError`。
+    pub fn run_in_guest(&self, script_path: &str) -> Result<bool, VMValidationError> {
+        self.check_guest_access()?;
+        
+        let commands = self.guest_commands(script_path)?;
+        let (run, copies) = commands.split_last()
+            .ok_or_else(|| VMValidationError::Other("没有可执行的客户机命令".to_string()))?;
+        for copy in copies {
+            if !copy.status()? {
+                return Err(VMValidationError::ValidationError(format!("无法复制脚本到客户机: {}", script_path)));
+            }
+        }
+        run.status()
+    }
+    
+    /// 在客户机中运行验证脚本，无法运行时返回`None`
+    fn run_validation_script(&mut self, test_name: &str, script_path: &str) -> Option<ValidationStatus> {
+        match self.run_in_guest(script_path) {
+            Ok(success) => Some(ValidationStatus::from(success)),
+            Err(VMValidationError::CompatibilityError(reason)) => {
+                self.log(&format!("警告: {}，{}标记为未验证", reason, test_name));
+                None
+            },
+            Err(e) => {
+                self.log(&format!("警告: 无法在{}虚拟机中运行{}验证脚本: {}", self.vm_type.as_str(), test_name, e));
+                Some(ValidationStatus::Failed)
+            },
         }
     }
     
@@ -97,7 +428,7 @@ impl VMValidator {
         self.log(&format!(
             "测试 '{}': {}",
             result.test_name,
-            if result.passed { "通过" } else { "失败" }
+            result.status
         ));
         
         if !result.details.is_empty() {
@@ -113,19 +444,61 @@ impl VMValidator {
         self.results.push(result);
     }
     
+    /// 添加未验证结果，用于当前虚拟机类型不支持的验证项
+    fn add_not_validated(&mut self, test_name: &str) -> Result<(), VMValidationError> {
+        let details = format!("{}虚拟机暂不支持验证{}，未实际执行", self.vm_type.as_str(), test_name);
+        self.add_result(VMValidationResult {
+            test_name: test_name.to_string(),
+            status: ValidationStatus::NotValidated,
+            details,
+            performance_metrics: None,
+        });
+        Ok(())
+    }
+    
     /// 获取所有验证结果
     pub fn get_results(&self) -> &[VMValidationResult] {
         &self.results
     }
     
+    /// 获取指定状态的测试数量
+    pub fn get_count(&self, status: ValidationStatus) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+    
     /// 获取通过的测试数量
     pub fn get_passed_count(&self) -> usize {
-        self.results.iter().filter(|r| r.passed).count()
+        self.get_count(ValidationStatus::Passed)
     }
     
     /// 获取失败的测试数量
     pub fn get_failed_count(&self) -> usize {
-        self.results.iter().filter(|r| !r.passed).count()
+        self.get_count(ValidationStatus::Failed)
+    }
+    
+    /// 获取未验证（不支持）的测试数量
+    pub fn get_not_validated_count(&self) -> usize {
+        self.get_count(ValidationStatus::NotValidated)
+    }
+    
+    /// 获取通过率，只统计实际执行的验证；没有实际执行的验证时返回`None`
+    pub fn pass_rate(&self) -> Option<f64> {
+        let validated = self.get_passed_count() + self.get_failed_count();
+        (validated > 0).then(|| self.get_passed_count() as f64 / validated as f64)
+    }
+    
+    /// 获取整体验证结果：至少实际执行了一项关键测试，且没有关键测试失败
+    pub fn overall_passed(&self) -> bool {
+        let critical: Vec<&VMValidationResult> = self.results.iter()
+            .filter(|r| self.is_critical_test(&r.test_name))
+            .collect();
+        critical.iter().any(|r| r.status != ValidationStatus::NotValidated)
+            && critical.iter().all(|r| r.status != ValidationStatus::Failed)
+    }
+    
+    /// 获取进程退出码，整体验证通过时为0，否则为1
+    pub fn exit_code(&self) -> i32 {
+        if self.overall_passed() { 0 } else { 1 }
     }
     
     /// 验证虚拟机环境
@@ -154,7 +527,7 @@ impl VMValidator {
         
         let result = VMValidationResult {
             test_name: "虚拟机配置文件存在".to_string(),
-            passed: exists,
+            status: ValidationStatus::from(exists),
             details: if exists {
                 format!("配置文件路径: {}", self.vm_config_path)
             } else {
@@ -178,7 +551,7 @@ impl VMValidator {
     fn validate_vm_status(&mut self) -> Result<(), VMValidationError> {
         self.log("验证虚拟机状态...");
         
-        let status_ok = match self.vm_type {
+        let vm_status = match self.vm_type {
             crate::gui::deployment::VirtualMachineType::VMware => {
                 // 检查VMware工具是否可用
                 let vmrun_status = Command::new("which")
@@ -187,7 +560,7 @@ impl VMValidator {
                     
                 if vmrun_status.is_err() || !vmrun_status.unwrap().success() {
                     self.log("警告: VMware工具(vmrun)不可用，无法验证虚拟机状态");
-                    false
+                    ValidationStatus::Failed
                 } else {
                     // 检查虚拟机状态
                     let status = Command::new("vmrun")
@@ -196,10 +569,11 @@ impl VMValidator {
                         
                     if status.is_err() {
                         self.log("警告: 无法获取VMware虚拟机列表");
-                        false
+                        ValidationStatus::Failed
                     } else {
-                        let output = String::from_utf8_lossy(&status.unwrap().stdout);
-                        output.contains(&self.vm_config_path)
+                        let stdout = status.unwrap().stdout;
+                        let output = String::from_utf8_lossy(&stdout);
+                        ValidationStatus::from(output.contains(&self.vm_config_path))
                     }
                 }
             },
@@ -211,7 +585,7 @@ impl VMValidator {
                     
                 if vboxmanage_status.is_err() || !vboxmanage_status.unwrap().success() {
                     self.log("警告: VirtualBox工具(VBoxManage)不可用，无法验证虚拟机状态");
-                    false
+                    ValidationStatus::Failed
                 } else {
                     // 检查虚拟机状态
                     let status = Command::new("VBoxManage")
@@ -220,10 +594,11 @@ impl VMValidator {
                         
                     if status.is_err() {
                         self.log("警告: 无法获取VirtualBox虚拟机列表");
-                        false
+                        ValidationStatus::Failed
                     } else {
-                        let output = String::from_utf8_lossy(&status.unwrap().stdout);
-                        output.contains("Rust AGI OS GUI")
+                        let stdout = status.unwrap().stdout;
+                        let output = String::from_utf8_lossy(&stdout);
+                        ValidationStatus::from(output.contains("Rust AGI OS GUI"))
                     }
                 }
             },
@@ -235,7 +610,7 @@ impl VMValidator {
                     
                 if qemu_status.is_err() || !qemu_status.unwrap().success() {
                     self.log("警告: QEMU工具(qemu-system-x86_64)不可用，无法验证虚拟机状态");
-                    false
+                    ValidationStatus::Failed
                 } else {
                     // 检查虚拟机状态（简化版，实际上QEMU没有简单的列表命令）
                     let status = Command::new("ps")
@@ -244,27 +619,28 @@ impl VMValidator {
                         
                     if status.is_err() {
                         self.log("警告: 无法获取进程列表");
-                        false
+                        ValidationStatus::Failed
                     } else {
-                        let output = String::from_utf8_lossy(&status.unwrap().stdout);
-                        output.contains("qemu-system-x86_64") && output.contains("Rust AGI OS GUI")
+                        let stdout = status.unwrap().stdout;
+                        let output = String::from_utf8_lossy(&stdout);
+                        ValidationStatus::from(output.contains("qemu-system-x86_64") && output.contains("Rust AGI OS GUI"))
                     }
                 }
             },
             crate::gui::deployment::VirtualMachineType::HyperV => {
                 // 在Linux环境中无法验证Hyper-V状态
                 self.log("警告: 在当前环境中无法验证Hyper-V虚拟机状态");
-                false
+                ValidationStatus::NotValidated
             },
         };
         
         let result = VMValidationResult {
             test_name: "虚拟机状态".to_string(),
-            passed: status_ok,
-            details: if status_ok {
-                "虚拟机正在运行".to_string()
-            } else {
-                "无法确认虚拟机是否正在运行".to_string()
+            status: vm_status,
+            details: match vm_status {
+                ValidationStatus::Passed => "虚拟机正在运行".to_string(),
+                ValidationStatus::Failed => "无法确认虚拟机是否正在运行".to_string(),
+                ValidationStatus::NotValidated => "当前环境不支持验证Hyper-V虚拟机状态".to_string(),
             },
             performance_metrics: None,
         };
@@ -302,7 +678,7 @@ impl VMValidator {
                 
                 let result = VMValidationResult {
                     test_name: "CPU和内存配置".to_string(),
-                    passed: false,
+                    status: ValidationStatus::Failed,
                     details: "无法读取虚拟机配置文件".to_string(),
                     performance_metrics: None,
                 };
@@ -331,7 +707,7 @@ impl VMValidator {
         
         let result = VMValidationResult {
             test_name: "CPU配置".to_string(),
-            passed: cpu_ok,
+            status: ValidationStatus::from(cpu_ok),
             details: if cpu_ok {
                 "CPU配置正确".to_string()
             } else {
@@ -360,7 +736,7 @@ impl VMValidator {
         
         let result = VMValidationResult {
             test_name: "内存配置".to_string(),
-            passed: memory_ok,
+            status: ValidationStatus::from(memory_ok),
             details: if memory_ok {
                 "内存配置正确".to_string()
             } else {
@@ -386,7 +762,7 @@ impl VMValidator {
                 
                 let result = VMValidationResult {
                     test_name: "显示配置".to_string(),
-                    passed: false,
+                    status: ValidationStatus::Failed,
                     details: "无法读取虚拟机配置文件".to_string(),
                     performance_metrics: None,
                 };
@@ -423,7 +799,7 @@ impl VMValidator {
         
         let result = VMValidationResult {
             test_name: "4K分辨率配置".to_string(),
-            passed: resolution_ok,
+            status: ValidationStatus::from(resolution_ok),
             details: if resolution_ok {
                 "4K分辨率配置正确".to_string()
             } else {
@@ -450,14 +826,14 @@ impl VMValidator {
                 config_content.contains("display gtk,gl=on")
             },
             crate::gui::deployment::VirtualMachineType::HyperV => {
-                // Hyper-V没有明确的硬件加速配置
-                true
+                // Hyper-V没有明确的硬件加速配置，无法验证
+                return self.add_not_validated("硬件加速配置");
             },
         };
         
         let result = VMValidationResult {
             test_name: "硬件加速配置".to_string(),
-            passed: acceleration_ok,
+            status: ValidationStatus::from(acceleration_ok),
             details: if acceleration_ok {
                 "硬件加速配置正确".to_string()
             } else {
@@ -483,7 +859,7 @@ impl VMValidator {
                 
                 let result = VMValidationResult {
                     test_name: "输入设备配置".to_string(),
-                    passed: false,
+                    status: ValidationStatus::Failed,
                     details: "无法读取虚拟机配置文件".to_string(),
                     performance_metrics: None,
                 };
@@ -501,23 +877,15 @@ impl VMValidator {
                 config_content.contains("TRUE") && 
                 config_content.contains("usb.generic.autoconnect")
             },
-            crate::gui::deployment::VirtualMachineType::VirtualBox => {
-                // VirtualBox的USB配置较为复杂，这里简化处理
-                true
-            },
-            crate::gui::deployment::VirtualMachineType::QEMU => {
-                // QEMU的输入设备配置较为复杂，这里简化处理
-                true
-            },
-            crate::gui::deployment::VirtualMachineType::HyperV => {
-                // Hyper-V的输入设备配置较为复杂，这里简化处理
-                true
+            _ => {
+                // 其他虚拟机的输入设备配置较为复杂，尚不支持验证
+                return self.add_not_validated("输入设备直通配置");
             },
         };
         
         let result = VMValidationResult {
             test_name: "输入设备直通配置".to_string(),
-            passed: input_ok,
+            status: ValidationStatus::from(input_ok),
             details: if input_ok {
                 "输入设备直通配置正确".to_string()
             } else {
@@ -578,34 +946,15 @@ impl VMValidator {
         }
         
         // 在虚拟机中运行脚本
-        let script_ok = match self.vm_type {
-            crate::gui::deployment::VirtualMachineType::VMware => {
-                let status = Command::new("vmrun")
-                    .args(&[
-                        "runProgramInGuest",
-                        &self.vm_config_path,
-                        "/bin/bash",
-                        script_path,
-                    ])
-                    .status();
-                    
-                if status.is_err() {
-                    self.log("警告: 无法在VMware虚拟机中运行窗口系统验证脚本");
-                    false
-                } else {
-                    status.unwrap().success()
-                }
-            },
-            _ => {
-                self.log("警告: 当前虚拟机类型不支持自动验证窗口系统");
-                true // 简化处理，假设验证通过
-            },
+        let script_status = match self.run_validation_script("窗口系统", script_path) {
+            Some(status) => status,
+            None => return self.add_not_validated("窗口系统"),
         };
         
         let result = VMValidationResult {
             test_name: "窗口系统".to_string(),
-            passed: script_ok,
-            details: if script_ok {
+            status: script_status,
+            details: if script_status == ValidationStatus::Passed {
                 "窗口系统验证通过".to_string()
             } else {
                 "窗口系统验证失败".to_string()
@@ -644,34 +993,18 @@ impl VMValidator {
         }
         
         // 在虚拟机中运行脚本
-        let script_ok = match self.vm_type {
-            crate::gui::deployment::VirtualMachineType::VMware => {
-                let status = Command::new("vmrun")
-                    .args(&[
-                        "runProgramInGuest",
-                        &self.vm_config_path,
-                        "/bin/bash",
-                        script_path,
-                    ])
-                    .status();
-                    
-                if status.is_err() {
-                    self.log("警告: 无法在VMware虚拟机中运行输入系统验证脚本");
-                    false
-                } else {
-                    status.unwrap().success()
-                }
-            },
-            _ => {
-                self.log("警告: 当前虚拟机类型不支持自动验证输入系统");
-                true // 简化处理，假设验证通过
+        let script_status = match self.run_validation_script("输入系统", script_path) {
+            Some(status) => status,
+            None => {
+                self.add_not_validated("输入系统")?;
+                return self.validate_input_method();
             },
         };
         
         let result = VMValidationResult {
             test_name: "输入系统".to_string(),
-            passed: script_ok,
-            details: if script_ok {
+            status: script_status,
+            details: if script_status == ValidationStatus::Passed {
                 "输入系统验证通过".to_string()
             } else {
                 "输入系统验证失败".to_string()
@@ -713,34 +1046,15 @@ impl VMValidator {
         }
         
         // 在虚拟机中运行脚本
-        let script_ok = match self.vm_type {
-            crate::gui::deployment::VirtualMachineType::VMware => {
-                let status = Command::new("vmrun")
-                    .args(&[
-                        "runProgramInGuest",
-                        &self.vm_config_path,
-                        "/bin/bash",
-                        script_path,
-                    ])
-                    .status();
-                    
-                if status.is_err() {
-                    self.log("警告: 无法在VMware虚拟机中运行输入法验证脚本");
-                    false
-                } else {
-                    status.unwrap().success()
-                }
-            },
-            _ => {
-                self.log("警告: 当前虚拟机类型不支持自动验证输入法");
-                true // 简化处理，假设验证通过
-            },
+        let script_status = match self.run_validation_script("输入法", script_path) {
+            Some(status) => status,
+            None => return self.add_not_validated("输入法"),
         };
         
         let result = VMValidationResult {
             test_name: "输入法".to_string(),
-            passed: script_ok,
-            details: if script_ok {
+            status: script_status,
+            details: if script_status == ValidationStatus::Passed {
                 "输入法验证通过".to_string()
             } else {
                 "输入法验证失败".to_string()
@@ -779,34 +1093,15 @@ impl VMValidator {
         }
         
         // 在虚拟机中运行脚本
-        let script_ok = match self.vm_type {
-            crate::gui::deployment::VirtualMachineType::VMware => {
-                let status = Command::new("vmrun")
-                    .args(&[
-                        "runProgramInGuest",
-                        &self.vm_config_path,
-                        "/bin/bash",
-                        script_path,
-                    ])
-                    .status();
-                    
-                if status.is_err() {
-                    self.log("警告: 无法在VMware虚拟机中运行应用功能验证脚本");
-                    false
-                } else {
-                    status.unwrap().success()
-                }
-            },
-            _ => {
-                self.log("警告: 当前虚拟机类型不支持自动验证应用功能");
-                true // 简化处理，假设验证通过
-            },
+        let script_status = match self.run_validation_script("应用功能", script_path) {
+            Some(status) => status,
+            None => return self.add_not_validated("应用功能"),
         };
         
         let result = VMValidationResult {
             test_name: "应用功能".to_string(),
-            passed: script_ok,
-            details: if script_ok {
+            status: script_status,
+            details: if script_status == ValidationStatus::Passed {
                 "应用功能验证通过".to_string()
             } else {
                 "应用功能验证失败".to_string()
@@ -832,7 +1127,8 @@ impl VMValidator {
         writeln!(file, "cd /opt/rust_agi_os")?;
         writeln!(file, "export DISPLAY=:0")?;
         writeln!(file, "export RUST_AGI_OS_TEST=performance")?;
-        writeln!(file, "./bin/rust_agi_os --test performance > /tmp/performance_test.log 2>&1")?;
+        writeln!(file, "rm -f {}", GUEST_PERFORMANCE_LOG_PATH)?;
+        writeln!(file, "./bin/rust_agi_os --test performance > {} 2>&1", GUEST_PERFORMANCE_LOG_PATH)?;
         writeln!(file, "exit $?")?;
         
         // 设置脚本为可执行
@@ -845,46 +1141,26 @@ impl VMValidator {
         }
         
         // 在虚拟机中运行脚本
-        let script_ok = match self.vm_type {
-            crate::gui::deployment::VirtualMachineType::VMware => {
-                let status = Command::new("vmrun")
-                    .args(&[
-                        "runProgramInGuest",
-                        &self.vm_config_path,
-                        "/bin/bash",
-                        script_path,
-                    ])
-                    .status();
-                    
-                if status.is_err() {
-                    self.log("警告: 无法在VMware虚拟机中运行性能验证脚本");
-                    false
-                } else {
-                    status.unwrap().success()
-                }
-            },
-            _ => {
-                self.log("警告: 当前虚拟机类型不支持自动验证性能");
-                true // 简化处理，假设验证通过
-            },
+        let script_status = match self.run_validation_script("性能", script_path) {
+            Some(status) => status,
+            None => return self.add_not_validated("性能"),
         };
         
-        // 模拟性能指标
-        let performance_metrics = vec![
-            ("render_time_ms".to_string(), 5.2),
-            ("input_time_ns".to_string(), 850.0),
-            ("fps".to_string(), 60.0),
-        ];
+        // 取回客户机中的性能测试日志并解析指标，日志缺失或无法解析时验证失败
+        let metrics = self.fetch_performance_log().and_then(|_| self.read_performance_metrics());
+        let (script_status, details, performance_metrics) = match metrics {
+            Ok(metrics) if script_status == ValidationStatus::Passed => {
+                (script_status, "性能验证通过".to_string(), Some(metrics))
+            },
+            Ok(metrics) => (script_status, "性能验证失败".to_string(), Some(metrics)),
+            Err(e) => (ValidationStatus::Failed, format!("性能验证失败: {}", e), None),
+        };
         
         let result = VMValidationResult {
             test_name: "性能".to_string(),
-            passed: script_ok,
-            details: if script_ok {
-                "性能验证通过".to_string()
-            } else {
-                "性能验证失败".to_string()
-            },
-            performance_metrics: Some(performance_metrics),
+            status: script_status,
+            details,
+            performance_metrics,
         };
         
         self.add_result(result);
@@ -894,7 +1170,7 @@ impl VMValidator {
     
     /// 生成验证报告
     pub fn generate_validation_report(&self) -> Result<String, VMValidationError> {
-        let report_path = "/tmp/vm_validation_report.md";
+        let report_path = MARKDOWN_REPORT_PATH;
         let mut file = File::create(report_path)?;
         
         writeln!(file, "# 虚拟机环境GUI桌面验证报告")?;
@@ -911,7 +1187,11 @@ impl VMValidator {
         writeln!(file, "- 总测试数: {}", self.results.len())?;
         writeln!(file, "- 通过测试数: {}", self.get_passed_count())?;
         writeln!(file, "- 失败测试数: {}", self.get_failed_count())?;
-        writeln!(file, "- 通过率: {:.2}%", (self.get_passed_count() as f64 / self.results.len() as f64) * 100.0)?;
+        writeln!(file, "- 未验证测试数(不支持): {}", self.get_not_validated_count())?;
+        match self.pass_rate() {
+            Some(rate) => writeln!(file, "- 通过率: {:.2}%", rate * 100.0)?,
+            None => writeln!(file, "- 通过率: 无（没有实际执行的验证）")?,
+        }
         
         writeln!(file)?;
         writeln!(file, "## 详细测试结果")?;
@@ -919,7 +1199,12 @@ impl VMValidator {
         
         for (i, result) in self.results.iter().enumerate() {
             writeln!(file, "### {}. {}", i + 1, result.test_name)?;
-            writeln!(file, "- 状态: {}", if result.passed { "通过 ✅" } else { "失败 ❌" })?;
+            let status = match result.status {
+                ValidationStatus::Passed => "通过 ✅",
+                ValidationStatus::Failed => "失败 ❌",
+                ValidationStatus::NotValidated => "未验证(不支持) ⚠️",
+            };
+            writeln!(file, "- 状态: {}", status)?;
             writeln!(file, "- 详细信息: {}", result.details)?;
             
             if let Some(metrics) = &result.performance_metrics {
@@ -936,11 +1221,43 @@ impl VMValidator {
         writeln!(file)?;
         
         for log in &self.logs {
-            writeln!(file, "- {}", log)?;
+            writeln!(file, "- {}", truncate_chars(log, REPORT_LOG_CHARS))?;
         }
         
         Ok(report_path.to_string())
     }
+    
+    /// 生成JSON验证报告，供CI读取
+    ///
+    /// 报告写入Markdown报告旁的`vm_validation_report.json`，并返回JSON内容。
+    pub fn generate_json_report(&self) -> Result<String, VMValidationError> {
+        let report = JsonReport {
+            vm_type: self.vm_type.as_str(),
+            vm_config_path: &self.vm_config_path,
+            summary: JsonReportSummary {
+                total: self.results.len(),
+                passed: self.get_passed_count(),
+                failed: self.get_failed_count(),
+                not_validated: self.get_not_validated_count(),
+                pass_rate: self.pass_rate(),
+            },
+            results: self.results.iter().map(|result| JsonReportResult {
+                test_name: &result.test_name,
+                status: result.status,
+                passed: result.passed(),
+                details: &result.details,
+                performance_metrics: result.performance_metrics.as_ref().map(|metrics| {
+                    metrics.iter().map(|(name, value)| (name.as_str(), *value)).collect()
+                }),
+            }).collect(),
+        };
+        
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| VMValidationError::Other(format!("序列化验证报告失败: {}", e)))?;
+        fs::write(JSON_REPORT_PATH, &json)?;
+        
+        Ok(json)
+    }
 }
 
 /// 创建虚拟机验证器
@@ -0,0 +1,311 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::deployment::VirtualMachineType;
+    use crate::gui::vm_validation::{
+        parse_performance_log, powershell_quote, ValidationStatus, DEFAULT_PERFORMANCE_LOG_PATH, GUEST_PERFORMANCE_LOG_PATH, VMValidationError, VMValidationResult, VMValidator,
+    };
+
+    /// 写入临时的QEMU虚拟机配置文件
+    fn qemu_config(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}-{}.sh", name, std::process::id()));
+        std::fs::write(
+            &path,
+            "qemu-system-x86_64 -smp 4 -m 4G -device virtio-vga-gl,xres=3840,yres=2160 -display gtk,gl=on\n",
+        ).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_non_vmware_gui_validation_is_not_validated() {
+        let mut validator = VMValidator::new(VirtualMachineType::QEMU, "/nonexistent/qemu.sh");
+        validator.validate_gui_desktop().unwrap();
+
+        let results = validator.get_results();
+        assert_eq!(results.len(), 5);
+        for result in results {
+            assert_eq!(result.status, ValidationStatus::NotValidated, "{}", result.test_name);
+            assert!(!result.passed());
+            // 未执行的性能验证不应附带模拟的性能指标
+            assert!(result.performance_metrics.is_none());
+        }
+        assert_eq!(validator.get_passed_count(), 0);
+        assert_eq!(validator.get_failed_count(), 0);
+        assert_eq!(validator.get_not_validated_count(), 5);
+    }
+
+    #[test]
+    fn test_unsupported_hardware_checks_are_not_validated() {
+        let config = qemu_config("qemu-hardware");
+        let mut validator = VMValidator::new(VirtualMachineType::QEMU, &config);
+        validator.validate_vm_hardware().unwrap();
+
+        let status_of = |name: &str| {
+            validator.get_results().iter().find(|r| r.test_name == name).map(|r| r.status)
+        };
+        // 配置文件检查实际执行
+        assert_eq!(status_of("CPU配置"), Some(ValidationStatus::Passed));
+        assert_eq!(status_of("4K分辨率配置"), Some(ValidationStatus::Passed));
+        // 输入设备直通对QEMU不支持验证
+        assert_eq!(status_of("输入设备直通配置"), Some(ValidationStatus::NotValidated));
+
+        let mut hyperv = VMValidator::new(VirtualMachineType::HyperV, &config);
+        hyperv.validate_vm_status().unwrap();
+        hyperv.validate_display().unwrap();
+        let statuses: Vec<ValidationStatus> = hyperv.get_results().iter().map(|r| r.status).collect();
+        assert!(statuses.contains(&ValidationStatus::NotValidated));
+        assert!(hyperv.get_results().iter()
+            .filter(|r| r.test_name == "虚拟机状态" || r.test_name == "硬件加速配置")
+            .all(|r| r.status == ValidationStatus::NotValidated));
+
+        std::fs::remove_file(&config).unwrap();
+    }
+
+    fn command_lines(validator: &VMValidator, script_path: &str) -> Vec<Vec<String>> {
+        validator.guest_commands(script_path).unwrap().into_iter()
+            .map(|command| std::iter::once(command.program).chain(command.args).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_guest_commands_copy_script_before_running() {
+        let script = "/tmp/validate_window_system.sh";
+
+        let vmware = VMValidator::new(VirtualMachineType::VMware, "/vm/rust_agi_os.vmx");
+        assert_eq!(command_lines(&vmware, script), vec![
+            vec!["vmrun", "copyFileFromHostToGuest", "/vm/rust_agi_os.vmx", script, script],
+            vec!["vmrun", "runProgramInGuest", "/vm/rust_agi_os.vmx", "/bin/bash", script],
+        ]);
+
+        let virtualbox = VMValidator::new(VirtualMachineType::VirtualBox, "/vm/rust_agi_os.vbox");
+        assert_eq!(command_lines(&virtualbox, script), vec![
+            vec!["VBoxManage", "guestcontrol", "Rust AGI OS GUI", "copyto", script, script],
+            vec!["VBoxManage", "guestcontrol", "Rust AGI OS GUI", "run", "--exe", "/bin/bash", "--", "/bin/bash", script],
+        ]);
+
+        // QEMU经标准输入传入脚本，不依赖客户机中的文件
+        let mut qemu = VMValidator::new(VirtualMachineType::QEMU, "/vm/start_qemu.sh");
+        qemu.set_guest_ssh_target("agi@192.168.122.10");
+        let commands = qemu.guest_commands(script).unwrap();
+        assert_eq!(command_lines(&qemu, script), vec![vec![
+            "ssh", "-o", "BatchMode=yes", "-o", "ConnectTimeout=10", "agi@192.168.122.10", "/bin/bash", "-s",
+        ]]);
+        assert_eq!(commands[0].stdin_path.as_deref(), Some(script));
+
+        let hyperv = VMValidator::new(VirtualMachineType::HyperV, "/vm/create_hyperv.ps1");
+        let commands = command_lines(&hyperv, script);
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0][..3], ["powershell", "-NoProfile", "-Command"]);
+        assert!(commands[0][3].starts_with("Copy-VMFile -Name 'Rust AGI OS GUI'"));
+        assert!(commands[1][3].contains("Invoke-Command -VMName 'Rust AGI OS GUI'"));
+        assert!(commands[1][3].contains("-ArgumentList '/tmp/validate_window_system.sh'"));
+    }
+
+    #[test]
+    fn test_hyperv_script_path_is_quoted() {
+        assert_eq!(powershell_quote("/tmp/it's.sh"), "'/tmp/it''s.sh'");
+
+        let hyperv = VMValidator::new(VirtualMachineType::HyperV, "/vm/create_hyperv.ps1");
+        let commands = command_lines(&hyperv, "/tmp/x'; Remove-Item C:\\ -Recurse; '.sh");
+        for command in &commands {
+            assert!(command[3].contains("'/tmp/x''; Remove-Item C:\\ -Recurse; ''.sh'"));
+        }
+    }
+
+    #[test]
+    fn test_run_in_guest_reports_unsupported_environment() {
+        let qemu = VMValidator::new(VirtualMachineType::QEMU, "/vm/start_qemu.sh");
+        assert!(matches!(qemu.run_in_guest("/tmp/x.sh"), Err(VMValidationError::CompatibilityError(_))));
+
+        if !cfg!(target_os = "windows") {
+            let hyperv = VMValidator::new(VirtualMachineType::HyperV, "/vm/create_hyperv.ps1");
+            assert!(matches!(hyperv.run_in_guest("/tmp/x.sh"), Err(VMValidationError::CompatibilityError(_))));
+        }
+    }
+
+    #[test]
+    fn test_performance_metrics_parsed_from_log() {
+        let path = std::env::temp_dir().join(format!("performance-{}.log", std::process::id()));
+        std::fs::write(
+            &path,
+            "Running performance suite\nrender_time_ms: 7.25\ninput_time_ns: 912\nnote: warmed up\nfps: 58.5\n",
+        ).unwrap();
+
+        let mut validator = VMValidator::new(VirtualMachineType::QEMU, "/vm/start_qemu.sh");
+        validator.set_performance_log_path(&path.to_string_lossy());
+        let metrics = validator.read_performance_metrics();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(metrics.unwrap(), vec![
+            ("render_time_ms".to_string(), 7.25),
+            ("input_time_ns".to_string(), 912.0),
+            ("fps".to_string(), 58.5),
+        ]);
+
+        assert!(matches!(parse_performance_log("build failed\nerror: oops"), Err(VMValidationError::ValidationError(_))));
+        validator.set_performance_log_path("/nonexistent/performance.log");
+        assert!(validator.read_performance_metrics().is_err());
+    }
+
+    #[test]
+    fn test_performance_log_fetched_from_guest() {
+        let host = "/tmp/vm_performance_test.log";
+
+        let vmware = VMValidator::new(VirtualMachineType::VMware, "/vm/rust_agi_os.vmx");
+        let command = vmware.fetch_command(GUEST_PERFORMANCE_LOG_PATH, host).unwrap();
+        assert_eq!(command.program, "vmrun");
+        assert_eq!(command.args, vec!["copyFileFromGuestToHost", "/vm/rust_agi_os.vmx", GUEST_PERFORMANCE_LOG_PATH, host]);
+
+        let virtualbox = VMValidator::new(VirtualMachineType::VirtualBox, "/vm/rust_agi_os.vbox");
+        let command = virtualbox.fetch_command(GUEST_PERFORMANCE_LOG_PATH, host).unwrap();
+        assert_eq!(command.args, vec!["guestcontrol", "Rust AGI OS GUI", "copyfrom", GUEST_PERFORMANCE_LOG_PATH, host]);
+
+        let mut qemu = VMValidator::new(VirtualMachineType::QEMU, "/vm/start_qemu.sh");
+        assert!(matches!(qemu.fetch_command(GUEST_PERFORMANCE_LOG_PATH, host), Err(VMValidationError::CompatibilityError(_))));
+        qemu.set_guest_ssh_target("agi@192.168.122.10");
+        let command = qemu.fetch_command(GUEST_PERFORMANCE_LOG_PATH, host).unwrap();
+        assert_eq!(command.program, "scp");
+        assert_eq!(command.args[4..], ["agi@192.168.122.10:/tmp/performance_test.log", host]);
+
+        let hyperv = VMValidator::new(VirtualMachineType::HyperV, "/vm/create_hyperv.ps1");
+        let command = hyperv.fetch_command(GUEST_PERFORMANCE_LOG_PATH, host).unwrap();
+        assert!(command.args[2].contains("-ArgumentList '/tmp/performance_test.log'"));
+        assert!(command.args[2].ends_with("Set-Content -NoNewline -Path '/tmp/vm_performance_test.log'"));
+
+        // 主机和客户机的日志路径不同，本地运行留下的日志不会被当成客户机结果
+        assert_ne!(DEFAULT_PERFORMANCE_LOG_PATH, GUEST_PERFORMANCE_LOG_PATH);
+    }
+
+    #[test]
+    fn test_failed_fetch_removes_stale_performance_log() {
+        let path = std::env::temp_dir().join(format!("stale-performance-{}.log", std::process::id()));
+        std::fs::write(&path, "fps: 60.0\n").unwrap();
+
+        // 未设置SSH目标时无法取回，旧日志不能再被读到
+        let mut qemu = VMValidator::new(VirtualMachineType::QEMU, "/vm/start_qemu.sh");
+        qemu.set_performance_log_path(&path.to_string_lossy());
+        let fetched = qemu.fetch_performance_log();
+        let stale = path.exists();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(fetched, Err(VMValidationError::CompatibilityError(_))));
+        assert!(!stale);
+        assert!(qemu.read_performance_metrics().is_err());
+    }
+
+    #[test]
+    fn test_json_report_structure() {
+        let mut validator = VMValidator::new(VirtualMachineType::VMware, "/vm/rust_agi_os.vmx");
+        validator.add_result(VMValidationResult {
+            test_name: "窗口系统".to_string(),
+            status: ValidationStatus::Passed,
+            details: "窗口系统验证通过".to_string(),
+            performance_metrics: None,
+        });
+        validator.add_result(VMValidationResult {
+            test_name: "性能".to_string(),
+            status: ValidationStatus::Failed,
+            details: "性能验证失败".to_string(),
+            performance_metrics: Some(vec![("fps".to_string(), 24.0)]),
+        });
+        validator.add_result(VMValidationResult {
+            test_name: "输入法".to_string(),
+            status: ValidationStatus::NotValidated,
+            details: String::new(),
+            performance_metrics: None,
+        });
+
+        let json = validator.generate_json_report().unwrap();
+        let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(report["vm_type"], "VMware");
+        assert_eq!(report["summary"]["total"], 3);
+        assert_eq!(report["summary"]["passed"], 1);
+        assert_eq!(report["summary"]["failed"], 1);
+        assert_eq!(report["summary"]["not_validated"], 1);
+        assert_eq!(report["summary"]["pass_rate"], 0.5);
+
+        let results = report["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["test_name"], "窗口系统");
+        assert_eq!(results[0]["passed"], true);
+        assert!(results[0]["performance_metrics"].is_null());
+        assert_eq!(results[1]["status"], "failed");
+        assert_eq!(results[1]["performance_metrics"]["fps"], 24.0);
+        assert_eq!(results[2]["status"], "not_validated");
+    }
+
+    fn validator_with_results(results: &[(&str, ValidationStatus)]) -> VMValidator {
+        let mut validator = VMValidator::new(VirtualMachineType::VMware, "/vm/rust_agi_os.vmx");
+        for (test_name, status) in results {
+            validator.add_result(VMValidationResult {
+                test_name: test_name.to_string(),
+                status: *status,
+                details: String::new(),
+                performance_metrics: None,
+            });
+        }
+        validator
+    }
+
+    #[test]
+    fn test_overall_passed_when_all_tests_pass() {
+        let validator = validator_with_results(&[
+            ("窗口系统", ValidationStatus::Passed),
+            ("输入法", ValidationStatus::NotValidated),
+        ]);
+        assert!(validator.overall_passed());
+        assert_eq!(validator.exit_code(), 0);
+
+        // 没有实际执行的验证不算通过
+        let validator = validator_with_results(&[("输入法", ValidationStatus::NotValidated)]);
+        assert!(!validator.overall_passed());
+        assert_eq!(validator.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_overall_failed_when_any_test_fails() {
+        let validator = validator_with_results(&[
+            ("窗口系统", ValidationStatus::Passed),
+            ("性能", ValidationStatus::Failed),
+        ]);
+        assert!(!validator.overall_passed());
+        assert_eq!(validator.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_non_critical_failures_do_not_fail_overall() {
+        let mut validator = validator_with_results(&[
+            ("窗口系统", ValidationStatus::Passed),
+            ("性能", ValidationStatus::Failed),
+        ]);
+        validator.add_critical_test("窗口系统");
+        assert!(!validator.is_critical_test("性能"));
+        assert!(validator.overall_passed());
+        assert_eq!(validator.exit_code(), 0);
+
+        validator.add_critical_test("性能");
+        assert!(!validator.overall_passed());
+        assert_eq!(validator.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_overall_requires_a_critical_test_to_run() {
+        // 关键测试都未实际执行时，非关键测试通过也不算整体通过
+        let mut validator = validator_with_results(&[
+            ("窗口系统", ValidationStatus::Passed),
+            ("输入法", ValidationStatus::NotValidated),
+        ]);
+        validator.add_critical_test("输入法");
+        assert!(!validator.overall_passed());
+        assert_eq!(validator.exit_code(), 1);
+
+        // 未产生结果的关键测试同样不算执行
+        let mut validator = validator_with_results(&[("窗口系统", ValidationStatus::Passed)]);
+        validator.add_critical_test("性能");
+        assert!(!validator.overall_passed());
+
+        validator.add_critical_test("窗口系统");
+        assert!(validator.overall_passed());
+    }
+}
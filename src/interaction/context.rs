@@ -1,7 +1,8 @@
 // AGI操作系统 - 上下文管理模块
 // 此文件实现上下文管理功能
 
-use std::collections::{HashMap, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
 use std::error::Error;
 use std::fmt;
@@ -63,6 +64,73 @@ pub enum MemoryType {
     Procedural,
 }
 
+/// 向量时钟比较结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockOrdering {
+    Before,
+    After,
+    Equal,
+    Concurrent,
+}
+
+/// 向量时钟
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VectorClock {
+    counters: BTreeMap<String, u64>,
+}
+
+impl VectorClock {
+    pub fn new() -> Self {
+        VectorClock {
+            counters: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, writer_id: &str) -> u64 {
+        self.counters.get(writer_id).copied().unwrap_or(0)
+    }
+
+    pub fn increment(&mut self, writer_id: &str) {
+        *self.counters.entry(writer_id.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &VectorClock) {
+        for (writer_id, counter) in &other.counters {
+            let entry = self.counters.entry(writer_id.clone()).or_insert(0);
+            *entry = (*entry).max(*counter);
+        }
+    }
+
+    pub fn compare(&self, other: &VectorClock) -> ClockOrdering {
+        let mut less = false;
+        let mut greater = false;
+
+        for writer_id in self.counters.keys().chain(other.counters.keys()) {
+            match self.get(writer_id).cmp(&other.get(writer_id)) {
+                Ordering::Less => less = true,
+                Ordering::Greater => greater = true,
+                Ordering::Equal => {}
+            }
+        }
+
+        match (less, greater) {
+            (false, false) => ClockOrdering::Equal,
+            (true, false) => ClockOrdering::Before,
+            (false, true) => ClockOrdering::After,
+            (true, true) => ClockOrdering::Concurrent,
+        }
+    }
+}
+
+/// 并发写入合并策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// 并发写入时按写入者ID决定胜者
+    LastWriterWins,
+    /// 并发写入时保留双方内容（按行合并）
+    AppendMerge,
+}
+
 /// 上下文项
 #[derive(Debug, Clone)]
 pub struct ContextItem {
@@ -74,6 +142,9 @@ pub struct ContextItem {
     timestamp: SystemTime,
     metadata: HashMap<String, String>,
     ttl: Option<Duration>,
+    clock: VectorClock,
+    last_writer: Option<String>,
+    merge_strategy: MergeStrategy,
 }
 
 impl ContextItem {
@@ -93,9 +164,17 @@ impl ContextItem {
             timestamp: SystemTime::now(),
             metadata: HashMap::new(),
             ttl: None,
+            clock: VectorClock::new(),
+            last_writer: None,
+            merge_strategy: MergeStrategy::LastWriterWins,
         }
     }
 
+    pub fn with_merge_strategy(mut self, merge_strategy: MergeStrategy) -> Self {
+        self.merge_strategy = merge_strategy;
+        self
+    }
+
     pub fn with_ttl(mut self, ttl: Duration) -> Self {
         self.ttl = Some(ttl);
         self
@@ -138,6 +217,18 @@ impl ContextItem {
         self.ttl
     }
 
+    pub fn get_clock(&self) -> &VectorClock {
+        &self.clock
+    }
+
+    pub fn get_last_writer(&self) -> Option<&str> {
+        self.last_writer.as_deref()
+    }
+
+    pub fn get_merge_strategy(&self) -> MergeStrategy {
+        self.merge_strategy
+    }
+
     pub fn is_expired(&self) -> bool {
         if let Some(ttl) = self.ttl {
             if let Ok(elapsed) = self.timestamp.elapsed() {
@@ -159,6 +250,59 @@ impl ContextItem {
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
     }
+
+    /// 合并一次带向量时钟的写入，返回内容是否发生变化
+    pub fn merge_write(&mut self, content: &str, writer_id: &str, clock: &VectorClock) -> bool {
+        match clock.compare(&self.clock) {
+            // 过期或重复写入，直接忽略
+            ClockOrdering::Before | ClockOrdering::Equal => false,
+            ClockOrdering::After => {
+                self.update_content(content);
+                self.clock = clock.clone();
+                self.last_writer = Some(writer_id.to_string());
+                true
+            },
+            ClockOrdering::Concurrent => {
+                let current_writer = self.last_writer.clone().unwrap_or_default();
+                // 按写入者ID排序，保证合并结果与到达顺序无关
+                let incoming_first = writer_id < current_writer.as_str();
+
+                let merged = match self.merge_strategy {
+                    MergeStrategy::LastWriterWins => {
+                        if incoming_first {
+                            self.content.clone()
+                        } else {
+                            content.to_string()
+                        }
+                    },
+                    MergeStrategy::AppendMerge => {
+                        let (first, second) = if incoming_first {
+                            (content, self.content.as_str())
+                        } else {
+                            (self.content.as_str(), content)
+                        };
+
+                        let mut lines: Vec<&str> = Vec::new();
+                        for line in first.lines().chain(second.lines()) {
+                            if !lines.contains(&line) {
+                                lines.push(line);
+                            }
+                        }
+                        lines.join("\n")
+                    },
+                };
+
+                self.clock.merge(clock);
+                if !incoming_first {
+                    self.last_writer = Some(writer_id.to_string());
+                }
+
+                let changed = merged != self.content;
+                self.update_content(&merged);
+                changed
+            },
+        }
+    }
 }
 
 /// 上下文窗口
@@ -254,6 +398,37 @@ impl ContextConfig {
     }
 }
 
+/// 一致性检查与修复报告，窗口相关条目为（窗口ID，项ID）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// 已移出窗口的悬空引用，对应的项已不存在
+    pub dangling_window_entries: Vec<(String, String)>,
+    /// 已按当前项刷新的窗口副本
+    pub stale_window_entries: Vec<(String, String)>,
+    /// 超出窗口容量而移出的项，优先移出低优先级的旧项
+    pub overflow_window_entries: Vec<(String, String)>,
+    /// 已移除的悬空、重复或类型不符的索引项ID
+    pub dangling_index_entries: Vec<String>,
+    /// 已重新加入索引的孤立项ID
+    pub orphaned_items: Vec<String>,
+}
+
+impl RepairReport {
+    /// 是否未发现任何不一致
+    pub fn is_clean(&self) -> bool {
+        self.repair_count() == 0
+    }
+
+    /// 修复的条目总数
+    pub fn repair_count(&self) -> usize {
+        self.dangling_window_entries.len()
+            + self.stale_window_entries.len()
+            + self.overflow_window_entries.len()
+            + self.dangling_index_entries.len()
+            + self.orphaned_items.len()
+    }
+}
+
 /// 上下文管理器
 pub struct ContextManager {
     config: ContextConfig,
@@ -319,6 +494,29 @@ impl ContextManager {
         Ok(())
     }
 
+    /// 以指定写入者身份写入上下文项，并发写入按项的合并策略合并
+    pub fn write_item(
+        &mut self,
+        id: &str,
+        content: &str,
+        writer_id: &str,
+        clock: &VectorClock,
+    ) -> Result<bool, ContextError> {
+        let item = self.items.get_mut(id).ok_or_else(|| {
+            ContextError::InvalidContextError(format!("上下文项 '{}' 不存在", id))
+        })?;
+
+        Ok(item.merge_write(content, writer_id, clock))
+    }
+
+    /// 获取写入者的下一个时钟，基于该项当前的时钟
+    pub fn next_clock(&self, id: &str, writer_id: &str) -> Result<VectorClock, ContextError> {
+        let item = self.get_item(id)?;
+        let mut clock = item.get_clock().clone();
+        clock.increment(writer_id);
+        Ok(clock)
+    }
+
     pub fn remove_item(&mut self, id: &str) -> Result<ContextItem, ContextError> {
         let item = self.items.remove(id).ok_or_else(|| {
             ContextError::InvalidContextError(format!("上下文项 '{}' 不存在", id))
@@ -435,4 +633,103 @@ impl ContextManager {
     pub fn get_all_windows(&self) -> Vec<(&String, &ContextWindow)> {
         self.windows.iter().collect()
     }
+
+    /// 检查窗口与索引是否与上下文项一致，修复并报告发现的问题
+    pub fn verify_and_repair(&mut self) -> RepairReport {
+        let mut report = RepairReport::default();
+
+        let mut window_ids: Vec<String> = self.windows.keys().cloned().collect();
+        window_ids.sort();
+
+        for window_id in window_ids {
+            let window = match self.windows.get_mut(&window_id) {
+                Some(window) => window,
+                None => continue,
+            };
+
+            // 移除悬空引用，并按当前项刷新过期副本
+            let mut entries = VecDeque::with_capacity(window.items.len());
+            for entry in window.items.drain(..) {
+                match self.items.get(entry.get_id()) {
+                    None => report.dangling_window_entries.push((window_id.clone(), entry.id)),
+                    Some(item) if Self::is_stale_copy(&entry, item) => {
+                        report.stale_window_entries.push((window_id.clone(), entry.id));
+                        entries.push_back(item.clone());
+                    },
+                    Some(_) => entries.push_back(entry),
+                }
+            }
+
+            // 超出容量时移出优先级最低的最旧项
+            while entries.len() > window.max_items {
+                let lowest = entries.iter()
+                    .enumerate()
+                    .min_by_key(|(index, entry)| (entry.get_priority(), *index))
+                    .map(|(index, _)| index)
+                    .unwrap_or(0);
+                if let Some(entry) = entries.remove(lowest) {
+                    report.overflow_window_entries.push((window_id.clone(), entry.id));
+                }
+            }
+
+            window.items = entries;
+        }
+
+        // 移除悬空、重复或类型不符的索引项
+        for (context_type, ids) in self.type_indices.iter_mut() {
+            let mut seen = HashSet::new();
+            ids.retain(|id| {
+                let valid = self.items.get(id).is_some_and(|item| item.get_context_type() == context_type)
+                    && seen.insert(id.clone());
+                if !valid {
+                    report.dangling_index_entries.push(id.clone());
+                }
+                valid
+            });
+        }
+        for (memory_type, ids) in self.memory_indices.iter_mut() {
+            let mut seen = HashSet::new();
+            ids.retain(|id| {
+                let valid = self.items.get(id).is_some_and(|item| item.get_memory_type() == memory_type)
+                    && seen.insert(id.clone());
+                if !valid {
+                    report.dangling_index_entries.push(id.clone());
+                }
+                valid
+            });
+        }
+
+        // 将未被索引的孤立项重新加入索引
+        let mut item_ids: Vec<&String> = self.items.keys().collect();
+        item_ids.sort();
+        for id in item_ids {
+            let item = &self.items[id];
+            let type_items = self.type_indices.entry(item.get_context_type().clone()).or_default();
+            let mut orphaned = false;
+            if !type_items.contains(id) {
+                type_items.push(id.clone());
+                orphaned = true;
+            }
+            let memory_items = self.memory_indices.entry(item.get_memory_type().clone()).or_default();
+            if !memory_items.contains(id) {
+                memory_items.push(id.clone());
+                orphaned = true;
+            }
+            if orphaned {
+                report.orphaned_items.push(id.clone());
+            }
+        }
+
+        report.dangling_index_entries.sort();
+        report.dangling_index_entries.dedup();
+        report
+    }
+
+    /// 窗口副本是否与当前项不一致
+    fn is_stale_copy(entry: &ContextItem, item: &ContextItem) -> bool {
+        entry.content != item.content
+            || entry.priority != item.priority
+            || entry.context_type != item.context_type
+            || entry.memory_type != item.memory_type
+    }
 }
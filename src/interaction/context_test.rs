@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interaction::context::{
+        ContextConfig, ContextItem, ContextManager, ContextPriority, ContextType, MemoryType,
+        MergeStrategy, RepairReport, VectorClock,
+    };
+
+    fn create_manager(merge_strategy: MergeStrategy) -> ContextManager {
+        let mut manager = ContextManager::new(ContextConfig::new("test")).unwrap();
+        let item = ContextItem::new(
+            "multimodal",
+            "base",
+            ContextType::Conversation,
+            ContextPriority::Medium,
+            MemoryType::WorkingMemory,
+        )
+        .with_merge_strategy(merge_strategy);
+        manager.add_item(item).unwrap();
+        manager
+    }
+
+    fn write_concurrently(manager: &mut ContextManager, speech_first: bool) {
+        // 两个写入者基于同一版本并发写入
+        let speech_clock = manager.next_clock("multimodal", "speech").unwrap();
+        let keyboard_clock = manager.next_clock("multimodal", "keyboard").unwrap();
+
+        if speech_first {
+            manager.write_item("multimodal", "base\nspeech: hi", "speech", &speech_clock).unwrap();
+            manager.write_item("multimodal", "base\nkeyboard: hello", "keyboard", &keyboard_clock).unwrap();
+        } else {
+            manager.write_item("multimodal", "base\nkeyboard: hello", "keyboard", &keyboard_clock).unwrap();
+            manager.write_item("multimodal", "base\nspeech: hi", "speech", &speech_clock).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_concurrent_append_merge_is_deterministic() {
+        let mut first = create_manager(MergeStrategy::AppendMerge);
+        let mut second = create_manager(MergeStrategy::AppendMerge);
+
+        write_concurrently(&mut first, true);
+        write_concurrently(&mut second, false);
+
+        let expected = "base\nkeyboard: hello\nspeech: hi";
+        assert_eq!(first.get_item("multimodal").unwrap().get_content(), expected);
+        assert_eq!(second.get_item("multimodal").unwrap().get_content(), expected);
+
+        let clock = first.get_item("multimodal").unwrap().get_clock();
+        assert_eq!(clock.get("speech"), 1);
+        assert_eq!(clock.get("keyboard"), 1);
+        assert_eq!(clock, second.get_item("multimodal").unwrap().get_clock());
+    }
+
+    #[test]
+    fn test_concurrent_last_writer_wins_is_deterministic() {
+        let mut first = create_manager(MergeStrategy::LastWriterWins);
+        let mut second = create_manager(MergeStrategy::LastWriterWins);
+
+        write_concurrently(&mut first, true);
+        write_concurrently(&mut second, false);
+
+        assert_eq!(first.get_item("multimodal").unwrap().get_content(), "base\nspeech: hi");
+        assert_eq!(second.get_item("multimodal").unwrap().get_content(), "base\nspeech: hi");
+    }
+
+    #[test]
+    fn test_stale_write_is_ignored() {
+        let mut manager = create_manager(MergeStrategy::LastWriterWins);
+
+        let stale_clock = VectorClock::new();
+        let clock = manager.next_clock("multimodal", "speech").unwrap();
+        assert!(manager.write_item("multimodal", "new", "speech", &clock).unwrap());
+        assert!(!manager.write_item("multimodal", "old", "keyboard", &stale_clock).unwrap());
+
+        assert_eq!(manager.get_item("multimodal").unwrap().get_content(), "new");
+    }
+
+    fn item(id: &str, content: &str, context_type: ContextType, priority: ContextPriority) -> ContextItem {
+        ContextItem::new(id, content, context_type, priority, MemoryType::WorkingMemory)
+    }
+
+    #[test]
+    fn test_verify_and_repair_fixes_dangling_references() {
+        let mut manager = ContextManager::new(ContextConfig::new("test")).unwrap();
+        manager.add_item(item("greeting", "hi", ContextType::Conversation, ContextPriority::Low)).unwrap();
+        manager.add_item(item("question", "weather?", ContextType::Conversation, ContextPriority::High)).unwrap();
+        manager.create_window("chat", 4).unwrap();
+        manager.add_to_window("chat", "greeting").unwrap();
+        manager.add_to_window("chat", "question").unwrap();
+
+        // 移除项后窗口仍引用它；更新内容后窗口副本过期
+        manager.remove_item("greeting").unwrap();
+        manager.update_item("question", "weather tomorrow?").unwrap();
+
+        // 以相同ID重新添加不同类型的项，旧索引项悬空
+        manager.add_item(item("note", "draft", ContextType::Task, ContextPriority::Medium)).unwrap();
+        manager.add_item(item("note", "draft", ContextType::User, ContextPriority::Medium)).unwrap();
+
+        let report = manager.verify_and_repair();
+        assert_eq!(report.dangling_window_entries, vec![("chat".to_string(), "greeting".to_string())]);
+        assert_eq!(report.stale_window_entries, vec![("chat".to_string(), "question".to_string())]);
+        assert_eq!(report.dangling_index_entries, vec!["note".to_string()]);
+        assert!(report.orphaned_items.is_empty());
+        assert!(!report.is_clean());
+
+        let window = manager.get_window("chat").unwrap();
+        let contents: Vec<&str> = window.get_items().iter().map(|i| i.get_content()).collect();
+        assert_eq!(contents, vec!["weather tomorrow?"]);
+        assert!(manager.get_items_by_type(&ContextType::Task).is_empty());
+        assert_eq!(manager.get_items_by_memory_type(&MemoryType::WorkingMemory).len(), 2);
+
+        assert_eq!(manager.verify_and_repair(), RepairReport::default());
+    }
+}
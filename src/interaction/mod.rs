@@ -8,13 +8,13 @@ mod context;
 mod interface;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use vision::{VisionSystem, Image, Object, VisionError};
-pub use speech::{SpeechSystem, Audio, Recording, SpeechError};
-pub use natural_language::{NaturalLanguageSystem, Language, Sentiment, Entity, Intent, NaturalLanguageError};
+pub use vision::{VisionSystem, Image, ImageFormat, Object, VisionError, NmsAdaptation};
+pub use speech::{SpeechSystem, Audio, AudioFormat, Recording, SpeechError, StreamHandle, OverflowPolicy};
+pub use natural_language::{NaturalLanguageSystem, Language, Sentiment, Entity, Intent, NaturalLanguageError, FallbackChain, FallbackAttempt, FallbackOutcome, IntentHandler, EmbeddingCache, EmbeddingCacheStats, LanguageProfile, NormalizationRules};
 pub use natural_language_processor::{NaturalLanguageProcessor, NaturalLanguageProcessorError};
 pub use language::{LanguageSystem, LanguageError};
 pub use multimodal::{MultimodalSystem, MultimodalError};
-pub use context::{ContextManager, ContextItem, ContextType, ContextError, ContextWindow, ContextConfig, ContextPriority, MemoryType};
+pub use context::{ContextManager, ContextItem, ContextType, ContextError, RepairReport, ContextWindow, ContextConfig, ContextPriority, MemoryType, VectorClock, ClockOrdering, MergeStrategy};
 pub use interface::{InterfaceManager, InterfaceConfig, InterfaceType, InterfaceError, UIComponent, EventHandler, InteractionMode, AccessibilityLevel};
 
 // 导出特定函数，避免冲突
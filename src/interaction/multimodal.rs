@@ -2,6 +2,9 @@ use std::fmt;
 use std::error::Error;
 use std::collections::HashMap;
 
+use crate::neuro_symbolic::verify_model_checksum;
+use crate::system::ModelPrecision;
+
 /// Multimodal error
 #[derive(Debug)]
 pub enum MultimodalError {
@@ -9,6 +12,8 @@ pub enum MultimodalError {
     InitializationError(String),
     /// Processing error
     ProcessingError(String),
+    /// Model integrity error
+    IntegrityError(String),
     /// Other error
     Other(String),
 }
@@ -20,6 +25,7 @@ impl fmt::Display for MultimodalError {
         match self {
             MultimodalError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             MultimodalError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
+            MultimodalError::IntegrityError(msg) => write!(f, "Integrity error: {}", msg),
             MultimodalError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -63,6 +69,10 @@ pub struct MultimodalSystem {
     pub output_history: Vec<MultimodalOutput>,
     /// System metadata
     pub metadata: HashMap<String, String>,
+    /// Model precision
+    pub precision: ModelPrecision,
+    /// Loaded model weights, keyed by modality
+    pub models: HashMap<String, Vec<u8>>,
 }
 
 impl MultimodalSystem {
@@ -72,6 +82,8 @@ impl MultimodalSystem {
             input_history: Vec::new(),
             output_history: Vec::new(),
             metadata: HashMap::new(),
+            precision: ModelPrecision::FP32,
+            models: HashMap::new(),
         })
     }
     
@@ -106,6 +118,41 @@ impl MultimodalSystem {
         Ok(output)
     }
     
+    /// Set model precision
+    pub fn set_precision(&mut self, precision: ModelPrecision) {
+        self.precision = precision;
+        self.add_metadata("precision", &precision.to_string());
+    }
+    
+    /// Get model precision
+    pub fn get_precision(&self) -> ModelPrecision {
+        self.precision
+    }
+    
+    /// Load model bytes for a modality, verifying the checksum if one is given
+    pub fn load_model(&mut self, modality: &str, bytes: Vec<u8>, expected_checksum: Option<u32>) -> Result<(), MultimodalError> {
+        if let Some(expected) = expected_checksum {
+            verify_model_checksum(&bytes, expected)
+                .map_err(|e| MultimodalError::IntegrityError(format!("{} model: {}", modality, e)))?;
+        }
+        
+        self.models.insert(modality.to_string(), bytes);
+        Ok(())
+    }
+    
+    /// Load model file for a modality, verifying the checksum if one is given
+    pub fn load_model_file(&mut self, modality: &str, path: &str, expected_checksum: Option<u32>) -> Result<(), MultimodalError> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| MultimodalError::InitializationError(format!("Failed to read {}: {}", path, e)))?;
+        
+        self.load_model(modality, bytes, expected_checksum)
+    }
+    
+    /// Get loaded model bytes
+    pub fn get_model(&self, modality: &str) -> Option<&[u8]> {
+        self.models.get(modality).map(|m| m.as_slice())
+    }
+    
     /// Get input history
     pub fn get_input_history(&self) -> &[MultimodalInput] {
         &self.input_history
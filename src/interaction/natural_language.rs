@@ -1,8 +1,16 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
 
+use crate::neuro_symbolic::Calibrator;
+
+/// Default embedding dimension
+pub const DEFAULT_EMBEDDING_DIMENSION: usize = 64;
+
+/// Default embedding cache capacity
+pub const DEFAULT_EMBEDDING_CACHE_CAPACITY: usize = 1024;
+
 /// Natural language error
 #[derive(Debug)]
 pub enum NaturalLanguageError {
@@ -10,6 +18,8 @@ pub enum NaturalLanguageError {
     InitializationError(String),
     /// Processing error
     ProcessingError(String),
+    /// Every handler in a fallback chain failed
+    FallbackExhausted(Vec<FallbackAttempt>),
     /// Other error
     Other(String),
 }
@@ -21,13 +31,17 @@ impl fmt::Display for NaturalLanguageError {
         match self {
             NaturalLanguageError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             NaturalLanguageError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
+            NaturalLanguageError::FallbackExhausted(attempts) => {
+                let attempts: Vec<String> = attempts.iter().map(|a| a.to_string()).collect();
+                write!(f, "All intent handlers failed: {}", attempts.join("; "))
+            },
             NaturalLanguageError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
 }
 
 /// Language
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Language {
     /// English
     English,
@@ -171,6 +185,435 @@ impl Intent {
     }
 }
 
+/// Intent handler
+pub type IntentHandler = Box<dyn Fn(&Intent) -> Result<String, NaturalLanguageError> + Send + Sync>;
+
+/// Failed attempt to handle an intent
+#[derive(Debug, Clone, PartialEq)]
+pub struct FallbackAttempt {
+    /// Intent name
+    pub intent: String,
+    /// Intent confidence
+    pub confidence: f32,
+    /// Failure reason
+    pub error: String,
+}
+
+impl fmt::Display for FallbackAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:.2}): {}", self.intent, self.confidence, self.error)
+    }
+}
+
+/// Intent handled by a fallback chain
+#[derive(Debug, Clone)]
+pub struct FallbackOutcome {
+    /// Intent that was handled
+    pub intent: Intent,
+    /// Handler response
+    pub response: String,
+    /// Attempts that failed before the handler succeeded
+    pub failed_attempts: Vec<FallbackAttempt>,
+}
+
+/// Tries handlers for ranked intents in order until one succeeds
+pub struct FallbackChain {
+    /// Handlers by intent name
+    pub handlers: HashMap<String, IntentHandler>,
+    /// Intents below this confidence are not attempted
+    pub min_confidence: f32,
+    /// Maximum number of intents attempted, unlimited if `None`
+    pub max_attempts: Option<usize>,
+}
+
+impl FallbackChain {
+    /// Create a new fallback chain
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            min_confidence: 0.0,
+            max_attempts: None,
+        }
+    }
+    
+    /// Register handler for an intent
+    pub fn register_handler<F>(&mut self, intent: &str, handler: F)
+    where
+        F: Fn(&Intent) -> Result<String, NaturalLanguageError> + Send + Sync + 'static,
+    {
+        self.handlers.insert(intent.to_string(), Box::new(handler));
+    }
+    
+    /// Set minimum confidence
+    pub fn set_min_confidence(&mut self, min_confidence: f32) {
+        self.min_confidence = min_confidence;
+    }
+    
+    /// Set maximum number of attempts
+    pub fn set_max_attempts(&mut self, max_attempts: Option<usize>) {
+        self.max_attempts = max_attempts;
+    }
+    
+    /// Handle ranked intents, trying each candidate's handler in order
+    ///
+    /// Intents without a handler count as failed attempts. If no handler
+    /// succeeds, the error lists every attempt.
+    pub fn handle(&self, intents: &[Intent]) -> Result<FallbackOutcome, NaturalLanguageError> {
+        let mut failed_attempts = Vec::new();
+        let candidates = intents.iter()
+            .filter(|intent| intent.confidence >= self.min_confidence)
+            .take(self.max_attempts.unwrap_or(usize::MAX));
+        
+        for intent in candidates {
+            let result = match self.handlers.get(&intent.name) {
+                Some(handler) => handler(intent),
+                None => Err(NaturalLanguageError::ProcessingError("No handler registered".to_string())),
+            };
+            
+            match result {
+                Ok(response) => {
+                    return Ok(FallbackOutcome {
+                        intent: intent.clone(),
+                        response,
+                        failed_attempts,
+                    });
+                },
+                Err(e) => failed_attempts.push(FallbackAttempt {
+                    intent: intent.name.clone(),
+                    confidence: intent.confidence,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        
+        Err(NaturalLanguageError::FallbackExhausted(failed_attempts))
+    }
+}
+
+impl Default for FallbackChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Embedding cache statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmbeddingCacheStats {
+    /// Cache hits
+    pub hits: u64,
+    /// Cache misses
+    pub misses: u64,
+    /// Evicted entries
+    pub evictions: u64,
+    /// Current number of entries
+    pub size: usize,
+    /// Maximum number of entries
+    pub capacity: usize,
+}
+
+/// Bounded LRU cache of embeddings keyed by normalized text
+#[derive(Debug, Clone)]
+pub struct EmbeddingCache {
+    /// Cached embeddings
+    pub entries: HashMap<String, Vec<f32>>,
+    /// Keys from least to most recently used
+    pub order: VecDeque<String>,
+    /// Maximum number of entries
+    pub capacity: usize,
+    /// Cache hits
+    pub hits: u64,
+    /// Cache misses
+    pub misses: u64,
+    /// Evicted entries
+    pub evictions: u64,
+}
+
+impl EmbeddingCache {
+    /// Create a new embedding cache
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+    
+    /// Get embedding, marking it as most recently used
+    pub fn get(&mut self, key: &str) -> Option<Vec<f32>> {
+        match self.entries.get(key) {
+            Some(embedding) => {
+                let embedding = embedding.clone();
+                self.touch(key);
+                self.hits += 1;
+                Some(embedding)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+    
+    /// Insert embedding, evicting the least recently used entry if full
+    pub fn insert(&mut self, key: &str, embedding: Vec<f32>) {
+        if self.capacity == 0 {
+            return;
+        }
+        
+        if self.entries.contains_key(key) {
+            self.touch(key);
+        } else {
+            while self.entries.len() >= self.capacity {
+                match self.order.pop_front() {
+                    Some(oldest) => {
+                        self.entries.remove(&oldest);
+                        self.evictions += 1;
+                    }
+                    None => break,
+                }
+            }
+            self.order.push_back(key.to_string());
+        }
+        
+        self.entries.insert(key.to_string(), embedding);
+    }
+    
+    /// Clear cache
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+    
+    /// Get cache statistics
+    pub fn stats(&self) -> EmbeddingCacheStats {
+        EmbeddingCacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            size: self.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+    
+    /// Move key to the most recently used position
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|k| k == key) {
+            if let Some(k) = self.order.remove(position) {
+                self.order.push_back(k);
+            }
+        }
+    }
+}
+
+/// Normalize text for embedding lookup
+pub fn normalize_text(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Text normalization rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizationRules {
+    /// Fold case to lowercase
+    pub lowercase: bool,
+    /// Strip Latin diacritics, e.g. "é" to "e"
+    pub strip_diacritics: bool,
+    /// Fold full-width ASCII and half-width katakana to their canonical width
+    pub fold_width: bool,
+    /// Split Han and hiragana text into single-character tokens
+    pub segment_cjk: bool,
+}
+
+impl Default for NormalizationRules {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            strip_diacritics: false,
+            fold_width: false,
+            segment_cjk: false,
+        }
+    }
+}
+
+impl NormalizationRules {
+    /// Normalize text, collapsing whitespace
+    pub fn apply(&self, text: &str) -> String {
+        let mut chars: Vec<char> = Vec::with_capacity(text.len());
+        
+        for c in text.chars() {
+            let c = if self.fold_width { fold_width(c) } else { c };
+            
+            // Half-width voiced sound marks combine with the preceding kana
+            if self.fold_width && (c == '\u{309B}' || c == '\u{309C}') {
+                if let Some(composed) = chars.last().and_then(|prev| compose_kana(*prev, c)) {
+                    chars.pop();
+                    chars.push(composed);
+                    continue;
+                }
+            }
+            
+            let c = if self.strip_diacritics { strip_diacritic(c) } else { c };
+            if self.lowercase {
+                chars.extend(c.to_lowercase());
+            } else {
+                chars.push(c);
+            }
+        }
+        
+        chars.into_iter()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Half-width katakana U+FF61..U+FF9F and their full-width forms
+const HALF_WIDTH_KATAKANA: &str = "｡｢｣､･ｦｧｨｩｪｫｬｭｮｯｰｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝﾞﾟ";
+const FULL_WIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// Fold a character to its canonical width
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{3000}' => ' ',
+        '\u{FF01}'..='\u{FF5E}' => std::char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{FF61}'..='\u{FF9F}' => HALF_WIDTH_KATAKANA.chars()
+            .position(|h| h == c)
+            .and_then(|i| FULL_WIDTH_KATAKANA.chars().nth(i))
+            .unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// Combine a katakana with a following voiced (゛) or semi-voiced (゜) mark
+fn compose_kana(base: char, mark: char) -> Option<char> {
+    let offset = match (base, mark) {
+        ('ウ', '\u{309B}') => return Some('ヴ'),
+        ('カ'..='ト', '\u{309B}') if "カキクケコサシスセソタチツテト".contains(base) => 1,
+        ('ハ'..='ホ', '\u{309B}') if "ハヒフヘホ".contains(base) => 1,
+        ('ハ'..='ホ', '\u{309C}') if "ハヒフヘホ".contains(base) => 2,
+        _ => return None,
+    };
+    std::char::from_u32(base as u32 + offset)
+}
+
+/// Strip the diacritic from a Latin character
+fn strip_diacritic(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => 'a',
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' => 'A',
+        'ç' | 'ć' | 'č' => 'c',
+        'Ç' | 'Ć' | 'Č' => 'C',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' => 'e',
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ę' => 'E',
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => 'i',
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ī' => 'I',
+        'ñ' | 'ń' => 'n',
+        'Ñ' | 'Ń' => 'N',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => 'o',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' => 'O',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' => 'u',
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ū' => 'U',
+        'ý' | 'ÿ' => 'y',
+        'Ý' | 'Ÿ' => 'Y',
+        'ś' | 'š' => 's',
+        'Ś' | 'Š' => 'S',
+        'ź' | 'ż' | 'ž' => 'z',
+        'Ź' | 'Ż' | 'Ž' => 'Z',
+        _ => c,
+    }
+}
+
+/// Check if a character is segmented into its own token
+fn is_cjk_unigram(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{309F}' | // Hiragana
+        '\u{3400}'..='\u{4DBF}' | // CJK Extension A
+        '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+    )
+}
+
+/// Per-language stopwords and normalization
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LanguageProfile {
+    /// Stopwords, in normalized form
+    pub stopwords: HashSet<String>,
+    /// Normalization rules
+    pub normalization: NormalizationRules,
+}
+
+impl LanguageProfile {
+    /// Create a new language profile
+    pub fn new(normalization: NormalizationRules, stopwords: &[&str]) -> Self {
+        let stopwords = stopwords.iter().map(|w| normalization.apply(w)).collect();
+        Self {
+            stopwords,
+            normalization,
+        }
+    }
+    
+    /// Get the default profile shipped for a language
+    pub fn default_for(language: &Language) -> Self {
+        let latin = NormalizationRules {
+            strip_diacritics: true,
+            ..NormalizationRules::default()
+        };
+        let cjk = NormalizationRules {
+            fold_width: true,
+            segment_cjk: true,
+            ..NormalizationRules::default()
+        };
+        
+        match language {
+            Language::English => Self::new(NormalizationRules::default(), &[
+                "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is",
+                "it", "of", "on", "or", "that", "the", "this", "to", "was", "were", "with",
+            ]),
+            Language::Chinese => Self::new(cjk, &[
+                "的", "了", "是", "在", "和", "也", "就", "都", "而", "及", "与", "着", "之", "或",
+            ]),
+            Language::Japanese => Self::new(cjk, &[
+                "の", "に", "は", "を", "た", "が", "で", "て", "と", "し", "れ", "さ", "も", "な",
+            ]),
+            Language::Korean => Self::new(NormalizationRules {
+                fold_width: true,
+                ..NormalizationRules::default()
+            }, &[
+                "그리고", "그러나", "또는", "및", "등", "이", "그", "저", "것",
+            ]),
+            Language::Spanish => Self::new(latin, &[
+                "de", "del", "el", "en", "es", "la", "las", "los", "o", "para", "por", "que",
+                "se", "un", "una", "y",
+            ]),
+            Language::French => Self::new(latin, &[
+                "au", "de", "des", "du", "en", "est", "et", "la", "le", "les", "ou", "pour",
+                "que", "un", "une",
+            ]),
+            Language::German => Self::new(latin, &[
+                "das", "dem", "den", "der", "die", "ein", "eine", "ist", "mit", "oder", "und",
+                "von", "zu",
+            ]),
+            Language::Russian => Self::new(NormalizationRules::default(), &[
+                "в", "и", "как", "на", "не", "по", "с", "что", "это",
+            ]),
+            Language::Arabic => Self::new(NormalizationRules::default(), &[
+                "في", "من", "على", "و", "إلى", "عن", "أن",
+            ]),
+            Language::Other(_) => Self::default(),
+        }
+    }
+    
+    /// Check if a normalized token is a stopword
+    pub fn is_stopword(&self, token: &str) -> bool {
+        self.stopwords.contains(token)
+    }
+}
+
 /// Natural language system
 pub struct NaturalLanguageSystem {
     /// Current language
@@ -179,6 +622,14 @@ pub struct NaturalLanguageSystem {
     pub available_languages: Vec<Language>,
     /// System metadata
     pub metadata: HashMap<String, String>,
+    /// Embedding dimension
+    pub embedding_dimension: usize,
+    /// Embedding cache
+    pub embedding_cache: EmbeddingCache,
+    /// Per-language stopwords and normalization
+    pub language_profiles: HashMap<Language, LanguageProfile>,
+    /// Intent confidence calibrator
+    pub intent_calibrator: Option<Calibrator>,
 }
 
 impl NaturalLanguageSystem {
@@ -197,17 +648,90 @@ impl NaturalLanguageSystem {
         available_languages.push(Language::Russian);
         available_languages.push(Language::Arabic);
         
+        let language_profiles = available_languages.iter()
+            .map(|language| (language.clone(), LanguageProfile::default_for(language)))
+            .collect();
+        
         Ok(Self {
             current_language,
             available_languages,
             metadata: HashMap::new(),
+            embedding_dimension: DEFAULT_EMBEDDING_DIMENSION,
+            embedding_cache: EmbeddingCache::new(DEFAULT_EMBEDDING_CACHE_CAPACITY),
+            language_profiles,
+            intent_calibrator: None,
         })
     }
     
+    /// Set language profile
+    pub fn set_language_profile(&mut self, language: Language, profile: LanguageProfile) {
+        self.language_profiles.insert(language, profile);
+        self.embedding_cache.clear();
+    }
+    
+    /// Get language profile, falling back to the shipped default
+    pub fn get_language_profile(&self, language: &Language) -> LanguageProfile {
+        self.language_profiles.get(language)
+            .cloned()
+            .unwrap_or_else(|| LanguageProfile::default_for(language))
+    }
+    
+    /// Normalize text using the current language's rules
+    pub fn normalize(&self, text: &str) -> String {
+        match self.language_profiles.get(&self.current_language) {
+            Some(profile) => profile.normalization.apply(text),
+            None => LanguageProfile::default_for(&self.current_language).normalization.apply(text),
+        }
+    }
+    
+    /// Tokenize text in the current language, removing stopwords
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        let profile = self.get_language_profile(&self.current_language);
+        let normalized = profile.normalization.apply(text);
+        
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        
+        for c in normalized.chars() {
+            if profile.normalization.segment_cjk && is_cjk_unigram(c) {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            } else if c.is_alphanumeric() || c == 'ー' {
+                current.push(c);
+            } else if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+        
+        tokens.retain(|token| !profile.is_stopword(token));
+        tokens
+    }
+    
+    /// Token overlap similarity (Jaccard) between two texts
+    pub fn similarity(&self, a: &str, b: &str) -> f32 {
+        let a: HashSet<String> = self.tokenize(a).into_iter().collect();
+        let b: HashSet<String> = self.tokenize(b).into_iter().collect();
+        
+        let union = a.union(&b).count();
+        if union == 0 {
+            return 0.0;
+        }
+        
+        a.intersection(&b).count() as f32 / union as f32
+    }
+    
     /// Set current language
     pub fn set_current_language(&mut self, language: Language) -> Result<(), NaturalLanguageError> {
         if self.available_languages.contains(&language) {
-            self.current_language = language;
+            if self.current_language != language {
+                self.current_language = language;
+                self.embedding_cache.clear();
+            }
             Ok(())
         } else {
             Err(NaturalLanguageError::ProcessingError(format!("Language {:?} not available", language)))
@@ -254,9 +778,87 @@ impl NaturalLanguageSystem {
         
         intents.push(Intent::new("greeting", 0.9));
         
+        if let Some(calibrator) = &self.intent_calibrator {
+            for intent in &mut intents {
+                intent.confidence = calibrator.apply_confidence(intent.confidence);
+            }
+        }
+        
+        Ok(intents)
+    }
+    
+    /// Classify intent, returns candidate intents ranked by descending confidence
+    pub fn classify_intent(&self, text: &str) -> Result<Vec<Intent>, NaturalLanguageError> {
+        let mut intents = self.extract_intents(text)?;
+        intents.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
         Ok(intents)
     }
     
+    /// Classify intent and handle it through a fallback chain
+    pub fn handle_intent(&self, text: &str, chain: &FallbackChain) -> Result<FallbackOutcome, NaturalLanguageError> {
+        let intents = self.classify_intent(text)?;
+        chain.handle(&intents)
+    }
+    
+    /// Set intent confidence calibrator
+    pub fn set_intent_calibrator(&mut self, calibrator: Option<Calibrator>) {
+        self.intent_calibrator = calibrator;
+    }
+    
+    /// Embed text, reusing cached embeddings for identical normalized text
+    pub fn embed(&mut self, text: &str) -> Result<Vec<f32>, NaturalLanguageError> {
+        let key = self.normalize(text);
+        
+        if let Some(embedding) = self.embedding_cache.get(&key) {
+            return Ok(embedding);
+        }
+        
+        let embedding = self.compute_embedding(&key)?;
+        self.embedding_cache.insert(&key, embedding.clone());
+        
+        Ok(embedding)
+    }
+    
+    /// Set embedding cache capacity
+    pub fn set_embedding_cache_capacity(&mut self, capacity: usize) {
+        self.embedding_cache = EmbeddingCache::new(capacity);
+    }
+    
+    /// Get embedding cache statistics
+    pub fn get_embedding_cache_stats(&self) -> EmbeddingCacheStats {
+        self.embedding_cache.stats()
+    }
+    
+    /// Compute embedding
+    fn compute_embedding(&self, text: &str) -> Result<Vec<f32>, NaturalLanguageError> {
+        if self.embedding_dimension == 0 {
+            return Err(NaturalLanguageError::ProcessingError("Embedding dimension must be greater than zero".to_string()));
+        }
+        
+        // In a real implementation, this would run an embedding model
+        // For now, we hash character trigrams into a fixed-size vector
+        let mut embedding = vec![0.0f32; self.embedding_dimension];
+        let chars: Vec<char> = text.chars().collect();
+        
+        for window in chars.windows(3.min(chars.len().max(1))) {
+            let mut hash: u64 = 14695981039346656037;
+            for c in window {
+                hash ^= *c as u64;
+                hash = hash.wrapping_mul(1099511628211);
+            }
+            embedding[(hash % self.embedding_dimension as u64) as usize] += 1.0;
+        }
+        
+        let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for value in &mut embedding {
+                *value /= norm;
+            }
+        }
+        
+        Ok(embedding)
+    }
+    
     /// Add metadata
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
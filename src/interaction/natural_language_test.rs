@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interaction::natural_language::{
+        FallbackChain, Intent, NaturalLanguageError, NaturalLanguageSystem, Language, LanguageProfile, NormalizationRules,
+    };
+
+    #[test]
+    fn test_embed_cache_hit_on_normalized_text() {
+        let mut system = NaturalLanguageSystem::new(Language::English).unwrap();
+        
+        let first = system.embed("Hello   World").unwrap();
+        let second = system.embed("  hello world ").unwrap();
+        
+        assert_eq!(first, second);
+        
+        let stats = system.get_embedding_cache_stats();
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.size, 1);
+    }
+
+    #[test]
+    fn test_embed_cache_miss_on_distinct_text() {
+        let mut system = NaturalLanguageSystem::new(Language::English).unwrap();
+        
+        let first = system.embed("hello world").unwrap();
+        let second = system.embed("goodbye world").unwrap();
+        
+        assert_ne!(first, second);
+        
+        let stats = system.get_embedding_cache_stats();
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.size, 2);
+    }
+
+    #[test]
+    fn test_embed_cache_evicts_least_recently_used() {
+        let mut system = NaturalLanguageSystem::new(Language::English).unwrap();
+        system.set_embedding_cache_capacity(2);
+        
+        system.embed("first").unwrap();
+        system.embed("second").unwrap();
+        system.embed("first").unwrap();
+        system.embed("third").unwrap();
+        
+        let stats = system.get_embedding_cache_stats();
+        assert_eq!(stats.evictions, 1);
+        assert!(system.embedding_cache.entries.contains_key("first"));
+        assert!(!system.embedding_cache.entries.contains_key("second"));
+    }
+
+    #[test]
+    fn test_tokenize_removes_stopwords() {
+        let system = NaturalLanguageSystem::new(Language::English).unwrap();
+        assert_eq!(system.tokenize("The Cat is on the mat."), vec!["cat", "mat"]);
+        
+        let system = NaturalLanguageSystem::new(Language::Chinese).unwrap();
+        assert_eq!(system.tokenize("我的猫在家"), vec!["我", "猫", "家"]);
+    }
+    
+    #[test]
+    fn test_full_and_half_width_normalize_to_same_token() {
+        let system = NaturalLanguageSystem::new(Language::Japanese).unwrap();
+        
+        assert_eq!(system.tokenize("ﾃﾞｰﾀ　ＡＢＣ１２３"), vec!["データ", "abc123"]);
+        assert_eq!(system.tokenize("データ abc123"), vec!["データ", "abc123"]);
+        assert_eq!(system.tokenize("ﾊﾟｿｺﾝ"), system.tokenize("パソコン"));
+        assert_eq!(system.similarity("ﾃﾞｰﾀの分析", "データの分析"), 1.0);
+    }
+    
+    #[test]
+    fn test_diacritics_and_custom_profile() {
+        let mut system = NaturalLanguageSystem::new(Language::French).unwrap();
+        assert_eq!(system.tokenize("Le café est fermé"), vec!["cafe", "ferme"]);
+        
+        let profile = LanguageProfile::new(NormalizationRules::default(), &["café"]);
+        system.set_language_profile(Language::French, profile);
+        assert_eq!(system.tokenize("Le café est fermé"), vec!["le", "est", "fermé"]);
+    }
+
+    #[test]
+    fn test_fallback_chain_tries_next_intent() {
+        let mut chain = FallbackChain::new();
+        chain.register_handler("book_flight", |_| {
+            Err(NaturalLanguageError::ProcessingError("booking service down".to_string()))
+        });
+        chain.register_handler("search_flights", |intent| Ok(format!("searching for {}", intent.name)));
+
+        let intents = vec![
+            Intent::new("book_flight", 0.7),
+            Intent::new("search_flights", 0.2),
+            Intent::new("greeting", 0.1),
+        ];
+        let outcome = chain.handle(&intents).unwrap();
+
+        assert_eq!(outcome.intent.name, "search_flights");
+        assert_eq!(outcome.response, "searching for search_flights");
+        assert_eq!(outcome.failed_attempts.len(), 1);
+        assert_eq!(outcome.failed_attempts[0].intent, "book_flight");
+        assert!(outcome.failed_attempts[0].error.contains("booking service down"));
+    }
+
+    #[test]
+    fn test_fallback_chain_reports_every_attempt() {
+        let mut chain = FallbackChain::new();
+        chain.register_handler("book_flight", |_| Err(NaturalLanguageError::ProcessingError("timeout".to_string())));
+        chain.set_min_confidence(0.15);
+
+        let intents = vec![
+            Intent::new("book_flight", 0.7),
+            Intent::new("cancel_flight", 0.2),
+            Intent::new("greeting", 0.1),
+        ];
+
+        match chain.handle(&intents) {
+            Err(NaturalLanguageError::FallbackExhausted(attempts)) => {
+                let names: Vec<&str> = attempts.iter().map(|a| a.intent.as_str()).collect();
+                assert_eq!(names, vec!["book_flight", "cancel_flight"]);
+                let message = NaturalLanguageError::FallbackExhausted(attempts).to_string();
+                assert!(message.contains("book_flight (0.70): Processing error: timeout"));
+                assert!(message.contains("cancel_flight (0.20): Processing error: No handler registered"));
+            },
+            other => panic!("expected exhausted fallback chain, got {:?}", other.map(|o| o.response)),
+        }
+
+        let system = NaturalLanguageSystem::new(Language::English).unwrap();
+        chain.register_handler("greeting", |_| Ok("hello".to_string()));
+        assert_eq!(system.handle_intent("hi there", &chain).unwrap().response, "hello");
+    }
+}
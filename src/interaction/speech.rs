@@ -1,7 +1,8 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
 
 /// Speech error
 #[derive(Debug)]
@@ -29,6 +30,48 @@ impl fmt::Display for SpeechError {
     }
 }
 
+/// Sample format of decoded audio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    /// Signed 16-bit little-endian PCM, interleaved
+    PCM16,
+    /// 32-bit little-endian float in [-1.0, 1.0], interleaved
+    Float32,
+}
+
+impl AudioFormat {
+    /// Get format name as stored in `Audio::format`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioFormat::PCM16 => "PCM16",
+            AudioFormat::Float32 => "F32",
+        }
+    }
+    
+    /// Parse a format name as stored in `Audio::format`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "PCM16" | "S16LE" => Some(AudioFormat::PCM16),
+            "F32" | "FLOAT32" | "F32LE" => Some(AudioFormat::Float32),
+            _ => None,
+        }
+    }
+    
+    /// Get number of bytes per sample
+    pub fn bytes_per_sample(&self) -> usize {
+        match self {
+            AudioFormat::PCM16 => 2,
+            AudioFormat::Float32 => 4,
+        }
+    }
+}
+
+impl fmt::Display for AudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Audio
 #[derive(Debug, Clone)]
 pub struct Audio {
@@ -71,6 +114,119 @@ impl Audio {
     pub fn get_metadata(&self, key: &str) -> Option<&String> {
         self.metadata.get(key)
     }
+    
+    /// Load a 16-bit PCM WAV file as `AudioFormat::PCM16`
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, SpeechError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|e| SpeechError::AudioError(format!("Failed to read {}: {}", path.display(), e)))?;
+        
+        let is_wav = bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE";
+        if !is_wav {
+            return Err(SpeechError::AudioError(format!("Unsupported audio format: {}", path.display())));
+        }
+        
+        let mut audio = Self::from_wav(&bytes)
+            .map_err(|msg| SpeechError::AudioError(format!("Invalid WAV file {}: {}", path.display(), msg)))?;
+        audio.add_metadata("source", &path.display().to_string());
+        audio.add_metadata("source_format", "wav");
+        Ok(audio)
+    }
+    
+    /// Convert sample data to another format
+    ///
+    /// PCM16 samples map to `sample / 32768.0`, so PCM16 to Float32 and back
+    /// is lossless. Float32 samples outside [-1.0, 1.0] are clipped; NaN or
+    /// infinite samples cannot be converted. The converted audio keeps the
+    /// metadata and gets a new ID.
+    pub fn convert(&self, format: AudioFormat) -> Result<Audio, SpeechError> {
+        let source = AudioFormat::parse(&self.format).ok_or_else(|| {
+            SpeechError::AudioError(format!("Cannot convert from unsupported format {}", self.format))
+        })?;
+        
+        if !self.data.len().is_multiple_of(source.bytes_per_sample()) {
+            return Err(SpeechError::AudioError(format!(
+                "Audio data has {} bytes, not a whole number of {} samples",
+                self.data.len(), source
+            )));
+        }
+        
+        let samples = self.data.len() / source.bytes_per_sample();
+        let mut data = Vec::with_capacity(samples * format.bytes_per_sample());
+        for (index, bytes) in self.data.chunks_exact(source.bytes_per_sample()).enumerate() {
+            let sample = match source {
+                AudioFormat::PCM16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32768.0,
+                AudioFormat::Float32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            };
+            if !sample.is_finite() {
+                return Err(SpeechError::AudioError(format!("Sample {} is not finite", index)));
+            }
+            
+            match format {
+                AudioFormat::PCM16 => {
+                    let value = (sample * 32768.0).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+                    data.extend_from_slice(&value.to_le_bytes());
+                },
+                AudioFormat::Float32 => data.extend_from_slice(&sample.clamp(-1.0, 1.0).to_le_bytes()),
+            }
+        }
+        
+        let mut audio = Self::new(data, self.duration, self.sample_rate, self.channels, format.as_str());
+        audio.metadata = self.metadata.clone();
+        Ok(audio)
+    }
+    
+    /// Decode a RIFF/WAVE buffer
+    fn from_wav(bytes: &[u8]) -> Result<Self, String> {
+        let read_u16 = |offset: usize| u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+        };
+        
+        let mut fmt_chunk = None;
+        let mut data_chunk = None;
+        let mut offset = 12;
+        
+        while offset + 8 <= bytes.len() {
+            let id = &bytes[offset..offset + 4];
+            let size = read_u32(offset + 4) as usize;
+            let start = offset + 8;
+            let end = start.checked_add(size).filter(|&end| end <= bytes.len())
+                .ok_or_else(|| "truncated chunk".to_string())?;
+            
+            match id {
+                b"fmt " if size >= 16 => fmt_chunk = Some(start),
+                b"data" => data_chunk = Some(&bytes[start..end]),
+                _ => {}
+            }
+            
+            // Chunks are padded to an even size
+            offset = end + (size & 1);
+        }
+        
+        let fmt_start = fmt_chunk.ok_or_else(|| "missing fmt chunk".to_string())?;
+        let data = data_chunk.ok_or_else(|| "missing data chunk".to_string())?;
+        
+        let encoding = read_u16(fmt_start);
+        let channels = read_u16(fmt_start + 2);
+        let sample_rate = read_u32(fmt_start + 4);
+        let bits_per_sample = read_u16(fmt_start + 14);
+        
+        if encoding != 1 || bits_per_sample != 16 {
+            return Err(format!(
+                "unsupported encoding {} with {} bits per sample, expected 16-bit PCM",
+                encoding, bits_per_sample
+            ));
+        }
+        if channels == 0 || channels > u8::MAX as u16 || sample_rate == 0 {
+            return Err(format!("invalid layout: {} channels at {} Hz", channels, sample_rate));
+        }
+        
+        let frames = data.len() / (2 * channels as usize);
+        let duration = frames as f32 / sample_rate as f32;
+        
+        Ok(Self::new(data.to_vec(), duration, sample_rate, channels as u8, AudioFormat::PCM16.as_str()))
+    }
 }
 
 /// Recording
@@ -111,10 +267,146 @@ impl Recording {
     }
 }
 
+/// Stream overflow policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Drop the oldest buffered samples to make room (real-time)
+    DropOldest,
+    /// Block the writer until the reader frees space (accuracy)
+    Block,
+}
+
+/// Stream buffer state
+#[derive(Debug)]
+struct StreamBuffer {
+    /// Buffered samples
+    samples: VecDeque<u8>,
+    /// Number of dropped samples
+    dropped_samples: u64,
+    /// Whether the stream is closed
+    closed: bool,
+}
+
+/// Streaming audio handle with a bounded buffer
+#[derive(Debug)]
+pub struct StreamHandle {
+    /// Stream ID
+    pub id: String,
+    /// Buffer capacity in samples
+    pub capacity: usize,
+    /// Overflow policy
+    pub overflow_policy: OverflowPolicy,
+    /// Buffer
+    buffer: Mutex<StreamBuffer>,
+    /// Signalled when space is freed or the stream is closed
+    space_available: Condvar,
+}
+
+impl StreamHandle {
+    /// Create a new stream handle
+    pub fn new(capacity: usize, overflow_policy: OverflowPolicy) -> Result<Self, SpeechError> {
+        if capacity == 0 {
+            return Err(SpeechError::InitializationError("Stream capacity must be greater than zero".to_string()));
+        }
+        
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            capacity,
+            overflow_policy,
+            buffer: Mutex::new(StreamBuffer {
+                samples: VecDeque::with_capacity(capacity),
+                dropped_samples: 0,
+                closed: false,
+            }),
+            space_available: Condvar::new(),
+        })
+    }
+    
+    /// Write samples, applying the overflow policy when the buffer is full
+    pub fn write(&self, samples: &[u8]) -> Result<(), SpeechError> {
+        let mut buffer = self.lock_buffer()?;
+        
+        for &sample in samples {
+            if buffer.closed {
+                return Err(SpeechError::AudioError(format!("Stream {} is closed", self.id)));
+            }
+            
+            if buffer.samples.len() >= self.capacity {
+                match self.overflow_policy {
+                    OverflowPolicy::DropOldest => {
+                        buffer.samples.pop_front();
+                        buffer.dropped_samples += 1;
+                    },
+                    OverflowPolicy::Block => {
+                        while buffer.samples.len() >= self.capacity && !buffer.closed {
+                            buffer = self.space_available.wait(buffer).map_err(|_| {
+                                SpeechError::AudioError("Stream buffer lock poisoned".to_string())
+                            })?;
+                        }
+                        
+                        if buffer.closed {
+                            return Err(SpeechError::AudioError(format!("Stream {} is closed", self.id)));
+                        }
+                    },
+                }
+            }
+            
+            buffer.samples.push_back(sample);
+        }
+        
+        Ok(())
+    }
+    
+    /// Read up to max_samples buffered samples
+    pub fn read(&self, max_samples: usize) -> Result<Vec<u8>, SpeechError> {
+        let mut buffer = self.lock_buffer()?;
+        let count = max_samples.min(buffer.samples.len());
+        let samples: Vec<u8> = buffer.samples.drain(..count).collect();
+        
+        if count > 0 {
+            self.space_available.notify_all();
+        }
+        
+        Ok(samples)
+    }
+    
+    /// Get number of buffered samples
+    pub fn buffered_samples(&self) -> usize {
+        self.buffer.lock().map(|b| b.samples.len()).unwrap_or(0)
+    }
+    
+    /// Get number of dropped samples
+    pub fn dropped_samples(&self) -> u64 {
+        self.buffer.lock().map(|b| b.dropped_samples).unwrap_or(0)
+    }
+    
+    /// Close the stream, waking any blocked writers
+    pub fn close(&self) -> Result<(), SpeechError> {
+        let mut buffer = self.lock_buffer()?;
+        buffer.closed = true;
+        self.space_available.notify_all();
+        Ok(())
+    }
+    
+    /// Check if the stream is closed
+    pub fn is_closed(&self) -> bool {
+        self.buffer.lock().map(|b| b.closed).unwrap_or(true)
+    }
+    
+    /// Lock buffer
+    fn lock_buffer(&self) -> Result<std::sync::MutexGuard<'_, StreamBuffer>, SpeechError> {
+        self.buffer.lock().map_err(|_| {
+            SpeechError::AudioError("Stream buffer lock poisoned".to_string())
+        })
+    }
+}
+
 /// Speech system
 pub struct SpeechSystem {
     /// Recordings
     pub recordings: HashMap<String, Recording>,
+    /// Streams
+    pub streams: HashMap<String, Arc<StreamHandle>>,
     /// System metadata
     pub metadata: HashMap<String, String>,
 }
@@ -124,6 +416,7 @@ impl SpeechSystem {
     pub fn new() -> Result<Self, SpeechError> {
         Ok(Self {
             recordings: HashMap::new(),
+            streams: HashMap::new(),
             metadata: HashMap::new(),
         })
     }
@@ -203,6 +496,38 @@ impl SpeechSystem {
         Ok(transcript)
     }
     
+    /// Open a streaming transcription buffer
+    pub fn open_stream(&mut self, capacity: usize, overflow_policy: OverflowPolicy) -> Result<Arc<StreamHandle>, SpeechError> {
+        let handle = Arc::new(StreamHandle::new(capacity, overflow_policy)?);
+        self.streams.insert(handle.id.clone(), handle.clone());
+        Ok(handle)
+    }
+    
+    /// Close a stream
+    pub fn close_stream(&mut self, stream_id: &str) -> Result<(), SpeechError> {
+        let handle = self.streams.remove(stream_id).ok_or_else(|| {
+            SpeechError::AudioError(format!("Stream {} not found", stream_id))
+        })?;
+        handle.close()
+    }
+    
+    /// Transcribe buffered stream samples
+    pub fn transcribe_stream(&self, stream_id: &str) -> Result<String, SpeechError> {
+        let handle = self.streams.get(stream_id).ok_or_else(|| {
+            SpeechError::AudioError(format!("Stream {} not found", stream_id))
+        })?;
+        
+        let samples = handle.read(handle.capacity)?;
+        
+        // In a real implementation, this would transcribe the samples
+        // For now, we just return a dummy transcript
+        if samples.is_empty() {
+            Ok(String::new())
+        } else {
+            Ok("This is a dummy transcript.".to_string())
+        }
+    }
+    
     /// Text to speech
     pub fn text_to_speech(&self, text: &str, _voice: &str) -> Result<Audio, SpeechError> {
         // In a real implementation, this would convert text to speech
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interaction::speech::{Audio, AudioFormat, OverflowPolicy, SpeechError, SpeechSystem, StreamHandle};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_stream_drop_oldest_when_saturated() {
+        let handle = StreamHandle::new(4, OverflowPolicy::DropOldest).unwrap();
+        
+        handle.write(&[1, 2, 3, 4]).unwrap();
+        handle.write(&[5, 6]).unwrap();
+        
+        assert_eq!(handle.buffered_samples(), 4);
+        assert_eq!(handle.dropped_samples(), 2);
+        assert_eq!(handle.read(10).unwrap(), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_stream_block_when_saturated() {
+        let handle = Arc::new(StreamHandle::new(4, OverflowPolicy::Block).unwrap());
+        
+        let writer_handle = handle.clone();
+        let writer = thread::spawn(move || {
+            writer_handle.write(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        });
+        
+        // Writer fills the buffer and waits for the reader
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(handle.buffered_samples(), 4);
+        
+        let mut received = Vec::new();
+        while received.len() < 8 {
+            received.extend(handle.read(2).unwrap());
+            thread::sleep(Duration::from_millis(5));
+        }
+        writer.join().unwrap();
+        
+        assert_eq!(received, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(handle.dropped_samples(), 0);
+    }
+
+    #[test]
+    fn test_stream_close_wakes_blocked_writer() {
+        let mut system = SpeechSystem::new().unwrap();
+        let handle = system.open_stream(2, OverflowPolicy::Block).unwrap();
+        
+        let writer_handle = handle.clone();
+        let writer = thread::spawn(move || writer_handle.write(&[1, 2, 3]));
+        
+        thread::sleep(Duration::from_millis(50));
+        system.close_stream(&handle.id).unwrap();
+        
+        assert!(writer.join().unwrap().is_err());
+        assert!(handle.is_closed());
+    }
+
+    /// Build a 16-bit PCM WAV file with an extra chunk before the data
+    fn wav_bytes(sample_rate: u32, channels: u16, samples: &[i16]) -> Vec<u8> {
+        let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let block_align = channels * 2;
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(4 + 24 + 14 + 8 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"LIST");
+        wav.extend_from_slice(&5u32.to_le_bytes());
+        wav.extend_from_slice(b"INFO!\0");
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&data);
+        wav
+    }
+
+    #[test]
+    fn test_audio_from_wav_file() {
+        let dir = std::env::temp_dir().join(format!("speech_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tone.wav");
+
+        let samples: Vec<i16> = (0..16000).map(|i| (i % 100) as i16 - 50).collect();
+        std::fs::write(&path, wav_bytes(8000, 2, &samples)).unwrap();
+
+        let audio = Audio::from_file(&path).unwrap();
+        assert_eq!(audio.sample_rate, 8000);
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.format, AudioFormat::PCM16.as_str());
+        assert_eq!(audio.data.len(), samples.len() * 2);
+        assert!((audio.duration - 1.0).abs() < 1e-6);
+        assert_eq!(i16::from_le_bytes([audio.data[2], audio.data[3]]), -49);
+
+        let mp3 = dir.join("song.mp3");
+        std::fs::write(&mp3, b"ID3 not a wav file").unwrap();
+        match Audio::from_file(&mp3) {
+            Err(SpeechError::AudioError(msg)) => assert!(msg.contains("Unsupported audio format")),
+            other => panic!("expected audio error, got {:?}", other),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_audio_convert_pcm16_to_float32() {
+        let samples: [i16; 4] = [0, 16384, -32768, 32767];
+        let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let pcm = Audio::new(data, 0.5, 8, 1, AudioFormat::PCM16.as_str());
+
+        let float = pcm.convert(AudioFormat::Float32).unwrap();
+        assert_eq!(float.format, "F32");
+        assert_eq!(float.sample_rate, 8);
+        let values: Vec<f32> = float.data.chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        assert_eq!(values, vec![0.0, 0.5, -1.0, 32767.0 / 32768.0]);
+
+        // Round trip is lossless
+        assert_eq!(float.convert(AudioFormat::PCM16).unwrap().data, pcm.data);
+
+        let nan = Audio::new(f32::NAN.to_le_bytes().to_vec(), 0.1, 10, 1, "F32");
+        assert!(matches!(nan.convert(AudioFormat::PCM16), Err(SpeechError::AudioError(_))));
+        let odd = Audio::new(vec![0; 3], 0.1, 10, 1, "PCM16");
+        assert!(matches!(odd.convert(AudioFormat::Float32), Err(SpeechError::AudioError(_))));
+    }
+}
@@ -1,8 +1,12 @@
 use std::fmt;
 use std::error::Error;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+/// Default IoU threshold for non-maximum suppression
+pub const DEFAULT_IOU_THRESHOLD: f32 = 0.5;
+
 /// Vision error
 #[derive(Debug)]
 pub enum VisionError {
@@ -29,6 +33,53 @@ impl fmt::Display for VisionError {
     }
 }
 
+/// Pixel format of decoded images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// 8-bit RGB, three bytes per pixel
+    RGB,
+    /// 8-bit RGBA, four bytes per pixel
+    RGBA,
+    /// 8-bit luma, one byte per pixel
+    Grayscale,
+}
+
+impl ImageFormat {
+    /// Get format name as stored in `Image::format`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageFormat::RGB => "RGB",
+            ImageFormat::RGBA => "RGBA",
+            ImageFormat::Grayscale => "GRAY",
+        }
+    }
+    
+    /// Parse a format name as stored in `Image::format`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "RGB" => Some(ImageFormat::RGB),
+            "RGBA" => Some(ImageFormat::RGBA),
+            "GRAY" | "GRAYSCALE" | "L" => Some(ImageFormat::Grayscale),
+            _ => None,
+        }
+    }
+    
+    /// Get number of bytes per pixel
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            ImageFormat::RGB => 3,
+            ImageFormat::RGBA => 4,
+            ImageFormat::Grayscale => 1,
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Image
 #[derive(Debug, Clone)]
 pub struct Image {
@@ -68,6 +119,89 @@ impl Image {
     pub fn get_metadata(&self, key: &str) -> Option<&String> {
         self.metadata.get(key)
     }
+    
+    /// Load a PNG or JPEG file, decoded to `ImageFormat::RGB`
+    ///
+    /// The container is detected from the magic bytes, falling back to the
+    /// file extension.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, VisionError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|e| VisionError::ImageError(format!("Failed to read {}: {}", path.display(), e)))?;
+        
+        let container = Self::detect_container(&bytes, path).ok_or_else(|| {
+            VisionError::ImageError(format!("Unsupported image format: {}", path.display()))
+        })?;
+        
+        let decoded = ::image::load_from_memory_with_format(&bytes, container)
+            .map_err(|e| VisionError::ImageError(format!("Failed to decode {}: {}", path.display(), e)))?
+            .to_rgb8();
+        
+        let (width, height) = decoded.dimensions();
+        let mut image = Self::new(decoded.into_raw(), width, height, ImageFormat::RGB.as_str());
+        image.add_metadata("source", &path.display().to_string());
+        image.add_metadata("source_format", container.extensions_str()[0]);
+        Ok(image)
+    }
+    
+    /// Convert pixel data to another format
+    ///
+    /// Gray to RGB/RGBA and RGB to RGBA are lossless; converting to grayscale
+    /// uses BT.601 luma and dropping to RGB discards alpha. The converted
+    /// image keeps the metadata and gets a new ID.
+    pub fn convert(&self, format: ImageFormat) -> Result<Image, VisionError> {
+        let source = ImageFormat::parse(&self.format).ok_or_else(|| {
+            VisionError::ImageError(format!("Cannot convert from unsupported format {}", self.format))
+        })?;
+        
+        let pixels = self.width as usize * self.height as usize;
+        if self.data.len() != pixels * source.bytes_per_pixel() {
+            return Err(VisionError::ImageError(format!(
+                "Image data has {} bytes, expected {} for {}x{} {}",
+                self.data.len(), pixels * source.bytes_per_pixel(), self.width, self.height, source
+            )));
+        }
+        
+        let mut data = Vec::with_capacity(pixels * format.bytes_per_pixel());
+        for pixel in self.data.chunks_exact(source.bytes_per_pixel()) {
+            let (r, g, b, a) = match source {
+                ImageFormat::RGB => (pixel[0], pixel[1], pixel[2], u8::MAX),
+                ImageFormat::RGBA => (pixel[0], pixel[1], pixel[2], pixel[3]),
+                ImageFormat::Grayscale => (pixel[0], pixel[0], pixel[0], u8::MAX),
+            };
+            
+            match format {
+                ImageFormat::RGB => data.extend_from_slice(&[r, g, b]),
+                ImageFormat::RGBA => data.extend_from_slice(&[r, g, b, a]),
+                ImageFormat::Grayscale if source == ImageFormat::Grayscale => data.push(r),
+                ImageFormat::Grayscale => {
+                    let luma = (299 * r as u32 + 587 * g as u32 + 114 * b as u32 + 500) / 1000;
+                    data.push(luma as u8);
+                },
+            }
+        }
+        
+        let mut image = Self::new(data, self.width, self.height, format.as_str());
+        image.metadata = self.metadata.clone();
+        Ok(image)
+    }
+    
+    /// Detect a supported container from magic bytes or extension
+    fn detect_container(bytes: &[u8], path: &Path) -> Option<::image::ImageFormat> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return Some(::image::ImageFormat::Png);
+        }
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some(::image::ImageFormat::Jpeg);
+        }
+        
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some(::image::ImageFormat::Png),
+            "jpg" | "jpeg" => Some(::image::ImageFormat::Jpeg),
+            _ => None,
+        }
+    }
 }
 
 /// Object
@@ -106,6 +240,80 @@ impl Object {
     pub fn get_metadata(&self, key: &str) -> Option<&String> {
         self.metadata.get(key)
     }
+    
+    /// Intersection over union with another object's bounding box
+    pub fn iou(&self, other: &Object) -> f32 {
+        let (ax, ay, aw, ah) = self.bbox;
+        let (bx, by, bw, bh) = other.bbox;
+        
+        let inter_w = ((ax + aw).min(bx + bw) - ax.max(bx)).max(0.0);
+        let inter_h = ((ay + ah).min(by + bh) - ay.max(by)).max(0.0);
+        let intersection = inter_w * inter_h;
+        let union = aw * ah + bw * bh - intersection;
+        
+        if union <= 0.0 {
+            0.0
+        } else {
+            intersection / union
+        }
+    }
+}
+
+/// Density-adaptive NMS configuration
+///
+/// A kept detection's IoU threshold rises from the base threshold towards
+/// `max_threshold` as the number of overlapping neighbors grows, so that
+/// distinct objects in a crowd survive while isolated duplicates are still
+/// suppressed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NmsAdaptation {
+    /// Minimum IoU for a detection to count as a neighbor
+    pub neighbor_iou: f32,
+    /// Threshold used at full density
+    pub max_threshold: f32,
+    /// Neighbor count at which the threshold reaches its maximum
+    pub saturation: usize,
+    /// Curve exponent; 1.0 is linear, larger values adapt later
+    pub exponent: f32,
+}
+
+impl Default for NmsAdaptation {
+    fn default() -> Self {
+        Self {
+            neighbor_iou: 0.2,
+            max_threshold: 0.7,
+            saturation: 3,
+            exponent: 1.0,
+        }
+    }
+}
+
+impl NmsAdaptation {
+    /// Validate the configuration against a base threshold
+    pub fn validate(&self, base_threshold: f32) -> Result<(), VisionError> {
+        if !(0.0..=1.0).contains(&self.neighbor_iou) {
+            return Err(VisionError::Other(format!("Neighbor IoU must be in [0, 1]: {}", self.neighbor_iou)));
+        }
+        if !(base_threshold..=1.0).contains(&self.max_threshold) {
+            return Err(VisionError::Other(format!(
+                "Max threshold must be in [{}, 1]: {}",
+                base_threshold, self.max_threshold
+            )));
+        }
+        if self.saturation == 0 {
+            return Err(VisionError::Other("Saturation must be positive".to_string()));
+        }
+        if self.exponent <= 0.0 {
+            return Err(VisionError::Other(format!("Exponent must be positive: {}", self.exponent)));
+        }
+        Ok(())
+    }
+    
+    /// Threshold for a detection with the given neighbor count
+    pub fn threshold(&self, base_threshold: f32, neighbors: usize) -> f32 {
+        let density = (neighbors as f32 / self.saturation as f32).min(1.0);
+        base_threshold + (self.max_threshold - base_threshold) * density.powf(self.exponent)
+    }
 }
 
 /// Vision system
@@ -114,6 +322,10 @@ pub struct VisionSystem {
     pub images: HashMap<String, Image>,
     /// System metadata
     pub metadata: HashMap<String, String>,
+    /// IoU threshold for non-maximum suppression
+    pub iou_threshold: f32,
+    /// Density-adaptive NMS, if enabled
+    pub nms_adaptation: Option<NmsAdaptation>,
 }
 
 impl VisionSystem {
@@ -122,6 +334,8 @@ impl VisionSystem {
         Ok(Self {
             images: HashMap::new(),
             metadata: HashMap::new(),
+            iou_threshold: DEFAULT_IOU_THRESHOLD,
+            nms_adaptation: None,
         })
     }
     
@@ -194,7 +408,71 @@ impl VisionSystem {
         objects.push(Object::new("person", 0.95, (0.1, 0.2, 0.3, 0.4)));
         objects.push(Object::new("car", 0.85, (0.5, 0.6, 0.2, 0.3)));
         
-        Ok(objects)
+        Ok(self.suppress_overlaps(objects))
+    }
+    
+    /// Set IoU threshold for non-maximum suppression
+    pub fn set_iou_threshold(&mut self, threshold: f32) -> Result<(), VisionError> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(VisionError::Other(format!("IoU threshold must be in [0, 1]: {}", threshold)));
+        }
+        if let Some(adaptation) = &self.nms_adaptation {
+            adaptation.validate(threshold)?;
+        }
+        
+        self.iou_threshold = threshold;
+        Ok(())
+    }
+    
+    /// Enable or disable density-adaptive NMS
+    pub fn set_nms_adaptation(&mut self, adaptation: Option<NmsAdaptation>) -> Result<(), VisionError> {
+        if let Some(adaptation) = &adaptation {
+            adaptation.validate(self.iou_threshold)?;
+        }
+        
+        self.nms_adaptation = adaptation;
+        Ok(())
+    }
+    
+    /// Suppress overlapping detections of the same label, keeping the most confident
+    pub fn suppress_overlaps(&self, mut objects: Vec<Object>) -> Vec<Object> {
+        objects.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        
+        // Density is measured on the candidate set before any suppression
+        let thresholds: Vec<f32> = match &self.nms_adaptation {
+            Some(adaptation) => objects.iter().enumerate()
+                .map(|(i, object)| {
+                    let neighbors = objects.iter().enumerate()
+                        .filter(|(j, other)| {
+                            *j != i && other.label == object.label && object.iou(other) > adaptation.neighbor_iou
+                        })
+                        .count();
+                    adaptation.threshold(self.iou_threshold, neighbors)
+                })
+                .collect(),
+            None => vec![self.iou_threshold; objects.len()],
+        };
+        
+        let mut suppressed = vec![false; objects.len()];
+        for i in 0..objects.len() {
+            if suppressed[i] {
+                continue;
+            }
+            for j in (i + 1)..objects.len() {
+                if !suppressed[j]
+                    && objects[j].label == objects[i].label
+                    && objects[i].iou(&objects[j]) > thresholds[i]
+                {
+                    suppressed[j] = true;
+                }
+            }
+        }
+        
+        objects.into_iter()
+            .zip(suppressed)
+            .filter(|(_, suppressed)| !suppressed)
+            .map(|(object, _)| object)
+            .collect()
     }
     
     /// Classify image
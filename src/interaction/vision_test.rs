@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interaction::{Image, ImageFormat, NmsAdaptation, Object, VisionError, VisionSystem};
+
+    /// A row of five heavily overlapping pedestrians plus a duplicated car
+    fn crowded_detections() -> Vec<Object> {
+        let mut objects = Vec::new();
+        for i in 0..5 {
+            let x = i as f32 * 0.3;
+            objects.push(Object::new("person", 0.9 - i as f32 * 0.01, (x, 0.0, 1.0, 2.0)));
+        }
+        objects.push(Object::new("car", 0.9, (10.0, 10.0, 1.0, 1.0)));
+        objects.push(Object::new("car", 0.8, (10.05, 10.0, 1.0, 1.0)));
+        objects
+    }
+
+    fn count(objects: &[Object], label: &str) -> usize {
+        objects.iter().filter(|o| o.label == label).count()
+    }
+
+    #[test]
+    fn test_iou() {
+        let a = Object::new("person", 0.9, (0.0, 0.0, 1.0, 2.0));
+        let b = Object::new("person", 0.8, (0.3, 0.0, 1.0, 2.0));
+        let c = Object::new("person", 0.8, (5.0, 0.0, 1.0, 2.0));
+
+        assert!((a.iou(&a) - 1.0).abs() < 1e-6);
+        assert!((a.iou(&b) - 1.4 / 2.6).abs() < 1e-6);
+        assert_eq!(a.iou(&c), 0.0);
+    }
+
+    #[test]
+    fn test_fixed_nms_drops_crowd() {
+        let vision = VisionSystem::new().unwrap();
+        let kept = vision.suppress_overlaps(crowded_detections());
+
+        assert_eq!(count(&kept, "person"), 3);
+        assert_eq!(count(&kept, "car"), 1);
+    }
+
+    #[test]
+    fn test_adaptive_nms_keeps_crowd_and_drops_duplicates() {
+        let mut vision = VisionSystem::new().unwrap();
+        vision.set_nms_adaptation(Some(NmsAdaptation {
+            neighbor_iou: 0.2,
+            max_threshold: 0.7,
+            saturation: 2,
+            exponent: 1.0,
+        })).unwrap();
+
+        let kept = vision.suppress_overlaps(crowded_detections());
+
+        assert_eq!(count(&kept, "person"), 5);
+        assert_eq!(count(&kept, "car"), 1);
+        assert!((kept.iter().find(|o| o.label == "car").unwrap().confidence - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_adaptation_rejects_max_below_base() {
+        let mut vision = VisionSystem::new().unwrap();
+        let adaptation = NmsAdaptation {
+            max_threshold: 0.4,
+            ..NmsAdaptation::default()
+        };
+
+        assert!(vision.set_nms_adaptation(Some(adaptation)).is_err());
+        assert!(vision.nms_adaptation.is_none());
+    }
+
+    #[test]
+    fn test_image_from_png_file() {
+        let dir = std::env::temp_dir().join(format!("vision_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("pixel.png");
+
+        let mut png = ::image::RgbImage::new(4, 3);
+        png.put_pixel(1, 2, ::image::Rgb([10, 20, 30]));
+        png.save_with_format(&path, ::image::ImageFormat::Png).unwrap();
+
+        let image = Image::from_file(&path).unwrap();
+        assert_eq!((image.width, image.height), (4, 3));
+        assert_eq!(image.format, ImageFormat::RGB.as_str());
+        assert_eq!(image.data.len(), 4 * 3 * 3);
+        let offset = (2 * 4 + 1) * 3;
+        assert_eq!(&image.data[offset..offset + 3], &[10, 20, 30]);
+        assert_eq!(image.get_metadata("source_format").map(String::as_str), Some("png"));
+
+        // Magic bytes win over a misleading extension
+        let renamed = dir.join("pixel.dat");
+        std::fs::copy(&path, &renamed).unwrap();
+        assert_eq!(Image::from_file(&renamed).unwrap().width, 4);
+
+        let unsupported = dir.join("notes.bmp");
+        std::fs::write(&unsupported, b"BM not really a bitmap").unwrap();
+        match Image::from_file(&unsupported) {
+            Err(VisionError::ImageError(msg)) => assert!(msg.contains("Unsupported image format")),
+            other => panic!("expected image error, got {:?}", other),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_image_convert_between_formats() {
+        let rgb = Image::new(vec![255, 0, 0, 0, 255, 0, 0, 0, 255, 10, 10, 10], 2, 2, ImageFormat::RGB.as_str());
+
+        let gray = rgb.convert(ImageFormat::Grayscale).unwrap();
+        assert_eq!(gray.format, "GRAY");
+        assert_eq!(gray.data, vec![76, 150, 29, 10]);
+
+        let rgba = gray.convert(ImageFormat::RGBA).unwrap();
+        assert_eq!(&rgba.data[0..4], &[76, 76, 76, 255]);
+        assert_eq!(rgba.convert(ImageFormat::Grayscale).unwrap().data, gray.data);
+        assert_eq!(rgb.convert(ImageFormat::RGBA).unwrap().convert(ImageFormat::RGB).unwrap().data, rgb.data);
+
+        let truncated = Image::new(vec![0; 5], 2, 1, "RGB");
+        assert!(matches!(truncated.convert(ImageFormat::Grayscale), Err(VisionError::ImageError(_))));
+        let unknown = Image::new(vec![0; 4], 2, 1, "YUV420");
+        assert!(matches!(unknown.convert(ImageFormat::RGB), Err(VisionError::ImageError(_))));
+    }
+}
@@ -1,6 +1,10 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use crate::core::id::IdGenerator;
 
 /// Memory error
 #[derive(Debug)]
@@ -9,6 +13,10 @@ pub enum MemoryError {
     AllocationError(String),
     /// Access error
     AccessError(String),
+    /// Memory limit would be exceeded
+    OutOfMemory(String),
+    /// Region overlaps a registered region
+    RegionAlreadyExists(String),
     /// Other error
     Other(String),
 }
@@ -20,6 +28,8 @@ impl fmt::Display for MemoryError {
         match self {
             MemoryError::AllocationError(msg) => write!(f, "Allocation error: {}", msg),
             MemoryError::AccessError(msg) => write!(f, "Access error: {}", msg),
+            MemoryError::OutOfMemory(msg) => write!(f, "Out of memory: {}", msg),
+            MemoryError::RegionAlreadyExists(msg) => write!(f, "Region already exists: {}", msg),
             MemoryError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -39,6 +49,8 @@ pub struct MemoryBlock {
     pub created_at: std::time::SystemTime,
     /// Block last access timestamp
     pub last_access: std::time::SystemTime,
+    /// Overwrite data with zeros when the block is dropped
+    pub zero_on_drop: bool,
 }
 
 impl MemoryBlock {
@@ -51,6 +63,56 @@ impl MemoryBlock {
             owner: None,
             created_at: std::time::SystemTime::now(),
             last_access: std::time::SystemTime::now(),
+            zero_on_drop: false,
+        }
+    }
+    
+    /// Set whether data is wiped on drop, for blocks holding sensitive data
+    pub fn set_zero_on_drop(&mut self, zero_on_drop: bool) {
+        self.zero_on_drop = zero_on_drop;
+    }
+    
+    /// Get raw data
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+    
+    /// Overwrite data with zeros, including spare capacity
+    ///
+    /// Uses volatile writes so the wipe is not optimized away even though
+    /// the data is never read again.
+    pub fn wipe(&mut self) {
+        let ptr = self.data.as_mut_ptr();
+        for i in 0..self.data.capacity() {
+            // SAFETY: `i` is within the allocation, and writing a byte to
+            // spare capacity does not read uninitialized memory
+            unsafe { std::ptr::write_volatile(ptr.add(i), 0) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+    
+    /// Resize data, zero-filling new bytes
+    ///
+    /// With `zero_on_drop`, data is copied into an exactly sized buffer and
+    /// the old buffer is wiped, so no copy is left in freed memory or in
+    /// spare capacity.
+    pub fn resize(&mut self, new_size: usize) {
+        if self.zero_on_drop {
+            let mut data = vec![0; new_size];
+            let kept = new_size.min(self.data.len());
+            data[..kept].copy_from_slice(&self.data[..kept]);
+            self.wipe();
+            self.data = data;
+        } else {
+            self.data.resize(new_size, 0);
+        }
+        self.size = new_size;
+    }
+    
+    /// Release data, wiping it if `zero_on_drop` is set
+    fn release(&mut self) {
+        if self.zero_on_drop {
+            self.wipe();
         }
     }
     
@@ -92,6 +154,108 @@ impl MemoryBlock {
     }
 }
 
+impl Drop for MemoryBlock {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// Address range registered with the memory manager
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryRegion {
+    /// Base address
+    pub base: usize,
+    /// Size in bytes
+    pub size: usize,
+    /// Region name
+    pub name: String,
+}
+
+impl MemoryRegion {
+    /// Create a new memory region
+    pub fn new(name: &str, base: usize, size: usize) -> Self {
+        Self {
+            base,
+            size,
+            name: name.to_string(),
+        }
+    }
+    
+    /// Get end address, one past the last byte
+    pub fn end(&self) -> usize {
+        self.base.saturating_add(self.size)
+    }
+    
+    /// Check if the region contains an address
+    pub fn contains(&self, address: usize) -> bool {
+        address >= self.base && address < self.end()
+    }
+    
+    /// Check if two regions share any address
+    pub fn overlaps(&self, other: &MemoryRegion) -> bool {
+        self.base < other.end() && other.base < self.end()
+    }
+}
+
+/// Eviction reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionReason {
+    /// Idle longer than the idle timeout
+    Idle,
+    /// Over the block cap
+    Capacity,
+}
+
+impl fmt::Display for EvictionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvictionReason::Idle => write!(f, "Idle"),
+            EvictionReason::Capacity => write!(f, "Capacity"),
+        }
+    }
+}
+
+/// Eviction policy
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvictionPolicy {
+    /// Evict blocks not accessed within this timeout
+    pub idle_timeout: Option<Duration>,
+    /// Maximum number of blocks kept
+    pub max_blocks: Option<usize>,
+}
+
+impl EvictionPolicy {
+    /// Create a new eviction policy that evicts nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set idle timeout
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+    
+    /// Set block cap
+    pub fn with_max_blocks(mut self, max_blocks: usize) -> Self {
+        self.max_blocks = Some(max_blocks);
+        self
+    }
+}
+
+/// Evicted block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictedBlock {
+    /// Block ID
+    pub id: String,
+    /// Block owner
+    pub owner: Option<String>,
+    /// Block size
+    pub size: usize,
+    /// Eviction reason
+    pub reason: EvictionReason,
+}
+
 /// Memory manager
 pub struct MemoryManager {
     /// Memory blocks
@@ -100,6 +264,16 @@ pub struct MemoryManager {
     pub total_size: usize,
     /// Used memory size
     pub used_size: usize,
+    /// Maximum bytes allocated at once; `None` is unlimited
+    pub memory_limit: Option<usize>,
+    /// Registered regions by base address, never overlapping
+    pub regions: BTreeMap<usize, MemoryRegion>,
+    /// Eviction policy
+    pub eviction_policy: Option<EvictionPolicy>,
+    /// Clock used for access tracking
+    pub clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+    /// Block ID generator
+    pub id_generator: IdGenerator,
 }
 
 impl MemoryManager {
@@ -109,12 +283,67 @@ impl MemoryManager {
             blocks: std::collections::HashMap::new(),
             total_size: 0,
             used_size: 0,
+            memory_limit: None,
+            regions: BTreeMap::new(),
+            eviction_policy: None,
+            clock: Box::new(SystemTime::now),
+            id_generator: IdGenerator::default(),
         })
     }
     
+    /// Set block ID generator
+    pub fn set_id_generator(&mut self, id_generator: IdGenerator) {
+        self.id_generator = id_generator;
+    }
+    
+    /// Set clock
+    pub fn set_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> SystemTime + Send + Sync + 'static,
+    {
+        self.clock = Box::new(clock);
+    }
+    
+    /// Set memory limit
+    pub fn set_memory_limit(&mut self, memory_limit: Option<usize>) {
+        self.memory_limit = memory_limit;
+    }
+    
+    /// Get bytes currently allocated
+    pub fn current_usage(&self) -> usize {
+        self.used_size
+    }
+    
+    /// Check that growing usage by `additional` bytes stays within the limit
+    fn check_limit(&self, additional: usize) -> Result<(), MemoryError> {
+        let limit = match self.memory_limit {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        
+        match self.current_usage().checked_add(additional) {
+            Some(requested) if requested <= limit => Ok(()),
+            _ => Err(MemoryError::OutOfMemory(format!(
+                "Requested {} bytes with {} of {} bytes in use",
+                additional, self.current_usage(), limit
+            ))),
+        }
+    }
+    
+    /// Set eviction policy
+    pub fn set_eviction_policy(&mut self, policy: Option<EvictionPolicy>) {
+        self.eviction_policy = policy;
+    }
+    
     /// Allocate memory
     pub fn allocate(&mut self, size: usize) -> Result<String, MemoryError> {
-        let block = MemoryBlock::new(size);
+        self.check_limit(size)?;
+        
+        let mut block = MemoryBlock::new(size);
+        block.id = self.id_generator.next_id();
+        let now = (self.clock)();
+        block.created_at = now;
+        block.last_access = now;
         let block_id = block.id.clone();
         
         self.blocks.insert(block_id.clone(), block);
@@ -123,6 +352,29 @@ impl MemoryManager {
         Ok(block_id)
     }
     
+    /// Resize a block, keeping its data up to the smaller size
+    ///
+    /// Only growth counts against the memory limit, so shrinking always
+    /// succeeds.
+    pub fn reallocate(&mut self, id: &str, new_size: usize) -> Result<(), MemoryError> {
+        let old_size = self.blocks.get(id).map(|b| b.size).ok_or_else(|| {
+            MemoryError::AccessError(format!("Block not found: id={}", id))
+        })?;
+        
+        if new_size > old_size {
+            self.check_limit(new_size - old_size)?;
+        }
+        
+        let now = (self.clock)();
+        if let Some(block) = self.blocks.get_mut(id) {
+            block.resize(new_size);
+            block.last_access = now;
+        }
+        self.used_size = self.used_size - old_size + new_size;
+        
+        Ok(())
+    }
+    
     /// Free memory
     pub fn free(&mut self, id: &str) -> Result<(), MemoryError> {
         let block = self.blocks.remove(id).ok_or_else(|| {
@@ -146,20 +398,137 @@ impl MemoryManager {
     
     /// Read memory
     pub fn read(&mut self, id: &str, offset: usize, length: usize) -> Result<&[u8], MemoryError> {
+        let now = (self.clock)();
         let block = self.blocks.get_mut(id).ok_or_else(|| {
             MemoryError::AccessError(format!("Block not found: id={}", id))
         })?;
         
-        block.read(offset, length)
+        block.read(offset, length)?;
+        block.last_access = now;
+        Ok(&block.data[offset..offset + length])
     }
     
     /// Write memory
     pub fn write(&mut self, id: &str, offset: usize, data: &[u8]) -> Result<(), MemoryError> {
+        let now = (self.clock)();
+        let block = self.blocks.get_mut(id).ok_or_else(|| {
+            MemoryError::AccessError(format!("Block not found: id={}", id))
+        })?;
+        
+        block.write(offset, data)?;
+        block.last_access = now;
+        Ok(())
+    }
+    
+    /// Mark block as accessed
+    pub fn touch(&mut self, id: &str) -> Result<(), MemoryError> {
+        let now = (self.clock)();
         let block = self.blocks.get_mut(id).ok_or_else(|| {
             MemoryError::AccessError(format!("Block not found: id={}", id))
         })?;
         
-        block.write(offset, data)
+        block.last_access = now;
+        Ok(())
+    }
+    
+    /// Evict blocks according to the eviction policy
+    ///
+    /// Idle blocks are evicted first; if the block cap is still exceeded,
+    /// the least recently accessed blocks are evicted next.
+    pub fn evict(&mut self) -> Vec<EvictedBlock> {
+        let policy = match &self.eviction_policy {
+            Some(policy) => policy.clone(),
+            None => return Vec::new(),
+        };
+        
+        let now = (self.clock)();
+        let mut candidates: Vec<(SystemTime, String)> = self.blocks.values()
+            .map(|b| (b.last_access, b.id.clone()))
+            .collect();
+        candidates.sort();
+        
+        let mut evicted = Vec::new();
+        
+        if let Some(idle_timeout) = policy.idle_timeout {
+            let idle: Vec<String> = candidates.iter()
+                .filter(|(last_access, _)| {
+                    now.duration_since(*last_access).unwrap_or_default() > idle_timeout
+                })
+                .map(|(_, id)| id.clone())
+                .collect();
+            
+            for id in idle {
+                if let Some(block) = self.evict_block(&id, EvictionReason::Idle) {
+                    evicted.push(block);
+                }
+            }
+            candidates.retain(|(_, id)| self.blocks.contains_key(id));
+        }
+        
+        if let Some(max_blocks) = policy.max_blocks {
+            let excess = self.blocks.len().saturating_sub(max_blocks);
+            for (_, id) in candidates.iter().take(excess) {
+                if let Some(block) = self.evict_block(id, EvictionReason::Capacity) {
+                    evicted.push(block);
+                }
+            }
+        }
+        
+        evicted
+    }
+    
+    /// Remove a block and record why
+    fn evict_block(&mut self, id: &str, reason: EvictionReason) -> Option<EvictedBlock> {
+        let block = self.blocks.remove(id)?;
+        self.used_size -= block.size;
+        
+        Some(EvictedBlock {
+            id: block.id.clone(),
+            owner: block.owner.clone(),
+            size: block.size,
+            reason,
+        })
+    }
+    
+    /// Register a region, rejecting any overlap with a registered region
+    ///
+    /// Registered regions never overlap, so only the nearest region at or
+    /// below `region.base` and the first region above it need checking.
+    pub fn register_region(&mut self, region: MemoryRegion) -> Result<(), MemoryError> {
+        if region.size == 0 {
+            return Err(MemoryError::AllocationError(format!(
+                "Region {} at {:#x} is empty",
+                region.name, region.base
+            )));
+        }
+        
+        let below = self.regions.range(..=region.base).next_back().map(|(_, r)| r);
+        let above = self.regions.range(region.base..).next().map(|(_, r)| r);
+        if let Some(existing) = below.into_iter().chain(above).find(|r| r.overlaps(&region)) {
+            return Err(MemoryError::RegionAlreadyExists(format!(
+                "{} [{:#x}, {:#x}) overlaps {} [{:#x}, {:#x})",
+                region.name, region.base, region.end(),
+                existing.name, existing.base, existing.end()
+            )));
+        }
+        
+        self.regions.insert(region.base, region);
+        Ok(())
+    }
+    
+    /// Unregister the region starting at `base`
+    pub fn unregister_region(&mut self, base: usize) -> Result<MemoryRegion, MemoryError> {
+        self.regions.remove(&base).ok_or_else(|| {
+            MemoryError::AccessError(format!("Region not found: base={:#x}", base))
+        })
+    }
+    
+    /// Find the region containing an address
+    pub fn find_region(&self, address: usize) -> Option<&MemoryRegion> {
+        self.regions.range(..=address)
+            .next_back()
+            .map(|(_, r)| r)
+            .filter(|r| r.contains(address))
     }
     
     /// Get memory usage
@@ -2,8 +2,8 @@ mod memory;
 mod process;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use memory::{Memory, MemoryManager, MemoryError};
-pub use process::{Process, ProcessManager, ProcessError};
+pub use memory::{Memory, MemoryManager, MemoryError, EvictionPolicy, EvictionReason, EvictedBlock, MemoryRegion, MemoryBlock};
+pub use process::{Process, ProcessManager, ProcessError, ProcessState, ProcessPriority, ProcessScheduler, RoundRobinScheduler, PriorityScheduler, WorkStealingScheduler, scheduler_for_policy, WorkloadSpec, WorkloadProcess, SchedulingPolicy, BenchmarkReport, scheduler_benchmark};
 
 // 导出特定函数，避免冲突
 pub use memory::init as memory_init;
@@ -1,7 +1,11 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
+use crate::core::id::IdGenerator;
+
 /// Process error
 #[derive(Debug)]
 pub enum ProcessError {
@@ -33,22 +37,56 @@ impl fmt::Display for ProcessError {
 pub enum ProcessState {
     /// Created
     Created,
+    /// Ready to run
+    Ready,
     /// Running
     Running,
     /// Waiting
     Waiting,
+    /// Suspended
+    Suspended,
     /// Terminated
     Terminated,
     /// Error
     Error,
 }
 
+impl ProcessState {
+    /// Check if a transition to `next` is legal
+    ///
+    /// Created -> Ready -> Running; Running -> Waiting, Ready or Terminated;
+    /// Waiting -> Ready. Running and Waiting processes may also be suspended
+    /// and resumed, waiting and suspended processes may be terminated, and
+    /// any live process may fail. Terminated and Error are final.
+    pub fn can_transition_to(self, next: ProcessState) -> bool {
+        use ProcessState::*;
+        
+        matches!(
+            (self, next),
+            (Created, Ready)
+                | (Ready, Running)
+                | (Running, Waiting)
+                | (Running, Ready)
+                | (Running, Terminated)
+                | (Waiting, Ready)
+                | (Running, Suspended)
+                | (Waiting, Suspended)
+                | (Suspended, Running)
+                | (Waiting, Terminated)
+                | (Suspended, Terminated)
+                | (Created | Ready | Running | Waiting | Suspended, Error)
+        )
+    }
+}
+
 impl fmt::Display for ProcessState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ProcessState::Created => write!(f, "Created"),
+            ProcessState::Ready => write!(f, "Ready"),
             ProcessState::Running => write!(f, "Running"),
             ProcessState::Waiting => write!(f, "Waiting"),
+            ProcessState::Suspended => write!(f, "Suspended"),
             ProcessState::Terminated => write!(f, "Terminated"),
             ProcessState::Error => write!(f, "Error"),
         }
@@ -80,6 +118,7 @@ impl fmt::Display for ProcessPriority {
 }
 
 /// Process
+#[derive(Debug, Clone)]
 pub struct Process {
     /// Process ID
     pub id: String,
@@ -101,6 +140,10 @@ pub struct Process {
     pub terminated_at: Option<std::time::SystemTime>,
     /// Process error
     pub error: Option<String>,
+    /// Memory usage in bytes
+    pub memory_usage: usize,
+    /// CPU usage in percent of one core
+    pub cpu_usage: f32,
 }
 
 impl Process {
@@ -117,9 +160,17 @@ impl Process {
             started_at: None,
             terminated_at: None,
             error: None,
+            memory_usage: 0,
+            cpu_usage: 0.0,
         }
     }
     
+    /// Record resource usage
+    pub fn set_usage(&mut self, memory_usage: usize, cpu_usage: f32) {
+        self.memory_usage = memory_usage;
+        self.cpu_usage = cpu_usage;
+    }
+    
     /// Set parent
     pub fn set_parent(&mut self, parent_id: &str) {
         self.parent_id = Some(parent_id.to_string());
@@ -145,6 +196,19 @@ impl Process {
         }
     }
     
+    /// Set state if the transition is legal, see `ProcessState::can_transition_to`
+    pub fn try_set_state(&mut self, state: ProcessState) -> Result<(), ProcessError> {
+        if !self.state.can_transition_to(state) {
+            return Err(ProcessError::ExecutionError(format!(
+                "Illegal state transition: id={}, {} -> {}",
+                self.id, self.state, state
+            )));
+        }
+        
+        self.set_state(state);
+        Ok(())
+    }
+    
     /// Set error
     pub fn set_error(&mut self, error: &str) {
         self.error = Some(error.to_string());
@@ -152,28 +216,763 @@ impl Process {
     }
 }
 
+/// Process scheduler
+pub trait ProcessScheduler: Send + Sync {
+    /// Get scheduler name
+    fn name(&self) -> &str;
+    
+    /// Add process
+    fn add_process(&self, process: Arc<Mutex<Process>>) -> Result<(), ProcessError>;
+    
+    /// Remove process
+    fn remove_process(&self, id: &str) -> Result<Arc<Mutex<Process>>, ProcessError>;
+    
+    /// Get next process to run
+    fn next_process(&self) -> Option<Arc<Mutex<Process>>>;
+    
+    /// Get process
+    fn get_process(&self, id: &str) -> Option<Arc<Mutex<Process>>>;
+    
+    /// Get all processes
+    fn get_all_processes(&self) -> Vec<Arc<Mutex<Process>>>;
+    
+    /// Get process count
+    fn get_process_count(&self) -> usize;
+}
+
+/// Get process ID, tolerating a poisoned lock
+fn process_id(process: &Arc<Mutex<Process>>) -> String {
+    match process.lock() {
+        Ok(p) => p.id.clone(),
+        Err(poisoned) => poisoned.into_inner().id.clone(),
+    }
+}
+
+/// Round robin scheduler state
+struct RoundRobinState {
+    /// Process queue
+    queue: Vec<Arc<Mutex<Process>>>,
+    /// Index of the next process to dispatch
+    current_index: usize,
+}
+
+/// Round robin scheduler
+pub struct RoundRobinScheduler {
+    /// Scheduler name
+    name: String,
+    /// Scheduler state
+    state: Mutex<RoundRobinState>,
+}
+
+impl RoundRobinScheduler {
+    /// Create a new round robin scheduler
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: Mutex::new(RoundRobinState {
+                queue: Vec::new(),
+                current_index: 0,
+            }),
+        }
+    }
+    
+    /// Lock scheduler state
+    fn lock_state(&self) -> Result<std::sync::MutexGuard<'_, RoundRobinState>, ProcessError> {
+        self.state.lock().map_err(|_| {
+            ProcessError::Other(format!("Scheduler {} lock poisoned", self.name))
+        })
+    }
+}
+
+impl ProcessScheduler for RoundRobinScheduler {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn add_process(&self, process: Arc<Mutex<Process>>) -> Result<(), ProcessError> {
+        let id = process_id(&process);
+        let mut state = self.lock_state()?;
+        
+        if state.queue.iter().any(|p| process_id(p) == id) {
+            return Err(ProcessError::CreationError(format!(
+                "Process already scheduled: id={}",
+                id
+            )));
+        }
+        
+        state.queue.push(process);
+        Ok(())
+    }
+    
+    fn remove_process(&self, id: &str) -> Result<Arc<Mutex<Process>>, ProcessError> {
+        let mut state = self.lock_state()?;
+        
+        let index = state.queue.iter().position(|p| process_id(p) == id).ok_or_else(|| {
+            ProcessError::TerminationError(format!("Process not scheduled: id={}", id))
+        })?;
+        
+        let process = state.queue.remove(index);
+        
+        // current_index points at the next process to dispatch. Removing an
+        // earlier entry shifts that process down by one; removing the entry at
+        // current_index leaves its successor in place, so no adjustment is needed.
+        if index < state.current_index {
+            state.current_index -= 1;
+        }
+        
+        if state.current_index >= state.queue.len() {
+            state.current_index = 0;
+        }
+        
+        Ok(process)
+    }
+    
+    fn next_process(&self) -> Option<Arc<Mutex<Process>>> {
+        let mut state = self.state.lock().ok()?;
+        
+        if state.queue.is_empty() {
+            return None;
+        }
+        
+        let index = state.current_index % state.queue.len();
+        let process = state.queue[index].clone();
+        state.current_index = (index + 1) % state.queue.len();
+        
+        Some(process)
+    }
+    
+    fn get_process(&self, id: &str) -> Option<Arc<Mutex<Process>>> {
+        let state = self.state.lock().ok()?;
+        state.queue.iter().find(|p| process_id(p) == id).cloned()
+    }
+    
+    fn get_all_processes(&self) -> Vec<Arc<Mutex<Process>>> {
+        self.state.lock().map(|s| s.queue.clone()).unwrap_or_default()
+    }
+    
+    fn get_process_count(&self) -> usize {
+        self.state.lock().map(|s| s.queue.len()).unwrap_or(0)
+    }
+}
+
+/// Per-worker process deque
+type WorkerDeque = Mutex<VecDeque<Arc<Mutex<Process>>>>;
+
+/// Work-stealing scheduler
+///
+/// Each worker has its own deque behind its own lock. A worker dispatches
+/// from the front of its deque and requeues the process at its back; when
+/// its deque is empty it steals from the back of the other workers' deques,
+/// starting with the next worker, and the stolen process migrates to it.
+/// A steal locks both deques, lower worker first, so a migrating process is
+/// always in some deque. Adds and removals hold the set of scheduled IDs
+/// while they touch a deque, so a process is never scheduled twice.
+pub struct WorkStealingScheduler {
+    /// Scheduler name
+    name: String,
+    /// Per-worker deques
+    deques: Vec<WorkerDeque>,
+    /// IDs of scheduled processes, locked before any deque
+    scheduled: Mutex<HashSet<String>>,
+    /// Worker receiving the next added process
+    next_add: AtomicUsize,
+    /// Worker served by the next `next_process` call
+    next_dispatch: AtomicUsize,
+}
+
+impl WorkStealingScheduler {
+    /// Create a new work-stealing scheduler with at least one worker
+    pub fn new(name: &str, workers: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            deques: (0..workers.max(1)).map(|_| Mutex::new(VecDeque::new())).collect(),
+            scheduled: Mutex::new(HashSet::new()),
+            next_add: AtomicUsize::new(0),
+            next_dispatch: AtomicUsize::new(0),
+        }
+    }
+    
+    /// Get worker count
+    pub fn worker_count(&self) -> usize {
+        self.deques.len()
+    }
+    
+    /// Add process to a specific worker's deque
+    pub fn add_process_to(&self, worker: usize, process: Arc<Mutex<Process>>) -> Result<(), ProcessError> {
+        let id = process_id(&process);
+        let deque = self.deques.get(worker).ok_or_else(|| {
+            ProcessError::CreationError(format!("Worker out of range: worker={}, workers={}", worker, self.deques.len()))
+        })?;
+        
+        let mut scheduled = self.lock_scheduled()?;
+        if scheduled.contains(&id) {
+            return Err(ProcessError::CreationError(format!(
+                "Process already scheduled: id={}",
+                id
+            )));
+        }
+        
+        self.lock_deque(deque)?.push_back(process);
+        scheduled.insert(id);
+        Ok(())
+    }
+    
+    /// Get next process for a worker, stealing if its own deque is empty
+    pub fn next_process_for(&self, worker: usize) -> Option<Arc<Mutex<Process>>> {
+        let own = self.deques.get(worker)?;
+        
+        {
+            let mut deque = own.lock().ok()?;
+            if let Some(process) = deque.pop_front() {
+                deque.push_back(process.clone());
+                return Some(process);
+            }
+        }
+        
+        self.steal(worker)
+    }
+    
+    /// Get the worker whose deque holds a process
+    pub fn worker_of(&self, id: &str) -> Option<usize> {
+        self.deques.iter().position(|deque| {
+            deque.lock().map(|d| d.iter().any(|p| process_id(p) == id)).unwrap_or(false)
+        })
+    }
+    
+    /// Move a process from the back of another worker's deque to the back of the thief's
+    fn steal(&self, thief: usize) -> Option<Arc<Mutex<Process>>> {
+        let workers = self.deques.len();
+        (1..workers).map(|offset| (thief + offset) % workers).find_map(|victim| {
+            // Lock both deques, lower worker first
+            let (low, high) = (thief.min(victim), thief.max(victim));
+            let mut low_deque = self.deques[low].lock().ok()?;
+            let mut high_deque = self.deques[high].lock().ok()?;
+            let (from, to) = if victim == low {
+                (&mut *low_deque, &mut *high_deque)
+            } else {
+                (&mut *high_deque, &mut *low_deque)
+            };
+            
+            let process = from.pop_back()?;
+            to.push_back(process.clone());
+            Some(process)
+        })
+    }
+    
+    /// Lock the set of scheduled IDs
+    fn lock_scheduled(&self) -> Result<std::sync::MutexGuard<'_, HashSet<String>>, ProcessError> {
+        self.scheduled.lock().map_err(|_| {
+            ProcessError::Other(format!("Scheduler {} lock poisoned", self.name))
+        })
+    }
+    
+    /// Lock a worker deque
+    fn lock_deque<'a>(&self, deque: &'a WorkerDeque) -> Result<std::sync::MutexGuard<'a, VecDeque<Arc<Mutex<Process>>>>, ProcessError> {
+        deque.lock().map_err(|_| {
+            ProcessError::Other(format!("Scheduler {} lock poisoned", self.name))
+        })
+    }
+}
+
+impl ProcessScheduler for WorkStealingScheduler {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn add_process(&self, process: Arc<Mutex<Process>>) -> Result<(), ProcessError> {
+        let worker = self.next_add.fetch_add(1, Ordering::Relaxed) % self.deques.len();
+        self.add_process_to(worker, process)
+    }
+    
+    fn remove_process(&self, id: &str) -> Result<Arc<Mutex<Process>>, ProcessError> {
+        let mut scheduled = self.lock_scheduled()?;
+        
+        for deque in &self.deques {
+            let mut deque = self.lock_deque(deque)?;
+            if let Some(index) = deque.iter().position(|p| process_id(p) == id) {
+                if let Some(process) = deque.remove(index) {
+                    scheduled.remove(id);
+                    return Ok(process);
+                }
+            }
+        }
+        
+        Err(ProcessError::TerminationError(format!("Process not scheduled: id={}", id)))
+    }
+    
+    fn next_process(&self) -> Option<Arc<Mutex<Process>>> {
+        let worker = self.next_dispatch.fetch_add(1, Ordering::Relaxed) % self.deques.len();
+        self.next_process_for(worker)
+    }
+    
+    fn get_process(&self, id: &str) -> Option<Arc<Mutex<Process>>> {
+        self.deques.iter().find_map(|deque| {
+            deque.lock().ok()?.iter().find(|p| process_id(p) == id).cloned()
+        })
+    }
+    
+    fn get_all_processes(&self) -> Vec<Arc<Mutex<Process>>> {
+        self.deques.iter()
+            .filter_map(|deque| deque.lock().ok().map(|d| d.iter().cloned().collect::<Vec<_>>()))
+            .flatten()
+            .collect()
+    }
+    
+    fn get_process_count(&self) -> usize {
+        self.deques.iter().map(|deque| deque.lock().map(|d| d.len()).unwrap_or(0)).sum()
+    }
+}
+
+/// Get process priority, tolerating a poisoned lock
+fn process_priority(process: &Arc<Mutex<Process>>) -> ProcessPriority {
+    match process.lock() {
+        Ok(p) => p.priority,
+        Err(poisoned) => poisoned.into_inner().priority,
+    }
+}
+
+/// Process queued in a priority scheduler
+struct PriorityEntry {
+    /// Process
+    process: Arc<Mutex<Process>>,
+    /// Scheduling rounds skipped since it last ran
+    skipped: u64,
+}
+
+/// Priority scheduler
+///
+/// Dispatches the process with the highest effective priority, oldest in
+/// the queue first among equals; a dispatched process moves to the back.
+/// With aging, a process's effective priority rises one level for every
+/// `starvation_threshold` rounds it has been skipped, so lower-priority
+/// processes cannot starve. Declared priorities are never changed.
+pub struct PriorityScheduler {
+    /// Scheduler name
+    name: String,
+    /// Skipped rounds per priority level boost, if aging is enabled
+    starvation_threshold: Option<u64>,
+    /// Process queue
+    queue: Mutex<Vec<PriorityEntry>>,
+}
+
+impl PriorityScheduler {
+    /// Create a new priority scheduler without aging
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            starvation_threshold: None,
+            queue: Mutex::new(Vec::new()),
+        }
+    }
+    
+    /// Create a new priority scheduler boosting a process one priority level
+    /// for every `threshold` rounds it is skipped
+    pub fn with_aging(name: &str, threshold: u64) -> Self {
+        Self {
+            starvation_threshold: Some(threshold.max(1)),
+            ..Self::new(name)
+        }
+    }
+    
+    /// Get starvation threshold, if aging is enabled
+    pub fn starvation_threshold(&self) -> Option<u64> {
+        self.starvation_threshold
+    }
+    
+    /// Get a process's current effective priority level
+    pub fn effective_priority(&self, id: &str) -> Option<u64> {
+        let queue = self.queue.lock().ok()?;
+        queue.iter()
+            .find(|e| process_id(&e.process) == id)
+            .map(|e| self.effective_level(e))
+    }
+    
+    /// Effective priority level of a queued process
+    fn effective_level(&self, entry: &PriorityEntry) -> u64 {
+        let boost = self.starvation_threshold.map_or(0, |threshold| entry.skipped / threshold);
+        priority_level(process_priority(&entry.process)) + boost
+    }
+    
+    /// Lock process queue
+    fn lock_queue(&self) -> Result<std::sync::MutexGuard<'_, Vec<PriorityEntry>>, ProcessError> {
+        self.queue.lock().map_err(|_| {
+            ProcessError::Other(format!("Scheduler {} lock poisoned", self.name))
+        })
+    }
+}
+
+impl ProcessScheduler for PriorityScheduler {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn add_process(&self, process: Arc<Mutex<Process>>) -> Result<(), ProcessError> {
+        let id = process_id(&process);
+        let mut queue = self.lock_queue()?;
+        
+        if queue.iter().any(|e| process_id(&e.process) == id) {
+            return Err(ProcessError::CreationError(format!(
+                "Process already scheduled: id={}",
+                id
+            )));
+        }
+        
+        queue.push(PriorityEntry { process, skipped: 0 });
+        Ok(())
+    }
+    
+    fn remove_process(&self, id: &str) -> Result<Arc<Mutex<Process>>, ProcessError> {
+        let mut queue = self.lock_queue()?;
+        
+        let index = queue.iter().position(|e| process_id(&e.process) == id).ok_or_else(|| {
+            ProcessError::TerminationError(format!("Process not scheduled: id={}", id))
+        })?;
+        
+        Ok(queue.remove(index).process)
+    }
+    
+    fn next_process(&self) -> Option<Arc<Mutex<Process>>> {
+        let mut queue = self.queue.lock().ok()?;
+        
+        let index = (0..queue.len())
+            .max_by_key(|&i| (self.effective_level(&queue[i]), std::cmp::Reverse(i)))?;
+        
+        for entry in queue.iter_mut() {
+            entry.skipped += 1;
+        }
+        
+        let mut entry = queue.remove(index);
+        entry.skipped = 0;
+        let process = entry.process.clone();
+        queue.push(entry);
+        
+        Some(process)
+    }
+    
+    fn get_process(&self, id: &str) -> Option<Arc<Mutex<Process>>> {
+        let queue = self.queue.lock().ok()?;
+        queue.iter().find(|e| process_id(&e.process) == id).map(|e| e.process.clone())
+    }
+    
+    fn get_all_processes(&self) -> Vec<Arc<Mutex<Process>>> {
+        self.queue.lock()
+            .map(|q| q.iter().map(|e| e.process.clone()).collect())
+            .unwrap_or_default()
+    }
+    
+    fn get_process_count(&self) -> usize {
+        self.queue.lock().map(|q| q.len()).unwrap_or(0)
+    }
+}
+
+/// Create a scheduler from a policy name: `round_robin`, `priority` or `work_stealing`
+pub fn scheduler_for_policy(policy: &str) -> Result<Box<dyn ProcessScheduler>, ProcessError> {
+    match policy {
+        "round_robin" => Ok(Box::new(RoundRobinScheduler::new(policy))),
+        "priority" => Ok(Box::new(PriorityScheduler::new(policy))),
+        "work_stealing" => {
+            let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+            Ok(Box::new(WorkStealingScheduler::new(policy, workers)))
+        },
+        _ => Err(ProcessError::CreationError(format!("Unknown scheduler policy: {}", policy))),
+    }
+}
+
+/// Process in a benchmark workload
+#[derive(Debug, Clone)]
+pub struct WorkloadProcess {
+    /// Process name
+    pub name: String,
+    /// Arrival tick
+    pub arrival: u64,
+    /// CPU ticks needed to complete
+    pub duration: u64,
+    /// Process priority
+    pub priority: ProcessPriority,
+}
+
+/// Workload model for scheduler benchmarks
+#[derive(Debug, Clone)]
+pub struct WorkloadSpec {
+    /// Processes
+    pub processes: Vec<WorkloadProcess>,
+    /// Continuous wait, in ticks, counted as a starvation incident
+    pub starvation_threshold: u64,
+}
+
+impl WorkloadSpec {
+    /// Create a new, empty workload
+    pub fn new(starvation_threshold: u64) -> Self {
+        Self {
+            processes: Vec::new(),
+            starvation_threshold,
+        }
+    }
+    
+    /// Add process
+    pub fn add_process(&mut self, name: &str, arrival: u64, duration: u64, priority: ProcessPriority) {
+        self.processes.push(WorkloadProcess {
+            name: name.to_string(),
+            arrival,
+            duration,
+            priority,
+        });
+    }
+    
+    /// Generate a random workload; the same seed yields the same workload
+    pub fn random(seed: u64, count: usize, max_arrival: u64, max_duration: u64, starvation_threshold: u64) -> Self {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let priorities = [ProcessPriority::Low, ProcessPriority::Normal, ProcessPriority::High, ProcessPriority::Critical];
+        
+        let mut workload = Self::new(starvation_threshold);
+        for i in 0..count {
+            let arrival = rng.gen_range(0..=max_arrival);
+            let duration = rng.gen_range(1..=max_duration.max(1));
+            let priority = priorities[rng.gen_range(0..priorities.len())];
+            workload.add_process(&format!("process-{}", i), arrival, duration, priority);
+        }
+        workload
+    }
+}
+
+/// Scheduling policy simulated by the scheduler benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulingPolicy {
+    /// Round robin with a fixed time quantum
+    RoundRobin { quantum: u64 },
+    /// Strict preemptive priority
+    Priority,
+    /// Completely fair scheduling, virtual runtime weighted by priority
+    Cfs,
+    /// Priority raised one level per `aging_interval` ticks of waiting
+    AgedPriority { aging_interval: u64 },
+}
+
+impl fmt::Display for SchedulingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulingPolicy::RoundRobin { quantum } => write!(f, "RoundRobin(quantum={})", quantum),
+            SchedulingPolicy::Priority => write!(f, "Priority"),
+            SchedulingPolicy::Cfs => write!(f, "CFS"),
+            SchedulingPolicy::AgedPriority { aging_interval } => write!(f, "AgedPriority(interval={})", aging_interval),
+        }
+    }
+}
+
+/// Scheduler benchmark report
+#[derive(Debug, Clone)]
+pub struct BenchmarkReport {
+    /// Policy
+    pub policy: SchedulingPolicy,
+    /// Completed processes
+    pub completed: usize,
+    /// Ticks until the last process completed
+    pub total_ticks: u64,
+    /// Completed processes per tick
+    pub throughput: f64,
+    /// Average ticks spent ready but not running
+    pub average_wait: f64,
+    /// Longest total wait of any process
+    pub max_wait: u64,
+    /// Waits longer than the starvation threshold
+    pub starvation_incidents: usize,
+}
+
+/// Simulated process state
+struct SimulatedProcess {
+    priority: ProcessPriority,
+    arrival: u64,
+    remaining: u64,
+    waiting_since: u64,
+    waited: u64,
+    vruntime: u64,
+    finished_at: Option<u64>,
+}
+
+/// Numeric priority level
+fn priority_level(priority: ProcessPriority) -> u64 {
+    match priority {
+        ProcessPriority::Low => 0,
+        ProcessPriority::Normal => 1,
+        ProcessPriority::High => 2,
+        ProcessPriority::Critical => 3,
+    }
+}
+
+/// Move arrived processes into the ready queue
+fn admit_arrivals(processes: &mut [SimulatedProcess], admitted: &mut [bool], queue: &mut Vec<usize>, now: u64) {
+    let mut arrivals: Vec<usize> = (0..processes.len())
+        .filter(|&i| !admitted[i] && processes[i].arrival <= now)
+        .collect();
+    arrivals.sort_by_key(|&i| (processes[i].arrival, i));
+    
+    // New processes start at the smallest virtual runtime so they cannot monopolize the CPU
+    let min_vruntime = queue.iter().map(|&i| processes[i].vruntime).min().unwrap_or(0);
+    
+    for i in arrivals {
+        admitted[i] = true;
+        processes[i].vruntime = min_vruntime;
+        queue.push(i);
+    }
+}
+
+/// Simulate a workload under a scheduling policy
+pub fn scheduler_benchmark(workload: &WorkloadSpec, policy: SchedulingPolicy) -> Result<BenchmarkReport, ProcessError> {
+    match policy {
+        SchedulingPolicy::RoundRobin { quantum: 0 } => {
+            return Err(ProcessError::Other("Round robin quantum must be positive".to_string()));
+        },
+        SchedulingPolicy::AgedPriority { aging_interval: 0 } => {
+            return Err(ProcessError::Other("Aging interval must be positive".to_string()));
+        },
+        _ => {},
+    }
+    
+    let mut processes: Vec<SimulatedProcess> = workload.processes.iter()
+        .map(|p| SimulatedProcess {
+            priority: p.priority,
+            arrival: p.arrival,
+            remaining: p.duration,
+            waiting_since: p.arrival,
+            waited: 0,
+            vruntime: 0,
+            finished_at: if p.duration == 0 { Some(p.arrival) } else { None },
+        })
+        .collect();
+    
+    // Ready queue in arrival order, used for round robin rotation
+    let mut queue: Vec<usize> = Vec::new();
+    let mut admitted: Vec<bool> = processes.iter().map(|p| p.finished_at.is_some()).collect();
+    let mut starvation_incidents = 0;
+    let mut now = 0;
+    
+    loop {
+        admit_arrivals(&mut processes, &mut admitted, &mut queue, now);
+        
+        if queue.is_empty() {
+            let next_arrival = processes.iter().enumerate()
+                .filter(|(i, _)| !admitted[*i])
+                .map(|(_, p)| p.arrival)
+                .min();
+            match next_arrival {
+                Some(arrival) => {
+                    now = arrival;
+                    continue;
+                },
+                None => break,
+            }
+        }
+        
+        let position = match policy {
+            SchedulingPolicy::RoundRobin { .. } => 0,
+            SchedulingPolicy::Priority => (0..queue.len())
+                .max_by_key(|&q| (priority_level(processes[queue[q]].priority), std::cmp::Reverse(q)))
+                .unwrap_or(0),
+            SchedulingPolicy::Cfs => (0..queue.len())
+                .min_by_key(|&q| (processes[queue[q]].vruntime, q))
+                .unwrap_or(0),
+            SchedulingPolicy::AgedPriority { aging_interval } => (0..queue.len())
+                .max_by_key(|&q| {
+                    let process = &processes[queue[q]];
+                    let boost = (now - process.waiting_since) / aging_interval;
+                    (priority_level(process.priority) + boost, std::cmp::Reverse(q))
+                })
+                .unwrap_or(0),
+        };
+        
+        let index = queue.remove(position);
+        let slice = match policy {
+            SchedulingPolicy::RoundRobin { quantum } => quantum,
+            _ => 1,
+        };
+        
+        let process = &mut processes[index];
+        let waited = now - process.waiting_since;
+        if waited > workload.starvation_threshold {
+            starvation_incidents += 1;
+        }
+        process.waited += waited;
+        
+        let ran = slice.min(process.remaining);
+        process.remaining -= ran;
+        process.vruntime += ran * 8 / (1 << priority_level(process.priority));
+        now += ran;
+        
+        if process.remaining == 0 {
+            process.finished_at = Some(now);
+        } else {
+            process.waiting_since = now;
+        }
+        
+        // Admit processes that arrived while this one ran before requeueing it
+        admit_arrivals(&mut processes, &mut admitted, &mut queue, now);
+        if processes[index].remaining > 0 {
+            queue.push(index);
+        }
+    }
+    
+    let completed = processes.iter().filter(|p| p.finished_at.is_some()).count();
+    let total_ticks = processes.iter().filter_map(|p| p.finished_at).max().unwrap_or(0);
+    let waits: Vec<u64> = processes.iter().map(|p| p.waited).collect();
+    
+    Ok(BenchmarkReport {
+        policy,
+        completed,
+        total_ticks,
+        throughput: if total_ticks > 0 { completed as f64 / total_ticks as f64 } else { 0.0 },
+        average_wait: if waits.is_empty() { 0.0 } else { waits.iter().sum::<u64>() as f64 / waits.len() as f64 },
+        max_wait: waits.iter().copied().max().unwrap_or(0),
+        starvation_incidents,
+    })
+}
+
 /// Process manager
+///
+/// The scheduler is the ready queue: processes are queued when created and
+/// leave it when they start or terminate, and the scheduler decides the
+/// order ready processes are dispatched in. It holds a copy of each process
+/// for ordering only; `processes` holds the live state.
 pub struct ProcessManager {
     /// Processes
     pub processes: std::collections::HashMap<String, Process>,
     /// Process execution handlers
     pub execution_handlers: std::collections::HashMap<String, Box<dyn Fn() -> Result<(), ProcessError> + Send + Sync>>,
+    /// Process ID generator
+    pub id_generator: IdGenerator,
+    /// Process scheduler
+    pub scheduler: Box<dyn ProcessScheduler>,
 }
 
 impl ProcessManager {
-    /// Create a new process manager
+    /// Create a new process manager with a round robin scheduler
     pub fn new() -> Result<Self, ProcessError> {
+        Self::with_scheduler_policy("round_robin")
+    }
+    
+    /// Create a new process manager with a scheduler policy, see `scheduler_for_policy`
+    pub fn with_scheduler_policy(scheduler_policy: &str) -> Result<Self, ProcessError> {
         Ok(Self {
             processes: std::collections::HashMap::new(),
             execution_handlers: std::collections::HashMap::new(),
+            id_generator: IdGenerator::default(),
+            scheduler: scheduler_for_policy(scheduler_policy)?,
         })
     }
     
-    /// Create process
+    /// Set process ID generator
+    pub fn set_id_generator(&mut self, id_generator: IdGenerator) {
+        self.id_generator = id_generator;
+    }
+    
+    /// Create process, ready to run
     pub fn create_process(&mut self, name: &str, priority: ProcessPriority) -> Result<String, ProcessError> {
-        let process = Process::new(name, priority);
+        let mut process = Process::new(name, priority);
+        process.id = self.id_generator.next_id();
+        process.try_set_state(ProcessState::Ready)?;
         let process_id = process.id.clone();
         
+        self.scheduler.add_process(Arc::new(Mutex::new(process.clone())))?;
         self.processes.insert(process_id.clone(), process);
         
         Ok(process_id)
@@ -211,14 +1010,19 @@ impl ProcessManager {
             ProcessError::ExecutionError(format!("Process not found: id={}", id))
         })?;
         
-        if process.state != ProcessState::Created && process.state != ProcessState::Waiting {
+        if !matches!(process.state, ProcessState::Created | ProcessState::Ready | ProcessState::Waiting) {
             return Err(ProcessError::ExecutionError(format!(
                 "Process is not in a startable state: id={}, state={:?}",
                 id, process.state
             )));
         }
         
-        process.set_state(ProcessState::Running);
+        // Created and waiting processes become ready before they run
+        if process.state != ProcessState::Ready {
+            process.try_set_state(ProcessState::Ready)?;
+        }
+        process.try_set_state(ProcessState::Running)?;
+        let _ = self.scheduler.remove_process(id);
         
         if let Some(handler) = self.execution_handlers.get(id) {
             if let Err(e) = handler() {
@@ -230,24 +1034,87 @@ impl ProcessManager {
         Ok(())
     }
     
-    /// Terminate process
-    pub fn terminate_process(&mut self, id: &str) -> Result<(), ProcessError> {
+    /// Suspend a running or waiting process
+    pub fn suspend_process(&mut self, id: &str) -> Result<(), ProcessError> {
         let process = self.processes.get_mut(id).ok_or_else(|| {
-            ProcessError::TerminationError(format!("Process not found: id={}", id))
+            ProcessError::ExecutionError(format!("Process not found: id={}", id))
         })?;
         
         if process.state != ProcessState::Running && process.state != ProcessState::Waiting {
-            return Err(ProcessError::TerminationError(format!(
-                "Process is not in a terminable state: id={}, state={:?}",
+            return Err(ProcessError::ExecutionError(format!(
+                "Process is not in a suspendable state: id={}, state={:?}",
                 id, process.state
             )));
         }
         
-        process.set_state(ProcessState::Terminated);
+        process.try_set_state(ProcessState::Suspended)?;
         
         Ok(())
     }
     
+    /// Resume a suspended process without re-running its execution handler
+    pub fn resume_process(&mut self, id: &str) -> Result<(), ProcessError> {
+        let process = self.processes.get_mut(id).ok_or_else(|| {
+            ProcessError::ExecutionError(format!("Process not found: id={}", id))
+        })?;
+        
+        if process.state != ProcessState::Suspended {
+            return Err(ProcessError::ExecutionError(format!(
+                "Process is not suspended: id={}, state={:?}",
+                id, process.state
+            )));
+        }
+        
+        // Keep the original start time
+        let started_at = process.started_at;
+        process.try_set_state(ProcessState::Running)?;
+        process.started_at = started_at;
+        
+        Ok(())
+    }
+    
+    /// Remove process and its execution handler
+    pub fn remove_process(&mut self, id: &str) -> Result<Process, ProcessError> {
+        self.execution_handlers.remove(id);
+        let process = self.processes.remove(id).ok_or_else(|| {
+            ProcessError::Other(format!("Process not found: id={}", id))
+        })?;
+        
+        // Started and terminated processes have already left the scheduler
+        let _ = self.scheduler.remove_process(id);
+        Ok(process)
+    }
+    
+    /// Terminate process
+    pub fn terminate_process(&mut self, id: &str) -> Result<(), ProcessError> {
+        let process = self.processes.get_mut(id).ok_or_else(|| {
+            ProcessError::TerminationError(format!("Process not found: id={}", id))
+        })?;
+        
+        process.try_set_state(ProcessState::Terminated)?;
+        let _ = self.scheduler.remove_process(id);
+        Ok(())
+    }
+    
+    /// Get the next ready process in scheduler order
+    pub fn next_process(&self) -> Option<String> {
+        (0..self.scheduler.get_process_count())
+            .filter_map(|_| self.scheduler.next_process())
+            .map(|process| process_id(&process))
+            .find(|id| self.processes.get(id).is_some_and(|p| p.state == ProcessState::Ready))
+    }
+    
+    /// Start the next ready process in scheduler order, returning its ID
+    pub fn dispatch_next(&mut self) -> Result<Option<String>, ProcessError> {
+        let id = match self.next_process() {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        
+        self.start_process(&id)?;
+        Ok(Some(id))
+    }
+    
     /// Get processes by state
     pub fn get_processes_by_state(&self, state: ProcessState) -> Vec<&Process> {
         self.processes.values()
@@ -262,6 +1129,34 @@ impl ProcessManager {
             .collect()
     }
     
+    /// Get total memory usage of all processes in bytes
+    ///
+    /// Locking: processes are owned by the manager, so this takes no process
+    /// locks and is safe to call while a scheduler's `Arc<Mutex<Process>>` is
+    /// held elsewhere. Only the caller's lock on the manager is needed.
+    pub fn total_memory_usage(&self) -> usize {
+        self.processes.values().map(|p| p.memory_usage).sum()
+    }
+    
+    /// Get total CPU usage of all processes in percent of one core
+    ///
+    /// Takes no process locks, see `total_memory_usage`.
+    pub fn total_cpu_usage(&self) -> f32 {
+        self.processes.values().map(|p| p.cpu_usage).sum()
+    }
+    
+    /// Get IDs of processes using more than `threshold` bytes, sorted
+    ///
+    /// Takes no process locks, see `total_memory_usage`.
+    pub fn processes_over_memory(&self, threshold: usize) -> Vec<String> {
+        let mut ids: Vec<String> = self.processes.values()
+            .filter(|p| p.memory_usage > threshold)
+            .map(|p| p.id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+    
     /// Get child processes
     pub fn get_child_processes(&self, parent_id: &str) -> Vec<&Process> {
         self.processes.values()
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::kernel::process::{Process, ProcessState, ProcessError};
+    use crate::kernel::process::{Process, ProcessState, ProcessError, ProcessPriority, ProcessScheduler, RoundRobinScheduler, WorkStealingScheduler};
+    use crate::kernel::process::{scheduler_benchmark, ProcessManager, SchedulingPolicy, WorkloadSpec};
+    use crate::kernel::process::PriorityScheduler;
+    use crate::core::id::IdGenerator;
     use std::sync::{Arc, Mutex};
 
     #[test]
@@ -123,4 +126,343 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    fn create_round_robin(names: &[&str]) -> (RoundRobinScheduler, Vec<String>) {
+        let scheduler = RoundRobinScheduler::new("round_robin");
+        let mut ids = Vec::new();
+        for name in names {
+            let process = Process::new(name, ProcessPriority::Normal);
+            ids.push(process.id.clone());
+            scheduler.add_process(Arc::new(Mutex::new(process))).unwrap();
+        }
+        (scheduler, ids)
+    }
+
+    fn dispatch(scheduler: &RoundRobinScheduler, count: usize) -> Vec<String> {
+        (0..count)
+            .map(|_| scheduler.next_process().unwrap().lock().unwrap().name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_round_robin_remove_head() {
+        let (scheduler, ids) = create_round_robin(&["a", "b", "c", "d"]);
+        assert_eq!(dispatch(&scheduler, 1), vec!["a"]);
+        
+        scheduler.remove_process(&ids[0]).unwrap();
+        assert_eq!(dispatch(&scheduler, 4), vec!["b", "c", "d", "b"]);
+    }
+
+    #[test]
+    fn test_round_robin_remove_middle() {
+        let (scheduler, ids) = create_round_robin(&["a", "b", "c", "d"]);
+        assert_eq!(dispatch(&scheduler, 1), vec!["a"]);
+        
+        scheduler.remove_process(&ids[2]).unwrap();
+        assert_eq!(dispatch(&scheduler, 4), vec!["b", "d", "a", "b"]);
+    }
+
+    #[test]
+    fn test_round_robin_remove_current() {
+        let (scheduler, ids) = create_round_robin(&["a", "b", "c", "d"]);
+        assert_eq!(dispatch(&scheduler, 1), vec!["a"]);
+        
+        // "b" is the next process to dispatch
+        scheduler.remove_process(&ids[1]).unwrap();
+        assert_eq!(dispatch(&scheduler, 4), vec!["c", "d", "a", "c"]);
+    }
+
+    #[test]
+    fn test_round_robin_remove_tail() {
+        let (scheduler, ids) = create_round_robin(&["a", "b", "c", "d"]);
+        assert_eq!(dispatch(&scheduler, 3), vec!["a", "b", "c"]);
+        
+        scheduler.remove_process(&ids[3]).unwrap();
+        assert_eq!(dispatch(&scheduler, 4), vec!["a", "b", "c", "a"]);
+        assert_eq!(scheduler.get_process_count(), 3);
+    }
+
+    /// One long low priority batch job competing with a stream of short interactive jobs
+    fn interactive_workload() -> WorkloadSpec {
+        let mut workload = WorkloadSpec::new(10);
+        workload.add_process("batch", 0, 20, ProcessPriority::Low);
+        for i in 0..20 {
+            workload.add_process(&format!("interactive-{}", i), i * 2, 2, ProcessPriority::High);
+        }
+        workload
+    }
+
+    #[test]
+    fn test_scheduler_benchmark_wait_time_ordering() {
+        let workload = interactive_workload();
+        let round_robin = scheduler_benchmark(&workload, SchedulingPolicy::RoundRobin { quantum: 4 }).unwrap();
+        let priority = scheduler_benchmark(&workload, SchedulingPolicy::Priority).unwrap();
+
+        assert_eq!(round_robin.completed, 21);
+        assert_eq!(priority.completed, 21);
+        assert_eq!(round_robin.total_ticks, 60);
+        assert_eq!(priority.throughput, round_robin.throughput);
+        assert!(priority.average_wait < round_robin.average_wait);
+        assert!(priority.starvation_incidents < round_robin.starvation_incidents);
+    }
+
+    #[test]
+    fn test_scheduler_benchmark_aging_prevents_starvation() {
+        let workload = interactive_workload();
+        let priority = scheduler_benchmark(&workload, SchedulingPolicy::Priority).unwrap();
+        let aged = scheduler_benchmark(&workload, SchedulingPolicy::AgedPriority { aging_interval: 5 }).unwrap();
+
+        assert_eq!(priority.starvation_incidents, 1);
+        assert_eq!(aged.starvation_incidents, 0);
+        assert!(scheduler_benchmark(&workload, SchedulingPolicy::RoundRobin { quantum: 0 }).is_err());
+    }
+
+    #[test]
+    fn test_process_ids_not_reused_after_removal() {
+        let mut manager = ProcessManager::new().unwrap();
+        manager.set_id_generator(IdGenerator::sequential("proc"));
+
+        let first = manager.create_process("indexer", ProcessPriority::Normal).unwrap();
+        let second = manager.create_process("planner", ProcessPriority::High).unwrap();
+        assert_eq!((first.as_str(), second.as_str()), ("proc-1", "proc-2"));
+
+        manager.remove_process(&second).unwrap();
+        manager.remove_process(&first).unwrap();
+        assert!(manager.remove_process(&first).is_err());
+
+        let recreated = manager.create_process("planner", ProcessPriority::High).unwrap();
+        assert_eq!(recreated, "proc-3");
+        assert_eq!(manager.get_process(&recreated).unwrap().name, "planner");
+    }
+
+    fn shared_process(name: &str) -> (String, Arc<Mutex<Process>>) {
+        let process = Process::new(name, ProcessPriority::Normal);
+        (process.id.clone(), Arc::new(Mutex::new(process)))
+    }
+
+    #[test]
+    fn test_work_stealing_idle_worker_steals() {
+        let scheduler = WorkStealingScheduler::new("work_stealing", 2);
+        let (first, process) = shared_process("a");
+        scheduler.add_process_to(0, process).unwrap();
+        let (second, process) = shared_process("b");
+        scheduler.add_process_to(0, process).unwrap();
+
+        // Worker 1 is idle and steals from the back of worker 0's deque
+        let stolen = scheduler.next_process_for(1).unwrap();
+        assert_eq!(stolen.lock().unwrap().id, second);
+        assert_eq!(scheduler.worker_of(&second), Some(1));
+        assert_eq!(scheduler.next_process_for(0).unwrap().lock().unwrap().id, first);
+
+        // Lookups see every deque
+        assert_eq!(scheduler.get_process_count(), 2);
+        assert!(scheduler.get_process(&first).is_some());
+        assert!(scheduler.get_process(&second).is_some());
+        assert_eq!(scheduler.get_all_processes().len(), 2);
+
+        let (_, duplicate) = shared_process("dup");
+        duplicate.lock().unwrap().id = first.clone();
+        assert!(scheduler.add_process_to(1, duplicate).is_err());
+
+        scheduler.remove_process(&first).unwrap();
+        assert_eq!(scheduler.worker_of(&first), None);
+        // Worker 0 is now idle and steals "b" back
+        assert_eq!(scheduler.next_process_for(0).unwrap().lock().unwrap().id, second);
+        assert_eq!(scheduler.worker_of(&second), Some(0));
+    }
+
+    #[test]
+    fn test_process_manager_scheduler_policy() {
+        let manager = ProcessManager::with_scheduler_policy("work_stealing").unwrap();
+        assert_eq!(manager.scheduler.name(), "work_stealing");
+        assert_eq!(ProcessManager::new().unwrap().scheduler.name(), "round_robin");
+        assert!(matches!(
+            ProcessManager::with_scheduler_policy("lottery"),
+            Err(ProcessError::CreationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_process_manager_dispatches_in_scheduler_order() {
+        let mut manager = ProcessManager::with_scheduler_policy("priority").unwrap();
+        let low = manager.create_process("indexer", ProcessPriority::Low).unwrap();
+        let high = manager.create_process("planner", ProcessPriority::High).unwrap();
+        assert_eq!(manager.scheduler.get_process_count(), 2);
+
+        assert_eq!(manager.next_process(), Some(high.clone()));
+        assert_eq!(manager.dispatch_next().unwrap(), Some(high.clone()));
+        assert_eq!(manager.get_process(&high).unwrap().state, ProcessState::Running);
+
+        // Started processes leave the ready queue
+        assert_eq!(manager.scheduler.get_process_count(), 1);
+        assert_eq!(manager.dispatch_next().unwrap(), Some(low.clone()));
+        assert_eq!(manager.dispatch_next().unwrap(), None);
+
+        let queued = manager.create_process("trainer", ProcessPriority::Normal).unwrap();
+        manager.remove_process(&queued).unwrap();
+        assert_eq!(manager.scheduler.get_process_count(), 0);
+
+        let mut stealing = ProcessManager::with_scheduler_policy("work_stealing").unwrap();
+        let mut ids: Vec<String> = (0..4)
+            .map(|i| stealing.create_process(&format!("p{}", i), ProcessPriority::Normal).unwrap())
+            .collect();
+        ids.sort();
+        assert_eq!(stealing.scheduler.get_process_count(), 4);
+        let mut dispatched: Vec<String> = (0..4).filter_map(|_| stealing.dispatch_next().unwrap()).collect();
+        dispatched.sort();
+        assert_eq!(dispatched, ids);
+    }
+
+    #[test]
+    fn test_work_stealing_concurrent_duplicate_add() {
+        let scheduler = Arc::new(WorkStealingScheduler::new("work_stealing", 4));
+        let (id, _) = shared_process("a");
+
+        let handles: Vec<_> = (0..4).map(|worker| {
+            let scheduler = scheduler.clone();
+            let id = id.clone();
+            std::thread::spawn(move || {
+                let mut process = Process::new("a", ProcessPriority::Normal);
+                process.id = id;
+                scheduler.add_process_to(worker, Arc::new(Mutex::new(process))).is_ok()
+            })
+        }).collect();
+
+        let added = handles.into_iter().map(|h| h.join().unwrap()).filter(|&ok| ok).count();
+        assert_eq!(added, 1);
+        assert_eq!(scheduler.get_process_count(), 1);
+    }
+
+    #[test]
+    fn test_process_manager_usage_rollups() {
+        let mut manager = ProcessManager::new().unwrap();
+        let usages = [("indexer", 4096, 12.5), ("planner", 1024, 50.0), ("trainer", 65536, 200.0)];
+        let mut ids = Vec::new();
+        for (name, memory, cpu) in usages {
+            let id = manager.create_process(name, ProcessPriority::Normal).unwrap();
+            manager.get_process_mut(&id).unwrap().set_usage(memory, cpu);
+            ids.push(id);
+        }
+
+        assert_eq!(manager.total_memory_usage(), 4096 + 1024 + 65536);
+        assert!((manager.total_cpu_usage() - 262.5).abs() < f32::EPSILON);
+
+        let mut expected = vec![ids[0].clone(), ids[2].clone()];
+        expected.sort();
+        assert_eq!(manager.processes_over_memory(2048), expected);
+        assert_eq!(manager.processes_over_memory(65536), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_process_legal_state_transitions() {
+        let legal = [
+            (ProcessState::Created, ProcessState::Ready),
+            (ProcessState::Ready, ProcessState::Running),
+            (ProcessState::Running, ProcessState::Waiting),
+            (ProcessState::Running, ProcessState::Ready),
+            (ProcessState::Running, ProcessState::Terminated),
+            (ProcessState::Waiting, ProcessState::Ready),
+        ];
+        for (from, to) in legal {
+            let mut process = Process::new("worker", ProcessPriority::Normal);
+            process.set_state(from);
+            assert!(process.try_set_state(to).is_ok(), "{} -> {}", from, to);
+            assert_eq!(process.state, to);
+        }
+
+        let mut process = Process::new("worker", ProcessPriority::Normal);
+        process.try_set_state(ProcessState::Ready).unwrap();
+        process.try_set_state(ProcessState::Running).unwrap();
+        assert!(process.started_at.is_some());
+        process.try_set_state(ProcessState::Terminated).unwrap();
+        assert!(process.terminated_at.is_some());
+    }
+
+    #[test]
+    fn test_process_illegal_state_transition() {
+        let mut process = Process::new("worker", ProcessPriority::Normal);
+        process.set_state(ProcessState::Terminated);
+
+        match process.try_set_state(ProcessState::Running) {
+            Err(ProcessError::ExecutionError(msg)) => {
+                assert!(msg.contains("Terminated -> Running"), "{}", msg);
+            },
+            other => panic!("expected illegal transition, got {:?}", other),
+        }
+        assert_eq!(process.state, ProcessState::Terminated);
+
+        let mut manager = ProcessManager::new().unwrap();
+        let id = manager.create_process("worker", ProcessPriority::Normal).unwrap();
+        assert!(matches!(manager.terminate_process(&id), Err(ProcessError::ExecutionError(_))));
+        manager.start_process(&id).unwrap();
+        manager.terminate_process(&id).unwrap();
+        assert!(manager.terminate_process(&id).is_err());
+    }
+
+    #[test]
+    fn test_process_manager_follows_state_machine() {
+        let mut manager = ProcessManager::new().unwrap();
+        let id = manager.create_process("worker", ProcessPriority::Normal).unwrap();
+        assert_eq!(manager.get_process(&id).unwrap().state, ProcessState::Ready);
+
+        manager.start_process(&id).unwrap();
+        assert_eq!(manager.get_process(&id).unwrap().state, ProcessState::Running);
+        let started_at = manager.get_process(&id).unwrap().started_at;
+
+        manager.suspend_process(&id).unwrap();
+        manager.resume_process(&id).unwrap();
+        assert_eq!(manager.get_process(&id).unwrap().state, ProcessState::Running);
+        assert_eq!(manager.get_process(&id).unwrap().started_at, started_at);
+
+        // A waiting process goes back through Ready when restarted
+        manager.get_process_mut(&id).unwrap().try_set_state(ProcessState::Waiting).unwrap();
+        manager.start_process(&id).unwrap();
+        assert_eq!(manager.get_process(&id).unwrap().state, ProcessState::Running);
+
+        manager.terminate_process(&id).unwrap();
+        assert!(manager.start_process(&id).is_err());
+        assert!(manager.suspend_process(&id).is_err());
+    }
+
+    fn dispatch_priorities(scheduler: &PriorityScheduler, rounds: usize) -> Vec<ProcessPriority> {
+        (0..rounds)
+            .map(|_| scheduler.next_process().unwrap().lock().unwrap().priority)
+            .collect()
+    }
+
+    fn add_prioritized(scheduler: &PriorityScheduler, priorities: &[ProcessPriority]) -> Vec<String> {
+        priorities.iter().enumerate().map(|(i, &priority)| {
+            let process = Process::new(&format!("p{}", i), priority);
+            let id = process.id.clone();
+            scheduler.add_process(Arc::new(Mutex::new(process))).unwrap();
+            id
+        }).collect()
+    }
+
+    #[test]
+    fn test_priority_scheduler_without_aging_starves_low() {
+        let scheduler = PriorityScheduler::new("priority");
+        add_prioritized(&scheduler, &[ProcessPriority::Low, ProcessPriority::High, ProcessPriority::High]);
+
+        let dispatched = dispatch_priorities(&scheduler, 50);
+        assert!(dispatched.iter().all(|&p| p == ProcessPriority::High));
+    }
+
+    #[test]
+    fn test_priority_scheduler_aging_schedules_low() {
+        let scheduler = PriorityScheduler::with_aging("priority", 3);
+        let ids = add_prioritized(&scheduler, &[ProcessPriority::Low, ProcessPriority::High, ProcessPriority::High]);
+
+        let dispatched = dispatch_priorities(&scheduler, 20);
+        let low_rounds = dispatched.iter().filter(|&&p| p == ProcessPriority::Low).count();
+        assert!(low_rounds >= 2, "dispatched: {:?}", dispatched);
+        // High processes keep most of the CPU
+        assert!(dispatched.iter().filter(|&&p| p == ProcessPriority::High).count() > low_rounds);
+
+        // The boost is not written back to the process
+        let low = scheduler.get_process(&ids[0]).unwrap();
+        assert_eq!(low.lock().unwrap().priority, ProcessPriority::Low);
+        assert!(scheduler.effective_priority(&ids[0]).is_some());
+        assert_eq!(scheduler.starvation_threshold(), Some(3));
+    }
 }
@@ -12,6 +12,7 @@ pub mod system;
 // Core模块导出
 pub use crate::core::config::{Config, ConfigManager, ConfigError};
 pub use crate::core::context::{Context, ContextManager, ContextError};
+pub use crate::core::id::{IdGenerator, IdStrategy};
 pub use crate::core::integration::{Integration as CoreIntegration, IntegrationManager as CoreIntegrationManager, IntegrationError as CoreIntegrationError};
 pub use crate::core::lifecycle::{Lifecycle as CoreLifecycle, LifecycleManager as CoreLifecycleManager, LifecycleError as CoreLifecycleError};
 
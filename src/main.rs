@@ -1,11 +1,19 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 use std::process::exit;
 
-// 导入核心模块
-use rust_agi_os::core::{Config, Context, ContextError, LifecycleManager};
-use rust_agi_os::system::{ResourceManager, MonitoringSystem, SystemConfig};
+// 导入系统模块
+use rust_agi_os::system::{
+    ResourceManager, MonitoringSystem, SystemConfig, ConfigValue,
+    LifecycleManager, LifecycleComponent, LifecycleState, LifecycleError, FindingSeverity,
+    HealthCheck, HealthStatus, CriticalState,
+    IdleDetector, IdleTransition, DEFAULT_IDLE_TIMEOUT, IntegrationInterface,
+    SessionRecorder, LeakedRegion, COUNTER_PROCESSES_RUN, COUNTER_PLANS_COMPLETED,
+    COUNTER_THREATS_DETECTED, COUNTER_VERIFICATIONS_PASSED, COUNTER_VERIFICATIONS_FAILED,
+    AlertPolicy, METRIC_PROCESS_CPU_USAGE, suspend_process_action, process_cpu_metrics,
+    LatencyTracer, LatencyStage, LatencyError, Ingress, ResourceError
+};
 use rust_agi_os::security::{SandboxManager, VerificationSystem, ThreatDetectionSystem, AccessControlSystem};
 use rust_agi_os::interaction::{
     NaturalLanguageProcessor, VisionSystem, SpeechSystem, 
@@ -13,9 +21,9 @@ use rust_agi_os::interaction::{
     ContextManager, InterfaceManager
 };
 use rust_agi_os::kernel::{MemoryManager, ProcessManager};
-use rust_agi_os::meta_reasoning::{PlanningSystem, ReasoningSystem};
+use rust_agi_os::meta_reasoning::{PlanningSystem, ReasoningSystem, PlanStepStatus};
 use rust_agi_os::neuro_symbolic::{
-    NeuralNetwork, SymbolicSystem, KnowledgeBase, 
+    NeuralNetwork, SharedNeuralNetwork, SymbolicSystem, KnowledgeBase, 
     LearningSystem, NeuroSymbolicIntegration
 };
 use rust_agi_os::reflection::{PerformanceMonitor, ReflectionSystem};
@@ -24,6 +32,9 @@ use rust_agi_os::gui::{
     DesktopEnvironment, InputManager
 };
 
+/// 默认的子系统布线配置路径
+const DEFAULT_WIRING_PATH: &str = "wiring.json";
+
 /// 应用程序错误
 #[derive(Debug)]
 enum AppError {
@@ -60,6 +71,182 @@ enum AppState {
     Stopped,
 }
 
+/// 子系统的生命周期操作，子系统错误包装为`LifecycleError::Other`
+type SubsystemHook = Box<dyn FnMut() -> Result<(), LifecycleError> + Send + Sync>;
+
+/// 由生命周期管理器统一初始化、启动、更新和停止的子系统
+///
+/// 各子系统的错误类型不同，适配器把它们统一包装为`LifecycleError`，
+/// 更新耗时记录到会话统计中。
+struct SubsystemComponent {
+    /// 子系统名称
+    name: &'static str,
+    /// 组件状态
+    state: LifecycleState,
+    /// 会话统计
+    session: Arc<Mutex<SessionRecorder>>,
+    /// 初始化
+    initialize: SubsystemHook,
+    /// 启动
+    start: SubsystemHook,
+    /// 更新
+    update: SubsystemHook,
+    /// 停止
+    stop: SubsystemHook,
+}
+
+impl SubsystemComponent {
+    /// 根据操作结果切换状态，失败时进入错误状态
+    ///
+    /// 关键状态损坏的错误原样传递，由生命周期管理器停机。
+    fn transition(
+        &mut self,
+        result: Result<(), LifecycleError>,
+        next: LifecycleState,
+        error: fn(String) -> LifecycleError,
+    ) -> Result<(), LifecycleError> {
+        match result {
+            Ok(()) => {
+                self.state = next;
+                Ok(())
+            },
+            Err(LifecycleError::StateCorrupted(reason)) => {
+                self.state = LifecycleState::Error;
+                Err(LifecycleError::StateCorrupted(format!("{}: {}", self.name, reason)))
+            },
+            Err(LifecycleError::Other(e)) => {
+                self.state = LifecycleState::Error;
+                Err(error(format!("{}: {}", self.name, e)))
+            },
+            Err(e) => {
+                self.state = LifecycleState::Error;
+                Err(error(format!("{}: {}", self.name, e)))
+            },
+        }
+    }
+}
+
+impl LifecycleComponent for SubsystemComponent {
+    fn name(&self) -> &str {
+        self.name
+    }
+    
+    fn initialize(&mut self) -> Result<(), LifecycleError> {
+        let result = (self.initialize)();
+        self.transition(result, LifecycleState::Initialized, LifecycleError::InitializationError)
+    }
+    
+    fn start(&mut self) -> Result<(), LifecycleError> {
+        let result = (self.start)();
+        self.transition(result, LifecycleState::Running, LifecycleError::StartError)
+    }
+    
+    fn stop(&mut self) -> Result<(), LifecycleError> {
+        let result = (self.stop)();
+        self.transition(result, LifecycleState::Stopped, LifecycleError::StopError)
+    }
+    
+    fn state(&self) -> LifecycleState {
+        self.state
+    }
+    
+    fn update(&mut self) -> Result<(), LifecycleError> {
+        let result = timed_update(&self.session, self.name, || (self.update)());
+        let state = self.state;
+        self.transition(result, state, LifecycleError::Other)
+    }
+}
+
+/// 构造调用子系统`$method`的生命周期操作，`$lock`为获取子系统的加锁方法
+macro_rules! subsystem_hook {
+    ($handle:expr, $lock:ident, $method:ident) => {{
+        let handle = $handle.clone();
+        Box::new(move || {
+            handle.$lock().unwrap().$method().map_err(|e| LifecycleError::Other(e.to_string()))
+        }) as SubsystemHook
+    }};
+}
+
+/// 构造在关键状态上调用子系统`$method`的生命周期操作，锁中毒时先重新初始化状态
+macro_rules! critical_hook {
+    ($state:expr, $method:ident) => {{
+        let state = $state.clone();
+        Box::new(move || {
+            state.with(|subsystem| subsystem.$method().map_err(|e| LifecycleError::Other(e.to_string())))?
+        }) as SubsystemHook
+    }};
+}
+
+/// 构造子系统的生命周期组件
+macro_rules! subsystem {
+    ($session:expr, $name:literal, critical $state:expr) => {
+        SubsystemComponent {
+            name: $name,
+            state: LifecycleState::Uninitialized,
+            session: $session.clone(),
+            initialize: critical_hook!($state, initialize),
+            start: critical_hook!($state, start),
+            update: critical_hook!($state, update),
+            stop: critical_hook!($state, stop),
+        }
+    };
+    ($session:expr, $name:literal, $handle:expr) => {
+        subsystem!($session, $name, $handle, lock)
+    };
+    ($session:expr, $name:literal, $handle:expr, $lock:ident) => {
+        SubsystemComponent {
+            name: $name,
+            state: LifecycleState::Uninitialized,
+            session: $session.clone(),
+            initialize: subsystem_hook!($handle, $lock, initialize),
+            start: subsystem_hook!($handle, $lock, start),
+            update: subsystem_hook!($handle, $lock, update),
+            stop: subsystem_hook!($handle, $lock, stop),
+        }
+    };
+}
+
+/// 内存压力达到该比例时健康检查报告降级
+const MEMORY_PRESSURE_DEGRADED: f64 = 0.9;
+
+/// 子系统锁的健康检查，更新时发生panic会使锁中毒，之后子系统无法再被使用
+struct LockHealthCheck<T> {
+    /// 子系统
+    handle: Arc<Mutex<T>>,
+}
+
+impl<T: Send> HealthCheck for LockHealthCheck<T> {
+    fn check(&self) -> HealthStatus {
+        if self.handle.is_poisoned() {
+            HealthStatus::Unhealthy("子系统锁已中毒".to_string())
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+}
+
+/// 内存管理器的健康检查，使用量接近上限时报告降级
+struct MemoryHealthCheck {
+    /// 内存管理器
+    memory_manager: Arc<Mutex<MemoryManager>>,
+}
+
+impl HealthCheck for MemoryHealthCheck {
+    fn check(&self) -> HealthStatus {
+        let Ok(memory_manager) = self.memory_manager.lock() else {
+            return HealthStatus::Unhealthy("内存管理器锁已中毒".to_string());
+        };
+        
+        match memory_manager.memory_limit {
+            Some(limit) if limit > 0
+                && memory_manager.current_usage() as f64 >= limit as f64 * MEMORY_PRESSURE_DEGRADED => {
+                HealthStatus::Degraded(format!("内存使用 {}/{} 字节", memory_manager.current_usage(), limit))
+            },
+            _ => HealthStatus::Healthy,
+        }
+    }
+}
+
 /// 应用程序
 struct Application {
     /// 应用程序状态
@@ -70,14 +257,14 @@ struct Application {
     resource_manager: Arc<Mutex<ResourceManager>>,
     /// 监控系统
     monitoring_system: Arc<Mutex<MonitoringSystem>>,
-    /// 沙箱管理器
-    sandbox_manager: Arc<Mutex<SandboxManager>>,
+    /// 沙箱管理器，锁中毒时重新初始化，无法恢复时系统停机
+    sandbox_manager: Arc<CriticalState<SandboxManager>>,
     /// 验证系统
     verification_system: Arc<Mutex<VerificationSystem>>,
     /// 威胁检测系统
     threat_detection_system: Arc<Mutex<ThreatDetectionSystem>>,
-    /// 访问控制系统
-    access_control_system: Arc<Mutex<AccessControlSystem>>,
+    /// 访问控制系统，锁中毒时重新初始化，无法恢复时系统停机
+    access_control_system: Arc<CriticalState<AccessControlSystem>>,
     /// 自然语言处理器
     nlp: Arc<Mutex<NaturalLanguageProcessor>>,
     /// 视觉系统
@@ -93,7 +280,7 @@ struct Application {
     /// 推理系统
     reasoning_system: Arc<Mutex<ReasoningSystem>>,
     /// 神经网络
-    neural_network: Arc<Mutex<NeuralNetwork>>,
+    neural_network: SharedNeuralNetwork,
     /// 符号系统
     symbolic_system: Arc<Mutex<SymbolicSystem>>,
     /// 知识库
@@ -116,6 +303,18 @@ struct Application {
     desktop_environment: Arc<Mutex<DesktopEnvironment>>,
     /// 输入管理器
     input_manager: Arc<Mutex<InputManager>>,
+    /// 集成总线
+    integration_bus: Arc<Mutex<IntegrationInterface>>,
+    /// 空闲检测器
+    idle_detector: Arc<Mutex<IdleDetector>>,
+    /// 会话统计
+    session: Arc<Mutex<SessionRecorder>>,
+    /// 会话报告路径
+    session_report_path: String,
+    /// 从输入到首个执行动作的端到端延迟追踪
+    latency_tracer: LatencyTracer,
+    /// 控制台输入及其到达时间，运行后由输入线程发送
+    console_input: Option<mpsc::Receiver<(String, Instant)>>,
 }
 
 impl Application {
@@ -124,26 +323,53 @@ impl Application {
         // 创建系统配置
         let system_config = SystemConfig::new();
         
-        // 创建上下文
-        let context = Context::new()
-            .map_err(|e| AppError::InitializationError(format!("无法创建上下文: {}", e)))?;
+        // 空闲超时（秒），0 表示禁用自动挂起
+        let idle_timeout = match system_config.get("idle_timeout_secs") {
+            Some(ConfigValue::Integer(0)) => None,
+            Some(ConfigValue::Integer(secs)) if *secs > 0 => Some(Duration::from_secs(*secs as u64)),
+            _ => Some(DEFAULT_IDLE_TIMEOUT),
+        };
+        let idle_detector = IdleDetector::new(idle_timeout, SystemTime::now());
+        
+        // 创建集成总线，总线消息视为活动
+        let mut integration_bus = IntegrationInterface::new()
+            .map_err(|e| AppError::InitializationError(format!("无法创建集成总线: {}", e)))?;
+        integration_bus.set_activity_signal(idle_detector.activity_signal());
+        
+        // 会话报告路径
+        let session_report_path = match system_config.get("session_report_path") {
+            Some(ConfigValue::String(path)) => path.clone(),
+            _ => std::env::temp_dir().join("rust_agi_os_session_report.md").to_string_lossy().into_owned(),
+        };
+        
+        // 子系统布线配置，显式配置的路径必须存在，默认路径不存在时按注册顺序启动
+        let wiring_path = match system_config.get("wiring_path") {
+            Some(ConfigValue::String(path)) => Some(path.clone()),
+            _ => std::path::Path::new(DEFAULT_WIRING_PATH).exists().then(|| DEFAULT_WIRING_PATH.to_string()),
+        };
         
         // 创建生命周期管理器
-        let lifecycle_manager = LifecycleManager::new(context)
+        let lifecycle_manager = LifecycleManager::new()
             .map_err(|e| AppError::InitializationError(format!("无法创建生命周期管理器: {}", e)))?;
         
         // 创建资源管理器
-        let resource_manager = ResourceManager::new(&system_config)
+        let mut resource_manager = ResourceManager::new(&system_config)
             .map_err(|e| AppError::InitializationError(format!("无法创建资源管理器: {}", e)))?;
         
         // 创建监控系统
-        let monitoring_system = MonitoringSystem::new()
+        let mut monitoring_system = MonitoringSystem::new()
             .map_err(|e| AppError::InitializationError(format!("无法创建监控系统: {}", e)))?;
         
         // 创建沙箱管理器
         let sandbox = Sandbox::new()
             .map_err(|e| AppError::InitializationError(format!("无法创建沙箱: {}", e)))?;
-        let sandbox_manager = Arc::new(Mutex::new(sandbox));
+        let sandbox_manager = Arc::new(CriticalState::new("sandbox_manager", sandbox, || {
+            let mut sandbox = Sandbox::new()
+                .map_err(|e| LifecycleError::InitializationError(e.to_string()))?;
+            sandbox.initialize().and_then(|_| sandbox.start())
+                .map_err(|e| LifecycleError::InitializationError(e.to_string()))?;
+            Ok(sandbox)
+        }));
         
         // 创建验证系统
         let verification = VerificationSystem::new()
@@ -158,7 +384,13 @@ impl Application {
         // 创建访问控制系统
         let access_control = AccessControlSystem::new()
             .map_err(|e| AppError::InitializationError(format!("无法创建访问控制系统: {}", e)))?;
-        let access_control_system = Arc::new(Mutex::new(access_control));
+        let access_control_system = Arc::new(CriticalState::new("access_control_system", access_control, || {
+            let mut access_control = AccessControlSystem::new()
+                .map_err(|e| LifecycleError::InitializationError(e.to_string()))?;
+            access_control.initialize().and_then(|_| access_control.start())
+                .map_err(|e| LifecycleError::InitializationError(e.to_string()))?;
+            Ok(access_control)
+        }));
         
         // 创建自然语言处理器
         let nlp_processor = NaturalLanguageProcessor::new()
@@ -185,9 +417,25 @@ impl Application {
             .map_err(|e| AppError::InitializationError(format!("无法创建进程管理器: {}", e)))?;
         let process_manager = Arc::new(Mutex::new(process));
         
+        // 进程 CPU 配额告警，启用自动处置时挂起超额进程；配额可写作整数或小数
+        let process_cpu_quota = match system_config.get("process_cpu_quota") {
+            Some(ConfigValue::Float(quota)) => Some(*quota),
+            Some(ConfigValue::Integer(quota)) => Some(*quota as f64),
+            _ => None,
+        };
+        if let Some(quota) = process_cpu_quota {
+            monitoring_system.add_alert_rule("process_cpu_quota", METRIC_PROCESS_CPU_USAGE, quota);
+        }
+        let auto_action = matches!(system_config.get("alert_auto_action"), Some(ConfigValue::Boolean(true)));
+        monitoring_system.set_alert_policy(AlertPolicy { auto_action });
+        monitoring_system.add_alert_action(suspend_process_action(process_manager.clone()));
+        
         // 创建规划系统
-        let planning = PlanningSystem::new()
+        let mut planning = PlanningSystem::new()
             .map_err(|e| AppError::InitializationError(format!("无法创建规划系统: {}", e)))?;
+        if let Some(ConfigValue::Integer(depth)) = system_config.get("max_planning_depth") {
+            planning.set_max_planning_depth((*depth).max(0) as usize);
+        }
         let planning_system = Arc::new(Mutex::new(planning));
         
         // 创建推理系统
@@ -198,7 +446,17 @@ impl Application {
         // 创建神经网络
         let neural_network = NeuralNetwork::new("主神经网络", 0.01)
             .map_err(|e| AppError::InitializationError(format!("无法创建神经网络: {}", e)))?;
-        let neural_network = Arc::new(Mutex::new(neural_network));
+        let neural_network = SharedNeuralNetwork::new(neural_network);
+        
+        // 内存压力变化时切换神经网络的推理精度
+        let precision_network = neural_network.clone();
+        resource_manager.add_precision_handler(move |change| {
+            let mut network = precision_network.write()
+                .map_err(|e| ResourceError::Other(format!("无法切换神经网络精度: {}", e)))?;
+            network.set_precision(change.to);
+            println!("神经网络精度: {} -> {}", change.from, change.to);
+            Ok(())
+        });
         
         // 创建符号系统
         let symbolic = SymbolicSystem::new()
@@ -254,15 +512,15 @@ impl Application {
         let input_method_manager = InputMethodManager::new();
         let input_manager = Arc::new(Mutex::new(input_method_manager));
         
-        Ok(Self {
+        let app = Self {
             state: Arc::new(Mutex::new(AppState::Initializing)),
             lifecycle_manager: Arc::new(Mutex::new(lifecycle_manager)),
             resource_manager: Arc::new(Mutex::new(resource_manager)),
             monitoring_system: Arc::new(Mutex::new(monitoring_system)),
-            sandbox_manager: Arc::new(Mutex::new(sandbox_manager)),
+            sandbox_manager,
             verification_system: Arc::new(Mutex::new(verification_system)),
             threat_detection_system: Arc::new(Mutex::new(threat_detection_system)),
-            access_control_system: Arc::new(Mutex::new(access_control_system)),
+            access_control_system,
             nlp: Arc::new(Mutex::new(nlp)),
             vision_system: Arc::new(Mutex::new(vision_system)),
             speech_system: Arc::new(Mutex::new(speech_system)),
@@ -270,7 +528,7 @@ impl Application {
             process_manager: Arc::new(Mutex::new(process_manager)),
             planning_system: Arc::new(Mutex::new(planning_system)),
             reasoning_system: Arc::new(Mutex::new(reasoning_system)),
-            neural_network: Arc::new(Mutex::new(neural_network)),
+            neural_network,
             symbolic_system: Arc::new(Mutex::new(symbolic_system)),
             knowledge_base: Arc::new(Mutex::new(knowledge_base)),
             learning_system: Arc::new(Mutex::new(learning_system)),
@@ -282,104 +540,86 @@ impl Application {
             theme_manager: Arc::new(Mutex::new(theme_manager)),
             desktop_environment: Arc::new(Mutex::new(desktop_environment)),
             input_manager: Arc::new(Mutex::new(input_manager)),
-        })
+            integration_bus: Arc::new(Mutex::new(integration_bus)),
+            idle_detector: Arc::new(Mutex::new(idle_detector)),
+            session: Arc::new(Mutex::new(SessionRecorder::new(SystemTime::now()))),
+            session_report_path,
+            latency_tracer: LatencyTracer::default(),
+            console_input: None,
+        };
+        app.register_components()?;
+        
+        // 按布线配置排序子系统并标记非关键子系统，缺失或循环依赖在此报错
+        if let Some(path) = wiring_path {
+            app.lifecycle_manager.lock().unwrap().load_wiring(&path)
+                .map_err(|e| AppError::InitializationError(format!("无法加载子系统布线配置: {}", e)))?;
+        }
+        
+        Ok(app)
+    }
+    
+    /// 按启动顺序把各子系统注册到生命周期管理器，停止时按相反顺序进行
+    ///
+    /// 名称需与布线配置中的组件名称一致。
+    fn register_components(&self) -> Result<(), AppError> {
+        let session = &self.session;
+        let components = vec![
+            subsystem!(session, "resource_manager", self.resource_manager),
+            subsystem!(session, "monitoring_system", self.monitoring_system),
+            subsystem!(session, "sandbox_manager", critical self.sandbox_manager),
+            subsystem!(session, "verification_system", self.verification_system),
+            subsystem!(session, "threat_detection_system", self.threat_detection_system),
+            subsystem!(session, "access_control_system", critical self.access_control_system),
+            subsystem!(session, "nlp", self.nlp),
+            subsystem!(session, "vision_system", self.vision_system),
+            subsystem!(session, "speech_system", self.speech_system),
+            subsystem!(session, "memory_manager", self.memory_manager),
+            subsystem!(session, "process_manager", self.process_manager),
+            subsystem!(session, "planning_system", self.planning_system),
+            subsystem!(session, "reasoning_system", self.reasoning_system),
+            subsystem!(session, "neural_network", self.neural_network, write),
+            subsystem!(session, "symbolic_system", self.symbolic_system),
+            subsystem!(session, "knowledge_base", self.knowledge_base),
+            subsystem!(session, "learning_system", self.learning_system),
+            subsystem!(session, "neuro_symbolic_integration", self.neuro_symbolic_integration),
+            subsystem!(session, "performance_monitor", self.performance_monitor),
+            subsystem!(session, "reflection_system", self.reflection_system),
+            subsystem!(session, "window_system", self.window_system),
+            subsystem!(session, "render_engine", self.render_engine),
+            subsystem!(session, "theme_manager", self.theme_manager),
+            subsystem!(session, "desktop_environment", self.desktop_environment),
+            subsystem!(session, "input_manager", self.input_manager),
+        ];
+        
+        let mut lifecycle_manager = self.lifecycle_manager.lock().unwrap();
+        for component in components {
+            lifecycle_manager.add_component(component)
+                .map_err(|e| AppError::InitializationError(format!("无法注册子系统: {}", e)))?;
+        }
+        
+        // 健康检查由主循环按间隔轮询
+        lifecycle_manager.add_health_check("memory_manager", MemoryHealthCheck {
+            memory_manager: self.memory_manager.clone(),
+        });
+        lifecycle_manager.add_health_check("process_manager", LockHealthCheck {
+            handle: self.process_manager.clone(),
+        });
+        lifecycle_manager.add_health_check("monitoring_system", LockHealthCheck {
+            handle: self.monitoring_system.clone(),
+        });
+        lifecycle_manager.add_health_check("sandbox_manager", self.sandbox_manager.clone());
+        lifecycle_manager.add_health_check("access_control_system", self.access_control_system.clone());
+        
+        Ok(())
     }
     
     /// 初始化应用程序
     fn initialize(&mut self) -> Result<(), AppError> {
         println!("正在初始化应用程序...");
         
-        // 初始化生命周期管理器
+        // 按注册顺序初始化各子系统，非关键子系统失败时继续初始化其余子系统
         self.lifecycle_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化生命周期管理器: {}", e)))?;
-        
-        // 初始化资源管理器
-        self.resource_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化资源管理器: {}", e)))?;
-        
-        // 初始化监控系统
-        self.monitoring_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化监控系统: {}", e)))?;
-        
-        // 初始化沙箱管理器
-        self.sandbox_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化沙箱管理器: {}", e)))?;
-        
-        // 初始化验证系统
-        self.verification_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化验证系统: {}", e)))?;
-        
-        // 初始化威胁检测系统
-        self.threat_detection_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化威胁检测系统: {}", e)))?;
-        
-        // 初始化访问控制系统
-        self.access_control_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化访问控制系统: {}", e)))?;
-        
-        // 初始化内存管理器
-        self.memory_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化内存管理器: {}", e)))?;
-        
-        // 初始化进程管理器
-        self.process_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化进程管理器: {}", e)))?;
-        
-        // 初始化规划系统
-        self.planning_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化规划系统: {}", e)))?;
-        
-        // 初始化推理系统
-        self.reasoning_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化推理系统: {}", e)))?;
-        
-        // 初始化神经网络
-        self.neural_network.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化神经网络: {}", e)))?;
-        
-        // 初始化符号系统
-        self.symbolic_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化符号系统: {}", e)))?;
-        
-        // 初始化知识库
-        self.knowledge_base.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化知识库: {}", e)))?;
-        
-        // 初始化学习系统
-        self.learning_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化学习系统: {}", e)))?;
-        
-        // 初始化神经符号集成
-        self.neuro_symbolic_integration.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化神经符号集成: {}", e)))?;
-        
-        // 初始化性能监控器
-        self.performance_monitor.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化性能监控器: {}", e)))?;
-        
-        // 初始化反思系统
-        self.reflection_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化反思系统: {}", e)))?;
-        
-        // 初始化窗口系统
-        self.window_system.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化窗口系统: {}", e)))?;
-        
-        // 初始化渲染引擎
-        self.render_engine.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化渲染引擎: {}", e)))?;
-        
-        // 初始化主题管理器
-        self.theme_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化主题管理器: {}", e)))?;
-        
-        // 初始化桌面环境
-        self.desktop_environment.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化桌面环境: {}", e)))?;
-        
-        // 初始化输入管理器
-        self.input_manager.lock().unwrap().initialize()
-            .map_err(|e| AppError::InitializationError(format!("无法初始化输入管理器: {}", e)))?;
+            .map_err(|e| AppError::InitializationError(format!("无法初始化子系统: {}", e)))?;
         
         // 更新应用程序状态
         *self.state.lock().unwrap() = AppState::Running;
@@ -398,97 +638,10 @@ impl Application {
             return Err(AppError::RuntimeError("应用程序未初始化或已停止".to_string()));
         }
         
-        // 启动生命周期管理器
+        // 按注册顺序启动各子系统，非关键子系统失败时以降级模式运行
         self.lifecycle_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动生命周期管理器: {}", e)))?;
-        
-        // 启动资源管理器
-        self.resource_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动资源管理器: {}", e)))?;
-        
-        // 启动监控系统
-        self.monitoring_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动监控系统: {}", e)))?;
-        
-        // 启动沙箱管理器
-        self.sandbox_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动沙箱管理器: {}", e)))?;
-        
-        // 启动验证系统
-        self.verification_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动验证系统: {}", e)))?;
-        
-        // 启动威胁检测系统
-        self.threat_detection_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动威胁检测系统: {}", e)))?;
-        
-        // 启动访问控制系统
-        self.access_control_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动访问控制系统: {}", e)))?;
-        
-        // 启动内存管理器
-        self.memory_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动内存管理器: {}", e)))?;
-        
-        // 启动进程管理器
-        self.process_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动进程管理器: {}", e)))?;
-        
-        // 启动规划系统
-        self.planning_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动规划系统: {}", e)))?;
-        
-        // 启动推理系统
-        self.reasoning_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动推理系统: {}", e)))?;
-        
-        // 启动神经网络
-        self.neural_network.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动神经网络: {}", e)))?;
-        
-        // 启动符号系统
-        self.symbolic_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动符号系统: {}", e)))?;
-        
-        // 启动知识库
-        self.knowledge_base.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动知识库: {}", e)))?;
-        
-        // 启动学习系统
-        self.learning_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动学习系统: {}", e)))?;
-        
-        // 启动神经符号集成
-        self.neuro_symbolic_integration.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动神经符号集成: {}", e)))?;
-        
-        // 启动性能监控器
-        self.performance_monitor.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动性能监控器: {}", e)))?;
-        
-        // 启动反思系统
-        self.reflection_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动反思系统: {}", e)))?;
-        
-        // 启动窗口系统
-        self.window_system.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动窗口系统: {}", e)))?;
-        
-        // 启动渲染引擎
-        self.render_engine.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动渲染引擎: {}", e)))?;
-        
-        // 启动主题管理器
-        self.theme_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动主题管理器: {}", e)))?;
-        
-        // 启动桌面环境
-        self.desktop_environment.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动桌面环境: {}", e)))?;
-        
-        // 启动输入管理器
-        self.input_manager.lock().unwrap().start()
-            .map_err(|e| AppError::RuntimeError(format!("无法启动输入管理器: {}", e)))?;
+            .map_err(|e| AppError::RuntimeError(format!("无法启动子系统: {}", e)))?;
+        self.report_unavailable();
         
         // 主循环
         let running = Arc::new(Mutex::new(true));
@@ -501,9 +654,55 @@ impl Application {
         
         println!("应用程序已启动，按Ctrl+C停止");
         
+        // 控制台输入视为用户活动，并交给主循环处理
+        let activity = self.idle_detector.lock().unwrap().activity_signal();
+        let (input_sender, input_receiver) = mpsc::channel();
+        self.console_input = Some(input_receiver);
+        thread::spawn(move || {
+            for line in std::io::stdin().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                activity.notify(SystemTime::now());
+                if input_sender.send((line, Instant::now())).is_err() {
+                    break;
+                }
+            }
+        });
+        
         while *running.lock().unwrap() {
-            // 更新各系统
-            self.update()?;
+            // 处理输入或总线活动，挂起时自动恢复
+            self.notify_activity()?;
+            
+            // 空闲检测，超时后自动挂起
+            self.check_idle()?;
+            
+            // 挂起期间不更新各系统
+            if *self.state.lock().unwrap() == AppState::Paused {
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+            
+            // 更新各系统，出错时记录并继续运行；进入错误状态的子系统由看门狗重启
+            if let Err(e) = self.update() {
+                println!("更新失败: {}", e);
+            }
+            
+            // 关键状态损坏且无法恢复时停机，不在未知状态上继续运行
+            if self.lifecycle_manager.lock().unwrap().state == LifecycleState::Halted {
+                self.stop()?;
+                return Err(AppError::RuntimeError("关键子系统状态已损坏，系统已停机".to_string()));
+            }
+            
+            // 看门狗按退避重启出错的子系统，超过重启上限后停用并降级运行
+            let findings = self.lifecycle_manager.lock().unwrap().watchdog_check(Instant::now())
+                .map_err(|e| AppError::RuntimeError(format!("看门狗检查失败: {}", e)))?;
+            for finding in findings.iter().filter(|f| f.severity == FindingSeverity::Critical) {
+                println!("看门狗: 子系统 {} 已停用: {}", finding.component, finding.message);
+            }
+            
+            // 按间隔轮询健康检查，异常结果由生命周期管理器记录
+            self.lifecycle_manager.lock().unwrap().poll_health(Instant::now());
             
             // 休眠一段时间，避免CPU占用过高
             thread::sleep(Duration::from_millis(100));
@@ -517,97 +716,141 @@ impl Application {
     
     /// 更新应用程序
     fn update(&mut self) -> Result<(), AppError> {
-        // 更新生命周期管理器
-        self.lifecycle_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新生命周期管理器: {}", e)))?;
-        
-        // 更新资源管理器
-        self.resource_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新资源管理器: {}", e)))?;
-        
-        // 更新监控系统
-        self.monitoring_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新监控系统: {}", e)))?;
-        
-        // 更新沙箱管理器
-        self.sandbox_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新沙箱管理器: {}", e)))?;
-        
-        // 更新验证系统
-        self.verification_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新验证系统: {}", e)))?;
-        
-        // 更新威胁检测系统
-        self.threat_detection_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新威胁检测系统: {}", e)))?;
-        
-        // 更新访问控制系统
-        self.access_control_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新访问控制系统: {}", e)))?;
-        
-        // 更新内存管理器
-        self.memory_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新内存管理器: {}", e)))?;
-        
-        // 更新进程管理器
-        self.process_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新进程管理器: {}", e)))?;
-        
-        // 更新规划系统
-        self.planning_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新规划系统: {}", e)))?;
-        
-        // 更新推理系统
-        self.reasoning_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新推理系统: {}", e)))?;
-        
-        // 更新神经网络
-        self.neural_network.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新神经网络: {}", e)))?;
-        
-        // 更新符号系统
-        self.symbolic_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新符号系统: {}", e)))?;
-        
-        // 更新知识库
-        self.knowledge_base.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新知识库: {}", e)))?;
+        // 更新各子系统，耗时由各子系统组件记录；个别子系统失败不影响后续步骤
+        let result = self.lifecycle_manager.lock().unwrap().update()
+            .map_err(|e| AppError::RuntimeError(format!("无法更新子系统: {}", e)));
+        
+        // 处理控制台输入，追踪端到端延迟
+        self.handle_console_input();
+        
+        // 按威胁检测结果刷新沙箱的威胁等级，策略引擎据此收紧权限
+        {
+            let threat_detection = self.threat_detection_system.lock().unwrap();
+            self.lifecycle_manager.lock().unwrap()
+                .with_critical(&self.sandbox_manager, |sandbox| sandbox.update_threat_level(&threat_detection))
+                .map_err(|e| AppError::RuntimeError(format!("无法刷新沙箱威胁等级: {}", e)))?;
+        }
         
-        // 更新学习系统
-        self.learning_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新学习系统: {}", e)))?;
+        // 记录各进程的 CPU 使用率，供进程 CPU 配额告警使用；告警处置会锁定进程管理器，
+        // 因此先释放进程管理器的锁再记录
+        let cpu_metrics = process_cpu_metrics(&self.process_manager.lock().unwrap());
+        {
+            let mut monitoring_system = self.monitoring_system.lock().unwrap();
+            for metric in cpu_metrics {
+                monitoring_system.add_metric(metric)
+                    .map_err(|e| AppError::RuntimeError(format!("无法记录进程 CPU 使用率: {}", e)))?;
+            }
+        }
         
-        // 更新神经符号集成
-        self.neuro_symbolic_integration.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新神经符号集成: {}", e)))?;
+        // 评估持续告警规则
+        self.monitoring_system.lock().unwrap().evaluate_alerts(SystemTime::now())
+            .map_err(|e| AppError::RuntimeError(format!("无法评估告警规则: {}", e)))?;
         
-        // 更新性能监控器
-        self.performance_monitor.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新性能监控器: {}", e)))?;
+        result
+    }
+    
+    /// 依次经过自然语言处理、推理和规划处理控制台输入
+    ///
+    /// 每条输入按到达时间开始追踪；出错或没有可执行的计划步骤时放弃追踪，
+    /// 长时间未到达动作的追踪也会过期。
+    fn handle_console_input(&mut self) {
+        let inputs: Vec<(String, Instant)> = match &self.console_input {
+            Some(receiver) => receiver.try_iter().collect(),
+            None => Vec::new(),
+        };
+        
+        for (line, received_at) in inputs {
+            let correlation_id = self.latency_tracer.begin_at(Ingress::Text, received_at);
+            match self.trace_request(&correlation_id, &line) {
+                Ok(true) => {},
+                Ok(false) => {
+                    self.latency_tracer.abandon(&correlation_id);
+                },
+                Err(e) => {
+                    self.latency_tracer.abandon(&correlation_id);
+                    println!("处理输入失败: {}", e);
+                },
+            }
+        }
         
-        // 更新反思系统
-        self.reflection_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新反思系统: {}", e)))?;
+        self.latency_tracer.expire();
+    }
+    
+    /// 处理一条输入并标记各阶段完成时间，返回是否执行了动作
+    fn trace_request(&mut self, correlation_id: &str, line: &str) -> Result<bool, AppError> {
+        let latency_error = |e: LatencyError| AppError::RuntimeError(format!("无法记录延迟: {}", e));
+        
+        let text = self.nlp.lock().unwrap().process_text(line)
+            .map_err(|e| AppError::RuntimeError(format!("自然语言处理失败: {}", e)))?;
+        self.latency_tracer.mark(correlation_id, LatencyStage::Nlp).map_err(latency_error)?;
+        
+        self.reasoning_system.lock().unwrap().reason(&text)
+            .map_err(|e| AppError::RuntimeError(format!("推理失败: {}", e)))?;
+        self.latency_tracer.mark(correlation_id, LatencyStage::Reasoning).map_err(latency_error)?;
+        
+        let mut planning_system = self.planning_system.lock().unwrap();
+        let has_step = planning_system.get_current_plan()
+            .is_some_and(|plan| !plan.get_next_steps().is_empty());
+        self.latency_tracer.mark(correlation_id, LatencyStage::Planning).map_err(latency_error)?;
+        if !has_step {
+            return Ok(false);
+        }
         
-        // 更新窗口系统
-        self.window_system.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新窗口系统: {}", e)))?;
+        let step = planning_system.execute_next_step()
+            .map_err(|e| AppError::RuntimeError(format!("无法执行计划步骤: {}", e)))?;
+        drop(planning_system);
+        if step.is_none() {
+            return Ok(false);
+        }
         
-        // 更新渲染引擎
-        self.render_engine.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新渲染引擎: {}", e)))?;
+        if let Some(trace) = self.latency_tracer.mark(correlation_id, LatencyStage::Action).map_err(latency_error)? {
+            LatencyTracer::record_metric(&trace, &mut self.monitoring_system.lock().unwrap())
+                .map_err(|e| AppError::RuntimeError(format!("无法记录端到端延迟: {}", e)))?;
+        }
+        Ok(true)
+    }
+    
+    /// 降级运行时列出初始化或启动失败的非关键子系统
+    fn report_unavailable(&self) {
+        let lifecycle_manager = self.lifecycle_manager.lock().unwrap();
+        if !lifecycle_manager.is_degraded() {
+            return;
+        }
         
-        // 更新主题管理器
-        self.theme_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新主题管理器: {}", e)))?;
+        println!("系统以降级模式运行，不可用的子系统:");
+        for component in lifecycle_manager.get_unavailable() {
+            println!("  {}: {}", component.name, component.reason);
+        }
+    }
+    
+    /// 检查空闲状态，超过空闲超时后自动挂起
+    fn check_idle(&mut self) -> Result<(), AppError> {
+        let now = SystemTime::now();
+        let (transition, metric) = {
+            let mut detector = self.idle_detector.lock().unwrap();
+            (detector.check(now), detector.idle_metric(now))
+        };
+        
+        // 记录空闲时间指标
+        self.monitoring_system.lock().unwrap().add_metric(metric)
+            .map_err(|e| AppError::RuntimeError(format!("无法记录空闲指标: {}", e)))?;
+        
+        if transition == IdleTransition::Suspend && *self.state.lock().unwrap() == AppState::Running {
+            println!("系统空闲超时，自动挂起");
+            self.pause()?;
+        }
         
-        // 更新桌面环境
-        self.desktop_environment.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新桌面环境: {}", e)))?;
+        Ok(())
+    }
+    
+    /// 处理自上次轮询以来的输入或总线活动，挂起时自动恢复
+    fn notify_activity(&mut self) -> Result<(), AppError> {
+        let transition = self.idle_detector.lock().unwrap().poll_activity();
         
-        // 更新输入管理器
-        self.input_manager.lock().unwrap().update()
-            .map_err(|e| AppError::RuntimeError(format!("无法更新输入管理器: {}", e)))?;
+        if transition == IdleTransition::Resume && *self.state.lock().unwrap() == AppState::Paused {
+            println!("检测到输入活动，自动恢复");
+            self.resume()?;
+        }
         
         Ok(())
     }
@@ -678,6 +921,35 @@ impl Application {
         Ok(())
     }
     
+    /// 收集会话计数，返回仍未释放的内存区域
+    fn collect_session_counters(&self) -> Vec<LeakedRegion> {
+        let processes_run = self.process_manager.lock().unwrap().processes.len() as u64;
+        let plans_completed = self.planning_system.lock().unwrap().plans.values()
+            .filter(|plan| !plan.steps.is_empty()
+                && plan.steps.values().all(|step| step.status == PlanStepStatus::Completed))
+            .count() as u64;
+        let threats_detected = self.threat_detection_system.lock().unwrap().threats.len() as u64;
+        let (passed, failed) = self.verification_system.lock().unwrap().results.values()
+            .fold((0, 0), |(passed, failed), result| {
+                if result.success { (passed + 1, failed) } else { (passed, failed + 1) }
+            });
+        
+        let mut session = self.session.lock().unwrap();
+        session.set_counter(COUNTER_PROCESSES_RUN, processes_run);
+        session.set_counter(COUNTER_PLANS_COMPLETED, plans_completed);
+        session.set_counter(COUNTER_THREATS_DETECTED, threats_detected);
+        session.set_counter(COUNTER_VERIFICATIONS_PASSED, passed);
+        session.set_counter(COUNTER_VERIFICATIONS_FAILED, failed);
+        
+        self.memory_manager.lock().unwrap().blocks.values()
+            .map(|block| LeakedRegion {
+                id: block.id.clone(),
+                owner: block.owner.clone(),
+                size: block.size,
+            })
+            .collect()
+    }
+    
     /// 停止应用程序
     fn stop(&mut self) -> Result<(), AppError> {
         println!("正在停止应用程序...");
@@ -687,119 +959,52 @@ impl Application {
             return Ok(());
         }
         
-        // 停止输入管理器
-        self.input_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止输入管理器: {}", e)))?;
-        
-        // 停止桌面环境
-        self.desktop_environment.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止桌面环境: {}", e)))?;
-        
-        // 停止主题管理器
-        self.theme_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止主题管理器: {}", e)))?;
-        
-        // 停止渲染引擎
-        self.render_engine.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止渲染引擎: {}", e)))?;
-        
-        // 停止窗口系统
-        self.window_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止窗口系统: {}", e)))?;
-        
-        // 停止反思系统
-        self.reflection_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止反思系统: {}", e)))?;
-        
-        // 停止性能监控器
-        self.performance_monitor.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止性能监控器: {}", e)))?;
-        
-        // 停止神经符号集成
-        self.neuro_symbolic_integration.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止神经符号集成: {}", e)))?;
-        
-        // 停止学习系统
-        self.learning_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止学习系统: {}", e)))?;
-        
-        // 停止知识库
-        self.knowledge_base.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止知识库: {}", e)))?;
+        // 在停止各系统之前收集会话统计
+        let leaked_regions = self.collect_session_counters();
         
-        // 停止符号系统
-        self.symbolic_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止符号系统: {}", e)))?;
-        
-        // 停止神经网络
-        self.neural_network.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止神经网络: {}", e)))?;
-        
-        // 停止推理系统
-        self.reasoning_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止推理系统: {}", e)))?;
-        
-        // 停止规划系统
-        self.planning_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止规划系统: {}", e)))?;
-        
-        // 停止进程管理器
-        self.process_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止进程管理器: {}", e)))?;
-        
-        // 停止内存管理器
-        self.memory_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止内存管理器: {}", e)))?;
-        
-        // 停止语音系统
-        self.speech_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止语音系统: {}", e)))?;
-        
-        // 停止视觉系统
-        self.vision_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止视觉系统: {}", e)))?;
-        
-        // 停止自然语言处理器
-        self.nlp.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止自然语言处理器: {}", e)))?;
-        
-        // 停止访问控制系统
-        self.access_control_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止访问控制系统: {}", e)))?;
-        
-        // 停止威胁检测系统
-        self.threat_detection_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止威胁检测系统: {}", e)))?;
-        
-        // 停止验证系统
-        self.verification_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止验证系统: {}", e)))?;
-        
-        // 停止沙箱管理器
-        self.sandbox_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止沙箱管理器: {}", e)))?;
-        
-        // 停止监控系统
-        self.monitoring_system.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止监控系统: {}", e)))?;
-        
-        // 停止资源管理器
-        self.resource_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止资源管理器: {}", e)))?;
-        
-        // 停止生命周期管理器
-        self.lifecycle_manager.lock().unwrap().stop()
-            .map_err(|e| AppError::RuntimeError(format!("无法停止生命周期管理器: {}", e)))?;
+        // 先排空各子系统的待处理工作，再按启动的逆序停止；排空或单个子系统
+        // 停止失败都不影响其余子系统的停止。未在运行时没有可排空的工作。
+        let result = {
+            let mut lifecycle_manager = self.lifecycle_manager.lock().unwrap();
+            match lifecycle_manager.state {
+                LifecycleState::Running | LifecycleState::Degraded => lifecycle_manager.stop(),
+                _ => lifecycle_manager.stop_system(),
+            }
+        }.map_err(|e| AppError::RuntimeError(format!("无法停止子系统: {}", e)));
         
         // 更新应用程序状态
         *self.state.lock().unwrap() = AppState::Stopped;
         
+        // 输出端到端延迟分布
+        if self.latency_tracer.completed().next().is_some() {
+            println!("{}", self.latency_tracer.report().render());
+        }
+        
+        // 写入会话报告
+        let report = self.session.lock().unwrap().finish(SystemTime::now(), leaked_regions);
+        match report.write_to(&self.session_report_path) {
+            Ok(()) => println!("会话报告已写入: {}", self.session_report_path),
+            Err(e) => eprintln!("无法写入会话报告: {}", e),
+        }
+        
         println!("应用程序已停止");
         
-        Ok(())
+        result
     }
 }
 
+/// 执行一次更新并记录耗时
+fn timed_update<T, E>(
+    session: &Mutex<SessionRecorder>,
+    subsystem: &str,
+    update: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let started = std::time::Instant::now();
+    let result = update();
+    session.lock().unwrap().record_update(subsystem, started.elapsed());
+    result
+}
+
 /// 主函数
 fn main() {
     println!("Rust AGI OS - 强人工智能操作系统");
@@ -2,8 +2,8 @@ mod planning;
 mod reasoning;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use planning::{Planning, PlanningSystem, PlanningError};
-pub use reasoning::{Reasoning, ReasoningSystem, ReasoningError};
+pub use planning::{Planning, PlanningSystem, PlanningError, PlanStepStatus, PlanState, SimulationReport, PreconditionViolation, BlockingReport, BlockedStep, BlockReason, DEFAULT_MAX_PLANNING_DEPTH};
+pub use reasoning::{Reasoning, ReasoningSystem, ReasoningError, ConfidencePropagation, parse_implication};
 
 // 导出特定函数，避免冲突
 pub use planning::init as planning_init;
@@ -1,6 +1,9 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Default maximum sub-plan nesting depth
+pub const DEFAULT_MAX_PLANNING_DEPTH: usize = 32;
 
 /// Planning error
 #[derive(Debug)]
@@ -9,6 +12,8 @@ pub enum PlanningError {
     InitializationError(String),
     /// Processing error
     ProcessingError(String),
+    /// Sub-plan nesting exceeded the maximum planning depth
+    DepthExceeded(String),
     /// Other error
     Other(String),
 }
@@ -20,6 +25,7 @@ impl fmt::Display for PlanningError {
         match self {
             PlanningError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             PlanningError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
+            PlanningError::DepthExceeded(msg) => write!(f, "Depth exceeded: {}", msg),
             PlanningError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -36,10 +42,19 @@ pub struct PlanStep {
     pub dependencies: Vec<String>,
     /// Step status
     pub status: PlanStepStatus,
+    /// Facts that must hold before the step runs
+    pub preconditions: HashMap<String, String>,
+    /// Facts set by the step
+    pub effects: HashMap<String, String>,
+    /// Plan the step expands into
+    pub sub_plan: Option<String>,
     /// Step metadata
     pub metadata: HashMap<String, String>,
 }
 
+/// Plan state, a set of facts
+pub type PlanState = HashMap<String, String>;
+
 /// Plan step status
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlanStepStatus {
@@ -72,10 +87,28 @@ impl PlanStep {
             description: description.to_string(),
             dependencies: Vec::new(),
             status: PlanStepStatus::NotStarted,
+            preconditions: HashMap::new(),
+            effects: HashMap::new(),
+            sub_plan: None,
             metadata: HashMap::new(),
         }
     }
     
+    /// Set the plan the step expands into
+    pub fn set_sub_plan(&mut self, plan_id: &str) {
+        self.sub_plan = Some(plan_id.to_string());
+    }
+    
+    /// Add precondition
+    pub fn add_precondition(&mut self, key: &str, value: &str) {
+        self.preconditions.insert(key.to_string(), value.to_string());
+    }
+    
+    /// Add effect
+    pub fn add_effect(&mut self, key: &str, value: &str) {
+        self.effects.insert(key.to_string(), value.to_string());
+    }
+    
     /// Add dependency
     pub fn add_dependency(&mut self, step_id: &str) {
         self.dependencies.push(step_id.to_string());
@@ -108,10 +141,135 @@ pub struct Plan {
     pub description: String,
     /// Plan steps
     pub steps: HashMap<String, PlanStep>,
+    /// Step IDs in insertion order
+    pub step_order: Vec<String>,
     /// Plan metadata
     pub metadata: HashMap<String, String>,
 }
 
+/// Precondition violation found during simulation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreconditionViolation {
+    /// Step ID
+    pub step_id: String,
+    /// Step description
+    pub step_description: String,
+    /// Fact key
+    pub key: String,
+    /// Expected value
+    pub expected: String,
+    /// Actual value
+    pub actual: Option<String>,
+}
+
+impl fmt::Display for PreconditionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Step '{}' requires {}={} but found {}",
+            self.step_description,
+            self.key,
+            self.expected,
+            self.actual.as_deref().unwrap_or("<unset>")
+        )
+    }
+}
+
+/// Simulation report
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    /// Plan ID
+    pub plan_id: String,
+    /// Whether every step ran with its preconditions satisfied
+    pub success: bool,
+    /// Executed step IDs in order
+    pub executed_steps: Vec<String>,
+    /// First precondition violation
+    pub violation: Option<PreconditionViolation>,
+    /// Steps that could never become ready
+    pub unreachable_steps: Vec<String>,
+    /// State after the last executed step
+    pub final_state: PlanState,
+}
+
+/// Reason a step cannot run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockReason {
+    /// Dependency is not part of the plan
+    MissingDependency(String),
+    /// Dependency failed
+    FailedDependency(String),
+    /// Dependency has not completed yet
+    PendingDependency(String),
+    /// Precondition unmet but a remaining step sets it
+    UnmetPrecondition { key: String, expected: String, actual: Option<String> },
+    /// Precondition unmet and no remaining step sets it
+    UnsatisfiablePrecondition { key: String, expected: String, actual: Option<String> },
+}
+
+impl fmt::Display for BlockReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockReason::MissingDependency(id) => write!(f, "dependency {} does not exist", id),
+            BlockReason::FailedDependency(id) => write!(f, "dependency {} failed", id),
+            BlockReason::PendingDependency(id) => write!(f, "dependency {} has not completed", id),
+            BlockReason::UnmetPrecondition { key, expected, actual } => write!(
+                f,
+                "requires {}={} but found {}",
+                key, expected, actual.as_deref().unwrap_or("<unset>")
+            ),
+            BlockReason::UnsatisfiablePrecondition { key, expected, actual } => write!(
+                f,
+                "requires {}={} but found {} and no remaining step sets it",
+                key, expected, actual.as_deref().unwrap_or("<unset>")
+            ),
+        }
+    }
+}
+
+/// Step that has not run yet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedStep {
+    /// Step ID
+    pub step_id: String,
+    /// Step description
+    pub step_description: String,
+    /// Whether the step could run now
+    pub ready: bool,
+    /// Reasons the step cannot run
+    pub reasons: Vec<BlockReason>,
+}
+
+/// Report on why a plan is not making progress
+#[derive(Debug, Clone)]
+pub struct BlockingReport {
+    /// Plan ID
+    pub plan_id: String,
+    /// Steps that have not started, in plan order
+    pub pending_steps: Vec<BlockedStep>,
+    /// Steps in progress
+    pub in_progress_steps: Vec<String>,
+    /// Steps on a circular dependency
+    pub deadlocked_steps: Vec<String>,
+}
+
+impl BlockingReport {
+    /// Check if the plan has a circular dependency
+    pub fn is_deadlock(&self) -> bool {
+        !self.deadlocked_steps.is_empty()
+    }
+    
+    /// Get steps that could run now
+    pub fn ready_steps(&self) -> Vec<&BlockedStep> {
+        self.pending_steps.iter().filter(|s| s.ready).collect()
+    }
+    
+    /// Get steps that cannot run now
+    pub fn blocked_steps(&self) -> Vec<&BlockedStep> {
+        self.pending_steps.iter().filter(|s| !s.ready).collect()
+    }
+}
+
 impl Plan {
     /// Create a new plan
     pub fn new(name: &str, description: &str) -> Self {
@@ -120,12 +278,16 @@ impl Plan {
             name: name.to_string(),
             description: description.to_string(),
             steps: HashMap::new(),
+            step_order: Vec::new(),
             metadata: HashMap::new(),
         }
     }
     
     /// Add step
     pub fn add_step(&mut self, step: PlanStep) -> Result<(), PlanningError> {
+        if !self.steps.contains_key(&step.id) {
+            self.step_order.push(step.id.clone());
+        }
         self.steps.insert(step.id.clone(), step);
         Ok(())
     }
@@ -169,6 +331,41 @@ impl Plan {
         next_steps
     }
     
+    /// Get step IDs in plan order, including steps not tracked in step_order
+    fn ordered_step_ids(&self) -> Vec<String> {
+        let mut order = self.step_order.clone();
+        let mut untracked: Vec<&String> = self.steps.keys().filter(|id| !order.contains(id)).collect();
+        untracked.sort();
+        order.extend(untracked.into_iter().cloned());
+        order
+    }
+    
+    /// Check if a step can reach another through unfinished dependencies
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        // Explicit stack, so long dependency chains cannot overflow
+        let mut visited = HashSet::new();
+        let mut stack = vec![from];
+        
+        while let Some(current) = stack.pop() {
+            let step = match self.steps.get(current) {
+                Some(step) => step,
+                None => continue,
+            };
+            
+            for dep in &step.dependencies {
+                if dep == to {
+                    return true;
+                }
+                let unfinished = self.steps.get(dep).is_some_and(|d| d.status != PlanStepStatus::Completed);
+                if unfinished && visited.insert(dep.as_str()) {
+                    stack.push(dep);
+                }
+            }
+        }
+        
+        false
+    }
+    
     /// Add metadata
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
@@ -186,6 +383,8 @@ pub struct PlanningSystem {
     pub plans: HashMap<String, Plan>,
     /// Current plan ID
     pub current_plan_id: Option<String>,
+    /// Maximum sub-plan nesting depth
+    pub max_planning_depth: usize,
 }
 
 impl PlanningSystem {
@@ -194,9 +393,70 @@ impl PlanningSystem {
         Ok(Self {
             plans: HashMap::new(),
             current_plan_id: None,
+            max_planning_depth: DEFAULT_MAX_PLANNING_DEPTH,
         })
     }
     
+    /// Set maximum sub-plan nesting depth
+    pub fn set_max_planning_depth(&mut self, depth: usize) {
+        self.max_planning_depth = depth;
+    }
+    
+    /// Expand a plan into its leaf steps, replacing steps that have a sub-plan
+    /// with the sub-plan's expanded steps
+    ///
+    /// Fails with `DepthExceeded` when sub-plans nest deeper than
+    /// `max_planning_depth`, which also catches plans that include themselves.
+    pub fn expand_plan(&self, plan_id: &str) -> Result<Vec<PlanStep>, PlanningError> {
+        let mut steps = Vec::new();
+        
+        // Explicit stack of (plan, ordered step IDs, next index), so deep
+        // hierarchies cannot overflow the call stack
+        let root = self.expansion_frame(plan_id)?;
+        let mut stack = vec![root];
+        
+        loop {
+            let depth = stack.len();
+            let (plan, order, index) = match stack.last_mut() {
+                Some(frame) => frame,
+                None => break,
+            };
+            let plan: &Plan = plan;
+            let step = match order.get(*index) {
+                Some(id) => &plan.steps[id],
+                None => {
+                    stack.pop();
+                    continue;
+                },
+            };
+            *index += 1;
+            
+            match &step.sub_plan {
+                Some(sub_plan) => {
+                    if depth > self.max_planning_depth {
+                        return Err(PlanningError::DepthExceeded(format!(
+                            "Sub-plan {} of {} is nested {} levels deep, the limit is {}",
+                            sub_plan, plan.name, depth, self.max_planning_depth
+                        )));
+                    }
+                    let frame = self.expansion_frame(sub_plan)?;
+                    stack.push(frame);
+                },
+                None => steps.push(step.clone()),
+            }
+        }
+        
+        Ok(steps)
+    }
+    
+    /// Get the starting expansion state for a plan
+    fn expansion_frame(&self, plan_id: &str) -> Result<(&Plan, Vec<String>, usize), PlanningError> {
+        let plan = self.get_plan(plan_id).ok_or_else(|| {
+            PlanningError::ProcessingError(format!("Plan with ID {} not found", plan_id))
+        })?;
+        Ok((plan, plan.ordered_step_ids(), 0))
+    }
+    
     /// Add plan
     pub fn add_plan(&mut self, plan: Plan) -> Result<(), PlanningError> {
         self.plans.insert(plan.id.clone(), plan);
@@ -318,6 +578,142 @@ impl PlanningSystem {
         Err(PlanningError::ProcessingError("Current plan not found".to_string()))
     }
     
+    /// Simulate a plan on a copy of the state without touching step status
+    pub fn simulate(&self, plan_id: &str, initial_state: &PlanState) -> Result<SimulationReport, PlanningError> {
+        let plan = self.get_plan(plan_id).ok_or_else(|| {
+            PlanningError::ProcessingError(format!("Plan with ID {} not found", plan_id))
+        })?;
+        
+        let mut state = initial_state.clone();
+        let mut executed: Vec<String> = Vec::new();
+        let mut violation = None;
+        
+        // Steps not tracked in step_order (inserted directly) run after ordered ones
+        let order = plan.ordered_step_ids();
+        
+        'simulation: loop {
+            let next = order.iter()
+                .filter(|id| !executed.contains(id))
+                .filter_map(|id| plan.steps.get(id))
+                .find(|step| step.dependencies.iter().all(|dep| executed.contains(dep)));
+            
+            let step = match next {
+                Some(step) => step,
+                None => break,
+            };
+            
+            let mut preconditions: Vec<(&String, &String)> = step.preconditions.iter().collect();
+            preconditions.sort();
+            for (key, expected) in preconditions {
+                let actual = state.get(key);
+                if actual != Some(expected) {
+                    violation = Some(PreconditionViolation {
+                        step_id: step.id.clone(),
+                        step_description: step.description.clone(),
+                        key: key.clone(),
+                        expected: expected.clone(),
+                        actual: actual.cloned(),
+                    });
+                    break 'simulation;
+                }
+            }
+            
+            for (key, value) in &step.effects {
+                state.insert(key.clone(), value.clone());
+            }
+            executed.push(step.id.clone());
+        }
+        
+        let unreachable_steps: Vec<String> = if violation.is_none() {
+            order.into_iter().filter(|id| !executed.contains(id)).collect()
+        } else {
+            Vec::new()
+        };
+        
+        Ok(SimulationReport {
+            plan_id: plan_id.to_string(),
+            success: violation.is_none() && unreachable_steps.is_empty(),
+            executed_steps: executed,
+            violation,
+            unreachable_steps,
+            final_state: state,
+        })
+    }
+    
+    /// Explain why steps of a plan have not run, given the current state
+    pub fn blocking_analysis(&self, plan_id: &str, state: &PlanState) -> Result<BlockingReport, PlanningError> {
+        let plan = self.get_plan(plan_id).ok_or_else(|| {
+            PlanningError::ProcessingError(format!("Plan with ID {} not found", plan_id))
+        })?;
+        
+        let order = plan.ordered_step_ids();
+        let mut pending_steps = Vec::new();
+        let mut in_progress_steps = Vec::new();
+        let mut deadlocked_steps = Vec::new();
+        
+        for id in &order {
+            let step = &plan.steps[id];
+            match step.status {
+                PlanStepStatus::InProgress => {
+                    in_progress_steps.push(id.clone());
+                    continue;
+                },
+                PlanStepStatus::NotStarted => {},
+                _ => continue,
+            }
+            
+            if plan.reaches(id, id) {
+                deadlocked_steps.push(id.clone());
+            }
+            
+            let mut reasons = Vec::new();
+            for dep in &step.dependencies {
+                match plan.steps.get(dep).map(|d| &d.status) {
+                    None => reasons.push(BlockReason::MissingDependency(dep.clone())),
+                    Some(PlanStepStatus::Failed(_)) => reasons.push(BlockReason::FailedDependency(dep.clone())),
+                    Some(PlanStepStatus::Completed) => {},
+                    Some(_) => reasons.push(BlockReason::PendingDependency(dep.clone())),
+                }
+            }
+            
+            let mut preconditions: Vec<(&String, &String)> = step.preconditions.iter().collect();
+            preconditions.sort();
+            for (key, expected) in preconditions {
+                let actual = state.get(key);
+                if actual == Some(expected) {
+                    continue;
+                }
+                
+                // Another unfinished step may still set the fact
+                let settable = plan.steps.values().any(|other| {
+                    other.id != step.id
+                        && matches!(other.status, PlanStepStatus::NotStarted | PlanStepStatus::InProgress)
+                        && other.effects.get(key) == Some(expected)
+                });
+                let (key, expected, actual) = (key.clone(), expected.clone(), actual.cloned());
+                reasons.push(if settable {
+                    BlockReason::UnmetPrecondition { key, expected, actual }
+                } else {
+                    BlockReason::UnsatisfiablePrecondition { key, expected, actual }
+                });
+            }
+            
+            pending_steps.push(BlockedStep {
+                step_id: id.clone(),
+                step_description: step.description.clone(),
+                ready: reasons.is_empty(),
+                reasons,
+            });
+        }
+        
+        Ok(BlockingReport {
+            plan_id: plan_id.to_string(),
+            pending_steps,
+            in_progress_steps,
+            deadlocked_steps,
+        })
+    }
+    
     /// Is plan completed
     pub fn is_plan_completed(&self) -> Result<bool, PlanningError> {
         if let Some(plan) = self.get_current_plan() {
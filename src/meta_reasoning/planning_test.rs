@@ -2,6 +2,8 @@
 mod tests {
     use super::*;
     use crate::meta_reasoning::planning::{PlanningSystem, PlanningStatus, PlanningError};
+    use crate::meta_reasoning::planning::{Plan, PlanState, PlanStep, PlanStepStatus};
+    use crate::meta_reasoning::{BlockReason, BlockedStep};
     use std::time::Duration;
 
     #[test]
@@ -81,4 +83,198 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_simulate_reports_precondition_violation() {
+        let mut plan = Plan::new("deploy", "Deploy a model");
+        
+        let mut fetch = PlanStep::new("fetch weights");
+        fetch.add_effect("weights", "downloaded");
+        let fetch_id = fetch.id.clone();
+        
+        let mut load = PlanStep::new("load model");
+        load.add_dependency(&fetch_id);
+        load.add_precondition("weights", "downloaded");
+        load.add_precondition("gpu", "available");
+        load.add_effect("model", "loaded");
+        let load_id = load.id.clone();
+        
+        let mut serve = PlanStep::new("serve model");
+        serve.add_dependency(&load_id);
+        serve.add_precondition("model", "loaded");
+        
+        plan.add_step(fetch).unwrap();
+        plan.add_step(load).unwrap();
+        plan.add_step(serve).unwrap();
+        let plan_id = plan.id.clone();
+        
+        let mut system = PlanningSystem::new().unwrap();
+        system.add_plan(plan).unwrap();
+        
+        let mut initial_state = PlanState::new();
+        initial_state.insert("gpu".to_string(), "busy".to_string());
+        
+        let report = system.simulate(&plan_id, &initial_state).unwrap();
+        
+        assert!(!report.success);
+        assert_eq!(report.executed_steps, vec![fetch_id]);
+        
+        let violation = report.violation.unwrap();
+        assert_eq!(violation.step_id, load_id);
+        assert_eq!(violation.key, "gpu");
+        assert_eq!(violation.expected, "available");
+        assert_eq!(violation.actual, Some("busy".to_string()));
+        
+        // Simulation has no side effects on the plan or the caller's state
+        let plan = system.get_plan(&plan_id).unwrap();
+        assert!(plan.steps.values().all(|s| s.status == PlanStepStatus::NotStarted));
+        assert!(!initial_state.contains_key("weights"));
+        
+        // With the GPU available the whole plan runs
+        initial_state.insert("gpu".to_string(), "available".to_string());
+        let report = system.simulate(&plan_id, &initial_state).unwrap();
+        assert!(report.success);
+        assert_eq!(report.executed_steps.len(), 3);
+        assert_eq!(report.final_state.get("model"), Some(&"loaded".to_string()));
+    }
+
+    #[test]
+    fn test_blocking_analysis_pinpoints_unsatisfiable_precondition() {
+        let mut plan = Plan::new("deploy", "Deploy a model");
+        
+        let mut fetch = PlanStep::new("fetch weights");
+        fetch.add_effect("weights", "downloaded");
+        let fetch_id = fetch.id.clone();
+        
+        let mut load = PlanStep::new("load model");
+        load.add_dependency(&fetch_id);
+        load.add_precondition("weights", "downloaded");
+        load.add_precondition("gpu", "available");
+        load.add_effect("model", "loaded");
+        let load_id = load.id.clone();
+        
+        let mut serve = PlanStep::new("serve model");
+        serve.add_dependency(&load_id);
+        serve.add_precondition("model", "loaded");
+        let serve_id = serve.id.clone();
+        
+        plan.add_step(fetch).unwrap();
+        plan.add_step(load).unwrap();
+        plan.add_step(serve).unwrap();
+        let plan_id = plan.id.clone();
+        
+        let mut system = PlanningSystem::new().unwrap();
+        system.add_plan(plan).unwrap();
+        system.set_current_plan(&plan_id).unwrap();
+        system.execute_next_step().unwrap();
+        system.complete_step(&fetch_id).unwrap();
+        
+        let mut state = PlanState::new();
+        state.insert("weights".to_string(), "downloaded".to_string());
+        state.insert("gpu".to_string(), "busy".to_string());
+        
+        let report = system.blocking_analysis(&plan_id, &state).unwrap();
+        
+        assert!(!report.is_deadlock());
+        assert!(report.ready_steps().is_empty());
+        assert_eq!(report.pending_steps, vec![
+            BlockedStep {
+                step_id: load_id.clone(),
+                step_description: "load model".to_string(),
+                ready: false,
+                reasons: vec![BlockReason::UnsatisfiablePrecondition {
+                    key: "gpu".to_string(),
+                    expected: "available".to_string(),
+                    actual: Some("busy".to_string()),
+                }],
+            },
+            BlockedStep {
+                step_id: serve_id,
+                step_description: "serve model".to_string(),
+                ready: false,
+                reasons: vec![
+                    BlockReason::PendingDependency(load_id),
+                    BlockReason::UnmetPrecondition {
+                        key: "model".to_string(),
+                        expected: "loaded".to_string(),
+                        actual: None,
+                    },
+                ],
+            },
+        ]);
+        assert_eq!(
+            report.pending_steps[0].reasons[0].to_string(),
+            "requires gpu=available but found busy and no remaining step sets it"
+        );
+    }
+    
+    #[test]
+    fn test_blocking_analysis_detects_deadlock() {
+        let mut plan = Plan::new("cycle", "Circular plan");
+        let mut first = PlanStep::new("first");
+        let mut second = PlanStep::new("second");
+        let independent = PlanStep::new("independent");
+        first.add_dependency(&second.id);
+        second.add_dependency(&first.id);
+        let (first_id, second_id, independent_id) = (first.id.clone(), second.id.clone(), independent.id.clone());
+        
+        plan.add_step(first).unwrap();
+        plan.add_step(second).unwrap();
+        plan.add_step(independent).unwrap();
+        let plan_id = plan.id.clone();
+        
+        let mut system = PlanningSystem::new().unwrap();
+        system.add_plan(plan).unwrap();
+        
+        let report = system.blocking_analysis(&plan_id, &PlanState::new()).unwrap();
+        
+        assert!(report.is_deadlock());
+        assert_eq!(report.deadlocked_steps, vec![first_id, second_id]);
+        let ready: Vec<&str> = report.ready_steps().iter().map(|s| s.step_id.as_str()).collect();
+        assert_eq!(ready, vec![independent_id.as_str()]);
+    }
+
+    /// Chain of plans where each plan's only step expands into the next
+    fn nested_plans(planning: &mut PlanningSystem, depth: usize) -> String {
+        let mut leaf = Plan::new("level_0", "Innermost plan");
+        leaf.add_step(PlanStep::new("leaf work")).unwrap();
+        let mut child_id = leaf.id.clone();
+        planning.add_plan(leaf).unwrap();
+
+        for level in 1..=depth {
+            let mut plan = Plan::new(&format!("level_{}", level), "Wrapper plan");
+            let mut step = PlanStep::new("expand");
+            step.set_sub_plan(&child_id);
+            plan.add_step(step).unwrap();
+            child_id = plan.id.clone();
+            planning.add_plan(plan).unwrap();
+        }
+
+        child_id
+    }
+
+    #[test]
+    fn test_expand_plan_enforces_depth_limit() {
+        let mut planning = PlanningSystem::new().unwrap();
+        let root = nested_plans(&mut planning, 10_000);
+
+        match planning.expand_plan(&root) {
+            Err(PlanningError::DepthExceeded(msg)) => assert!(msg.contains("the limit is 32"), "{}", msg),
+            other => panic!("expected depth error, got {:?}", other),
+        }
+
+        planning.set_max_planning_depth(20_000);
+        let steps = planning.expand_plan(&root);
+        assert!(matches!(steps, Ok(ref steps) if steps.len() == 1 && steps[0].description == "leaf work"));
+
+        // A plan that includes itself is cut off by the same limit
+        let mut cyclic = Plan::new("cyclic", "Includes itself");
+        let mut step = PlanStep::new("recurse");
+        step.set_sub_plan(&cyclic.id);
+        cyclic.add_step(step).unwrap();
+        let cyclic_id = cyclic.id.clone();
+        planning.add_plan(cyclic).unwrap();
+        planning.set_max_planning_depth(8);
+        assert!(matches!(planning.expand_plan(&cyclic_id), Err(PlanningError::DepthExceeded(_))));
+    }
 }
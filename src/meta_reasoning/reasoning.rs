@@ -1,6 +1,20 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Metadata key naming the inference rule that derived a step
+pub const METADATA_RULE: &str = "rule";
+
+/// Metadata key listing the IDs of the steps a step was derived from
+pub const METADATA_DERIVED_FROM: &str = "derived_from";
+
+/// Metadata key marking a step as the negation of its description ("true")
+pub const METADATA_NEGATED: &str = "negated";
+
+/// Inference rule name for modus ponens
+pub const RULE_MODUS_PONENS: &str = "modus_ponens";
 
 /// Reasoning error
 #[derive(Debug)]
@@ -26,7 +40,7 @@ impl fmt::Display for ReasoningError {
 }
 
 /// Reasoning strategy
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReasoningStrategy {
     /// Deductive reasoning
     Deductive,
@@ -55,8 +69,47 @@ impl fmt::Display for ReasoningStrategy {
     }
 }
 
+/// How a derived step's confidence is computed from its inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfidencePropagation {
+    /// Weakest input; long chains never decay below their weakest link
+    #[default]
+    Min,
+    /// Product of inputs, treating their uncertainty as independent
+    Product,
+    /// Mean of inputs, each weighted equally
+    WeightedAverage,
+}
+
+impl ConfidencePropagation {
+    /// Combine input confidences; empty input yields 0.0
+    pub fn combine(&self, confidences: &[f32]) -> f32 {
+        if confidences.is_empty() {
+            return 0.0;
+        }
+        
+        match self {
+            ConfidencePropagation::Min => confidences.iter().copied().fold(f32::INFINITY, f32::min),
+            ConfidencePropagation::Product => confidences.iter().product(),
+            ConfidencePropagation::WeightedAverage => {
+                confidences.iter().sum::<f32>() / confidences.len() as f32
+            }
+        }
+    }
+}
+
+impl fmt::Display for ConfidencePropagation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfidencePropagation::Min => write!(f, "Min"),
+            ConfidencePropagation::Product => write!(f, "Product"),
+            ConfidencePropagation::WeightedAverage => write!(f, "WeightedAverage"),
+        }
+    }
+}
+
 /// Reasoning step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasoningStep {
     /// Step ID
     pub id: String,
@@ -93,8 +146,52 @@ impl ReasoningStep {
     }
 }
 
+/// Split an "If X then Y" proposition into antecedent and consequent
+pub fn parse_implication(content: &str) -> Option<(&str, &str)> {
+    let content = content.trim();
+    let rest = content.get(..3)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("if "))
+        .map(|_| &content[3..])?;
+    let split = rest.to_ascii_lowercase().find(" then ")?;
+    let antecedent = rest[..split].trim();
+    let consequent = rest[split + " then ".len()..].trim();
+    
+    if antecedent.is_empty() || consequent.is_empty() {
+        None
+    } else {
+        Some((antecedent, consequent))
+    }
+}
+
+/// Normalize proposition content for duplicate detection
+fn normalize_proposition(content: &str) -> String {
+    content.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Split a step into its polarity and normalized proposition
+///
+/// Leading "not " and "¬" prefixes and the `METADATA_NEGATED` marker each
+/// flip polarity, so "not not X" is positive. Returns `true` for negated.
+fn proposition_polarity(step: &ReasoningStep) -> (bool, String) {
+    let mut negated = step.get_metadata(METADATA_NEGATED).is_some_and(|v| v == "true");
+    let mut content = normalize_proposition(&step.description);
+    
+    loop {
+        if let Some(rest) = content.strip_prefix('¬') {
+            content = rest.trim_start().to_string();
+        } else if let Some(rest) = content.strip_prefix("not ") {
+            content = rest.to_string();
+        } else {
+            break;
+        }
+        negated = !negated;
+    }
+    
+    (negated, content)
+}
+
 /// Reasoning chain
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasoningChain {
     /// Chain ID
     pub id: String,
@@ -168,6 +265,8 @@ pub struct ReasoningSystem {
     pub chains: HashMap<String, ReasoningChain>,
     /// Current chain ID
     pub current_chain_id: Option<String>,
+    /// Default confidence propagation for derived steps
+    pub confidence_propagation: ConfidencePropagation,
 }
 
 impl ReasoningSystem {
@@ -176,9 +275,15 @@ impl ReasoningSystem {
         Ok(Self {
             chains: HashMap::new(),
             current_chain_id: None,
+            confidence_propagation: ConfidencePropagation::default(),
         })
     }
     
+    /// Set default confidence propagation
+    pub fn set_confidence_propagation(&mut self, propagation: ConfidencePropagation) {
+        self.confidence_propagation = propagation;
+    }
+    
     /// Add chain
     pub fn add_chain(&mut self, chain: ReasoningChain) -> Result<(), ReasoningError> {
         self.chains.insert(chain.id.clone(), chain);
@@ -241,6 +346,169 @@ impl ReasoningSystem {
         }
     }
     
+    /// Derive new steps in a chain by forward chaining with modus ponens
+    ///
+    /// Each step's description is a proposition. Wherever one step reads
+    /// "If X then Y" and another reads X, a deductive step Y is added, with
+    /// confidence combined by the system's `confidence_propagation`. Each pass scans all steps, including
+    /// ones derived in earlier passes; chaining stops when a pass derives
+    /// nothing or after `max_steps` passes. Propositions already in the chain
+    /// are never derived again (compared ignoring case and whitespace).
+    ///
+    /// Returns the IDs of the added steps in derivation order.
+    pub fn forward_chain(&mut self, chain_id: &str, max_steps: usize) -> Result<Vec<String>, ReasoningError> {
+        let propagation = self.confidence_propagation;
+        let chain = self.chains.get_mut(chain_id).ok_or_else(|| {
+            ReasoningError::ProcessingError(format!("Chain with ID {} not found", chain_id))
+        })?;
+        
+        let mut known: HashSet<String> = chain.steps.iter()
+            .map(|s| normalize_proposition(&s.description))
+            .collect();
+        let mut derived = Vec::new();
+        
+        for _ in 0..max_steps {
+            // Antecedent lookup by normalized content
+            let facts: HashMap<String, &ReasoningStep> = chain.steps.iter()
+                .map(|s| (normalize_proposition(&s.description), s))
+                .collect();
+            
+            let mut new_steps = Vec::new();
+            for rule in &chain.steps {
+                let (antecedent, consequent) = match parse_implication(&rule.description) {
+                    Some(parts) => parts,
+                    None => continue,
+                };
+                let fact = match facts.get(&normalize_proposition(antecedent)) {
+                    Some(fact) => fact,
+                    None => continue,
+                };
+                if !known.insert(normalize_proposition(consequent)) {
+                    continue;
+                }
+                
+                let mut step = ReasoningStep::new(
+                    consequent,
+                    ReasoningStrategy::Deductive,
+                    propagation.combine(&[fact.confidence, rule.confidence]),
+                );
+                step.add_metadata(METADATA_RULE, RULE_MODUS_PONENS);
+                step.add_metadata(METADATA_DERIVED_FROM, &format!("{},{}", fact.id, rule.id));
+                new_steps.push(step);
+            }
+            
+            if new_steps.is_empty() {
+                break;
+            }
+            
+            for step in new_steps {
+                derived.push(step.id.clone());
+                chain.add_step(step)?;
+            }
+        }
+        
+        Ok(derived)
+    }
+    
+    /// Export a chain as JSON
+    pub fn export_chain(&self, chain_id: &str) -> Result<String, ReasoningError> {
+        let chain = self.chains.get(chain_id).ok_or_else(|| {
+            ReasoningError::ProcessingError(format!("Chain with ID {} not found", chain_id))
+        })?;
+        
+        serde_json::to_string_pretty(chain).map_err(|e| {
+            ReasoningError::ProcessingError(format!("Failed to export chain: {}", e))
+        })
+    }
+    
+    /// Import a chain exported by `export_chain`, returning its ID
+    ///
+    /// The chain keeps its exported ID unless a chain with that ID already
+    /// exists, in which case it is given a new one.
+    pub fn import_chain(&mut self, json: &str) -> Result<String, ReasoningError> {
+        let mut chain: ReasoningChain = serde_json::from_str(json).map_err(|e| {
+            ReasoningError::ProcessingError(format!("Failed to import chain: {}", e))
+        })?;
+        
+        if self.chains.contains_key(&chain.id) {
+            chain.id = uuid::Uuid::new_v4().to_string();
+        }
+        
+        let id = chain.id.clone();
+        self.add_chain(chain)?;
+        Ok(id)
+    }
+    
+    /// Find pairs of steps in a chain whose propositions negate each other
+    ///
+    /// Each pair holds the earlier step's ID first, in chain order.
+    pub fn find_contradictions(&self, chain_id: &str) -> Result<Vec<(String, String)>, ReasoningError> {
+        let chain = self.chains.get(chain_id).ok_or_else(|| {
+            ReasoningError::ProcessingError(format!("Chain with ID {} not found", chain_id))
+        })?;
+        
+        let propositions: Vec<(bool, String)> = chain.steps.iter().map(proposition_polarity).collect();
+        let mut contradictions = Vec::new();
+        
+        for (i, (negated, content)) in propositions.iter().enumerate() {
+            for (j, (other_negated, other_content)) in propositions.iter().enumerate().skip(i + 1) {
+                if negated != other_negated && content == other_content {
+                    contradictions.push((chain.steps[i].id.clone(), chain.steps[j].id.clone()));
+                }
+            }
+        }
+        
+        Ok(contradictions)
+    }
+    
+    /// Apply a rule to input steps using the default confidence propagation
+    pub fn apply_rule(
+        &mut self,
+        chain_id: &str,
+        rule: ReasoningStrategy,
+        inputs: &[&str],
+        description: &str,
+    ) -> Result<String, ReasoningError> {
+        let propagation = self.confidence_propagation;
+        self.apply_rule_with(chain_id, rule, inputs, description, propagation)
+    }
+    
+    /// Apply a rule to input steps, adding the conclusion as a new step
+    ///
+    /// `inputs` are step IDs in the chain. The new step's confidence is the
+    /// inputs' confidences combined by `propagation`. Returns its ID.
+    pub fn apply_rule_with(
+        &mut self,
+        chain_id: &str,
+        rule: ReasoningStrategy,
+        inputs: &[&str],
+        description: &str,
+        propagation: ConfidencePropagation,
+    ) -> Result<String, ReasoningError> {
+        let chain = self.chains.get_mut(chain_id).ok_or_else(|| {
+            ReasoningError::ProcessingError(format!("Chain with ID {} not found", chain_id))
+        })?;
+        
+        if inputs.is_empty() {
+            return Err(ReasoningError::ProcessingError("Rule requires at least one input".to_string()));
+        }
+        
+        let confidences = inputs.iter()
+            .map(|id| {
+                chain.get_step(id).map(|s| s.confidence).ok_or_else(|| {
+                    ReasoningError::ProcessingError(format!("Step with ID {} not found", id))
+                })
+            })
+            .collect::<Result<Vec<f32>, ReasoningError>>()?;
+        
+        let mut step = ReasoningStep::new(description, rule, propagation.combine(&confidences));
+        step.add_metadata(METADATA_DERIVED_FROM, &inputs.join(","));
+        let id = step.id.clone();
+        chain.add_step(step)?;
+        
+        Ok(id)
+    }
+    
     /// Reason
     pub fn reason(&self, _input: &str) -> Result<ReasoningChain, ReasoningError> {
         // In a real implementation, this would perform reasoning on the input
@@ -2,6 +2,10 @@
 mod tests {
     use super::*;
     use crate::meta_reasoning::reasoning::{ReasoningConfig, ReasoningResult};
+    use crate::meta_reasoning::reasoning::{
+        parse_implication, ConfidencePropagation, ReasoningChain, ReasoningStep, ReasoningStrategy, ReasoningSystem, METADATA_NEGATED, METADATA_RULE,
+        RULE_MODUS_PONENS,
+    };
     use std::collections::HashMap;
     use std::time::Duration;
 
@@ -57,4 +61,175 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_forward_chain_derives_transitive_conclusion() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let mut chain = ReasoningChain::new("kb", "Knowledge base");
+        chain.add_step(ReasoningStep::new("A", ReasoningStrategy::Other("premise".to_string()), 1.0)).unwrap();
+        chain.add_step(ReasoningStep::new("If A then B", ReasoningStrategy::Other("premise".to_string()), 0.9)).unwrap();
+        chain.add_step(ReasoningStep::new("If B then C", ReasoningStrategy::Other("premise".to_string()), 0.8)).unwrap();
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+
+        let derived = system.forward_chain(&chain_id, 10).unwrap();
+
+        let chain = system.get_chain(&chain_id).unwrap();
+        let contents: Vec<&str> = derived.iter()
+            .map(|id| chain.get_step(id).unwrap().description.as_str())
+            .collect();
+        assert_eq!(contents, vec!["B", "C"]);
+
+        let c = chain.get_step(&derived[1]).unwrap();
+        assert_eq!(c.strategy, ReasoningStrategy::Deductive);
+        assert_eq!(c.get_metadata(METADATA_RULE).map(String::as_str), Some(RULE_MODUS_PONENS));
+        assert!((c.confidence - 0.8).abs() < f32::EPSILON);
+
+        // Nothing new on a second run
+        assert!(system.forward_chain(&chain_id, 10).unwrap().is_empty());
+        assert_eq!(system.get_chain(&chain_id).unwrap().steps.len(), 5);
+    }
+
+    #[test]
+    fn test_forward_chain_respects_max_steps_and_duplicates() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let mut chain = ReasoningChain::new("kb", "Knowledge base");
+        for content in ["A", "If A then B", "if a THEN b", "If B then C", "c"] {
+            chain.add_step(ReasoningStep::new(content, ReasoningStrategy::Deductive, 1.0)).unwrap();
+        }
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+
+        // One pass derives B once; C already exists
+        let derived = system.forward_chain(&chain_id, 1).unwrap();
+        assert_eq!(derived.len(), 1);
+        assert!(system.forward_chain(&chain_id, 5).unwrap().is_empty());
+
+        assert_eq!(parse_implication("If it rains then the ground is wet"), Some(("it rains", "the ground is wet")));
+        assert_eq!(parse_implication("Iffy then"), None);
+        assert!(matches!(system.forward_chain("missing", 1), Err(ReasoningError::ProcessingError(_))));
+    }
+
+    fn chain_with_inputs(system: &mut ReasoningSystem) -> (String, String, String) {
+        let mut chain = ReasoningChain::new("evidence", "Two independent observations");
+        let a = ReasoningStep::new("Sensor reports smoke", ReasoningStrategy::Inductive, 0.8);
+        let b = ReasoningStep::new("Camera shows haze", ReasoningStrategy::Inductive, 0.5);
+        let (a_id, b_id) = (a.id.clone(), b.id.clone());
+        chain.add_step(a).unwrap();
+        chain.add_step(b).unwrap();
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+        (chain_id, a_id, b_id)
+    }
+
+    #[test]
+    fn test_apply_rule_confidence_propagation_modes() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let (chain_id, a, b) = chain_with_inputs(&mut system);
+
+        let cases = [
+            (ConfidencePropagation::Min, 0.5),
+            (ConfidencePropagation::Product, 0.4),
+            (ConfidencePropagation::WeightedAverage, 0.65),
+        ];
+        for (propagation, expected) in cases {
+            let id = system
+                .apply_rule_with(&chain_id, ReasoningStrategy::Abductive, &[&a, &b], "Fire", propagation)
+                .unwrap();
+            let confidence = system.get_chain(&chain_id).unwrap().get_step(&id).unwrap().confidence;
+            assert!((confidence - expected).abs() < 1e-6, "{}: {}", propagation, confidence);
+        }
+    }
+
+    #[test]
+    fn test_apply_rule_uses_default_propagation() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let (chain_id, a, b) = chain_with_inputs(&mut system);
+
+        let id = system.apply_rule(&chain_id, ReasoningStrategy::Abductive, &[&a, &b], "Fire").unwrap();
+        assert!((system.get_chain(&chain_id).unwrap().get_step(&id).unwrap().confidence - 0.5).abs() < 1e-6);
+
+        system.set_confidence_propagation(ConfidencePropagation::Product);
+        let id = system.apply_rule(&chain_id, ReasoningStrategy::Abductive, &[&a, &b], "Fire").unwrap();
+        assert!((system.get_chain(&chain_id).unwrap().get_step(&id).unwrap().confidence - 0.4).abs() < 1e-6);
+
+        assert!(system.apply_rule(&chain_id, ReasoningStrategy::Abductive, &[], "Fire").is_err());
+        assert!(system.apply_rule(&chain_id, ReasoningStrategy::Abductive, &["missing"], "Fire").is_err());
+    }
+
+    #[test]
+    fn test_find_contradictions() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let mut chain = ReasoningChain::new("beliefs", "Beliefs about the door");
+        let open = ReasoningStep::new("The door is open", ReasoningStrategy::Deductive, 0.9);
+        let not_open = ReasoningStep::new("Not the door is open", ReasoningStrategy::Deductive, 0.9);
+        let locked = ReasoningStep::new("¬ the door is locked", ReasoningStrategy::Inductive, 0.7);
+        let mut marked = ReasoningStep::new("the door is LOCKED", ReasoningStrategy::Inductive, 0.6);
+        marked.add_metadata(METADATA_NEGATED, "true");
+        let also_locked = ReasoningStep::new("The door is locked", ReasoningStrategy::Abductive, 0.8);
+        let ids: Vec<String> = [&open, &not_open, &locked, &marked, &also_locked].iter().map(|s| s.id.clone()).collect();
+        for step in [open, not_open, locked, marked, also_locked] {
+            chain.add_step(step).unwrap();
+        }
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+
+        let contradictions = system.find_contradictions(&chain_id).unwrap();
+        assert_eq!(contradictions, vec![
+            (ids[0].clone(), ids[1].clone()),
+            (ids[2].clone(), ids[4].clone()),
+            (ids[3].clone(), ids[4].clone()),
+        ]);
+        assert!(system.find_contradictions("missing").is_err());
+    }
+
+    #[test]
+    fn test_find_contradictions_consistent_beliefs() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let mut chain = ReasoningChain::new("beliefs", "Consistent beliefs");
+        for content in ["The light is on", "Not not the light is on", "Nothing is wrong", "not the fan is on"] {
+            chain.add_step(ReasoningStep::new(content, ReasoningStrategy::Deductive, 0.9)).unwrap();
+        }
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+
+        assert!(system.find_contradictions(&chain_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_import_chain_round_trip() {
+        let mut system = ReasoningSystem::new().unwrap();
+        let mut chain = ReasoningChain::new("trace", "Persisted trace");
+        chain.add_metadata("session", "42");
+        let mut step = ReasoningStep::new("Battery is low", ReasoningStrategy::Other("sensor".to_string()), 0.75);
+        step.add_metadata(METADATA_RULE, RULE_MODUS_PONENS);
+        let step_id = step.id.clone();
+        chain.add_step(step).unwrap();
+        let chain_id = chain.id.clone();
+        system.add_chain(chain).unwrap();
+
+        let json = system.export_chain(&chain_id).unwrap();
+
+        let mut restored = ReasoningSystem::new().unwrap();
+        let imported_id = restored.import_chain(&json).unwrap();
+        assert_eq!(imported_id, chain_id);
+
+        let chain = restored.get_chain(&imported_id).unwrap();
+        assert_eq!(chain.name, "trace");
+        assert_eq!(chain.get_metadata("session").map(String::as_str), Some("42"));
+        assert_eq!(chain.steps.len(), 1);
+        let step = chain.get_step(&step_id).unwrap();
+        assert_eq!(step.description, "Battery is low");
+        assert_eq!(step.strategy, ReasoningStrategy::Other("sensor".to_string()));
+        assert!((step.confidence - 0.75).abs() < f32::EPSILON);
+        assert_eq!(step.get_metadata(METADATA_RULE).map(String::as_str), Some(RULE_MODUS_PONENS));
+
+        // Importing again does not clobber the existing chain
+        let second_id = restored.import_chain(&json).unwrap();
+        assert_ne!(second_id, chain_id);
+        assert_eq!(restored.chains.len(), 2);
+
+        assert!(restored.import_chain("{").is_err());
+        assert!(system.export_chain("missing").is_err());
+    }
 }
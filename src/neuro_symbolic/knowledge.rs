@@ -1,6 +1,9 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Separator between namespace and key in qualified keys
+pub const NAMESPACE_SEPARATOR: char = ':';
 
 /// Knowledge error
 #[derive(Debug)]
@@ -73,6 +76,12 @@ pub struct KnowledgeEntry {
     pub confidence: f32,
     /// Entry timestamp
     pub timestamp: u64,
+    /// Keys of entries this entry refers to
+    pub references: Vec<String>,
+    /// Expiry timestamp
+    pub expires_at: Option<u64>,
+    /// Whether the entry is active
+    pub active: bool,
     /// Entry metadata
     pub metadata: HashMap<String, String>,
 }
@@ -90,10 +99,28 @@ impl KnowledgeEntry {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_secs(),
+            references: Vec::new(),
+            expires_at: None,
+            active: true,
             metadata: HashMap::new(),
         }
     }
     
+    /// Add reference
+    pub fn add_reference(&mut self, key: &str) {
+        self.references.push(key.to_string());
+    }
+    
+    /// Set expiry timestamp
+    pub fn set_expires_at(&mut self, expires_at: u64) {
+        self.expires_at = Some(expires_at);
+    }
+    
+    /// Check if entry is expired at the given time
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map_or(false, |expires_at| expires_at <= now)
+    }
+    
     /// Add metadata
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
@@ -105,6 +132,59 @@ impl KnowledgeEntry {
     }
 }
 
+/// Knowledge integrity issue
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrityIssue {
+    /// Active entries with the same key disagree
+    Contradiction {
+        /// Entry key
+        key: String,
+        /// Conflicting entry IDs
+        entry_ids: Vec<String>,
+    },
+    /// Entry references a key with no entries
+    DanglingReference {
+        /// Entry ID
+        entry_id: String,
+        /// Missing key
+        reference: String,
+    },
+    /// Entry is expired but still active
+    ExpiredActive {
+        /// Entry ID
+        entry_id: String,
+        /// Expiry timestamp
+        expires_at: u64,
+    },
+}
+
+impl IntegrityIssue {
+    /// Get IDs of the offending entries
+    pub fn entry_ids(&self) -> Vec<String> {
+        match self {
+            IntegrityIssue::Contradiction { entry_ids, .. } => entry_ids.clone(),
+            IntegrityIssue::DanglingReference { entry_id, .. } => vec![entry_id.clone()],
+            IntegrityIssue::ExpiredActive { entry_id, .. } => vec![entry_id.clone()],
+        }
+    }
+}
+
+impl fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityIssue::Contradiction { key, entry_ids } => {
+                write!(f, "Contradictory values for key {}: {}", key, entry_ids.join(", "))
+            },
+            IntegrityIssue::DanglingReference { entry_id, reference } => {
+                write!(f, "Entry {} references missing key {}", entry_id, reference)
+            },
+            IntegrityIssue::ExpiredActive { entry_id, expires_at } => {
+                write!(f, "Entry {} expired at {} but is still active", entry_id, expires_at)
+            },
+        }
+    }
+}
+
 /// Knowledge system
 pub struct KnowledgeSystem {
     /// Knowledge entries
@@ -220,6 +300,56 @@ impl KnowledgeSystem {
             .collect()
     }
     
+    /// Validate integrity, returning contradictions, dangling references and expired active entries
+    pub fn validate_integrity(&self, now: u64) -> Vec<IntegrityIssue> {
+        let mut issues = Vec::new();
+        
+        let mut keys: Vec<&String> = self.index.keys().collect();
+        keys.sort();
+        
+        for key in keys {
+            let mut entries: Vec<&KnowledgeEntry> = self.get_entries_by_key(key)
+                .into_iter()
+                .filter(|e| e.active && !e.is_expired(now))
+                .collect();
+            entries.sort_by(|a, b| a.id.cmp(&b.id));
+            
+            if let Some(first) = entries.first() {
+                if entries.iter().any(|e| e.value != first.value) {
+                    issues.push(IntegrityIssue::Contradiction {
+                        key: key.clone(),
+                        entry_ids: entries.iter().map(|e| e.id.clone()).collect(),
+                    });
+                }
+            }
+        }
+        
+        let mut entries: Vec<&KnowledgeEntry> = self.entries.values().collect();
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        
+        for entry in &entries {
+            for reference in &entry.references {
+                if !self.index.contains_key(reference) {
+                    issues.push(IntegrityIssue::DanglingReference {
+                        entry_id: entry.id.clone(),
+                        reference: reference.clone(),
+                    });
+                }
+            }
+        }
+        
+        for entry in &entries {
+            if entry.active && entry.is_expired(now) {
+                issues.push(IntegrityIssue::ExpiredActive {
+                    entry_id: entry.id.clone(),
+                    expires_at: entry.expires_at.unwrap_or_default(),
+                });
+            }
+        }
+        
+        issues
+    }
+    
     /// Get entries by time range
     pub fn get_entries_by_time_range(&self, start: u64, end: u64) -> Vec<&KnowledgeEntry> {
         self.entries.values()
@@ -228,6 +358,153 @@ impl KnowledgeSystem {
     }
 }
 
+/// Manager hosting multiple namespaced knowledge bases
+pub struct KnowledgeBaseManager {
+    /// Knowledge bases by namespace
+    pub namespaces: HashMap<String, KnowledgeSystem>,
+    /// Namespaces each namespace may read from
+    pub federations: HashMap<String, HashSet<String>>,
+}
+
+impl KnowledgeBaseManager {
+    /// Create a new knowledge base manager
+    pub fn new() -> Result<Self, KnowledgeError> {
+        Ok(Self {
+            namespaces: HashMap::new(),
+            federations: HashMap::new(),
+        })
+    }
+    
+    /// Split a key into an optional namespace qualifier and the bare key
+    ///
+    /// The prefix before the separator is a qualifier only if it names an
+    /// existing namespace, so keys such as `ratio:1` stay whole.
+    pub fn parse_key<'a>(&self, key: &'a str) -> (Option<&'a str>, &'a str) {
+        match key.split_once(NAMESPACE_SEPARATOR) {
+            Some((namespace, bare_key)) if self.namespaces.contains_key(namespace) => (Some(namespace), bare_key),
+            _ => (None, key),
+        }
+    }
+    
+    /// Create namespace
+    pub fn create_namespace(&mut self, namespace: &str) -> Result<(), KnowledgeError> {
+        if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+            return Err(KnowledgeError::InitializationError(format!("Invalid namespace: '{}'", namespace)));
+        }
+        if self.namespaces.contains_key(namespace) {
+            return Err(KnowledgeError::InitializationError(format!("Namespace {} already exists", namespace)));
+        }
+        
+        self.namespaces.insert(namespace.to_string(), KnowledgeSystem::new()?);
+        Ok(())
+    }
+    
+    /// Remove namespace and any federation links to it
+    pub fn remove_namespace(&mut self, namespace: &str) -> Result<KnowledgeSystem, KnowledgeError> {
+        let knowledge = self.namespaces.remove(namespace).ok_or_else(|| {
+            KnowledgeError::Other(format!("Namespace {} not found", namespace))
+        })?;
+        
+        self.federations.remove(namespace);
+        for targets in self.federations.values_mut() {
+            targets.remove(namespace);
+        }
+        
+        Ok(knowledge)
+    }
+    
+    /// Get namespace
+    pub fn get_namespace(&self, namespace: &str) -> Option<&KnowledgeSystem> {
+        self.namespaces.get(namespace)
+    }
+    
+    /// Get mutable namespace
+    pub fn get_namespace_mut(&mut self, namespace: &str) -> Option<&mut KnowledgeSystem> {
+        self.namespaces.get_mut(namespace)
+    }
+    
+    /// Get all namespace names
+    pub fn get_namespaces(&self) -> Vec<&String> {
+        self.namespaces.keys().collect()
+    }
+    
+    /// Add entry to namespace
+    pub fn add_entry(&mut self, namespace: &str, entry: KnowledgeEntry) -> Result<(), KnowledgeError> {
+        self.get_namespace_mut(namespace)
+            .ok_or_else(|| KnowledgeError::UpdateError(format!("Namespace {} not found", namespace)))?
+            .add_entry(entry)
+    }
+    
+    /// Allow queries from one namespace to read another
+    pub fn federate(&mut self, from: &str, to: &str) -> Result<(), KnowledgeError> {
+        for namespace in &[from, to] {
+            if !self.namespaces.contains_key(*namespace) {
+                return Err(KnowledgeError::Other(format!("Namespace {} not found", namespace)));
+            }
+        }
+        
+        if from != to {
+            self.federations.entry(from.to_string()).or_default().insert(to.to_string());
+        }
+        Ok(())
+    }
+    
+    /// Revoke federation between namespaces
+    pub fn unfederate(&mut self, from: &str, to: &str) {
+        if let Some(targets) = self.federations.get_mut(from) {
+            targets.remove(to);
+        }
+    }
+    
+    /// Check if a namespace may read another
+    pub fn is_federated(&self, from: &str, to: &str) -> bool {
+        from == to || self.federations.get(from).is_some_and(|t| t.contains(to))
+    }
+    
+    /// Query from a namespace; qualified keys must name the namespace itself or a federated one
+    pub fn query(&self, namespace: &str, key: &str) -> Result<Option<&KnowledgeEntry>, KnowledgeError> {
+        let (qualifier, bare_key) = self.parse_key(key);
+        let target = qualifier.unwrap_or(namespace);
+        
+        if !self.is_federated(namespace, target) {
+            return Err(KnowledgeError::QueryError(format!(
+                "Namespace {} is not federated with {}",
+                namespace, target
+            )));
+        }
+        
+        self.get_namespace(target)
+            .ok_or_else(|| KnowledgeError::QueryError(format!("Namespace {} not found", target)))?
+            .query(bare_key)
+    }
+    
+    /// Query a namespace and all namespaces federated with it, best match first
+    pub fn query_federated(&self, namespace: &str, key: &str) -> Result<Vec<(&str, &KnowledgeEntry)>, KnowledgeError> {
+        if !self.namespaces.contains_key(namespace) {
+            return Err(KnowledgeError::QueryError(format!("Namespace {} not found", namespace)));
+        }
+        
+        let mut targets: Vec<&str> = vec![namespace];
+        if let Some(federated) = self.federations.get(namespace) {
+            let mut federated: Vec<&str> = federated.iter().map(|s| s.as_str()).collect();
+            federated.sort();
+            targets.extend(federated);
+        }
+        
+        let mut results = Vec::new();
+        for target in targets {
+            if let Some((name, knowledge)) = self.namespaces.get_key_value(target) {
+                if let Some(entry) = knowledge.query(key)? {
+                    results.push((name.as_str(), entry));
+                }
+            }
+        }
+        
+        results.sort_by(|a, b| b.1.confidence.partial_cmp(&a.1.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(results)
+    }
+}
+
 /// Initialize knowledge module
 pub fn init() -> Result<(), KnowledgeError> {
     // Initialize knowledge module
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuro_symbolic::{KnowledgeBaseManager, KnowledgeEntry, KnowledgeError, KnowledgeSourceType};
+
+    fn manager() -> KnowledgeBaseManager {
+        let mut manager = KnowledgeBaseManager::new().unwrap();
+        manager.create_namespace("medical").unwrap();
+        manager.create_namespace("finance").unwrap();
+        manager.add_entry("medical", KnowledgeEntry::new("aspirin", "analgesic", KnowledgeSourceType::Internal, 0.9)).unwrap();
+        manager.add_entry("medical", KnowledgeEntry::new("interest", "attention", KnowledgeSourceType::Learned, 0.4)).unwrap();
+        manager.add_entry("finance", KnowledgeEntry::new("interest", "cost of borrowing", KnowledgeSourceType::Internal, 0.8)).unwrap();
+        manager
+    }
+
+    #[test]
+    fn test_namespaces_are_isolated() {
+        let manager = manager();
+
+        assert_eq!(manager.query("medical", "aspirin").unwrap().unwrap().value, "analgesic");
+        assert!(manager.query("finance", "aspirin").unwrap().is_none());
+        assert_eq!(manager.query("finance", "interest").unwrap().unwrap().value, "cost of borrowing");
+        assert_eq!(manager.query("medical", "interest").unwrap().unwrap().value, "attention");
+
+        match manager.query("finance", "medical:aspirin") {
+            Err(KnowledgeError::QueryError(msg)) => assert!(msg.contains("not federated")),
+            other => panic!("expected query error, got {:?}", other.map(|e| e.map(|e| e.value.clone()))),
+        }
+
+        let federated = manager.query_federated("finance", "interest").unwrap();
+        assert_eq!(federated.len(), 1);
+        assert_eq!(federated[0].0, "finance");
+    }
+
+    #[test]
+    fn test_federated_query_spans_namespaces() {
+        let mut manager = manager();
+        manager.federate("finance", "medical").unwrap();
+
+        assert_eq!(manager.query("finance", "medical:aspirin").unwrap().unwrap().value, "analgesic");
+        assert!(manager.query("finance", "aspirin").unwrap().is_none());
+        assert!(manager.query("medical", "finance:interest").is_err());
+
+        let results: Vec<(&str, &str)> = manager.query_federated("finance", "interest").unwrap()
+            .into_iter()
+            .map(|(namespace, entry)| (namespace, entry.value.as_str()))
+            .collect();
+        assert_eq!(results, vec![("finance", "cost of borrowing"), ("medical", "attention")]);
+
+        manager.unfederate("finance", "medical");
+        assert!(manager.query("finance", "medical:aspirin").is_err());
+    }
+
+    #[test]
+    fn test_key_prefix_is_a_qualifier_only_for_existing_namespaces() {
+        let mut manager = manager();
+        manager.add_entry("finance", KnowledgeEntry::new("ratio:1", "debt to equity", KnowledgeSourceType::Internal, 0.7)).unwrap();
+
+        assert_eq!(manager.parse_key("medical:aspirin"), (Some("medical"), "aspirin"));
+        assert_eq!(manager.parse_key("ratio:1"), (None, "ratio:1"));
+        assert_eq!(manager.query("finance", "ratio:1").unwrap().unwrap().value, "debt to equity");
+        assert_eq!(manager.query("finance", "finance:ratio:1").unwrap().unwrap().value, "debt to equity");
+    }
+
+    #[test]
+    fn test_create_namespace_rejects_invalid_names() {
+        let mut manager = manager();
+
+        assert!(manager.create_namespace("medical").is_err());
+        assert!(manager.create_namespace("a:b").is_err());
+        assert!(manager.create_namespace("").is_err());
+
+        manager.federate("finance", "medical").unwrap();
+        manager.remove_namespace("medical").unwrap();
+        assert!(!manager.is_federated("finance", "medical"));
+        assert!(manager.federate("finance", "medical").is_err());
+    }
+}
@@ -1,6 +1,12 @@
 use std::fmt;
 use std::error::Error;
 use std::collections::HashMap;
+use std::path::Path;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 /// Learning error
 #[derive(Debug)]
@@ -29,7 +35,7 @@ impl fmt::Display for LearningError {
 }
 
 /// Learning algorithm type
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LearningAlgorithmType {
     /// Supervised learning
     Supervised,
@@ -59,7 +65,7 @@ impl fmt::Display for LearningAlgorithmType {
 }
 
 /// Learning algorithm
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LearningAlgorithm {
     /// Algorithm ID
     pub id: String,
@@ -133,6 +139,74 @@ impl TrainingData {
         }
     }
     
+    /// Get number of samples
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+    
+    /// Check if there are no samples
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+    
+    /// Split into training and validation data, keeping sample order
+    ///
+    /// The last `validation_ratio` of samples, rounded, become validation data.
+    pub fn split(&self, validation_ratio: f64) -> Result<(TrainingData, TrainingData), LearningError> {
+        let indices: Vec<usize> = (0..self.len()).collect();
+        self.split_indices(&indices, validation_ratio)
+    }
+    
+    /// Split into training and validation data after shuffling samples with a
+    /// seeded random number generator
+    ///
+    /// Features and labels stay aligned. The same seed always gives the same split.
+    pub fn split_shuffled(&self, validation_ratio: f64, seed: u64) -> Result<(TrainingData, TrainingData), LearningError> {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.shuffle(&mut StdRng::seed_from_u64(seed));
+        self.split_indices(&indices, validation_ratio)
+    }
+    
+    /// Split samples in `indices` order, the tail ratio becoming validation data
+    fn split_indices(&self, indices: &[usize], validation_ratio: f64) -> Result<(TrainingData, TrainingData), LearningError> {
+        if !(0.0..=1.0).contains(&validation_ratio) {
+            return Err(LearningError::Other(format!(
+                "Validation ratio must be between 0 and 1, got {}",
+                validation_ratio
+            )));
+        }
+        
+        let validation_count = (indices.len() as f64 * validation_ratio).round() as usize;
+        self.split_tail(indices, validation_count)
+    }
+    
+    /// Split samples in `indices` order, the last `validation_count` becoming validation data
+    fn split_tail(&self, indices: &[usize], validation_count: usize) -> Result<(TrainingData, TrainingData), LearningError> {
+        if let Some(labels) = &self.labels {
+            if labels.len() != self.features.len() {
+                return Err(LearningError::Other(format!(
+                    "Label count mismatch: {} features, {} labels",
+                    self.features.len(),
+                    labels.len()
+                )));
+            }
+        }
+        
+        let (train, validation) = indices.split_at(indices.len().saturating_sub(validation_count));
+        
+        let subset = |suffix: &str, indices: &[usize]| {
+            let mut data = TrainingData::new(
+                &format!("{}-{}", self.name, suffix),
+                indices.iter().map(|&i| self.features[i].clone()).collect(),
+                self.labels.as_ref().map(|labels| indices.iter().map(|&i| labels[i]).collect()),
+            );
+            data.metadata = self.metadata.clone();
+            data
+        };
+        
+        Ok((subset("train", train), subset("validation", validation)))
+    }
+    
     /// Add metadata
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
@@ -145,7 +219,7 @@ impl TrainingData {
 }
 
 /// Learning model
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LearningModel {
     /// Model ID
     pub id: String,
@@ -192,6 +266,254 @@ impl LearningModel {
     }
 }
 
+/// Training metrics of a model
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TrainingMetrics {
+    /// Number of completed epochs
+    pub epochs: usize,
+    /// Loss after each epoch
+    pub losses: Vec<f32>,
+}
+
+impl TrainingMetrics {
+    /// Record a completed epoch
+    pub fn record_epoch(&mut self, loss: f32) {
+        self.epochs += 1;
+        self.losses.push(loss);
+    }
+    
+    /// Get loss after the latest epoch
+    pub fn latest_loss(&self) -> Option<f32> {
+        self.losses.last().copied()
+    }
+    
+    /// Get the epoch, counting from 1, with the lowest loss
+    pub fn best_epoch(&self) -> Option<usize> {
+        self.losses.iter()
+            .enumerate()
+            .min_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i + 1)
+    }
+    
+    /// Check if the last `patience` epochs brought no improvement on the best loss
+    ///
+    /// Always false for a patience of 0.
+    pub fn should_stop_early(&self, patience: usize) -> bool {
+        match self.best_epoch() {
+            Some(best) => patience > 0 && self.epochs - best >= patience,
+            None => false,
+        }
+    }
+}
+
+/// Training loop config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrainingConfig {
+    /// Maximum number of epochs to run
+    pub max_epochs: usize,
+    /// Epochs without improvement before stopping; `None` always runs `max_epochs`
+    pub early_stopping_patience: Option<usize>,
+}
+
+impl TrainingConfig {
+    /// Create a config running exactly `max_epochs` epochs
+    pub fn new(max_epochs: usize) -> Self {
+        Self {
+            max_epochs,
+            early_stopping_patience: None,
+        }
+    }
+    
+    /// Stop after `patience` epochs without improvement
+    pub fn with_early_stopping(mut self, patience: usize) -> Self {
+        self.early_stopping_patience = Some(patience);
+        self
+    }
+}
+
+/// Result of incremental training on a new task
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalTrainingResult {
+    /// Metrics of the incremental training run
+    pub metrics: TrainingMetrics,
+    /// Loss on the retained prior-task validation data before training
+    pub old_task_loss_before: f32,
+    /// Loss on the retained prior-task validation data after training
+    pub old_task_loss_after: f32,
+}
+
+impl IncrementalTrainingResult {
+    /// Change in prior-task loss; positive values mean prior knowledge was forgotten
+    pub fn forgetting(&self) -> f32 {
+        self.old_task_loss_after - self.old_task_loss_before
+    }
+}
+
+/// Serialized form of a training checkpoint
+#[derive(Serialize, Deserialize)]
+struct CheckpointFile {
+    /// Model, including its parameters
+    model: LearningModel,
+    /// Training metrics
+    metrics: TrainingMetrics,
+}
+
+/// Learner trained and evaluated on whole data sets
+pub trait Learner {
+    /// Train on data, continuing from the learner's current state
+    fn train(&mut self, data: &TrainingData, config: &TrainingConfig) -> Result<TrainingMetrics, LearningError>;
+    
+    /// Evaluate on data, returning loss and, for classifiers, accuracy
+    fn evaluate(&self, data: &TrainingData) -> Result<(f32, Option<f32>), LearningError>;
+}
+
+/// Run k-fold cross-validation
+///
+/// Samples are split, in order, into `k` folds whose sizes differ by at
+/// most one. Each fold in turn is held out while a fresh learner from
+/// `learner_factory` trains on the others. Returns each fold's `(loss,
+/// accuracy)` from evaluating on the held-out fold.
+pub fn cross_validate<F>(learner_factory: F, data: &TrainingData, k: usize, config: &TrainingConfig) -> Result<Vec<(f32, Option<f32>)>, LearningError>
+where
+    F: Fn() -> Box<dyn Learner>,
+{
+    if k < 2 {
+        return Err(LearningError::EvaluationError(format!("Cross-validation needs at least 2 folds, got {}", k)));
+    }
+    if data.len() < k {
+        return Err(LearningError::EvaluationError(format!(
+            "Cannot split {} samples into {} folds",
+            data.len(),
+            k
+        )));
+    }
+    
+    let mut results = Vec::with_capacity(k);
+    let mut start = 0;
+    for fold in 0..k {
+        let size = data.len() / k + usize::from(fold < data.len() % k);
+        let held_out = start..start + size;
+        start += size;
+        
+        // Order the held-out fold last so it becomes the validation split
+        let indices: Vec<usize> = (0..data.len())
+            .filter(|i| !held_out.contains(i))
+            .chain(held_out.clone())
+            .collect();
+        let (train, validation) = data.split_tail(&indices, size)?;
+        
+        let mut learner = learner_factory();
+        learner.train(&train, config)?;
+        results.push(learner.evaluate(&validation)?);
+    }
+    
+    Ok(results)
+}
+
+/// Exploration policy
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExplorationPolicy {
+    /// Take the best action with probability 1-epsilon, otherwise another action uniformly
+    EpsilonGreedy {
+        /// Exploration probability
+        epsilon: f64,
+        /// Multiplicative decay applied per decay step
+        decay: f64,
+        /// Lower bound for epsilon
+        min_epsilon: f64,
+    },
+    /// Sample actions with Boltzmann probabilities
+    Softmax {
+        /// Temperature
+        temperature: f64,
+        /// Multiplicative decay applied per decay step
+        decay: f64,
+        /// Lower bound for temperature
+        min_temperature: f64,
+    },
+    /// Upper confidence bound
+    UCB {
+        /// Exploration coefficient
+        c: f64,
+    },
+}
+
+impl ExplorationPolicy {
+    /// Create an epsilon-greedy policy without decay
+    pub fn epsilon_greedy(epsilon: f64) -> Self {
+        ExplorationPolicy::EpsilonGreedy {
+            epsilon,
+            decay: 1.0,
+            min_epsilon: 0.0,
+        }
+    }
+    
+    /// Create a softmax policy without decay
+    pub fn softmax(temperature: f64) -> Self {
+        ExplorationPolicy::Softmax {
+            temperature,
+            decay: 1.0,
+            min_temperature: 0.01,
+        }
+    }
+    
+    /// Create a UCB policy
+    pub fn ucb(c: f64) -> Self {
+        ExplorationPolicy::UCB { c }
+    }
+    
+    /// Validate policy parameters
+    pub fn validate(&self) -> Result<(), LearningError> {
+        match self {
+            ExplorationPolicy::EpsilonGreedy { epsilon, decay, min_epsilon } => {
+                if !(0.0..=1.0).contains(epsilon) || !(0.0..=1.0).contains(min_epsilon) || !(0.0..=1.0).contains(decay) {
+                    return Err(LearningError::InitializationError(format!(
+                        "Invalid epsilon-greedy parameters: epsilon={}, decay={}, min_epsilon={}",
+                        epsilon, decay, min_epsilon
+                    )));
+                }
+            },
+            ExplorationPolicy::Softmax { temperature, decay, min_temperature } => {
+                if *temperature <= 0.0 || *min_temperature <= 0.0 || !(0.0..=1.0).contains(decay) {
+                    return Err(LearningError::InitializationError(format!(
+                        "Invalid softmax parameters: temperature={}, decay={}, min_temperature={}",
+                        temperature, decay, min_temperature
+                    )));
+                }
+            },
+            ExplorationPolicy::UCB { c } => {
+                if *c < 0.0 {
+                    return Err(LearningError::InitializationError(format!(
+                        "Invalid UCB coefficient: {}",
+                        c
+                    )));
+                }
+            },
+        }
+        
+        Ok(())
+    }
+    
+    /// Decay epsilon or temperature by one step
+    pub fn decay(&mut self) {
+        match self {
+            ExplorationPolicy::EpsilonGreedy { epsilon, decay, min_epsilon } => {
+                *epsilon = (*epsilon * *decay).max(*min_epsilon);
+            },
+            ExplorationPolicy::Softmax { temperature, decay, min_temperature } => {
+                *temperature = (*temperature * *decay).max(*min_temperature);
+            },
+            ExplorationPolicy::UCB { .. } => {},
+        }
+    }
+}
+
+impl Default for ExplorationPolicy {
+    fn default() -> Self {
+        ExplorationPolicy::epsilon_greedy(0.1)
+    }
+}
+
 /// Learning system
 pub struct LearningSystem {
     /// Learning algorithms
@@ -200,6 +522,16 @@ pub struct LearningSystem {
     pub training_data: HashMap<String, TrainingData>,
     /// Learning models
     pub models: HashMap<String, LearningModel>,
+    /// Exploration policy
+    pub exploration_policy: ExplorationPolicy,
+    /// Number of times each action was selected
+    pub action_counts: Vec<u64>,
+    /// Random number generator
+    pub rng: StdRng,
+    /// Training metrics by model ID
+    pub training_metrics: HashMap<String, TrainingMetrics>,
+    /// Validation data retained from each model's prior task, by model ID
+    pub retained_validation: HashMap<String, TrainingData>,
 }
 
 impl LearningSystem {
@@ -209,9 +541,105 @@ impl LearningSystem {
             algorithms: HashMap::new(),
             training_data: HashMap::new(),
             models: HashMap::new(),
+            exploration_policy: ExplorationPolicy::default(),
+            action_counts: Vec::new(),
+            rng: StdRng::from_entropy(),
+            training_metrics: HashMap::new(),
+            retained_validation: HashMap::new(),
         })
     }
     
+    /// Create a new learning system with a seeded random number generator
+    pub fn with_seed(seed: u64) -> Result<Self, LearningError> {
+        let mut system = Self::new()?;
+        system.rng = StdRng::seed_from_u64(seed);
+        Ok(system)
+    }
+    
+    /// Set exploration policy
+    pub fn set_exploration_policy(&mut self, policy: ExplorationPolicy) -> Result<(), LearningError> {
+        policy.validate()?;
+        self.exploration_policy = policy;
+        self.action_counts.clear();
+        Ok(())
+    }
+    
+    /// Decay exploration by one step
+    pub fn decay_exploration(&mut self) {
+        self.exploration_policy.decay();
+    }
+    
+    /// Select an action given the estimated action values
+    pub fn select_action(&mut self, action_values: &[f32]) -> Result<usize, LearningError> {
+        if action_values.is_empty() {
+            return Err(LearningError::EvaluationError("No actions to select from".to_string()));
+        }
+        
+        if self.action_counts.len() != action_values.len() {
+            self.action_counts = vec![0; action_values.len()];
+        }
+        
+        let best = Self::best_action(action_values);
+        
+        let action = match self.exploration_policy.clone() {
+            ExplorationPolicy::EpsilonGreedy { epsilon, .. } => {
+                if action_values.len() > 1 && self.rng.gen::<f64>() < epsilon {
+                    // Explore among the non-greedy actions
+                    let other = self.rng.gen_range(0..action_values.len() - 1);
+                    if other >= best { other + 1 } else { other }
+                } else {
+                    best
+                }
+            },
+            ExplorationPolicy::Softmax { temperature, .. } => {
+                let max_value = action_values[best] as f64;
+                let weights: Vec<f64> = action_values.iter()
+                    .map(|v| ((*v as f64 - max_value) / temperature).exp())
+                    .collect();
+                let total: f64 = weights.iter().sum();
+                
+                let mut threshold = self.rng.gen::<f64>() * total;
+                let mut selected = action_values.len() - 1;
+                for (i, weight) in weights.iter().enumerate() {
+                    if threshold < *weight {
+                        selected = i;
+                        break;
+                    }
+                    threshold -= weight;
+                }
+                selected
+            },
+            ExplorationPolicy::UCB { c } => {
+                // Try every action once before using confidence bounds
+                match self.action_counts.iter().position(|count| *count == 0) {
+                    Some(untried) => untried,
+                    None => {
+                        let total = self.action_counts.iter().sum::<u64>() as f64;
+                        let scores: Vec<f32> = action_values.iter()
+                            .zip(self.action_counts.iter())
+                            .map(|(v, n)| (*v as f64 + c * (total.ln() / *n as f64).sqrt()) as f32)
+                            .collect();
+                        Self::best_action(&scores)
+                    },
+                }
+            },
+        };
+        
+        self.action_counts[action] += 1;
+        Ok(action)
+    }
+    
+    /// Get index of the highest value, preferring the lowest index on ties
+    fn best_action(values: &[f32]) -> usize {
+        let mut best = 0;
+        for (i, value) in values.iter().enumerate() {
+            if *value > values[best] {
+                best = i;
+            }
+        }
+        best
+    }
+    
     /// Add algorithm
     pub fn add_algorithm(&mut self, algorithm: LearningAlgorithm) -> Result<(), LearningError> {
         self.algorithms.insert(algorithm.id.clone(), algorithm);
@@ -246,20 +674,136 @@ impl LearningSystem {
     }
     
     /// Train model
-    pub fn train_model(&mut self, model_id: &str, data_id: &str, _epochs: usize) -> Result<f32, LearningError> {
+    pub fn train_model(&mut self, model_id: &str, data_id: &str, epochs: usize) -> Result<f32, LearningError> {
+        // In a real implementation, this would train the model using the data
+        // For now, each epoch just reports a dummy error value
+        let error = 0.1;
+        
+        self.train_with(model_id, data_id, TrainingConfig::new(epochs), |_, _, _| Ok(error))?;
+        
+        Ok(error)
+    }
+    
+    /// Run a training loop, calling `epoch` once per epoch with the model,
+    /// the training data and the epoch number of this run counting from 1
+    ///
+    /// `epoch` returns the epoch's validation loss, which is appended to the
+    /// model's training metrics. With early stopping configured, training
+    /// stops once `should_stop_early` holds for this run's losses, so a
+    /// better loss from an earlier run does not cut it short. Returns the
+    /// metrics of this run only.
+    pub fn train_with<F>(&mut self, model_id: &str, data_id: &str, config: TrainingConfig, mut epoch: F) -> Result<TrainingMetrics, LearningError>
+    where
+        F: FnMut(&mut LearningModel, &TrainingData, usize) -> Result<f32, LearningError>,
+    {
         let model = self.models.get_mut(model_id).ok_or_else(|| {
             LearningError::TrainingError(format!("Model with ID {} not found", model_id))
         })?;
         
-        let _data = self.training_data.get(data_id).ok_or_else(|| {
+        let data = self.training_data.get(data_id).ok_or_else(|| {
             LearningError::TrainingError(format!("Training data with ID {} not found", data_id))
         })?;
         
-        // In a real implementation, this would train the model using the data
-        // For now, we just return a dummy error value
-        let error = 0.1;
+        let history = self.training_metrics.entry(model_id.to_string()).or_default();
+        let mut run = TrainingMetrics::default();
+        for _ in 0..config.max_epochs {
+            let loss = epoch(model, data, run.epochs + 1)?;
+            run.record_epoch(loss);
+            history.record_epoch(loss);
+            
+            if config.early_stopping_patience.is_some_and(|patience| run.should_stop_early(patience)) {
+                break;
+            }
+        }
         
-        Ok(error)
+        Ok(run)
+    }
+    
+    /// Get training metrics of a model
+    pub fn get_training_metrics(&self, model_id: &str) -> Option<&TrainingMetrics> {
+        self.training_metrics.get(model_id)
+    }
+    
+    /// Retain validation data of a model's current task, replacing any retained earlier
+    ///
+    /// Later incremental training re-evaluates on it to measure forgetting.
+    pub fn retain_validation_data(&mut self, model_id: &str, data: TrainingData) {
+        self.retained_validation.insert(model_id.to_string(), data);
+    }
+    
+    /// Get validation data retained from a model's prior task
+    pub fn get_retained_validation_data(&self, model_id: &str) -> Option<&TrainingData> {
+        self.retained_validation.get(model_id)
+    }
+    
+    /// Continue training a model's learner on a new task without reinitializing it
+    ///
+    /// The learner is evaluated on the model's retained prior-task validation
+    /// data before and after training, so the result reports how much of the
+    /// prior task was forgotten. Epoch losses are appended to the model's
+    /// training metrics.
+    pub fn train_incremental(
+        &mut self,
+        model_id: &str,
+        learner: &mut dyn Learner,
+        new_data: &TrainingData,
+        config: &TrainingConfig,
+    ) -> Result<IncrementalTrainingResult, LearningError> {
+        if !self.models.contains_key(model_id) {
+            return Err(LearningError::TrainingError(format!("Model with ID {} not found", model_id)));
+        }
+        
+        let retained = self.retained_validation.get(model_id).ok_or_else(|| {
+            LearningError::TrainingError(format!("No retained validation data for model {}", model_id))
+        })?;
+        
+        let (old_task_loss_before, _) = learner.evaluate(retained)?;
+        let metrics = learner.train(new_data, config)?;
+        let (old_task_loss_after, _) = learner.evaluate(retained)?;
+        
+        let model_metrics = self.training_metrics.entry(model_id.to_string()).or_default();
+        for &loss in &metrics.losses {
+            model_metrics.record_epoch(loss);
+        }
+        
+        Ok(IncrementalTrainingResult {
+            metrics,
+            old_task_loss_before,
+            old_task_loss_after,
+        })
+    }
+    
+    /// Save a model's parameters and training metrics to a checkpoint file
+    pub fn save_checkpoint(&self, model_id: &str, path: &Path) -> Result<(), LearningError> {
+        let model = self.models.get(model_id).ok_or_else(|| {
+            LearningError::Other(format!("Model with ID {} not found", model_id))
+        })?;
+        
+        let checkpoint = CheckpointFile {
+            model: model.clone(),
+            metrics: self.training_metrics.get(model_id).cloned().unwrap_or_default(),
+        };
+        let bytes = serde_json::to_vec(&checkpoint)
+            .map_err(|e| LearningError::Other(format!("Failed to serialize checkpoint: {}", e)))?;
+        
+        std::fs::write(path, bytes)
+            .map_err(|e| LearningError::Other(format!("Failed to write {}: {}", path.display(), e)))
+    }
+    
+    /// Restore a model and its training metrics from a checkpoint file
+    ///
+    /// Replaces any model with the same ID. Returns the model ID; training can
+    /// resume from the restored metrics' epoch count or best epoch.
+    pub fn load_checkpoint(&mut self, path: &Path) -> Result<String, LearningError> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| LearningError::Other(format!("Failed to read {}: {}", path.display(), e)))?;
+        let checkpoint: CheckpointFile = serde_json::from_slice(&bytes)
+            .map_err(|e| LearningError::InitializationError(format!("Invalid checkpoint file: {}", e)))?;
+        
+        let model_id = checkpoint.model.id.clone();
+        self.training_metrics.insert(model_id.clone(), checkpoint.metrics);
+        self.add_model(checkpoint.model)?;
+        Ok(model_id)
     }
     
     /// Evaluate model
@@ -2,6 +2,10 @@
 mod tests {
     use super::*;
     use crate::neuro_symbolic::learning::{Learner, NeuralLearner, SymbolicLearner, LearningError};
+    use crate::neuro_symbolic::learning::{ExplorationPolicy, LearningSystem};
+    use crate::neuro_symbolic::learning::{LearningAlgorithm, LearningAlgorithmType, LearningModel, TrainingData};
+    use crate::neuro_symbolic::learning::{IncrementalTrainingResult, TrainingConfig, TrainingMetrics};
+    use crate::neuro_symbolic::learning::cross_validate;
     use crate::neuro_symbolic::neural::{NeuralModel, Tensor};
     use crate::neuro_symbolic::symbolic::{KnowledgeBase, SimpleKnowledgeBase};
     use std::sync::{Arc, Mutex};
@@ -159,4 +163,278 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_epsilon_greedy_exploration() {
+        let mut system = LearningSystem::with_seed(42).unwrap();
+        system.set_exploration_policy(ExplorationPolicy::epsilon_greedy(0.2)).unwrap();
+        
+        let action_values = [0.1, 0.9, 0.3, 0.5];
+        let trials = 10000;
+        let mut best_count = 0;
+        for _ in 0..trials {
+            if system.select_action(&action_values).unwrap() == 1 {
+                best_count += 1;
+            }
+        }
+        
+        // Best action with probability 1-epsilon, every other action explored
+        let best_ratio = best_count as f64 / trials as f64;
+        assert!((best_ratio - 0.8).abs() < 0.02, "best ratio {}", best_ratio);
+        assert!(system.action_counts.iter().all(|count| *count > 0));
+        
+        // Same seed reproduces the same choices
+        let mut first = LearningSystem::with_seed(7).unwrap();
+        let mut second = LearningSystem::with_seed(7).unwrap();
+        for _ in 0..100 {
+            assert_eq!(
+                first.select_action(&action_values).unwrap(),
+                second.select_action(&action_values).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_exploration_decay() {
+        let mut system = LearningSystem::with_seed(1).unwrap();
+        system.set_exploration_policy(ExplorationPolicy::EpsilonGreedy {
+            epsilon: 1.0,
+            decay: 0.5,
+            min_epsilon: 0.1,
+        }).unwrap();
+        
+        for _ in 0..10 {
+            system.decay_exploration();
+        }
+        
+        assert_eq!(system.exploration_policy, ExplorationPolicy::EpsilonGreedy {
+            epsilon: 0.1,
+            decay: 0.5,
+            min_epsilon: 0.1,
+        });
+        
+        // Without exploration the greedy action is always taken
+        system.set_exploration_policy(ExplorationPolicy::epsilon_greedy(0.0)).unwrap();
+        for _ in 0..100 {
+            assert_eq!(system.select_action(&[0.2, 0.1, 0.7]).unwrap(), 2);
+        }
+    }
+
+    #[test]
+    fn test_softmax_and_ucb_exploration() {
+        let mut system = LearningSystem::with_seed(3).unwrap();
+        system.set_exploration_policy(ExplorationPolicy::softmax(0.05)).unwrap();
+        let greedy = (0..1000)
+            .filter(|_| system.select_action(&[0.0, 1.0]).unwrap() == 1)
+            .count();
+        assert!(greedy > 990);
+        
+        system.set_exploration_policy(ExplorationPolicy::ucb(1.0)).unwrap();
+        let first: Vec<usize> = (0..3).map(|_| system.select_action(&[0.5, 0.2, 0.1]).unwrap()).collect();
+        assert_eq!(first, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_checkpoint_restores_model_and_metrics() {
+        let mut system = LearningSystem::with_seed(7).unwrap();
+        let mut model = LearningModel::new("regressor", LearningAlgorithm::new("sgd", LearningAlgorithmType::Supervised));
+        model.add_parameter("weight", 0.25);
+        model.add_parameter("bias", -1.5);
+        let model_id = model.id.clone();
+        system.add_model(model).unwrap();
+        let data = TrainingData::new("points", vec![vec![1.0], vec![2.0]], Some(vec![2.0, 4.0]));
+        let data_id = data.id.clone();
+        system.add_training_data(data).unwrap();
+
+        system.train_model(&model_id, &data_id, 1).unwrap();
+        let metrics = system.get_training_metrics(&model_id).unwrap().clone();
+        assert_eq!(metrics.epochs, 1);
+        assert_eq!(metrics.best_epoch(), Some(1));
+
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", uuid::Uuid::new_v4()));
+        system.save_checkpoint(&model_id, &path).unwrap();
+
+        let mut restored = LearningSystem::with_seed(7).unwrap();
+        let restored_id = restored.load_checkpoint(&path);
+        let _ = std::fs::remove_file(&path);
+        let restored_id = restored_id.unwrap();
+
+        assert_eq!(restored_id, model_id);
+        assert_eq!(restored.get_training_metrics(&model_id), Some(&metrics));
+        let model = restored.get_model(&model_id).unwrap();
+        assert_eq!(model.get_parameter("weight"), Some(&0.25));
+        assert_eq!(model.get_parameter("bias"), Some(&-1.5));
+        assert_eq!(model.algorithm.algorithm_type, LearningAlgorithmType::Supervised);
+
+        assert!(restored.load_checkpoint(&path).is_err());
+        assert!(system.save_checkpoint("missing", &path).is_err());
+    }
+
+    #[test]
+    fn test_training_stops_early_on_plateau() {
+        let mut system = LearningSystem::with_seed(7).unwrap();
+        let model = LearningModel::new("classifier", LearningAlgorithm::new("sgd", LearningAlgorithmType::Supervised));
+        let model_id = model.id.clone();
+        system.add_model(model).unwrap();
+        let data = TrainingData::new("samples", vec![vec![0.0]], Some(vec![1.0]));
+        let data_id = data.id.clone();
+        system.add_training_data(data).unwrap();
+
+        // Validation loss stops improving after epoch 4
+        let losses = [1.0, 0.6, 0.4, 0.3, 0.35, 0.3, 0.32, 0.29, 0.2, 0.1];
+        let config = TrainingConfig::new(losses.len()).with_early_stopping(3);
+        let metrics = system.train_with(&model_id, &data_id, config, |model, _, epoch| {
+            model.add_parameter("epoch", epoch as f32);
+            Ok(losses[epoch - 1])
+        }).unwrap();
+
+        assert_eq!(metrics.best_epoch(), Some(4));
+        assert_eq!(metrics.epochs, 7);
+        assert_eq!(metrics.losses, losses[..7].to_vec());
+        assert_eq!(system.get_model(&model_id).unwrap().get_parameter("epoch"), Some(&7.0));
+
+        // A second run stops on its own plateau, not on the first run's best loss
+        let later = [0.9, 0.8, 0.85, 0.86, 0.87, 0.5];
+        let config = TrainingConfig::new(later.len()).with_early_stopping(3);
+        let metrics = system.train_with(&model_id, &data_id, config, |_, _, epoch| Ok(later[epoch - 1])).unwrap();
+        assert_eq!(metrics.epochs, 5);
+        assert_eq!(metrics.best_epoch(), Some(2));
+        assert_eq!(metrics.losses, later[..5].to_vec());
+        assert_eq!(system.get_training_metrics(&model_id).unwrap().epochs, 12);
+
+        // Without early stopping every epoch runs
+        let mut full = TrainingMetrics::default();
+        for loss in losses {
+            full.record_epoch(loss);
+        }
+        assert!(!full.should_stop_early(3));
+        assert!(!full.should_stop_early(0));
+    }
+
+    fn class_ordered_data() -> TrainingData {
+        let features: Vec<Vec<f32>> = (0..100).map(|i| vec![i as f32]).collect();
+        let labels: Vec<f32> = (0..100).map(|i| if i < 50 { 0.0 } else { 1.0 }).collect();
+        TrainingData::new("ordered", features, Some(labels))
+    }
+
+    #[test]
+    fn test_split_shuffled_mixes_classes() {
+        let data = class_ordered_data();
+
+        // The ordered split puts only class 1 in validation
+        let (_, validation) = data.split(0.25).unwrap();
+        assert!(validation.labels.unwrap().iter().all(|&l| l == 1.0));
+
+        let (train, validation) = data.split_shuffled(0.25, 42).unwrap();
+        assert_eq!((train.len(), validation.len()), (75, 25));
+        for part in [&train, &validation] {
+            let labels = part.labels.as_ref().unwrap();
+            assert!(labels.contains(&0.0) && labels.contains(&1.0), "{}: {:?}", part.name, labels);
+            // Features and labels stay aligned
+            for (features, &label) in part.features.iter().zip(labels) {
+                assert_eq!(label, if features[0] < 50.0 { 0.0 } else { 1.0 });
+            }
+        }
+
+        let mut all: Vec<f32> = train.features.iter().chain(&validation.features).map(|f| f[0]).collect();
+        all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(all, (0..100).map(|i| i as f32).collect::<Vec<_>>());
+
+        let (again, _) = data.split_shuffled(0.25, 42).unwrap();
+        assert_eq!(again.features, train.features);
+        assert!(data.split_shuffled(1.5, 42).is_err());
+    }
+
+    /// Predicts the mean training label
+    struct MeanLearner {
+        mean: f32,
+    }
+
+    impl Learner for MeanLearner {
+        fn train(&mut self, data: &TrainingData, _config: &TrainingConfig) -> Result<TrainingMetrics, LearningError> {
+            let labels = data.labels.as_ref().ok_or_else(|| LearningError::TrainingError("No labels".to_string()))?;
+            self.mean = labels.iter().sum::<f32>() / labels.len() as f32;
+            let mut metrics = TrainingMetrics::default();
+            metrics.record_epoch(self.evaluate(data)?.0);
+            Ok(metrics)
+        }
+
+        fn evaluate(&self, data: &TrainingData) -> Result<(f32, Option<f32>), LearningError> {
+            let labels = data.labels.as_ref().ok_or_else(|| LearningError::EvaluationError("No labels".to_string()))?;
+            let loss = labels.iter().map(|l| (l - self.mean).powi(2)).sum::<f32>() / labels.len() as f32;
+            Ok((loss, None))
+        }
+    }
+
+    #[test]
+    fn test_cross_validate_two_folds() {
+        let data = TrainingData::new("tiny", vec![vec![0.0]; 4], Some(vec![1.0, 3.0, 5.0, 7.0]));
+        let factory = || -> Box<dyn Learner> { Box::new(MeanLearner { mean: 0.0 }) };
+
+        let results = cross_validate(factory, &data, 2, &TrainingConfig::new(1)).unwrap();
+
+        // Fold 1 trains on [5, 7] and tests on [1, 3]; fold 2 the reverse
+        assert_eq!(results, vec![(17.0, None), (17.0, None)]);
+        assert!(cross_validate(factory, &data, 1, &TrainingConfig::new(1)).is_err());
+        assert!(cross_validate(factory, &data, 5, &TrainingConfig::new(1)).is_err());
+    }
+
+    /// Moves its estimate halfway to the mean training label each epoch
+    struct GradientMeanLearner {
+        estimate: f32,
+    }
+
+    impl Learner for GradientMeanLearner {
+        fn train(&mut self, data: &TrainingData, config: &TrainingConfig) -> Result<TrainingMetrics, LearningError> {
+            let labels = data.labels.as_ref().ok_or_else(|| LearningError::TrainingError("No labels".to_string()))?;
+            let mean = labels.iter().sum::<f32>() / labels.len() as f32;
+            let mut metrics = TrainingMetrics::default();
+            for _ in 0..config.max_epochs {
+                self.estimate += 0.5 * (mean - self.estimate);
+                metrics.record_epoch(self.evaluate(data)?.0);
+            }
+            Ok(metrics)
+        }
+
+        fn evaluate(&self, data: &TrainingData) -> Result<(f32, Option<f32>), LearningError> {
+            let labels = data.labels.as_ref().ok_or_else(|| LearningError::EvaluationError("No labels".to_string()))?;
+            let loss = labels.iter().map(|l| (l - self.estimate).powi(2)).sum::<f32>() / labels.len() as f32;
+            Ok((loss, None))
+        }
+    }
+
+    #[test]
+    fn test_incremental_training_reports_forgetting() {
+        let mut system = LearningSystem::with_seed(7).unwrap();
+        let model = LearningModel::new("regressor", LearningAlgorithm::new("sgd", LearningAlgorithmType::Supervised));
+        let model_id = model.id.clone();
+        system.add_model(model).unwrap();
+
+        let task_a = TrainingData::new("task-a", vec![vec![0.0]; 4], Some(vec![2.0; 4]));
+        let task_b = TrainingData::new("task-b", vec![vec![0.0]; 4], Some(vec![6.0; 4]));
+        let (train_a, validation_a) = task_a.split(0.5).unwrap();
+
+        // Task A converges to an estimate of 1.5 after two epochs
+        let mut learner = GradientMeanLearner { estimate: 0.0 };
+        learner.train(&train_a, &TrainingConfig::new(2)).unwrap();
+        assert!(matches!(
+            system.train_incremental(&model_id, &mut learner, &task_b, &TrainingConfig::new(1)),
+            Err(LearningError::TrainingError(_))
+        ));
+        system.retain_validation_data(&model_id, validation_a);
+
+        let result: IncrementalTrainingResult = system
+            .train_incremental(&model_id, &mut learner, &task_b, &TrainingConfig::new(1))
+            .unwrap();
+
+        // Training continued from 1.5 rather than restarting from 0
+        assert_eq!(learner.estimate, 3.75);
+        assert_eq!(result.metrics.losses, vec![2.25f32.powi(2)]);
+        assert_eq!(result.old_task_loss_before, 0.25);
+        assert_eq!(result.old_task_loss_after, 1.75f32.powi(2));
+        assert_eq!(result.forgetting(), 1.75f32.powi(2) - 0.25);
+        assert_eq!(system.get_training_metrics(&model_id).unwrap().epochs, 1);
+
+        assert!(system.train_incremental("missing", &mut learner, &task_b, &TrainingConfig::new(1)).is_err());
+    }
 }
@@ -5,10 +5,10 @@ mod learning;
 mod integration;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use neural::{NeuralNetwork, NeuralNetworkManager, NeuralError};
-pub use symbolic::{Symbol, SymbolicSystem, SymbolicError};
-pub use knowledge::{Knowledge, KnowledgeBase, KnowledgeError};
-pub use learning::{Learning, LearningSystem, LearningError};
+pub use neural::{NeuralNetwork, NeuralNetworkManager, NeuralError, SharedNeuralNetwork, InferenceBatcher, BatchingConfig, BatchingStats, Layer, LayerType, ActivationFunction, InputSanitization, Calibrator, CalibrationMethod, softmax, quantize, ArchitectureSummary, LayerSummary, LayerDiff, model_checksum, verify_model_checksum, parse_checksum, LossFunction, MseLoss, CrossEntropyLoss};
+pub use symbolic::{Symbol, SymbolicSystem, SymbolicError, SymbolType, Rule, RuleFiring, Term, Clause, QueryResult, Substitution, unify, DEFAULT_MAX_PROOF_DEPTH, DEFAULT_INFERENCE_CACHE_CAPACITY};
+pub use knowledge::{Knowledge, KnowledgeBase, KnowledgeError, KnowledgeSystem, KnowledgeEntry, KnowledgeSourceType, IntegrityIssue, KnowledgeBaseManager, NAMESPACE_SEPARATOR};
+pub use learning::{Learning, LearningSystem, LearningError, ExplorationPolicy, TrainingMetrics, TrainingConfig, Learner, IncrementalTrainingResult, cross_validate};
 pub use integration::{Integration, IntegrationSystem, IntegrationError};
 
 // 导出特定函数，避免冲突
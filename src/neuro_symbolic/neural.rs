@@ -1,5 +1,18 @@
+use std::borrow::Cow;
 use std::fmt;
 use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::system::ModelPrecision;
+
+/// Extension of the checksum file stored alongside a saved model
+pub const CHECKSUM_EXTENSION: &str = "checksum";
 
 /// Neural network error
 #[derive(Debug)]
@@ -10,6 +23,10 @@ pub enum NeuralError {
     TrainingError(String),
     /// Inference error
     InferenceError(String),
+    /// Invalid input error
+    InvalidInput(String),
+    /// Model integrity error
+    IntegrityError(String),
     /// Other error
     Other(String),
 }
@@ -22,13 +39,15 @@ impl fmt::Display for NeuralError {
             NeuralError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             NeuralError::TrainingError(msg) => write!(f, "Training error: {}", msg),
             NeuralError::InferenceError(msg) => write!(f, "Inference error: {}", msg),
+            NeuralError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            NeuralError::IntegrityError(msg) => write!(f, "Integrity error: {}", msg),
             NeuralError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
 }
 
 /// Neural network layer type
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LayerType {
     /// Input layer
     Input,
@@ -61,7 +80,7 @@ impl fmt::Display for LayerType {
 }
 
 /// Activation function
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ActivationFunction {
     /// Sigmoid function
     Sigmoid,
@@ -107,7 +126,7 @@ impl ActivationFunction {
 }
 
 /// Neural network layer
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Layer {
     /// Layer ID
     pub id: String,
@@ -167,12 +186,25 @@ impl Layer {
     
     /// Forward pass
     pub fn forward(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        self.forward_at(inputs, ModelPrecision::FP32)
+    }
+    
+    /// Forward pass with weights and biases rounded to the given precision
+    pub fn forward_at(&self, inputs: &[f32], precision: ModelPrecision) -> Result<Vec<f32>, NeuralError> {
         if self.weights.is_none() || self.biases.is_none() {
             return Err(NeuralError::InferenceError("Layer not initialized".to_string()));
         }
         
-        let weights = self.weights.as_ref().unwrap();
-        let biases = self.biases.as_ref().unwrap();
+        let (weights, biases): (Cow<[Vec<f32>]>, Cow<[f32]>) = match precision {
+            ModelPrecision::FP32 => (
+                Cow::Borrowed(self.weights.as_ref().unwrap()),
+                Cow::Borrowed(self.biases.as_ref().unwrap()),
+            ),
+            _ => (
+                Cow::Owned(self.weights.as_ref().unwrap().iter().map(|row| quantize(row, precision)).collect()),
+                Cow::Owned(quantize(self.biases.as_ref().unwrap(), precision)),
+            ),
+        };
         
         if inputs.len() != weights[0].len() {
             return Err(NeuralError::InferenceError(format!(
@@ -196,6 +228,490 @@ impl Layer {
     }
 }
 
+/// Policy for NaN and infinite values in inference inputs
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputSanitization {
+    /// Reject inputs containing NaN or infinite values
+    #[default]
+    Reject,
+    /// Clamp all values to a range; NaN becomes the value nearest zero
+    Clamp { min: f32, max: f32 },
+    /// Replace NaN and infinite values with zero
+    ZeroFill,
+}
+
+impl InputSanitization {
+    /// Apply the policy to inputs
+    pub fn apply(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        match *self {
+            InputSanitization::Reject => {
+                let invalid = non_finite_indices(inputs);
+                if invalid.is_empty() {
+                    Ok(inputs.to_vec())
+                } else {
+                    Err(NeuralError::InvalidInput(format!(
+                        "Non-finite values at indices {:?}",
+                        invalid
+                    )))
+                }
+            },
+            InputSanitization::Clamp { min, max } => {
+                if min.is_nan() || max.is_nan() || min > max {
+                    return Err(NeuralError::InvalidInput(format!(
+                        "Invalid clamp range: [{}, {}]",
+                        min, max
+                    )));
+                }
+                Ok(inputs.iter()
+                    .map(|&x| if x.is_nan() { 0.0f32.clamp(min, max) } else { x.clamp(min, max) })
+                    .collect())
+            },
+            InputSanitization::ZeroFill => {
+                Ok(inputs.iter().map(|&x| if x.is_finite() { x } else { 0.0 }).collect())
+            },
+        }
+    }
+}
+
+/// Numerically stable softmax
+pub fn softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits.iter().map(|&x| (x - max).exp()).collect();
+    let sum: f32 = exps.iter().sum();
+    exps.into_iter().map(|e| e / sum).collect()
+}
+
+/// Smallest probability used by `CrossEntropyLoss`, avoiding `ln(0)`
+pub const CROSS_ENTROPY_EPSILON: f32 = 1e-7;
+
+/// Training loss function
+pub trait LossFunction: Send + Sync {
+    /// Get loss name
+    fn name(&self) -> &str;
+    
+    /// Compute loss of an output against its target
+    fn compute(&self, output: &[f32], target: &[f32]) -> f32;
+    
+    /// Compute the loss gradient with respect to each output
+    fn gradient(&self, output: &[f32], target: &[f32]) -> Vec<f32>;
+}
+
+/// Squared error loss, halved so its gradient is `output - target`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MseLoss;
+
+impl LossFunction for MseLoss {
+    fn name(&self) -> &str {
+        "mse"
+    }
+    
+    fn compute(&self, output: &[f32], target: &[f32]) -> f32 {
+        output.iter().zip(target).map(|(o, t)| 0.5 * (t - o).powi(2)).sum()
+    }
+    
+    fn gradient(&self, output: &[f32], target: &[f32]) -> Vec<f32> {
+        output.iter().zip(target).map(|(o, t)| o - t).collect()
+    }
+}
+
+/// Cross-entropy loss of predicted probabilities against target probabilities
+///
+/// Works for one-hot, soft and multi-label targets. Probabilities are
+/// clamped to `CROSS_ENTROPY_EPSILON` from below.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrossEntropyLoss;
+
+impl LossFunction for CrossEntropyLoss {
+    fn name(&self) -> &str {
+        "cross_entropy"
+    }
+    
+    fn compute(&self, output: &[f32], target: &[f32]) -> f32 {
+        output.iter().zip(target)
+            .map(|(o, t)| -t * o.max(CROSS_ENTROPY_EPSILON).ln())
+            .sum()
+    }
+    
+    fn gradient(&self, output: &[f32], target: &[f32]) -> Vec<f32> {
+        output.iter().zip(target)
+            .map(|(o, t)| -t / o.max(CROSS_ENTROPY_EPSILON))
+            .collect()
+    }
+}
+
+/// Index of the largest value
+fn argmax(values: &[f32]) -> Option<usize> {
+    (0..values.len()).max_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Logistic sigmoid
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Log-odds of a probability, the inverse of `sigmoid`
+fn log_odds(p: f32) -> f32 {
+    let p = p.clamp(1e-6, 1.0 - 1e-6);
+    (p / (1.0 - p)).ln()
+}
+
+/// Confidence calibration method
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalibrationMethod {
+    /// Divide logits by a single fitted temperature
+    Temperature { temperature: f32 },
+    /// Map the log-odds `s` of the top class's softmax confidence through a
+    /// fitted sigmoid `1 / (1 + exp(-(a * s + b)))`
+    Platt { a: f32, b: f32 },
+}
+
+/// Confidence calibrator fit on a validation set
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibrator {
+    /// Calibration method and parameters
+    pub method: CalibrationMethod,
+    /// Whether the calibrator has been fit
+    pub fitted: bool,
+}
+
+impl Calibrator {
+    /// Create an unfitted temperature scaling calibrator
+    pub fn temperature() -> Self {
+        Self {
+            method: CalibrationMethod::Temperature { temperature: 1.0 },
+            fitted: false,
+        }
+    }
+    
+    /// Create an unfitted Platt scaling calibrator
+    pub fn platt() -> Self {
+        Self {
+            method: CalibrationMethod::Platt { a: 1.0, b: 0.0 },
+            fitted: false,
+        }
+    }
+    
+    /// Fit on validation logits and their true class labels
+    pub fn calibrate(&mut self, logits: &[Vec<f32>], labels: &[usize]) -> Result<(), NeuralError> {
+        if logits.is_empty() || logits.len() != labels.len() {
+            return Err(NeuralError::TrainingError(format!(
+                "Calibration needs matching logits and labels, got {} and {}",
+                logits.len(),
+                labels.len()
+            )));
+        }
+        if let Some(i) = (0..logits.len()).find(|&i| labels[i] >= logits[i].len()) {
+            return Err(NeuralError::TrainingError(format!(
+                "Label {} out of range for sample {}",
+                labels[i], i
+            )));
+        }
+        
+        self.method = match self.method {
+            CalibrationMethod::Temperature { .. } => CalibrationMethod::Temperature {
+                temperature: Self::fit_temperature(logits, labels),
+            },
+            CalibrationMethod::Platt { .. } => {
+                let (a, b) = Self::fit_platt(logits, labels);
+                CalibrationMethod::Platt { a, b }
+            },
+        };
+        self.fitted = true;
+        
+        Ok(())
+    }
+    
+    /// Fit the temperature minimizing negative log-likelihood by golden-section search on log T
+    fn fit_temperature(logits: &[Vec<f32>], labels: &[usize]) -> f32 {
+        let nll = |log_t: f64| -> f64 {
+            let t = log_t.exp() as f32;
+            logits.iter().zip(labels)
+                .map(|(l, &y)| {
+                    let scaled: Vec<f32> = l.iter().map(|x| x / t).collect();
+                    -(softmax(&scaled)[y].max(1e-12) as f64).ln()
+                })
+                .sum()
+        };
+        
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        let (mut lo, mut hi) = (0.01f64.ln(), 100f64.ln());
+        for _ in 0..100 {
+            let m1 = hi - ratio * (hi - lo);
+            let m2 = lo + ratio * (hi - lo);
+            if nll(m1) < nll(m2) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        
+        ((lo + hi) / 2.0).exp() as f32
+    }
+    
+    /// Fit Platt parameters on the top confidence's log-odds against top-1 correctness by gradient descent
+    fn fit_platt(logits: &[Vec<f32>], labels: &[usize]) -> (f32, f32) {
+        let samples: Vec<(f32, f32)> = logits.iter().zip(labels)
+            .filter_map(|(l, &y)| {
+                let top = argmax(l)?;
+                Some((log_odds(softmax(l)[top]), if top == y { 1.0 } else { 0.0 }))
+            })
+            .collect();
+        
+        let (mut a, mut b) = (1.0f32, 0.0f32);
+        let n = samples.len() as f32;
+        for _ in 0..2000 {
+            let (mut grad_a, mut grad_b) = (0.0, 0.0);
+            for &(score, target) in &samples {
+                let error = sigmoid(a * score + b) - target;
+                grad_a += error * score;
+                grad_b += error;
+            }
+            a -= 0.1 * grad_a / n;
+            b -= 0.1 * grad_b / n;
+        }
+        
+        (a, b)
+    }
+    
+    /// Apply calibration to logits, returning class probabilities
+    pub fn apply(&self, logits: &[f32]) -> Vec<f32> {
+        match self.method {
+            CalibrationMethod::Temperature { temperature } => {
+                let scaled: Vec<f32> = logits.iter().map(|x| x / temperature).collect();
+                softmax(&scaled)
+            },
+            CalibrationMethod::Platt { a, b } => {
+                let mut probabilities = softmax(logits);
+                let top = match argmax(logits) {
+                    Some(top) => top,
+                    None => return probabilities,
+                };
+                
+                // Calibrate the top class and spread the rest proportionally
+                let calibrated = sigmoid(a * log_odds(probabilities[top]) + b);
+                let rest = 1.0 - probabilities[top];
+                for (i, p) in probabilities.iter_mut().enumerate() {
+                    *p = if i == top {
+                        calibrated
+                    } else if rest > 0.0 {
+                        *p / rest * (1.0 - calibrated)
+                    } else {
+                        (1.0 - calibrated) / (logits.len() - 1) as f32
+                    };
+                }
+                probabilities
+            },
+        }
+    }
+    
+    /// Calibrate a single confidence score in (0, 1)
+    ///
+    /// Platt parameters apply to the confidence's log-odds, the score they
+    /// were fitted on, so this matches the top class of `apply`.
+    pub fn apply_confidence(&self, confidence: f32) -> f32 {
+        let logit = log_odds(confidence);
+        match self.method {
+            CalibrationMethod::Temperature { temperature } => sigmoid(logit / temperature),
+            CalibrationMethod::Platt { a, b } => sigmoid(a * logit + b),
+        }
+    }
+}
+
+/// Round values to what the given precision can represent
+///
+/// FP16 keeps 10 mantissa bits and the FP16 range; INT8 maps the values
+/// onto 255 symmetric levels scaled by their largest magnitude.
+pub fn quantize(values: &[f32], precision: ModelPrecision) -> Vec<f32> {
+    match precision {
+        ModelPrecision::FP32 => values.to_vec(),
+        ModelPrecision::FP16 => values.iter().map(|&x| round_to_fp16(x)).collect(),
+        ModelPrecision::INT8 => {
+            let max = values.iter().filter(|x| x.is_finite()).fold(0.0f32, |max, x| max.max(x.abs()));
+            if max == 0.0 {
+                return values.to_vec();
+            }
+            let scale = max / 127.0;
+            values.iter().map(|&x| (x / scale).round().clamp(-127.0, 127.0) * scale).collect()
+        },
+    }
+}
+
+/// Round a value to the nearest FP16 value, saturating at the FP16 range
+fn round_to_fp16(value: f32) -> f32 {
+    const FP16_MAX: f32 = 65504.0;
+    if !value.is_finite() {
+        return value;
+    }
+    // Round half up at the 13 mantissa bits FP16 drops
+    let rounded = f32::from_bits((value.to_bits() + 0x1000) & !0x1FFF);
+    rounded.clamp(-FP16_MAX, FP16_MAX)
+}
+
+/// Get indices of NaN and infinite values
+fn non_finite_indices(values: &[f32]) -> Vec<usize> {
+    values.iter()
+        .enumerate()
+        .filter(|(_, x)| !x.is_finite())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Compute the Adler-32 rolling checksum of model bytes
+pub fn model_checksum(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    // 5552 is the largest block that cannot overflow b before reduction
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    
+    (b << 16) | a
+}
+
+/// Verify model bytes against an expected checksum
+pub fn verify_model_checksum(bytes: &[u8], expected: u32) -> Result<(), NeuralError> {
+    let actual = model_checksum(bytes);
+    if actual != expected {
+        return Err(NeuralError::IntegrityError(format!(
+            "Checksum mismatch: expected {:08x}, got {:08x}",
+            expected, actual
+        )));
+    }
+    Ok(())
+}
+
+/// Summary of a single layer
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LayerSummary {
+    /// Layer name
+    pub name: String,
+    /// Layer type
+    pub layer_type: LayerType,
+    /// Input size
+    pub input_size: usize,
+    /// Output size
+    pub output_size: usize,
+    /// Parameter count
+    pub parameters: usize,
+    /// Activation function
+    pub activation: ActivationFunction,
+}
+
+/// Difference between two layers at the same position
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayerDiff {
+    /// Layer attribute differs
+    Changed { index: usize, field: String, expected: String, actual: String },
+    /// Layer only present in the expected architecture
+    Missing { index: usize, layer: LayerSummary },
+    /// Layer only present in the actual architecture
+    Extra { index: usize, layer: LayerSummary },
+}
+
+impl fmt::Display for LayerDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayerDiff::Changed { index, field, expected, actual } => {
+                write!(f, "layer {}: {} differs (expected {}, got {})", index, field, expected, actual)
+            },
+            LayerDiff::Missing { index, layer } => {
+                write!(f, "layer {}: missing {} ({} -> {})", index, layer.name, layer.input_size, layer.output_size)
+            },
+            LayerDiff::Extra { index, layer } => {
+                write!(f, "layer {}: unexpected {} ({} -> {})", index, layer.name, layer.input_size, layer.output_size)
+            },
+        }
+    }
+}
+
+/// Summary of a network architecture
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArchitectureSummary {
+    /// Network name
+    pub name: String,
+    /// Layer summaries
+    pub layers: Vec<LayerSummary>,
+    /// Total parameter count
+    pub total_parameters: usize,
+}
+
+impl ArchitectureSummary {
+    /// Export summary as JSON
+    pub fn to_json(&self) -> Result<String, NeuralError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| NeuralError::Other(format!("Failed to serialize architecture: {}", e)))
+    }
+    
+    /// Compare layer by layer, treating `self` as the expected architecture
+    pub fn diff(&self, other: &ArchitectureSummary) -> Vec<LayerDiff> {
+        let mut diffs = Vec::new();
+        
+        for index in 0..self.layers.len().max(other.layers.len()) {
+            match (self.layers.get(index), other.layers.get(index)) {
+                (Some(expected), Some(actual)) => {
+                    let fields = [
+                        ("name", expected.name.clone(), actual.name.clone()),
+                        ("layer_type", expected.layer_type.to_string(), actual.layer_type.to_string()),
+                        ("input_size", expected.input_size.to_string(), actual.input_size.to_string()),
+                        ("output_size", expected.output_size.to_string(), actual.output_size.to_string()),
+                        ("activation", format!("{:?}", expected.activation), format!("{:?}", actual.activation)),
+                    ];
+                    for (field, expected, actual) in fields.iter() {
+                        if expected != actual {
+                            diffs.push(LayerDiff::Changed {
+                                index,
+                                field: field.to_string(),
+                                expected: expected.clone(),
+                                actual: actual.clone(),
+                            });
+                        }
+                    }
+                },
+                (Some(expected), None) => diffs.push(LayerDiff::Missing { index, layer: expected.clone() }),
+                (None, Some(actual)) => diffs.push(LayerDiff::Extra { index, layer: actual.clone() }),
+                (None, None) => {},
+            }
+        }
+        
+        diffs
+    }
+}
+
+impl fmt::Display for ArchitectureSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} ({} parameters)", self.name, self.total_parameters)?;
+        for (i, layer) in self.layers.iter().enumerate() {
+            writeln!(
+                f,
+                "  {}: {} [{}] {} -> {}, {} params, {:?}",
+                i, layer.name, layer.layer_type, layer.input_size, layer.output_size, layer.parameters, layer.activation
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialized form of a neural network
+#[derive(Serialize, Deserialize)]
+struct ModelFile {
+    /// Network name
+    name: String,
+    /// Learning rate
+    learning_rate: f32,
+    /// Network layers
+    layers: Vec<Layer>,
+}
+
+/// Weight and bias gradients of a layer
+type LayerGradients = (Vec<Vec<f32>>, Vec<f32>);
+
 /// Neural network
 pub struct NeuralNetwork {
     /// Network ID
@@ -206,6 +722,14 @@ pub struct NeuralNetwork {
     pub layers: Vec<Layer>,
     /// Learning rate
     pub learning_rate: f32,
+    /// Inference precision
+    pub precision: ModelPrecision,
+    /// Input sanitization policy
+    pub input_sanitization: InputSanitization,
+    /// Output confidence calibrator
+    pub calibrator: Option<Calibrator>,
+    /// Training loss function
+    pub loss: Box<dyn LossFunction>,
 }
 
 impl NeuralNetwork {
@@ -222,9 +746,18 @@ impl NeuralNetwork {
             name: name.to_string(),
             layers: Vec::new(),
             learning_rate,
+            precision: ModelPrecision::FP32,
+            input_sanitization: InputSanitization::default(),
+            calibrator: None,
+            loss: Box::new(MseLoss),
         })
     }
     
+    /// Set training loss function
+    pub fn set_loss_function<L: LossFunction + 'static>(&mut self, loss: L) {
+        self.loss = Box::new(loss);
+    }
+    
     /// Add layer
     pub fn add_layer(&mut self, layer: Layer) -> Result<(), NeuralError> {
         // Initialize weights if not the first layer
@@ -241,21 +774,146 @@ impl NeuralNetwork {
         Ok(())
     }
     
+    /// Set inference precision, which the forward pass rounds weights and biases to
+    pub fn set_precision(&mut self, precision: ModelPrecision) {
+        self.precision = precision;
+    }
+    
+    /// Get parameter count
+    pub fn parameter_count(&self) -> usize {
+        self.layers.iter()
+            .map(|l| {
+                let weights = l.weights.as_ref().map_or(0, |w| w.iter().map(|row| row.len()).sum());
+                let biases = l.biases.as_ref().map_or(0, |b| b.len());
+                weights + biases
+            })
+            .sum()
+    }
+    
+    /// Get architecture summary
+    pub fn architecture_summary(&self) -> ArchitectureSummary {
+        let mut previous_size = None;
+        let layers: Vec<LayerSummary> = self.layers.iter()
+            .map(|l| {
+                let input_size = l.weights.as_ref()
+                    .and_then(|w| w.first().map(|row| row.len()))
+                    .or(previous_size)
+                    .unwrap_or(l.size);
+                previous_size = Some(l.size);
+                
+                let weights = l.weights.as_ref().map_or(0, |w| w.iter().map(|row| row.len()).sum::<usize>());
+                let biases = l.biases.as_ref().map_or(0, |b| b.len());
+                
+                LayerSummary {
+                    name: l.name.clone(),
+                    layer_type: l.layer_type,
+                    input_size,
+                    output_size: l.size,
+                    parameters: weights + biases,
+                    activation: l.activation,
+                }
+            })
+            .collect();
+        
+        ArchitectureSummary {
+            name: self.name.clone(),
+            total_parameters: layers.iter().map(|l| l.parameters).sum(),
+            layers,
+        }
+    }
+    
+    /// Get estimated memory footprint in bytes at the current precision
+    pub fn memory_footprint(&self) -> usize {
+        self.parameter_count() * self.precision.bytes_per_parameter()
+    }
+    
+    /// Get input size, the size of the input layer
+    pub fn input_size(&self) -> Option<usize> {
+        self.layers.first().map(|l| l.size)
+    }
+    
+    /// Get output size, the size of the last layer
+    pub fn output_size(&self) -> Option<usize> {
+        self.layers.last().map(|l| l.size)
+    }
+    
     /// Forward pass
+    ///
+    /// Inputs not matching the input layer's shape are rejected as invalid
+    /// input, naming the expected and actual shapes.
     pub fn forward(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
-        if self.layers.is_empty() {
-            return Err(NeuralError::InferenceError("No layers in network".to_string()));
+        let input_layer = self.layers.first().ok_or_else(|| {
+            NeuralError::InferenceError("No layers in network".to_string())
+        })?;
+        
+        if inputs.len() != input_layer.size {
+            return Err(NeuralError::InvalidInput(format!(
+                "Input shape mismatch for layer '{}': expected [{}], got [{}]",
+                input_layer.name,
+                input_layer.size,
+                inputs.len()
+            )));
         }
         
         let mut current_outputs = inputs.to_vec();
         
-        for (i, layer) in self.layers.iter().enumerate().skip(1) {
-            current_outputs = layer.forward(&current_outputs)?;
+        for layer in self.layers.iter().skip(1) {
+            current_outputs = layer.forward_at(&current_outputs, self.precision)?;
         }
         
         Ok(current_outputs)
     }
     
+    /// Forward pass over a batch of inputs, returning one output per input
+    ///
+    /// Fails on the first input that cannot be processed.
+    pub fn forward_batch(&self, batch: &[Vec<f32>]) -> Result<Vec<Vec<f32>>, NeuralError> {
+        batch.iter().map(|inputs| self.forward(inputs)).collect()
+    }
+    
+    /// Set input sanitization policy
+    pub fn set_input_sanitization(&mut self, policy: InputSanitization) {
+        self.input_sanitization = policy;
+    }
+    
+    /// Predict, a read-only forward pass
+    ///
+    /// Inputs are sanitized first; NaN or infinite outputs are reported as
+    /// an inference error.
+    pub fn predict(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        let inputs = self.input_sanitization.apply(inputs)?;
+        let outputs = self.forward(&inputs)?;
+        
+        let invalid = non_finite_indices(&outputs);
+        if !invalid.is_empty() {
+            return Err(NeuralError::InferenceError(format!(
+                "Non-finite outputs at indices {:?}",
+                invalid
+            )));
+        }
+        
+        Ok(outputs)
+    }
+    
+    /// Set output confidence calibrator
+    pub fn set_calibrator(&mut self, calibrator: Option<Calibrator>) {
+        self.calibrator = calibrator;
+    }
+    
+    /// Predict class probabilities, treating outputs as logits and applying the calibrator if set
+    pub fn predict_proba(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        let logits = self.predict(inputs)?;
+        Ok(match &self.calibrator {
+            Some(calibrator) => calibrator.apply(&logits),
+            None => softmax(&logits),
+        })
+    }
+    
+    /// Predict a batch of inputs, returning one result per input
+    pub fn predict_batch(&self, batch: &[Vec<f32>]) -> Vec<Result<Vec<f32>, NeuralError>> {
+        batch.iter().map(|inputs| self.predict(inputs)).collect()
+    }
+    
     /// Train
     pub fn train(&mut self, inputs: &[f32], targets: &[f32]) -> Result<f32, NeuralError> {
         // Forward pass
@@ -270,10 +928,7 @@ impl NeuralNetwork {
         }
         
         // Calculate error
-        let mut error = 0.0;
-        for i in 0..outputs.len() {
-            error += 0.5 * (targets[i] - outputs[i]).powi(2);
-        }
+        let error = self.loss.compute(&outputs, targets);
         
         // Backpropagation (simplified)
         // In a real implementation, this would be more complex
@@ -281,16 +936,408 @@ impl NeuralNetwork {
         Ok(error)
     }
     
-    /// Save network
+    /// Train on a batch with one gradient descent step on its mean loss
+    ///
+    /// Gradients are backpropagated at full precision and averaged over the
+    /// batch before any weight changes. Returns the mean loss over the
+    /// samples before the update.
+    pub fn train_batch(&mut self, inputs: &[Vec<f32>], targets: &[Vec<f32>]) -> Result<f32, NeuralError> {
+        if inputs.is_empty() {
+            return Err(NeuralError::TrainingError("Empty batch".to_string()));
+        }
+        if inputs.len() != targets.len() {
+            return Err(NeuralError::TrainingError(format!(
+                "Batch size mismatch: {} inputs, {} targets",
+                inputs.len(),
+                targets.len()
+            )));
+        }
+        
+        let mut gradients: Vec<LayerGradients> = Vec::with_capacity(self.layers.len().saturating_sub(1));
+        let mut total = 0.0;
+        for (inputs, targets) in inputs.iter().zip(targets) {
+            let (loss, sample) = self.backpropagate(inputs, targets)?;
+            total += loss;
+            if gradients.is_empty() {
+                gradients = sample;
+                continue;
+            }
+            for ((weights, biases), (sample_weights, sample_biases)) in gradients.iter_mut().zip(sample) {
+                for (row, sample_row) in weights.iter_mut().zip(sample_weights) {
+                    row.iter_mut().zip(sample_row).for_each(|(g, s)| *g += s);
+                }
+                biases.iter_mut().zip(sample_biases).for_each(|(g, s)| *g += s);
+            }
+        }
+        
+        let step = self.learning_rate / inputs.len() as f32;
+        for (layer, (weight_gradients, bias_gradients)) in self.layers.iter_mut().skip(1).zip(gradients) {
+            if let (Some(weights), Some(biases)) = (layer.weights.as_mut(), layer.biases.as_mut()) {
+                for (row, gradient_row) in weights.iter_mut().zip(weight_gradients) {
+                    row.iter_mut().zip(gradient_row).for_each(|(w, g)| *w -= step * g);
+                }
+                biases.iter_mut().zip(bias_gradients).for_each(|(b, g)| *b -= step * g);
+            }
+        }
+        
+        Ok(total / inputs.len() as f32)
+    }
+    
+    /// Compute the loss of one sample and the weight and bias gradients of
+    /// every layer after the input layer
+    fn backpropagate(&self, inputs: &[f32], targets: &[f32]) -> Result<(f32, Vec<LayerGradients>), NeuralError> {
+        let input_layer = self.layers.first().ok_or_else(|| {
+            NeuralError::TrainingError("No layers in network".to_string())
+        })?;
+        if inputs.len() != input_layer.size {
+            return Err(NeuralError::InvalidInput(format!(
+                "Input shape mismatch for layer '{}': expected [{}], got [{}]",
+                input_layer.name,
+                input_layer.size,
+                inputs.len()
+            )));
+        }
+        
+        // Keep each layer's weighted sums for the activation derivatives
+        let layers = &self.layers[1..];
+        let mut activations = vec![inputs.to_vec()];
+        let mut sums: Vec<Vec<f32>> = Vec::with_capacity(layers.len());
+        for layer in layers {
+            let (weights, biases) = match (&layer.weights, &layer.biases) {
+                (Some(weights), Some(biases)) => (weights, biases),
+                _ => return Err(NeuralError::TrainingError(format!("Layer '{}' not initialized", layer.name))),
+            };
+            let previous = activations.last().expect("activations start with the inputs");
+            let layer_sums: Vec<f32> = weights.iter()
+                .zip(biases)
+                .map(|(row, bias)| bias + row.iter().zip(previous).map(|(w, x)| w * x).sum::<f32>())
+                .collect();
+            activations.push(layer_sums.iter().map(|&sum| layer.activation.apply(sum)).collect());
+            sums.push(layer_sums);
+        }
+        
+        let outputs = activations.last().expect("activations start with the inputs");
+        if outputs.len() != targets.len() {
+            return Err(NeuralError::TrainingError(format!(
+                "Target size mismatch: expected {}, got {}",
+                outputs.len(),
+                targets.len()
+            )));
+        }
+        let loss = self.loss.compute(outputs, targets);
+        
+        let mut gradients = Vec::with_capacity(layers.len());
+        let mut errors = self.loss.gradient(outputs, targets);
+        for (l, layer) in layers.iter().enumerate().rev() {
+            let deltas: Vec<f32> = errors.iter()
+                .zip(&sums[l])
+                .map(|(error, &sum)| error * layer.activation.apply_derivative(sum))
+                .collect();
+            let weight_gradients = deltas.iter()
+                .map(|delta| activations[l].iter().map(|x| delta * x).collect())
+                .collect();
+            
+            if let Some(weights) = &layer.weights {
+                errors = (0..activations[l].len())
+                    .map(|j| deltas.iter().zip(weights).map(|(delta, row)| delta * row[j]).sum())
+                    .collect();
+            }
+            gradients.push((weight_gradients, deltas));
+        }
+        gradients.reverse();
+        
+        Ok((loss, gradients))
+    }
+    
+    /// Serialize network to model bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>, NeuralError> {
+        let model = ModelFile {
+            name: self.name.clone(),
+            learning_rate: self.learning_rate,
+            layers: self.layers.clone(),
+        };
+        
+        serde_json::to_vec(&model)
+            .map_err(|e| NeuralError::Other(format!("Failed to serialize network: {}", e)))
+    }
+    
+    /// Deserialize network from model bytes, verifying the checksum if one is given
+    pub fn from_bytes(bytes: &[u8], expected_checksum: Option<u32>) -> Result<Self, NeuralError> {
+        if let Some(expected) = expected_checksum {
+            verify_model_checksum(bytes, expected)?;
+        }
+        
+        let model: ModelFile = serde_json::from_slice(bytes)
+            .map_err(|e| NeuralError::InitializationError(format!("Invalid model file: {}", e)))?;
+        
+        let mut network = Self::new(&model.name, model.learning_rate)?;
+        network.layers = model.layers;
+        Ok(network)
+    }
+    
+    /// Load weights from bytes into this network, reporting any architecture mismatch
+    pub fn load_weights(&mut self, bytes: &[u8], expected_checksum: Option<u32>) -> Result<(), NeuralError> {
+        let loaded = Self::from_bytes(bytes, expected_checksum)?;
+        
+        let diffs = self.architecture_summary().diff(&loaded.architecture_summary());
+        let mismatches: Vec<String> = diffs.iter()
+            .filter(|d| !matches!(d, LayerDiff::Changed { field, .. } if field == "name"))
+            .map(|d| d.to_string())
+            .collect();
+        if !mismatches.is_empty() {
+            return Err(NeuralError::InitializationError(format!(
+                "Architecture mismatch: {}",
+                mismatches.join("; ")
+            )));
+        }
+        
+        self.layers = loaded.layers;
+        Ok(())
+    }
+    
+    /// Save network, writing its checksum alongside the model file
     pub fn save(&self, path: &str) -> Result<(), NeuralError> {
-        // In a real implementation, this would save the network to a file
+        let bytes = self.to_bytes()?;
+        
+        std::fs::write(path, &bytes)
+            .map_err(|e| NeuralError::Other(format!("Failed to write {}: {}", path, e)))?;
+        std::fs::write(checksum_path(path), format!("{:08x}\n", model_checksum(&bytes)))
+            .map_err(|e| NeuralError::Other(format!("Failed to write checksum for {}: {}", path, e)))?;
+        
         Ok(())
     }
     
-    /// Load network
+    /// Load network, verifying it against the checksum file if one exists
     pub fn load(path: &str) -> Result<Self, NeuralError> {
-        // In a real implementation, this would load the network from a file
-        Err(NeuralError::Other("Not implemented".to_string()))
+        let expected = match std::fs::read_to_string(checksum_path(path)) {
+            Ok(content) => Some(parse_checksum(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(NeuralError::Other(format!("Failed to read checksum for {}: {}", path, e))),
+        };
+        
+        Self::load_with_checksum(path, expected)
+    }
+    
+    /// Load network, requiring a match against the expected checksum if one is given
+    pub fn load_with_checksum(path: &str, expected_checksum: Option<u32>) -> Result<Self, NeuralError> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| NeuralError::Other(format!("Failed to read {}: {}", path, e)))?;
+        
+        Self::from_bytes(&bytes, expected_checksum)
+    }
+}
+
+/// Get the checksum file path for a model file
+fn checksum_path(path: &str) -> String {
+    format!("{}.{}", path, CHECKSUM_EXTENSION)
+}
+
+/// Parse a hex checksum
+pub fn parse_checksum(content: &str) -> Result<u32, NeuralError> {
+    u32::from_str_radix(content.trim(), 16)
+        .map_err(|e| NeuralError::IntegrityError(format!("Invalid checksum '{}': {}", content.trim(), e)))
+}
+
+/// Neural network shared between threads.
+///
+/// Inference takes a read lock so predictions run in parallel, while
+/// training takes the write lock.
+#[derive(Clone)]
+pub struct SharedNeuralNetwork {
+    /// Inner network
+    inner: Arc<RwLock<NeuralNetwork>>,
+}
+
+impl SharedNeuralNetwork {
+    /// Create a new shared neural network
+    pub fn new(network: NeuralNetwork) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(network)),
+        }
+    }
+    
+    /// Predict
+    pub fn predict(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        self.read()?.predict(inputs)
+    }
+    
+    /// Train
+    pub fn train(&self, inputs: &[f32], targets: &[f32]) -> Result<f32, NeuralError> {
+        self.write()?.train(inputs, targets)
+    }
+    
+    /// Get read access
+    pub fn read(&self) -> Result<RwLockReadGuard<'_, NeuralNetwork>, NeuralError> {
+        self.inner.read().map_err(|_| {
+            NeuralError::InferenceError("Neural network lock poisoned".to_string())
+        })
+    }
+    
+    /// Get write access
+    pub fn write(&self) -> Result<RwLockWriteGuard<'_, NeuralNetwork>, NeuralError> {
+        self.inner.write().map_err(|_| {
+            NeuralError::TrainingError("Neural network lock poisoned".to_string())
+        })
+    }
+}
+
+/// Inference batching config
+///
+/// A larger `max_batch_size` or `max_wait` raises throughput under load at
+/// the cost of latency for the first request in each batch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchingConfig {
+    /// Maximum number of requests per batch
+    pub max_batch_size: usize,
+    /// Maximum time the first request in a batch waits for others
+    pub max_wait: Duration,
+}
+
+impl Default for BatchingConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 16,
+            max_wait: Duration::from_millis(5),
+        }
+    }
+}
+
+/// Inference batching statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchingStats {
+    /// Batches run
+    pub batches: usize,
+    /// Requests served
+    pub requests: usize,
+    /// Largest batch run
+    pub largest_batch: usize,
+}
+
+/// Pending inference request
+type InferenceRequest = (Vec<f32>, Sender<Result<Vec<f32>, NeuralError>>);
+
+/// Inference scheduler coalescing concurrent predictions into batches
+pub struct InferenceBatcher {
+    /// Batching config
+    config: BatchingConfig,
+    /// Request queue
+    sender: Mutex<Option<Sender<InferenceRequest>>>,
+    /// Worker thread
+    worker: Option<JoinHandle<()>>,
+    /// Batches run
+    batches: Arc<AtomicUsize>,
+    /// Requests served
+    requests: Arc<AtomicUsize>,
+    /// Largest batch run
+    largest_batch: Arc<AtomicUsize>,
+}
+
+impl InferenceBatcher {
+    /// Create a new inference batcher serving a shared network
+    pub fn new(network: SharedNeuralNetwork, config: BatchingConfig) -> Result<Self, NeuralError> {
+        if config.max_batch_size == 0 {
+            return Err(NeuralError::InitializationError(
+                "Max batch size must be positive".to_string()
+            ));
+        }
+        
+        let (sender, receiver) = mpsc::channel();
+        let batches = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let largest_batch = Arc::new(AtomicUsize::new(0));
+        
+        let worker = {
+            let (batches, requests, largest_batch) = (batches.clone(), requests.clone(), largest_batch.clone());
+            thread::spawn(move || {
+                while let Some(batch) = Self::collect_batch(&receiver, &config) {
+                    let (inputs, replies): (Vec<Vec<f32>>, Vec<_>) = batch.into_iter().unzip();
+                    let results = match network.read() {
+                        Ok(network) => network.predict_batch(&inputs),
+                        Err(_) => inputs.iter()
+                            .map(|_| Err(NeuralError::InferenceError("Neural network lock poisoned".to_string())))
+                            .collect(),
+                    };
+                    
+                    // Record the batch before replying so callers observe it in stats
+                    batches.fetch_add(1, Ordering::SeqCst);
+                    requests.fetch_add(inputs.len(), Ordering::SeqCst);
+                    largest_batch.fetch_max(inputs.len(), Ordering::SeqCst);
+                    
+                    for (result, reply) in results.into_iter().zip(replies) {
+                        let _ = reply.send(result);
+                    }
+                }
+            })
+        };
+        
+        Ok(Self {
+            config,
+            sender: Mutex::new(Some(sender)),
+            worker: Some(worker),
+            batches,
+            requests,
+            largest_batch,
+        })
+    }
+    
+    /// Wait for the first request, then gather more until the batch is full or `max_wait` elapses
+    fn collect_batch(receiver: &Receiver<InferenceRequest>, config: &BatchingConfig) -> Option<Vec<InferenceRequest>> {
+        let first = receiver.recv().ok()?;
+        let deadline = Instant::now() + config.max_wait;
+        let mut batch = vec![first];
+        
+        while batch.len() < config.max_batch_size {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok(request) => batch.push(request),
+                Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        
+        Some(batch)
+    }
+    
+    /// Submit inputs and wait for the prediction
+    pub fn predict(&self, inputs: &[f32]) -> Result<Vec<f32>, NeuralError> {
+        let (reply, result) = mpsc::channel();
+        
+        {
+            let sender = self.sender.lock().map_err(|_| {
+                NeuralError::InferenceError("Inference queue lock poisoned".to_string())
+            })?;
+            sender.as_ref()
+                .ok_or_else(|| NeuralError::InferenceError("Inference batcher stopped".to_string()))?
+                .send((inputs.to_vec(), reply))
+                .map_err(|_| NeuralError::InferenceError("Inference worker stopped".to_string()))?;
+        }
+        
+        result.recv()
+            .map_err(|_| NeuralError::InferenceError("Inference worker dropped request".to_string()))?
+    }
+    
+    /// Get batching config
+    pub fn get_config(&self) -> BatchingConfig {
+        self.config
+    }
+    
+    /// Get batching statistics
+    pub fn stats(&self) -> BatchingStats {
+        BatchingStats {
+            batches: self.batches.load(Ordering::SeqCst),
+            requests: self.requests.load(Ordering::SeqCst),
+            largest_batch: self.largest_batch.load(Ordering::SeqCst),
+        }
+    }
+}
+
+impl Drop for InferenceBatcher {
+    fn drop(&mut self) {
+        if let Ok(mut sender) = self.sender.lock() {
+            sender.take();
+        }
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
     }
 }
 
@@ -2,6 +2,11 @@
 mod tests {
     use super::*;
     use crate::neuro_symbolic::neural::{NeuralModel, Layer, LinearLayer, Tensor, NeuralError};
+    use crate::neuro_symbolic::{model_checksum, softmax, ActivationFunction, BatchingConfig, Calibrator, CalibrationMethod, InferenceBatcher, LayerDiff, InputSanitization, LayerType, NeuralNetwork, SharedNeuralNetwork};
+    use crate::neuro_symbolic::{quantize, CrossEntropyLoss, LossFunction, MseLoss};
+    use std::sync::{mpsc, Arc, Barrier};
+    use std::thread;
+    use std::time::Duration;
 
     #[test]
     fn test_tensor_creation() {
@@ -151,4 +156,469 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_shared_network_concurrent_predict() {
+        let mut network = NeuralNetwork::new("shared", 0.1).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 4, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("hidden", LayerType::Hidden, 8, ActivationFunction::Tanh)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 2, ActivationFunction::Sigmoid)).unwrap();
+        
+        let inputs: Vec<Vec<f32>> = (0..8)
+            .map(|i| vec![i as f32 * 0.1, 0.5, -0.25, 1.0 - i as f32 * 0.1])
+            .collect();
+        let expected: Vec<Vec<f32>> = inputs.iter().map(|x| network.forward(x).unwrap()).collect();
+        
+        let shared = SharedNeuralNetwork::new(network);
+        let handles: Vec<_> = inputs.into_iter().zip(expected.into_iter())
+            .map(|(input, expected)| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        assert_eq!(shared.predict(&input).unwrap(), expected);
+                    }
+                })
+            })
+            .collect();
+        
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+    
+    #[test]
+    fn test_shared_network_write_blocks_predict() {
+        let mut network = NeuralNetwork::new("shared", 0.5).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 2, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 1, ActivationFunction::Sigmoid)).unwrap();
+        
+        let shared = SharedNeuralNetwork::new(network);
+        let guard = shared.write().unwrap();
+        
+        let (tx, rx) = mpsc::channel();
+        let reader = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let outputs = shared.predict(&[1.0, 0.0]).unwrap();
+                tx.send(outputs).unwrap();
+            })
+        };
+        
+        thread::sleep(Duration::from_millis(50));
+        assert!(rx.try_recv().is_err());
+        
+        drop(guard);
+        assert_eq!(rx.recv().unwrap().len(), 1);
+        reader.join().unwrap();
+        
+        assert!(shared.train(&[1.0, 0.0], &[1.0]).is_ok());
+    }
+
+    fn identity_network() -> NeuralNetwork {
+        let mut network = NeuralNetwork::new("sanitized", 0.1).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 3, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 3, ActivationFunction::Linear)).unwrap();
+        network.layers[1].weights = Some(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]);
+        network
+    }
+    
+    #[test]
+    fn test_predict_rejects_nan_input() {
+        let network = identity_network();
+        
+        match network.predict(&[1.0, f32::NAN, f32::INFINITY]) {
+            Err(NeuralError::InvalidInput(msg)) => assert!(msg.contains("[1, 2]")),
+            other => panic!("expected invalid input, got {:?}", other),
+        }
+        assert_eq!(network.predict(&[1.0, 2.0, 3.0]).unwrap(), vec![1.0, 2.0, 3.0]);
+    }
+    
+    #[test]
+    fn test_predict_clamps_nan_input() {
+        let mut network = identity_network();
+        network.set_input_sanitization(InputSanitization::Clamp { min: -1.0, max: 1.0 });
+        
+        assert_eq!(network.predict(&[f32::NAN, f32::NEG_INFINITY, 5.0]).unwrap(), vec![0.0, -1.0, 1.0]);
+        
+        network.set_input_sanitization(InputSanitization::Clamp { min: 2.0, max: 3.0 });
+        assert_eq!(network.predict(&[f32::NAN, 2.5, 0.0]).unwrap(), vec![2.0, 2.5, 2.0]);
+    }
+    
+    #[test]
+    fn test_predict_zero_fills_nan_input() {
+        let mut network = identity_network();
+        network.set_input_sanitization(InputSanitization::ZeroFill);
+        
+        assert_eq!(network.predict(&[f32::NAN, 0.5, f32::INFINITY]).unwrap(), vec![0.0, 0.5, 0.0]);
+    }
+    
+    #[test]
+    fn test_predict_reports_non_finite_output() {
+        let mut network = identity_network();
+        network.layers[1].weights.as_mut().unwrap()[2][2] = f32::NAN;
+        
+        match network.predict(&[1.0, 2.0, 3.0]) {
+            Err(NeuralError::InferenceError(msg)) => assert!(msg.contains("[2]")),
+            other => panic!("expected inference error, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_model_checksum_known_value() {
+        assert_eq!(model_checksum(b""), 1);
+        assert_eq!(model_checksum(b"Wikipedia"), 0x11e6_0398);
+    }
+    
+    #[test]
+    fn test_from_bytes_verifies_checksum() {
+        let bytes = identity_network().to_bytes().unwrap();
+        let checksum = model_checksum(&bytes);
+        
+        let loaded = NeuralNetwork::from_bytes(&bytes, Some(checksum)).unwrap();
+        assert_eq!(loaded.name, "sanitized");
+        assert_eq!(loaded.predict(&[1.0, 2.0, 3.0]).unwrap(), vec![1.0, 2.0, 3.0]);
+        
+        let mut corrupted = bytes.clone();
+        let pos = corrupted.iter().position(|&b| b == b'1').unwrap();
+        corrupted[pos] = b'7';
+        
+        match NeuralNetwork::from_bytes(&corrupted, Some(checksum)) {
+            Err(NeuralError::IntegrityError(msg)) => assert!(msg.contains("Checksum mismatch")),
+            other => panic!("expected integrity error, got {:?}", other.map(|n| n.name)),
+        }
+    }
+    
+    #[test]
+    fn test_load_rejects_altered_model_file() {
+        let path = std::env::temp_dir().join(format!("model-{}.json", uuid::Uuid::new_v4()));
+        let path = path.to_str().unwrap().to_string();
+        
+        identity_network().save(&path).unwrap();
+        assert!(NeuralNetwork::load(&path).is_ok());
+        
+        let mut bytes = std::fs::read(&path).unwrap();
+        let last = bytes.len() - 2;
+        bytes[last] ^= 0x01;
+        std::fs::write(&path, &bytes).unwrap();
+        
+        let result = NeuralNetwork::load(&path);
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(format!("{}.checksum", path));
+        
+        match result {
+            Err(NeuralError::IntegrityError(msg)) => assert!(msg.contains("Checksum mismatch")),
+            other => panic!("expected integrity error, got {:?}", other.map(|n| n.name)),
+        }
+    }
+    
+    fn classifier(hidden: usize) -> NeuralNetwork {
+        let mut network = NeuralNetwork::new("classifier", 0.1).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 4, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("hidden", LayerType::Hidden, hidden, ActivationFunction::ReLU)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 2, ActivationFunction::Sigmoid)).unwrap();
+        network
+    }
+    
+    #[test]
+    fn test_architecture_summary() {
+        let summary = classifier(8).architecture_summary();
+        
+        let shapes: Vec<(usize, usize, usize)> = summary.layers.iter()
+            .map(|l| (l.input_size, l.output_size, l.parameters))
+            .collect();
+        assert_eq!(shapes, vec![(4, 4, 0), (4, 8, 40), (8, 2, 18)]);
+        assert_eq!(summary.total_parameters, 58);
+        assert!(summary.to_json().unwrap().contains("\"hidden\""));
+        assert!(summary.diff(&classifier(8).architecture_summary()).is_empty());
+    }
+    
+    #[test]
+    fn test_architecture_diff_reports_layer_size() {
+        let expected = classifier(8).architecture_summary();
+        let actual = classifier(16).architecture_summary();
+        
+        let diffs = expected.diff(&actual);
+        assert_eq!(diffs, vec![
+            LayerDiff::Changed { index: 1, field: "output_size".to_string(), expected: "8".to_string(), actual: "16".to_string() },
+            LayerDiff::Changed { index: 2, field: "input_size".to_string(), expected: "8".to_string(), actual: "16".to_string() },
+        ]);
+        assert_eq!(diffs[0].to_string(), "layer 1: output_size differs (expected 8, got 16)");
+        
+        let mut truncated = classifier(8);
+        truncated.layers.pop();
+        match expected.diff(&truncated.architecture_summary()).as_slice() {
+            [LayerDiff::Missing { index: 2, layer }] => assert_eq!(layer.name, "output"),
+            other => panic!("expected missing layer, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_load_weights_rejects_mismatched_architecture() {
+        let bytes = classifier(16).to_bytes().unwrap();
+        let mut network = classifier(8);
+        
+        match network.load_weights(&bytes, None) {
+            Err(NeuralError::InitializationError(msg)) => assert!(msg.contains("layer 1: output_size differs")),
+            other => panic!("expected architecture mismatch, got {:?}", other),
+        }
+        
+        let bytes = classifier(8).to_bytes().unwrap();
+        assert!(network.load_weights(&bytes, Some(model_checksum(&bytes))).is_ok());
+    }
+    
+    #[test]
+    fn test_inference_batcher_coalesces_concurrent_requests() {
+        let config = BatchingConfig {
+            max_batch_size: 8,
+            max_wait: Duration::from_millis(200),
+        };
+        let batcher = Arc::new(InferenceBatcher::new(SharedNeuralNetwork::new(identity_network()), config).unwrap());
+        let barrier = Arc::new(Barrier::new(6));
+        
+        let handles: Vec<_> = (0..6)
+            .map(|i| {
+                let batcher = batcher.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let inputs = vec![i as f32, i as f32 * 2.0, -(i as f32)];
+                    barrier.wait();
+                    (inputs.clone(), batcher.predict(&inputs))
+                })
+            })
+            .collect();
+        
+        for handle in handles {
+            let (inputs, result) = handle.join().unwrap();
+            assert_eq!(result.unwrap(), inputs);
+        }
+        
+        let stats = batcher.stats();
+        assert_eq!(stats.requests, 6);
+        assert!(stats.batches < 6, "expected batching, got {:?}", stats);
+        assert!(stats.largest_batch > 1);
+    }
+    
+    #[test]
+    fn test_inference_batcher_reports_per_request_errors() {
+        let config = BatchingConfig {
+            max_batch_size: 1,
+            max_wait: Duration::from_millis(0),
+        };
+        let batcher = InferenceBatcher::new(SharedNeuralNetwork::new(identity_network()), config).unwrap();
+        
+        assert!(matches!(batcher.predict(&[f32::NAN, 0.0, 0.0]), Err(NeuralError::InvalidInput(_))));
+        assert_eq!(batcher.predict(&[1.0, 2.0, 3.0]).unwrap(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(batcher.stats().batches, 2);
+        
+        let config = BatchingConfig { max_batch_size: 0, ..config };
+        assert!(InferenceBatcher::new(SharedNeuralNetwork::new(identity_network()), config).is_err());
+    }
+    
+    /// Logits scored `scale` on the predicted class, correct for 7 in every 10 samples
+    fn overconfident_logits(scale: f32) -> (Vec<Vec<f32>>, Vec<usize>) {
+        (0..100)
+            .map(|i| {
+                let label = i % 3;
+                let predicted = if i % 10 < 7 { label } else { (label + 1) % 3 };
+                let mut logits = vec![0.0; 3];
+                logits[predicted] = scale;
+                (logits, label)
+            })
+            .unzip()
+    }
+    
+    #[test]
+    fn test_temperature_scaling_softens_overconfident_logits() {
+        let (logits, labels) = overconfident_logits(5.0);
+        let raw = softmax(&logits[0]);
+        assert!(raw[0] > 0.98);
+        
+        let mut calibrator = Calibrator::temperature();
+        calibrator.calibrate(&logits, &labels).unwrap();
+        
+        let temperature = match calibrator.method {
+            CalibrationMethod::Temperature { temperature } => temperature,
+            other => panic!("unexpected method {:?}", other),
+        };
+        assert!(calibrator.fitted);
+        assert!(temperature > 1.0, "temperature {}", temperature);
+        
+        // Confidence now matches the 70% accuracy
+        let calibrated = calibrator.apply(&logits[0]);
+        assert!((calibrated[0] - 0.7).abs() < 0.01, "calibrated {:?}", calibrated);
+        assert!((calibrated.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!(calibrator.apply_confidence(0.99) < 0.99);
+    }
+    
+    #[test]
+    fn test_temperature_scaling_sharpens_underconfident_logits() {
+        let logits: Vec<Vec<f32>> = (0..30).map(|i| {
+            let mut l = vec![0.0; 3];
+            l[i % 3] = 0.5;
+            l
+        }).collect();
+        let labels: Vec<usize> = (0..30).map(|i| i % 3).collect();
+        
+        let mut calibrator = Calibrator::temperature();
+        calibrator.calibrate(&logits, &labels).unwrap();
+        
+        assert!(calibrator.apply(&logits[0])[0] > softmax(&logits[0])[0] + 0.3);
+    }
+    
+    #[test]
+    fn test_platt_scaling_and_network_calibration() {
+        let (logits, labels) = overconfident_logits(5.0);
+        let mut calibrator = Calibrator::platt();
+        calibrator.calibrate(&logits, &labels).unwrap();
+        
+        let calibrated = calibrator.apply(&logits[0]);
+        assert!((calibrated[0] - 0.7).abs() < 0.02, "calibrated {:?}", calibrated);
+        assert!((calibrated[1] - calibrated[2]).abs() < 1e-6);
+        
+        // A bare confidence calibrates to the same value as the full logits
+        let confidence = softmax(&logits[0])[0];
+        assert!((calibrator.apply_confidence(confidence) - calibrated[0]).abs() < 1e-5);
+        
+        assert!(Calibrator::temperature().calibrate(&logits, &labels[..10]).is_err());
+        
+        let mut network = identity_network();
+        let uncalibrated = network.predict_proba(&[5.0, 0.0, 0.0]).unwrap();
+        network.set_calibrator(Some(calibrator));
+        let calibrated = network.predict_proba(&[5.0, 0.0, 0.0]).unwrap();
+        assert!(calibrated[0] < uncalibrated[0]);
+    }
+
+    #[test]
+    fn test_cross_entropy_prefers_confident_correct_prediction() {
+        let loss = CrossEntropyLoss;
+        let target = [0.0, 1.0, 0.0];
+        let correct = loss.compute(&[0.05, 0.9, 0.05], &target);
+        let wrong = loss.compute(&[0.9, 0.05, 0.05], &target);
+        assert!(correct < wrong);
+        assert!((correct - -(0.9f32).ln()).abs() < 1e-6);
+
+        // A zero probability on the target is clamped rather than infinite
+        assert!(loss.compute(&[1.0, 0.0, 0.0], &target).is_finite());
+        assert!(loss.gradient(&[0.05, 0.9, 0.05], &target)[1] < 0.0);
+    }
+
+    #[test]
+    fn test_mse_loss_matches_default_training_error() {
+        let loss = MseLoss;
+        assert!((loss.compute(&[1.0, 2.0], &[0.0, 4.0]) - 2.5).abs() < 1e-6);
+        assert_eq!(loss.gradient(&[1.0, 2.0], &[0.0, 4.0]), vec![1.0, -2.0]);
+
+        let mut network = identity_network();
+        assert_eq!(network.loss.name(), "mse");
+        // Identity network outputs its inputs
+        let error = network.train(&[0.5, 1.0, 2.0], &[0.0, 0.0, 0.0]).unwrap();
+        assert!((error - 2.625).abs() < 1e-6);
+
+        network.set_loss_function(CrossEntropyLoss);
+        assert_eq!(network.loss.name(), "cross_entropy");
+    }
+
+    #[test]
+    fn test_forward_rejects_wrong_input_shape() {
+        let network = classifier(8);
+        assert_eq!(network.input_size(), Some(4));
+        assert_eq!(network.output_size(), Some(2));
+        
+        match network.forward(&[1.0, 2.0, 3.0]) {
+            Err(NeuralError::InvalidInput(msg)) => {
+                assert_eq!(msg, "Input shape mismatch for layer 'input': expected [4], got [3]");
+            },
+            other => panic!("expected input shape error, got {:?}", other),
+        }
+        assert!(matches!(network.predict(&[0.0; 5]), Err(NeuralError::InvalidInput(_))));
+        assert_eq!(network.forward(&[0.0; 4]).unwrap().len(), 2);
+        
+        let empty = NeuralNetwork::new("empty", 0.1).unwrap();
+        assert_eq!(empty.input_size(), None);
+        assert!(matches!(empty.forward(&[0.0]), Err(NeuralError::InferenceError(_))));
+    }
+
+    #[test]
+    fn test_forward_batch_matches_repeated_forward() {
+        let mut network = classifier(8);
+        let batch: Vec<Vec<f32>> = (0..5).map(|i| vec![i as f32, 1.0, -0.5 * i as f32, 2.0]).collect();
+        
+        let outputs = network.forward_batch(&batch).unwrap();
+        let repeated: Vec<Vec<f32>> = batch.iter().map(|inputs| network.forward(inputs).unwrap()).collect();
+        assert_eq!(outputs, repeated);
+        assert!(network.forward_batch(&[]).unwrap().is_empty());
+        
+        let mut invalid = batch.clone();
+        invalid[3] = vec![0.0; 3];
+        assert!(matches!(network.forward_batch(&invalid), Err(NeuralError::InvalidInput(_))));
+        
+        let targets: Vec<Vec<f32>> = (0..5).map(|i| vec![(i % 2) as f32, 1.0]).collect();
+        let mut expected = 0.0;
+        for (inputs, targets) in batch.iter().zip(&targets) {
+            expected += network.train(inputs, targets).unwrap();
+        }
+        let loss = network.train_batch(&batch, &targets).unwrap();
+        assert!((loss - expected / 5.0).abs() < 1e-6);
+        assert!(matches!(network.train_batch(&batch, &targets[..4]), Err(NeuralError::TrainingError(_))));
+    }
+
+    #[test]
+    fn test_train_batch_takes_a_gradient_step() {
+        let mut network = NeuralNetwork::new("linear", 0.1).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 1, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 1, ActivationFunction::Linear)).unwrap();
+        network.layers[1].weights = Some(vec![vec![0.5]]);
+        
+        // Outputs 0.5 and 1.0 against targets 2.0 and 4.0
+        let batch = vec![vec![1.0], vec![2.0]];
+        let targets = vec![vec![2.0], vec![4.0]];
+        let loss = network.train_batch(&batch, &targets).unwrap();
+        assert!((loss - 2.8125).abs() < 1e-6);
+        
+        // Mean gradients are -3.75 for the weight and -2.25 for the bias
+        let weight = network.layers[1].weights.as_ref().unwrap()[0][0];
+        let bias = network.layers[1].biases.as_ref().unwrap()[0];
+        assert!((weight - 0.875).abs() < 1e-6);
+        assert!((bias - 0.225).abs() < 1e-6);
+        
+        for _ in 0..500 {
+            network.train_batch(&batch, &targets).unwrap();
+        }
+        assert!(network.train_batch(&batch, &targets).unwrap() < 1e-3);
+    }
+
+    #[test]
+    fn test_precision_rounds_weights_in_forward_pass() {
+        use crate::system::ModelPrecision;
+        
+        let weights = [0.1f32, -0.73, 1.0 / 3.0, 2.5];
+        assert_eq!(quantize(&weights, ModelPrecision::FP32), weights.to_vec());
+        let fp16 = quantize(&weights, ModelPrecision::FP16);
+        let int8 = quantize(&weights, ModelPrecision::INT8);
+        for (i, &w) in weights.iter().enumerate() {
+            assert!((fp16[i] - w).abs() <= w.abs() / 1024.0);
+            assert!((int8[i] - w).abs() <= 2.5 / 254.0 + f32::EPSILON);
+        }
+        assert_ne!(fp16[2], weights[2]);
+        assert_eq!(quantize(&[1.0e6], ModelPrecision::FP16), vec![65504.0]);
+        
+        let mut network = NeuralNetwork::new("linear", 0.1).unwrap();
+        network.add_layer(Layer::new("input", LayerType::Input, 4, ActivationFunction::Linear)).unwrap();
+        network.add_layer(Layer::new("output", LayerType::Output, 1, ActivationFunction::Linear)).unwrap();
+        network.layers[1].weights = Some(vec![weights.to_vec()]);
+        
+        let inputs = [0.3, -1.2, 0.7, 2.0];
+        let full = network.forward(&inputs).unwrap();
+        network.set_precision(ModelPrecision::INT8);
+        let reduced = network.forward(&inputs).unwrap();
+        let expected: f32 = int8.iter().zip(&inputs).map(|(w, x)| w * x).sum();
+        assert_ne!(full, reduced);
+        assert!((reduced[0] - expected).abs() < 1e-5);
+        network.set_precision(ModelPrecision::FP32);
+        assert_eq!(network.forward(&inputs).unwrap(), full);
+    }
 }
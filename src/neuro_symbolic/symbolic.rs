@@ -1,6 +1,17 @@
 use std::fmt;
 use std::error::Error;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::system::ReasoningSample;
+
+/// Default maximum number of nested clause applications when proving a goal
+pub const DEFAULT_MAX_PROOF_DEPTH: usize = 16;
+
+/// Default maximum number of cached query results
+pub const DEFAULT_INFERENCE_CACHE_CAPACITY: usize = 1024;
 
 /// Symbolic error
 #[derive(Debug)]
@@ -9,6 +20,8 @@ pub enum SymbolicError {
     InitializationError(String),
     /// Processing error
     ProcessingError(String),
+    /// Depth exceeded error
+    DepthExceeded(String),
     /// Other error
     Other(String),
 }
@@ -20,6 +33,7 @@ impl fmt::Display for SymbolicError {
         match self {
             SymbolicError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             SymbolicError::ProcessingError(msg) => write!(f, "Processing error: {}", msg),
+            SymbolicError::DepthExceeded(msg) => write!(f, "Depth exceeded: {}", msg),
             SymbolicError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -233,6 +247,216 @@ impl Rule {
     }
 }
 
+/// Logic term
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    /// Variable, bound during unification
+    Variable(String),
+    /// Constant, matched against symbol names
+    Constant(String),
+    /// Compound term, e.g. `parent(?X, bob)`
+    Compound(String, Vec<Term>),
+}
+
+impl Term {
+    /// Create a compound term
+    pub fn compound(functor: &str, arguments: Vec<Term>) -> Self {
+        Term::Compound(functor.to_string(), arguments)
+    }
+    
+    /// Collect variable names in order of first appearance
+    fn variables(&self, out: &mut Vec<String>) {
+        match self {
+            Term::Variable(name) if !out.contains(name) => out.push(name.clone()),
+            Term::Compound(_, arguments) => arguments.iter().for_each(|a| a.variables(out)),
+            _ => {},
+        }
+    }
+    
+    /// Rename variables apart by appending a suffix
+    fn rename(&self, suffix: usize) -> Term {
+        match self {
+            Term::Variable(name) => Term::Variable(format!("{}#{}", name, suffix)),
+            Term::Constant(_) => self.clone(),
+            Term::Compound(functor, arguments) => {
+                Term::Compound(functor.clone(), arguments.iter().map(|a| a.rename(suffix)).collect())
+            },
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Variable(name) => write!(f, "?{}", name),
+            Term::Constant(name) => write!(f, "{}", name),
+            Term::Compound(functor, arguments) => {
+                let arguments: Vec<String> = arguments.iter().map(|a| a.to_string()).collect();
+                write!(f, "{}({})", functor, arguments.join(", "))
+            },
+        }
+    }
+}
+
+/// Horn clause used for backward chaining, `head :- body`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clause {
+    /// Clause name
+    pub name: String,
+    /// Clause head
+    pub head: Term,
+    /// Clause body goals, proved left to right
+    pub body: Vec<Term>,
+}
+
+impl Clause {
+    /// Create a new clause
+    pub fn new(name: &str, head: Term, body: Vec<Term>) -> Self {
+        Self {
+            name: name.to_string(),
+            head,
+            body,
+        }
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body: Vec<String> = self.body.iter().map(|t| t.to_string()).collect();
+        write!(f, "{} :- {}", self.head, body.join(", "))
+    }
+}
+
+/// Backward chaining query result
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Whether the goal was proved
+    pub success: bool,
+    /// Goal variable bindings, one map per solution
+    pub bindings: Vec<HashMap<String, Term>>,
+    /// Names of the clauses applied for each solution, in application order
+    pub rule_chains: Vec<Vec<String>>,
+    /// Whether a branch was abandoned at the proof depth limit, so solutions may be missing
+    pub depth_exceeded: bool,
+}
+
+impl QueryResult {
+    /// Create a successful result without rule chains
+    pub fn success(bindings: Vec<HashMap<String, Term>>) -> Self {
+        let rule_chains = vec![Vec::new(); bindings.len()];
+        Self {
+            success: true,
+            bindings,
+            rule_chains,
+            depth_exceeded: false,
+        }
+    }
+    
+    /// Create a failed result
+    pub fn failure() -> Self {
+        Self {
+            success: false,
+            bindings: Vec::new(),
+            rule_chains: Vec::new(),
+            depth_exceeded: false,
+        }
+    }
+}
+
+/// Variable bindings produced by unification
+pub type Substitution = HashMap<String, Term>;
+
+/// Follow variable bindings until an unbound variable or a non-variable term
+fn walk<'a>(term: &'a Term, substitution: &'a Substitution) -> &'a Term {
+    let mut term = term;
+    while let Term::Variable(name) = term {
+        match substitution.get(name) {
+            Some(bound) => term = bound,
+            None => break,
+        }
+    }
+    term
+}
+
+/// Apply bindings to every variable in a term
+fn resolve(term: &Term, substitution: &Substitution) -> Term {
+    match walk(term, substitution) {
+        Term::Compound(functor, arguments) => {
+            Term::Compound(functor.clone(), arguments.iter().map(|a| resolve(a, substitution)).collect())
+        },
+        term => term.clone(),
+    }
+}
+
+/// Check if a variable occurs in a term under the substitution
+fn occurs(variable: &str, term: &Term, substitution: &Substitution) -> bool {
+    match walk(term, substitution) {
+        Term::Variable(name) => name == variable,
+        Term::Constant(_) => false,
+        Term::Compound(_, arguments) => arguments.iter().any(|a| occurs(variable, a, substitution)),
+    }
+}
+
+/// Unify two terms, extending the substitution
+///
+/// Binding a variable to a term that contains it is rejected (occurs check),
+/// so the substitution never describes a cyclic term. On failure the
+/// substitution may hold bindings made before the mismatch; unify a clone to
+/// keep the original.
+pub fn unify(a: &Term, b: &Term, substitution: &mut Substitution) -> bool {
+    let (a, b) = (walk(a, substitution).clone(), walk(b, substitution).clone());
+    match (&a, &b) {
+        (Term::Variable(x), Term::Variable(y)) if x == y => true,
+        (Term::Variable(x), _) => {
+            if occurs(x, &b, substitution) {
+                return false;
+            }
+            substitution.insert(x.clone(), b);
+            true
+        },
+        (_, Term::Variable(y)) => {
+            if occurs(y, &a, substitution) {
+                return false;
+            }
+            substitution.insert(y.clone(), a);
+            true
+        },
+        (Term::Constant(x), Term::Constant(y)) => x == y,
+        (Term::Compound(f, xs), Term::Compound(g, ys)) => {
+            f == g && xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| unify(x, y, substitution))
+        },
+        _ => false,
+    }
+}
+
+/// Rule firing recorded during inference
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleFiring {
+    /// Firing step, starting at 0
+    pub step: usize,
+    /// Rule ID
+    pub rule_id: String,
+    /// Rule name
+    pub rule_name: String,
+    /// Names of the facts matched by the rule conditions
+    pub matched_facts: Vec<String>,
+    /// Name of the derived fact
+    pub derived_fact: String,
+}
+
+impl fmt::Display for RuleFiring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{} {}: [{}] => {}",
+            self.step,
+            self.rule_name,
+            self.matched_facts.join(", "),
+            self.derived_fact
+        )
+    }
+}
+
 /// Symbolic system
 pub struct SymbolicSystem {
     /// Symbols
@@ -241,6 +465,22 @@ pub struct SymbolicSystem {
     pub relations: HashMap<String, Relation>,
     /// Rules
     pub rules: HashMap<String, Rule>,
+    /// Whether rule firings are traced
+    pub tracing: bool,
+    /// Rule firing trace
+    pub trace: Vec<RuleFiring>,
+    /// Cached query results keyed by sorted input fact IDs, cleared when symbols, relations, rules or clauses change
+    pub inference_cache: HashMap<Vec<String>, HashSet<Symbol>>,
+    /// Cache keys in insertion order, oldest first
+    inference_cache_order: VecDeque<Vec<String>>,
+    /// Maximum number of cached query results, 0 disables the cache
+    pub inference_cache_capacity: usize,
+    /// Relation IDs by predicate name, in insertion order
+    predicate_index: HashMap<String, Vec<String>>,
+    /// Clauses for backward chaining, tried in insertion order
+    pub clauses: Vec<Clause>,
+    /// Maximum number of nested clause applications when proving a goal
+    pub max_proof_depth: usize,
 }
 
 impl SymbolicSystem {
@@ -250,12 +490,21 @@ impl SymbolicSystem {
             symbols: HashMap::new(),
             relations: HashMap::new(),
             rules: HashMap::new(),
+            tracing: false,
+            trace: Vec::new(),
+            inference_cache: HashMap::new(),
+            inference_cache_order: VecDeque::new(),
+            inference_cache_capacity: DEFAULT_INFERENCE_CACHE_CAPACITY,
+            predicate_index: HashMap::new(),
+            clauses: Vec::new(),
+            max_proof_depth: DEFAULT_MAX_PROOF_DEPTH,
         })
     }
     
     /// Add symbol
     pub fn add_symbol(&mut self, symbol: Symbol) -> Result<(), SymbolicError> {
         self.symbols.insert(symbol.id.clone(), symbol);
+        self.clear_inference_cache();
         Ok(())
     }
     
@@ -269,12 +518,40 @@ impl SymbolicSystem {
         self.symbols.values().find(|s| s.name == name)
     }
     
-    /// Add relation
+    /// Add relation, replacing any relation with the same ID
     pub fn add_relation(&mut self, relation: Relation) -> Result<(), SymbolicError> {
+        self.unindex_relation(&relation.id);
+        self.predicate_index
+            .entry(relation.relation_type.to_string())
+            .or_default()
+            .push(relation.id.clone());
         self.relations.insert(relation.id.clone(), relation);
+        self.clear_inference_cache();
         Ok(())
     }
     
+    /// Remove relation
+    pub fn remove_relation(&mut self, id: &str) -> Option<Relation> {
+        self.unindex_relation(id);
+        let relation = self.relations.remove(id)?;
+        self.clear_inference_cache();
+        Some(relation)
+    }
+    
+    /// Remove a relation from the predicate index
+    fn unindex_relation(&mut self, id: &str) {
+        let Some(predicate) = self.relations.get(id).map(|r| r.relation_type.to_string()) else {
+            return;
+        };
+        
+        if let Some(ids) = self.predicate_index.get_mut(&predicate) {
+            ids.retain(|i| i != id);
+            if ids.is_empty() {
+                self.predicate_index.remove(&predicate);
+            }
+        }
+    }
+    
     /// Get relation
     pub fn get_relation(&self, id: &str) -> Option<&Relation> {
         self.relations.get(id)
@@ -283,6 +560,7 @@ impl SymbolicSystem {
     /// Add rule
     pub fn add_rule(&mut self, rule: Rule) -> Result<(), SymbolicError> {
         self.rules.insert(rule.id.clone(), rule);
+        self.clear_inference_cache();
         Ok(())
     }
     
@@ -296,6 +574,156 @@ impl SymbolicSystem {
         self.rules.values().find(|r| r.name == name)
     }
     
+    /// Add clause for backward chaining
+    pub fn add_clause(&mut self, clause: Clause) -> Result<(), SymbolicError> {
+        self.clauses.push(clause);
+        self.clear_inference_cache();
+        Ok(())
+    }
+    
+    /// Clear cached query results
+    pub fn clear_inference_cache(&mut self) {
+        self.inference_cache.clear();
+        self.inference_cache_order.clear();
+    }
+    
+    /// Set maximum number of cached query results, evicting the oldest beyond it
+    pub fn set_inference_cache_capacity(&mut self, capacity: usize) {
+        self.inference_cache_capacity = capacity;
+        self.trim_inference_cache(capacity);
+    }
+    
+    /// Evict the oldest cached query results until at most `capacity` remain
+    fn trim_inference_cache(&mut self, capacity: usize) {
+        while self.inference_cache_order.len() > capacity {
+            if let Some(key) = self.inference_cache_order.pop_front() {
+                self.inference_cache.remove(&key);
+            }
+        }
+    }
+    
+    /// Set maximum number of nested clause applications when proving a goal
+    pub fn set_max_proof_depth(&mut self, depth: usize) {
+        self.max_proof_depth = depth;
+    }
+    
+    /// Prove a goal by backward chaining
+    ///
+    /// Goals are matched against relations, as `relation_type(source, target)`
+    /// with symbol names as constants, and recursively against clause heads.
+    /// Branches nesting more than `max_proof_depth` clause applications are
+    /// abandoned, so recursive clauses always terminate, and the result's
+    /// `depth_exceeded` flag is set. Each distinct binding of the goal's
+    /// variables is reported once, with the first rule chain that proved it.
+    pub fn prove(&self, goal: &Term) -> QueryResult {
+        let mut solutions = Vec::new();
+        let mut renames = 0;
+        let mut depth_exceeded = false;
+        self.solve(vec![(goal.clone(), 0)], Substitution::new(), Vec::new(), &mut renames, &mut depth_exceeded, &mut solutions);
+        
+        let mut variables = Vec::new();
+        goal.variables(&mut variables);
+        
+        let mut result = QueryResult::failure();
+        for (substitution, chain) in solutions {
+            let bindings: HashMap<String, Term> = variables.iter()
+                .map(|v| (v.clone(), resolve(&Term::Variable(v.clone()), &substitution)))
+                .collect();
+            if !result.bindings.contains(&bindings) {
+                result.bindings.push(bindings);
+                result.rule_chains.push(chain);
+            }
+        }
+        result.success = !result.bindings.is_empty();
+        result.depth_exceeded = depth_exceeded;
+        result
+    }
+    
+    /// Prove a goal by backward chaining, failing when the proof is cut short
+    ///
+    /// Fails with `DepthExceeded` when any branch nests more than
+    /// `max_proof_depth` clause applications, since solutions beyond the
+    /// limit may be missing from the result.
+    pub fn try_prove(&self, goal: &Term) -> Result<QueryResult, SymbolicError> {
+        let result = self.prove(goal);
+        if result.depth_exceeded {
+            return Err(SymbolicError::DepthExceeded(format!(
+                "Proof of {} needs more nested clause applications, the limit is {}",
+                goal, self.max_proof_depth
+            )));
+        }
+        Ok(result)
+    }
+    
+    /// Prove goals left to right, collecting each substitution and rule chain that proves them all
+    ///
+    /// Each goal carries the number of clause applications it is nested
+    /// under, so a clause body's goals sit one level below the goal they
+    /// replace rather than below each other.
+    fn solve(
+        &self,
+        mut goals: Vec<(Term, usize)>,
+        substitution: Substitution,
+        chain: Vec<String>,
+        renames: &mut usize,
+        depth_exceeded: &mut bool,
+        solutions: &mut Vec<(Substitution, Vec<String>)>,
+    ) {
+        if goals.is_empty() {
+            solutions.push((substitution, chain));
+            return;
+        }
+        
+        let (goal, depth) = goals.remove(0);
+        let goal = resolve(&goal, &substitution);
+        let Term::Compound(functor, arguments) = &goal else {
+            return;
+        };
+        
+        // Relations are binary facts
+        if arguments.len() == 2 {
+            for relation in self.facts_with_predicate(functor) {
+                let fact = Term::compound(functor, vec![
+                    Term::Constant(relation.source.name.clone()),
+                    Term::Constant(relation.target.name.clone()),
+                ]);
+                let mut extended = substitution.clone();
+                if unify(&goal, &fact, &mut extended) {
+                    self.solve(goals.clone(), extended, chain.clone(), renames, depth_exceeded, solutions);
+                }
+            }
+        }
+        
+        if depth >= self.max_proof_depth {
+            // Only a goal some clause could still expand is cut short
+            let expandable = self.clauses.iter().any(|clause| matches!(
+                &clause.head,
+                Term::Compound(name, parameters) if name == functor && parameters.len() == arguments.len()
+            ));
+            if expandable {
+                *depth_exceeded = true;
+            }
+            return;
+        }
+        
+        for clause in &self.clauses {
+            *renames += 1;
+            let head = clause.head.rename(*renames);
+            let mut extended = substitution.clone();
+            if !unify(&goal, &head, &mut extended) {
+                continue;
+            }
+            
+            let mut subgoals: Vec<(Term, usize)> = clause.body.iter()
+                .map(|t| (t.rename(*renames), depth + 1))
+                .collect();
+            subgoals.extend(goals.iter().cloned());
+            let mut extended_chain = chain.clone();
+            extended_chain.push(clause.name.clone());
+            self.solve(subgoals, extended, extended_chain, renames, depth_exceeded, solutions);
+        }
+    }
+    
     /// Get symbols by type
     pub fn get_symbols_by_type(&self, symbol_type: &SymbolType) -> Vec<&Symbol> {
         self.symbols.values()
@@ -305,11 +733,20 @@ impl SymbolicSystem {
     
     /// Get relations by type
     pub fn get_relations_by_type(&self, relation_type: &RelationType) -> Vec<&Relation> {
-        self.relations.values()
+        // A custom type may share its name with a built-in one
+        self.facts_with_predicate(&relation_type.to_string())
+            .into_iter()
             .filter(|r| r.relation_type == *relation_type)
             .collect()
     }
     
+    /// Get relations whose predicate, i.e. relation type, has the given name, in insertion order
+    pub fn facts_with_predicate(&self, name: &str) -> Vec<&Relation> {
+        self.predicate_index.get(name)
+            .map(|ids| ids.iter().filter_map(|id| self.relations.get(id)).collect())
+            .unwrap_or_default()
+    }
+    
     /// Get relations by source
     pub fn get_relations_by_source(&self, source_id: &str) -> Vec<&Relation> {
         self.relations.values()
@@ -346,6 +783,113 @@ impl SymbolicSystem {
         
         Ok(new_facts)
     }
+    
+    /// Enable or disable rule firing tracing
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+    
+    /// Get rule firing trace
+    pub fn get_trace(&self) -> &[RuleFiring] {
+        &self.trace
+    }
+    
+    /// Clear rule firing trace
+    pub fn clear_trace(&mut self) {
+        self.trace.clear();
+    }
+    
+    /// Export rule firing trace as JSON
+    pub fn export_trace(&self) -> Result<String, SymbolicError> {
+        serde_json::to_string_pretty(&self.trace).map_err(|e| {
+            SymbolicError::ProcessingError(format!("Failed to export trace: {}", e))
+        })
+    }
+    
+    /// Forward chain until no rule derives a new fact, returns all known facts
+    pub fn forward_chain(&mut self, facts: &HashSet<Symbol>) -> Result<HashSet<Symbol>, SymbolicError> {
+        let (known, firings) = self.chain(facts);
+        
+        if self.tracing {
+            self.trace.extend(firings);
+        }
+        
+        Ok(known)
+    }
+    
+    /// Forward chain through the inference cache, returns all known facts and query measurements
+    pub fn query(&mut self, facts: &HashSet<Symbol>) -> Result<(HashSet<Symbol>, ReasoningSample), SymbolicError> {
+        let started = Instant::now();
+        
+        let mut key: Vec<String> = facts.iter().map(|f| f.id.clone()).collect();
+        key.sort();
+        
+        if let Some(known) = self.inference_cache.get(&key) {
+            let sample = ReasoningSample {
+                latency: started.elapsed(),
+                cache_hit: true,
+                facts_derived: known.len() - facts.len(),
+                rule_firings: 0,
+            };
+            return Ok((known.clone(), sample));
+        }
+        
+        let (known, firings) = self.chain(facts);
+        let sample = ReasoningSample {
+            latency: started.elapsed(),
+            cache_hit: false,
+            facts_derived: known.len() - facts.len(),
+            rule_firings: firings.len(),
+        };
+        
+        if self.tracing {
+            self.trace.extend(firings);
+        }
+        if self.inference_cache_capacity > 0 {
+            self.trim_inference_cache(self.inference_cache_capacity - 1);
+            self.inference_cache_order.push_back(key.clone());
+            self.inference_cache.insert(key, known.clone());
+        }
+        
+        Ok((known, sample))
+    }
+    
+    /// Run forward chaining, returns all known facts and the rule firings
+    fn chain(&self, facts: &HashSet<Symbol>) -> (HashSet<Symbol>, Vec<RuleFiring>) {
+        let mut known = facts.clone();
+        
+        // Sort rules by priority, then name, so firing order is deterministic
+        let mut sorted_rules: Vec<&Rule> = self.rules.values().collect();
+        sorted_rules.sort_by(|a, b| b.priority.cmp(&a.priority).then_with(|| a.name.cmp(&b.name)));
+        
+        let mut firings = Vec::new();
+        let mut changed = true;
+        
+        while changed {
+            changed = false;
+            
+            for rule in &sorted_rules {
+                if !rule.conditions.iter().all(|c| known.contains(c)) {
+                    continue;
+                }
+                
+                for action in &rule.actions {
+                    if known.insert(action.clone()) {
+                        changed = true;
+                        firings.push(RuleFiring {
+                            step: self.trace.len() + firings.len(),
+                            rule_id: rule.id.clone(),
+                            rule_name: rule.name.clone(),
+                            matched_facts: rule.conditions.iter().map(|c| c.name.clone()).collect(),
+                            derived_fact: action.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        
+        (known, firings)
+    }
 }
 
 /// Initialize symbolic module
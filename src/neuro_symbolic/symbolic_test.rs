@@ -4,6 +4,9 @@ mod tests {
     use crate::neuro_symbolic::symbolic::{
         Fact, KnowledgeBase, QueryResult, Rule, SimpleKnowledgeBase, Term, SymbolicError
     };
+    use crate::neuro_symbolic::symbolic::{unify, Clause, Relation, RelationType, Substitution};
+    use crate::neuro_symbolic::{Symbol, SymbolType, SymbolicSystem};
+    use std::collections::HashSet;
 
     #[test]
     fn test_term_display() {
@@ -176,4 +179,378 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_forward_chain_traces_rule_firings() {
+        let bird = Symbol::new("bird", SymbolType::Concept);
+        let has_wings = Symbol::new("has_wings", SymbolType::Property);
+        let can_fly = Symbol::new("can_fly", SymbolType::Property);
+        let can_migrate = Symbol::new("can_migrate", SymbolType::Action);
+        
+        let mut wings = Rule::new("bird_has_wings", 10);
+        wings.add_condition(bird.clone());
+        wings.add_action(has_wings.clone());
+        
+        let mut flight = Rule::new("wings_allow_flight", 5);
+        flight.add_condition(bird.clone());
+        flight.add_condition(has_wings.clone());
+        flight.add_action(can_fly.clone());
+        
+        let mut migration = Rule::new("flight_allows_migration", 1);
+        migration.add_condition(can_fly.clone());
+        migration.add_action(can_migrate.clone());
+        
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_rule(migration).unwrap();
+        system.add_rule(flight).unwrap();
+        system.add_rule(wings).unwrap();
+        system.set_tracing(true);
+        
+        let mut facts = HashSet::new();
+        facts.insert(bird.clone());
+        let derived = system.forward_chain(&facts).unwrap();
+        
+        assert!(derived.contains(&can_migrate));
+        
+        let trace: Vec<(&str, Vec<&str>, &str)> = system.get_trace().iter()
+            .map(|f| {
+                let matched = f.matched_facts.iter().map(|m| m.as_str()).collect();
+                (f.rule_name.as_str(), matched, f.derived_fact.as_str())
+            })
+            .collect();
+        assert_eq!(trace, vec![
+            ("bird_has_wings", vec!["bird"], "has_wings"),
+            ("wings_allow_flight", vec!["bird", "has_wings"], "can_fly"),
+            ("flight_allows_migration", vec!["can_fly"], "can_migrate"),
+        ]);
+        assert_eq!(system.get_trace()[2].step, 2);
+        
+        let exported = system.export_trace().unwrap();
+        assert!(exported.contains("\"rule_name\": \"wings_allow_flight\""));
+    }
+    
+    #[test]
+    fn test_forward_chain_without_tracing_records_nothing() {
+        let a = Symbol::new("a", SymbolType::Concept);
+        let b = Symbol::new("b", SymbolType::Concept);
+        
+        let mut rule = Rule::new("a_implies_b", 1);
+        rule.add_condition(a.clone());
+        rule.add_action(b.clone());
+        
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_rule(rule).unwrap();
+        
+        let mut facts = HashSet::new();
+        facts.insert(a);
+        
+        assert!(system.forward_chain(&facts).unwrap().contains(&b));
+        assert!(system.get_trace().is_empty());
+    }
+
+    #[test]
+    fn test_query_records_reasoning_metrics() {
+        use crate::system::{
+            MetricType, MonitoringSystem, METRIC_REASONING_CACHE_HIT_RATE, METRIC_REASONING_FACTS_DERIVED,
+            METRIC_REASONING_LATENCY, METRIC_REASONING_RULE_FIRINGS,
+        };
+
+        let bird = Symbol::new("bird", SymbolType::Concept);
+        let fish = Symbol::new("fish", SymbolType::Concept);
+        let has_wings = Symbol::new("has_wings", SymbolType::Property);
+        let can_fly = Symbol::new("can_fly", SymbolType::Property);
+
+        let mut wings = Rule::new("bird_has_wings", 10);
+        wings.add_condition(bird.clone());
+        wings.add_action(has_wings.clone());
+
+        let mut flight = Rule::new("wings_allow_flight", 5);
+        flight.add_condition(has_wings.clone());
+        flight.add_action(can_fly.clone());
+
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_rule(wings).unwrap();
+        system.add_rule(flight).unwrap();
+
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        monitoring.register_reasoning_metrics().unwrap();
+
+        let birds: HashSet<Symbol> = [bird.clone()].into_iter().collect();
+        let fishes: HashSet<Symbol> = [fish.clone()].into_iter().collect();
+        for facts in &[&birds, &birds, &fishes, &birds] {
+            let (known, sample) = system.query(facts).unwrap();
+            assert_eq!(known.contains(&can_fly), facts.contains(&bird));
+            monitoring.record_reasoning(&sample).unwrap();
+        }
+
+        let latency = monitoring.metric_stats(METRIC_REASONING_LATENCY, 10).unwrap();
+        assert_eq!(latency.count, 4);
+        assert!(latency.min >= 0.0 && latency.max < 1000.0);
+
+        let hit_rate = monitoring.get_latest_metric(METRIC_REASONING_CACHE_HIT_RATE).unwrap();
+        assert_eq!(hit_rate.value, 0.5);
+        assert_eq!(hit_rate.metric_type, MetricType::Reasoning);
+
+        let derived = monitoring.metric_stats(METRIC_REASONING_FACTS_DERIVED, 10).unwrap();
+        assert_eq!((derived.min, derived.max, derived.last), (0.0, 2.0, 2.0));
+
+        let firings: Vec<f64> = monitoring.get_metric_history(METRIC_REASONING_RULE_FIRINGS).unwrap()
+            .iter()
+            .map(|m| m.value)
+            .collect();
+        assert_eq!(firings, vec![2.0, 0.0, 0.0, 0.0]);
+
+        let exported = monitoring.export_prometheus();
+        assert!(exported.contains("# TYPE reasoning_cache_hit_rate gauge\nreasoning_cache_hit_rate 0.5\n"));
+        assert!(exported.contains("reasoning_rule_firings 0\n"));
+
+        // Adding a rule invalidates cached results
+        system.add_rule(Rule::new("unused", 1)).unwrap();
+        assert!(!system.query(&birds).unwrap().1.cache_hit);
+    }
+
+    #[test]
+    fn test_inference_cache_is_bounded_and_cleared_on_mutation() {
+        let facts: Vec<HashSet<Symbol>> = (0..3)
+            .map(|i| [Symbol::new(&format!("fact_{}", i), SymbolType::Concept)].into_iter().collect())
+            .collect();
+
+        let mut system = SymbolicSystem::new().unwrap();
+        system.set_inference_cache_capacity(2);
+        for f in &facts {
+            system.query(f).unwrap();
+        }
+        // The oldest result was evicted
+        assert_eq!(system.inference_cache.len(), 2);
+        assert!(!system.query(&facts[0]).unwrap().1.cache_hit);
+        assert!(system.query(&facts[2]).unwrap().1.cache_hit);
+
+        let relation = Relation::new(
+            Symbol::new("a", SymbolType::Entity),
+            Symbol::new("b", SymbolType::Entity),
+            RelationType::Custom("parent".to_string()),
+            1.0,
+        );
+        let relation_id = relation.id.clone();
+        system.add_relation(relation).unwrap();
+        assert!(system.inference_cache.is_empty());
+
+        system.query(&facts[0]).unwrap();
+        system.remove_relation(&relation_id).unwrap();
+        assert!(!system.query(&facts[0]).unwrap().1.cache_hit);
+
+        system.set_inference_cache_capacity(0);
+        assert!(system.inference_cache.is_empty());
+        system.query(&facts[1]).unwrap();
+        assert!(!system.query(&facts[1]).unwrap().1.cache_hit);
+    }
+
+    #[test]
+    fn test_predicate_index_tracks_insertion_and_retraction() {
+        let tweety = Symbol::new("tweety", SymbolType::Entity);
+        let bird = Symbol::new("bird", SymbolType::Concept);
+        let wings = Symbol::new("wings", SymbolType::Property);
+        let seeds = Symbol::new("seeds", SymbolType::Entity);
+
+        let is_bird = Relation::new(tweety.clone(), bird.clone(), RelationType::IsA, 1.0);
+        let has_wings = Relation::new(bird.clone(), wings.clone(), RelationType::HasA, 1.0);
+        let tweety_wings = Relation::new(tweety.clone(), wings.clone(), RelationType::HasA, 1.0);
+        let likes = Relation::new(tweety.clone(), seeds.clone(), RelationType::Custom("likes".to_string()), 0.8);
+        // A custom predicate named like a built-in one shares its index entry
+        let custom_is_a = Relation::new(seeds.clone(), bird.clone(), RelationType::Custom("IsA".to_string()), 0.1);
+
+        let mut system = SymbolicSystem::new().unwrap();
+        for relation in [&is_bird, &has_wings, &tweety_wings, &likes, &custom_is_a] {
+            system.add_relation(relation.clone()).unwrap();
+        }
+
+        let ids = |relations: Vec<&Relation>| relations.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(system.facts_with_predicate("HasA")), vec![has_wings.id.clone(), tweety_wings.id.clone()]);
+        assert_eq!(ids(system.facts_with_predicate("likes")), vec![likes.id.clone()]);
+        assert_eq!(ids(system.facts_with_predicate("IsA")), vec![is_bird.id.clone(), custom_is_a.id.clone()]);
+        assert_eq!(ids(system.get_relations_by_type(&RelationType::IsA)), vec![is_bird.id.clone()]);
+        assert!(system.facts_with_predicate("Causes").is_empty());
+
+        // Retraction and replacement keep the index consistent
+        assert!(system.remove_relation(&has_wings.id).is_some());
+        assert!(system.remove_relation(&has_wings.id).is_none());
+        let mut retyped = likes.clone();
+        retyped.relation_type = RelationType::Custom("eats".to_string());
+        system.add_relation(retyped).unwrap();
+
+        assert_eq!(ids(system.facts_with_predicate("HasA")), vec![tweety_wings.id.clone()]);
+        assert!(system.facts_with_predicate("likes").is_empty());
+        assert_eq!(ids(system.facts_with_predicate("eats")), vec![likes.id.clone()]);
+    }
+
+    #[test]
+    fn test_prove_grandparent_by_backward_chaining() {
+        let parent = RelationType::Custom("parent".to_string());
+        let person = |name: &str| Symbol::new(name, SymbolType::Entity);
+        let var = |name: &str| Term::Variable(name.to_string());
+        let constant = |name: &str| Term::Constant(name.to_string());
+
+        let mut system = SymbolicSystem::new().unwrap();
+        for (source, target) in [("alice", "bob"), ("bob", "carol"), ("bob", "dave"), ("erin", "frank")] {
+            system.add_relation(Relation::new(person(source), person(target), parent.clone(), 1.0)).unwrap();
+        }
+        system.add_clause(Clause::new(
+            "grandparent",
+            Term::compound("grandparent", vec![var("X"), var("Z")]),
+            vec![
+                Term::compound("parent", vec![var("X"), var("Y")]),
+                Term::compound("parent", vec![var("Y"), var("Z")]),
+            ],
+        )).unwrap();
+
+        let result = system.prove(&Term::compound("grandparent", vec![constant("alice"), var("Who")]));
+        assert!(result.success);
+        let who: Vec<&Term> = result.bindings.iter().map(|b| &b["Who"]).collect();
+        assert_eq!(who, vec![&constant("carol"), &constant("dave")]);
+        assert_eq!(result.rule_chains, vec![vec!["grandparent".to_string()]; 2]);
+
+        // Ground goals succeed with no bindings
+        let result = system.prove(&Term::compound("grandparent", vec![constant("alice"), constant("carol")]));
+        assert!(result.success);
+        assert_eq!(result.bindings, vec![HashMap::new()]);
+
+        assert!(!system.prove(&Term::compound("grandparent", vec![constant("erin"), var("Who")])).success);
+    }
+
+    #[test]
+    fn test_prove_depth_limit_stops_left_recursion() {
+        let var = |name: &str| Term::Variable(name.to_string());
+        let constant = |name: &str| Term::Constant(name.to_string());
+
+        let mut system = SymbolicSystem::new().unwrap();
+        for (source, target) in [("a", "b"), ("b", "c"), ("c", "d")] {
+            system.add_relation(Relation::new(
+                Symbol::new(source, SymbolType::Entity),
+                Symbol::new(target, SymbolType::Entity),
+                RelationType::Custom("parent".to_string()),
+                1.0,
+            )).unwrap();
+        }
+        // Left-recursive clause would never terminate without a depth limit
+        system.add_clause(Clause::new(
+            "ancestor_step",
+            Term::compound("ancestor", vec![var("X"), var("Z")]),
+            vec![
+                Term::compound("ancestor", vec![var("X"), var("Y")]),
+                Term::compound("parent", vec![var("Y"), var("Z")]),
+            ],
+        )).unwrap();
+        system.add_clause(Clause::new(
+            "ancestor_base",
+            Term::compound("ancestor", vec![var("X"), var("Y")]),
+            vec![Term::compound("parent", vec![var("X"), var("Y")])],
+        )).unwrap();
+        system.set_max_proof_depth(4);
+
+        let result = system.prove(&Term::compound("ancestor", vec![constant("a"), var("D")]));
+        let mut descendants: Vec<String> = result.bindings.iter().map(|b| b["D"].to_string()).collect();
+        descendants.sort();
+        assert_eq!(descendants, vec!["b", "c", "d"]);
+
+        let index = result.bindings.iter().position(|b| b["D"] == constant("d")).unwrap();
+        assert_eq!(result.rule_chains[index], vec!["ancestor_step", "ancestor_step", "ancestor_base"]);
+        assert!(result.depth_exceeded);
+    }
+
+    #[test]
+    fn test_prove_depth_counts_nesting_not_sibling_goals() {
+        let var = |name: &str| Term::Variable(name.to_string());
+        let constant = |name: &str| Term::Constant(name.to_string());
+
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_clause(Clause::new("ready", Term::compound("ready", vec![var("X")]), Vec::new())).unwrap();
+        // Twenty sibling goals, each proved by a clause, nest only two levels deep
+        let body = (0..20).map(|_| Term::compound("ready", vec![var("X")])).collect();
+        system.add_clause(Clause::new("all_ready", Term::compound("all_ready", vec![var("X")]), body)).unwrap();
+        assert!(system.max_proof_depth < 20);
+
+        let result = system.prove(&Term::compound("all_ready", vec![constant("a")]));
+        assert!(result.success);
+        assert!(!result.depth_exceeded);
+        assert_eq!(result.rule_chains[0].len(), 21);
+
+        // A chain nested past the limit is reported rather than silently failing
+        system.add_clause(Clause::new(
+            "forever",
+            Term::compound("forever", vec![var("X")]),
+            vec![Term::compound("forever", vec![var("X")])],
+        )).unwrap();
+        system.set_max_proof_depth(3);
+        let result = system.prove(&Term::compound("forever", vec![constant("a")]));
+        assert!(!result.success);
+        assert!(result.depth_exceeded);
+    }
+
+    #[test]
+    fn test_try_prove_fails_when_depth_exceeded() {
+        let var = |name: &str| Term::Variable(name.to_string());
+        let constant = |name: &str| Term::Constant(name.to_string());
+
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_clause(Clause::new("base", Term::compound("forever", vec![constant("a")]), Vec::new())).unwrap();
+        system.add_clause(Clause::new(
+            "step",
+            Term::compound("forever", vec![var("X")]),
+            vec![Term::compound("forever", vec![var("X")])],
+        )).unwrap();
+        system.set_max_proof_depth(5);
+
+        // prove reports the solutions it found before the cut-off
+        let goal = Term::compound("forever", vec![constant("a")]);
+        assert!(system.prove(&goal).success);
+        match system.try_prove(&goal) {
+            Err(SymbolicError::DepthExceeded(msg)) => assert!(msg.contains("the limit is 5"), "{}", msg),
+            other => panic!("expected DepthExceeded, got {:?}", other),
+        }
+
+        let result = system.try_prove(&Term::compound("ready", vec![constant("a")])).unwrap();
+        assert!(!result.success);
+    }
+
+    #[test]
+    fn test_unify_binds_variables_to_constants_and_compounds() {
+        let x = Term::Variable("X".to_string());
+        let y = Term::Variable("Y".to_string());
+        let john = Term::Constant("john".to_string());
+        let father = Term::compound("father", vec![john.clone()]);
+
+        let mut bindings = Substitution::new();
+        assert!(unify(&x, &john, &mut bindings));
+        assert_eq!(bindings.get("X"), Some(&john));
+        assert!(!unify(&x, &Term::Constant("mary".to_string()), &mut bindings));
+
+        let mut bindings = Substitution::new();
+        assert!(unify(&Term::compound("parent", vec![y.clone(), x.clone()]), &Term::compound("parent", vec![father.clone(), john.clone()]), &mut bindings));
+        assert_eq!(bindings.get("Y"), Some(&father));
+        assert_eq!(bindings.get("X"), Some(&john));
+        assert!(!unify(&Term::compound("parent", vec![x.clone()]), &Term::compound("parent", vec![x.clone(), y.clone()]), &mut bindings));
+    }
+
+    #[test]
+    fn test_unify_occurs_check_rejects_cyclic_terms() {
+        let x = Term::Variable("X".to_string());
+        let y = Term::Variable("Y".to_string());
+        let f = |t: &Term| Term::compound("f", vec![t.clone()]);
+
+        let mut bindings = Substitution::new();
+        assert!(!unify(&x, &f(&x), &mut bindings));
+        assert!(!unify(&f(&x), &x, &mut bindings));
+        assert!(bindings.is_empty());
+
+        // Cycles through earlier bindings are caught too
+        assert!(unify(&x, &y, &mut bindings));
+        assert!(!unify(&y, &f(&f(&x)), &mut bindings));
+        assert!(unify(&x, &x, &mut bindings));
+
+        // The prover never builds a cyclic term from a self-referencing clause
+        let mut system = SymbolicSystem::new().unwrap();
+        system.add_clause(Clause::new("loop", Term::compound("p", vec![x.clone(), f(&x)]), Vec::new())).unwrap();
+        assert!(!system.prove(&Term::compound("p", vec![y.clone(), y.clone()])).success);
+        assert!(system.prove(&Term::compound("p", vec![Term::Constant("a".to_string()), y.clone()])).success);
+    }
 }
@@ -3,7 +3,7 @@ mod reflection;
 
 // 使用具体的模块导出，避免glob导出冲突
 pub use performance::{Performance, PerformanceMetric, PerformanceError, MetricType};
-pub use reflection::{Reflection, ReflectionManager, ReflectionError};
+pub use reflection::{Reflection, ReflectionManager, ReflectionError, Recommendation, RecommendationSeverity, RecommendationOutcome, ReflectionProcessor, ReflectionEntryStatus, ReflectionTrigger, ReflectionTriggerType};
 
 // 导出特定函数，避免冲突
 pub use performance::init as performance_init;
@@ -1,6 +1,16 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::neuro_symbolic::{IntegrityIssue, KnowledgeSystem};
+
+/// Default maximum number of entries processed by `process_all`
+pub const DEFAULT_MAX_PROCESSING_ITERATIONS: usize = 1000;
+
+/// Default knowledge consistency check interval
+pub const DEFAULT_KNOWLEDGE_CHECK_INTERVAL: Duration = Duration::from_secs(300);
 
 /// Reflection error
 #[derive(Debug)]
@@ -36,6 +46,8 @@ pub enum ReflectionType {
     Decision,
     /// Learning
     Learning,
+    /// Consistency
+    Consistency,
     /// Other
     Other,
 }
@@ -47,11 +59,33 @@ impl fmt::Display for ReflectionType {
             ReflectionType::Error => write!(f, "Error"),
             ReflectionType::Decision => write!(f, "Decision"),
             ReflectionType::Learning => write!(f, "Learning"),
+            ReflectionType::Consistency => write!(f, "Consistency"),
             ReflectionType::Other => write!(f, "Other"),
         }
     }
 }
 
+/// Reflection entry status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectionEntryStatus {
+    /// Waiting for processors
+    Pending,
+    /// Run through processors
+    Processed,
+    /// A processor failed on the entry
+    Failed,
+}
+
+impl fmt::Display for ReflectionEntryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectionEntryStatus::Pending => write!(f, "Pending"),
+            ReflectionEntryStatus::Processed => write!(f, "Processed"),
+            ReflectionEntryStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
 /// Reflection entry
 #[derive(Debug, Clone)]
 pub struct ReflectionEntry {
@@ -67,6 +101,10 @@ pub struct ReflectionEntry {
     pub timestamp: std::time::SystemTime,
     /// Entry metadata
     pub metadata: std::collections::HashMap<String, String>,
+    /// Entry status
+    pub status: ReflectionEntryStatus,
+    /// Entry tags; change them on stored entries through `ReflectionSystem`
+    pub tags: HashSet<String>,
 }
 
 impl ReflectionEntry {
@@ -79,9 +117,21 @@ impl ReflectionEntry {
             content: content.to_string(),
             timestamp: std::time::SystemTime::now(),
             metadata: std::collections::HashMap::new(),
+            status: ReflectionEntryStatus::Pending,
+            tags: HashSet::new(),
         }
     }
     
+    /// Add tag
+    pub fn add_tag(&mut self, tag: &str) {
+        self.tags.insert(tag.to_string());
+    }
+    
+    /// Check if the entry has a tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+    
     /// Add metadata
     pub fn add_metadata(&mut self, key: &str, value: &str) {
         self.metadata.insert(key.to_string(), value.to_string());
@@ -93,12 +143,240 @@ impl ReflectionEntry {
     }
 }
 
+/// Reflection processor deriving new entries from queued ones
+pub trait ReflectionProcessor: Send + Sync {
+    /// Get processor name
+    fn name(&self) -> &str;
+    
+    /// Check if the processor is enabled
+    fn is_enabled(&self) -> bool {
+        true
+    }
+    
+    /// Process an entry, returning any entries derived from it
+    fn process(&mut self, entry: &ReflectionEntry) -> Result<Vec<ReflectionEntry>, ReflectionError>;
+}
+
+/// Recommendation severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecommendationSeverity {
+    /// Low risk, safe to apply automatically
+    Low,
+    /// Medium risk
+    Medium,
+    /// High risk
+    High,
+}
+
+impl fmt::Display for RecommendationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecommendationSeverity::Low => write!(f, "Low"),
+            RecommendationSeverity::Medium => write!(f, "Medium"),
+            RecommendationSeverity::High => write!(f, "High"),
+        }
+    }
+}
+
+/// Recommendation produced by reflection
+pub struct Recommendation {
+    /// Recommendation ID
+    pub id: String,
+    /// Recommendation severity
+    pub severity: RecommendationSeverity,
+    /// Triggering metric name
+    pub metric: String,
+    /// Triggering metric value
+    pub metric_value: f64,
+    /// Recommendation description
+    pub description: String,
+    /// Action applying the recommendation
+    pub auto_apply: Option<Box<dyn Fn() -> Result<(), ReflectionError> + Send + Sync>>,
+}
+
+impl Recommendation {
+    /// Create a new recommendation
+    pub fn new(severity: RecommendationSeverity, metric: &str, metric_value: f64, description: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            severity,
+            metric: metric.to_string(),
+            metric_value,
+            description: description.to_string(),
+            auto_apply: None,
+        }
+    }
+    
+    /// Attach an action applying the recommendation
+    pub fn with_auto_apply<F>(mut self, action: F) -> Self
+    where
+        F: Fn() -> Result<(), ReflectionError> + Send + Sync + 'static,
+    {
+        self.auto_apply = Some(Box::new(action));
+        self
+    }
+    
+    /// Check if the recommendation may be applied without confirmation
+    pub fn is_auto_applicable(&self) -> bool {
+        self.auto_apply.is_some() && self.severity == RecommendationSeverity::Low
+    }
+}
+
+impl fmt::Debug for Recommendation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recommendation")
+            .field("id", &self.id)
+            .field("severity", &self.severity)
+            .field("metric", &self.metric)
+            .field("metric_value", &self.metric_value)
+            .field("description", &self.description)
+            .field("auto_apply", &self.auto_apply.is_some())
+            .finish()
+    }
+}
+
+/// Outcome of submitting a recommendation
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecommendationOutcome {
+    /// Applied automatically
+    Applied,
+    /// Waiting for confirmation
+    PendingConfirmation,
+}
+
+/// Reflection trigger type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectionTriggerType {
+    /// Fired by `ReflectionSystem::tick` once per interval
+    Periodic,
+    /// Fired only on request
+    Manual,
+}
+
+impl fmt::Display for ReflectionTriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectionTriggerType::Periodic => write!(f, "Periodic"),
+            ReflectionTriggerType::Manual => write!(f, "Manual"),
+        }
+    }
+}
+
+/// Trigger action
+pub type ReflectionTriggerAction = Box<dyn Fn(&mut ReflectionSystem) -> Result<(), ReflectionError> + Send + Sync>;
+
+/// Reflection trigger
+pub struct ReflectionTrigger {
+    /// Trigger ID
+    pub id: String,
+    /// Trigger name
+    pub name: String,
+    /// Trigger type
+    pub trigger_type: ReflectionTriggerType,
+    /// Minimum time between periodic firings
+    pub interval: Option<Duration>,
+    /// Whether the trigger is enabled
+    pub enabled: bool,
+    /// Last firing time
+    pub last_triggered: Option<Instant>,
+    /// Number of times fired
+    pub fire_count: u64,
+    /// Action run when fired
+    action: ReflectionTriggerAction,
+}
+
+impl ReflectionTrigger {
+    /// Create a new trigger
+    pub fn new<F>(name: &str, trigger_type: ReflectionTriggerType, action: F) -> Self
+    where
+        F: Fn(&mut ReflectionSystem) -> Result<(), ReflectionError> + Send + Sync + 'static,
+    {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            trigger_type,
+            interval: None,
+            enabled: true,
+            last_triggered: None,
+            fire_count: 0,
+            action: Box::new(action),
+        }
+    }
+    
+    /// Create a new periodic trigger firing at most once per `interval`
+    pub fn periodic<F>(name: &str, interval: Duration, action: F) -> Self
+    where
+        F: Fn(&mut ReflectionSystem) -> Result<(), ReflectionError> + Send + Sync + 'static,
+    {
+        Self {
+            interval: Some(interval),
+            ..Self::new(name, ReflectionTriggerType::Periodic, action)
+        }
+    }
+    
+    /// Check if a periodic trigger is due at `now`
+    ///
+    /// A trigger that has never fired is due immediately; one without an
+    /// interval never is.
+    pub fn is_due(&self, now: Instant) -> bool {
+        if !self.enabled || self.trigger_type != ReflectionTriggerType::Periodic {
+            return false;
+        }
+        
+        match (self.interval, self.last_triggered) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(interval), Some(last)) => now.saturating_duration_since(last) >= interval,
+        }
+    }
+    
+    /// Run the trigger's action, recording the firing at `now`
+    pub fn check_and_trigger(&mut self, system: &mut ReflectionSystem, now: Instant) -> Result<(), ReflectionError> {
+        self.last_triggered = Some(now);
+        self.fire_count += 1;
+        
+        (self.action)(system).map_err(|e| {
+            ReflectionError::ProcessingError(format!("Trigger {} failed: {}", self.name, e))
+        })
+    }
+}
+
+impl fmt::Debug for ReflectionTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectionTrigger")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("trigger_type", &self.trigger_type)
+            .field("interval", &self.interval)
+            .field("enabled", &self.enabled)
+            .field("last_triggered", &self.last_triggered)
+            .field("fire_count", &self.fire_count)
+            .finish()
+    }
+}
+
 /// Reflection system
 pub struct ReflectionSystem {
     /// Entries
     pub entries: std::collections::HashMap<String, ReflectionEntry>,
     /// Reflection handlers
     pub handlers: Vec<Box<dyn Fn(&ReflectionEntry) -> Result<(), ReflectionError> + Send + Sync>>,
+    /// Knowledge consistency check interval
+    pub knowledge_check_interval: Duration,
+    /// Last knowledge consistency check
+    pub last_knowledge_check: Option<SystemTime>,
+    /// Whether low-risk recommendations are applied automatically
+    pub auto_apply_enabled: bool,
+    /// Recommendations waiting for confirmation
+    pub pending_recommendations: Vec<Recommendation>,
+    /// Entry processors, run in order
+    pub processors: Vec<Box<dyn ReflectionProcessor>>,
+    /// IDs of entries waiting for processors, oldest first
+    pub entry_queue: VecDeque<String>,
+    /// Entry IDs by tag
+    tag_index: HashMap<String, HashSet<String>>,
+    /// Triggers
+    pub triggers: Vec<ReflectionTrigger>,
 }
 
 impl ReflectionSystem {
@@ -107,9 +385,63 @@ impl ReflectionSystem {
         Ok(Self {
             entries: std::collections::HashMap::new(),
             handlers: Vec::new(),
+            knowledge_check_interval: DEFAULT_KNOWLEDGE_CHECK_INTERVAL,
+            last_knowledge_check: None,
+            auto_apply_enabled: false,
+            pending_recommendations: Vec::new(),
+            processors: Vec::new(),
+            entry_queue: VecDeque::new(),
+            tag_index: HashMap::new(),
+            triggers: Vec::new(),
         })
     }
     
+    /// Add trigger, returning its ID
+    pub fn add_trigger(&mut self, trigger: ReflectionTrigger) -> String {
+        let id = trigger.id.clone();
+        self.triggers.push(trigger);
+        id
+    }
+    
+    /// Get trigger
+    pub fn get_trigger(&self, id: &str) -> Option<&ReflectionTrigger> {
+        self.triggers.iter().find(|t| t.id == id)
+    }
+    
+    /// Enable or disable a trigger
+    pub fn set_trigger_enabled(&mut self, id: &str, enabled: bool) -> Result<(), ReflectionError> {
+        let trigger = self.triggers.iter_mut().find(|t| t.id == id).ok_or_else(|| {
+            ReflectionError::ProcessingError(format!("Trigger not found: {}", id))
+        })?;
+        
+        trigger.enabled = enabled;
+        Ok(())
+    }
+    
+    /// Fire every enabled periodic trigger whose interval has elapsed
+    ///
+    /// Returns the IDs of fired triggers. Triggers are moved out of the
+    /// system while they run so their actions can use it; triggers added by
+    /// an action are kept but not fired until the next tick.
+    pub fn tick(&mut self, now: Instant) -> Result<Vec<String>, ReflectionError> {
+        let mut triggers = std::mem::take(&mut self.triggers);
+        let mut fired = Vec::new();
+        let mut result = Ok(());
+        
+        for trigger in triggers.iter_mut().filter(|t| t.is_due(now)) {
+            fired.push(trigger.id.clone());
+            result = trigger.check_and_trigger(self, now);
+            if result.is_err() {
+                break;
+            }
+        }
+        
+        triggers.append(&mut self.triggers);
+        self.triggers = triggers;
+        
+        result.map(|_| fired)
+    }
+    
     /// Add entry
     pub fn add_entry(&mut self, entry: ReflectionEntry) -> Result<(), ReflectionError> {
         // Notify handlers
@@ -119,10 +451,155 @@ impl ReflectionSystem {
             }
         }
         
+        if let Some(previous) = self.entries.get(&entry.id) {
+            let (id, tags) = (previous.id.clone(), previous.tags.clone());
+            self.unindex_tags(&id, &tags);
+        }
+        for tag in &entry.tags {
+            self.tag_index.entry(tag.clone()).or_default().insert(entry.id.clone());
+        }
+        
+        // An entry replaced while still queued keeps its place in the queue
+        if !self.entry_queue.contains(&entry.id) {
+            self.entry_queue.push_back(entry.id.clone());
+        }
         self.entries.insert(entry.id.clone(), entry);
         Ok(())
     }
     
+    /// Remove entry
+    pub fn remove_entry(&mut self, id: &str) -> Option<ReflectionEntry> {
+        let entry = self.entries.remove(id)?;
+        self.unindex_tags(id, &entry.tags);
+        Some(entry)
+    }
+    
+    /// Add tag to a stored entry
+    pub fn add_tag_to_entry(&mut self, entry_id: &str, tag: &str) -> Result<(), ReflectionError> {
+        let entry = self.entries.get_mut(entry_id).ok_or_else(|| {
+            ReflectionError::ProcessingError(format!("Entry not found: {}", entry_id))
+        })?;
+        
+        entry.add_tag(tag);
+        self.tag_index.entry(tag.to_string()).or_default().insert(entry_id.to_string());
+        Ok(())
+    }
+    
+    /// Remove tag from a stored entry, returning whether it had the tag
+    pub fn remove_tag_from_entry(&mut self, entry_id: &str, tag: &str) -> Result<bool, ReflectionError> {
+        let entry = self.entries.get_mut(entry_id).ok_or_else(|| {
+            ReflectionError::ProcessingError(format!("Entry not found: {}", entry_id))
+        })?;
+        
+        if !entry.tags.remove(tag) {
+            return Ok(false);
+        }
+        
+        self.unindex_tags(entry_id, &HashSet::from([tag.to_string()]));
+        Ok(true)
+    }
+    
+    /// Get entries by tag
+    pub fn get_entries_by_tag(&self, tag: &str) -> Vec<&ReflectionEntry> {
+        self.tag_index.get(tag)
+            .map(|ids| ids.iter().filter_map(|id| self.entries.get(id)).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Remove an entry's tags from the tag index
+    fn unindex_tags(&mut self, entry_id: &str, tags: &HashSet<String>) {
+        for tag in tags {
+            if let Some(ids) = self.tag_index.get_mut(tag) {
+                ids.remove(entry_id);
+                if ids.is_empty() {
+                    self.tag_index.remove(tag);
+                }
+            }
+        }
+    }
+    
+    /// Add processor
+    pub fn add_processor<P: ReflectionProcessor + 'static>(&mut self, processor: P) {
+        self.processors.push(Box::new(processor));
+    }
+    
+    /// Get number of entries waiting for processors
+    pub fn queued_count(&self) -> usize {
+        self.entry_queue.len()
+    }
+    
+    /// Run the oldest queued entry through every enabled processor
+    ///
+    /// Derived entries are added, and so queued, in processor order, and the
+    /// entry is marked processed. Returns the number of derived entries. If a
+    /// processor fails the entry is marked failed, is not queued again, and
+    /// nothing derived from it is kept.
+    pub fn process_next(&mut self) -> Result<usize, ReflectionError> {
+        let id = match self.entry_queue.pop_front() {
+            Some(id) => id,
+            None => return Ok(0),
+        };
+        
+        // Entries removed since they were queued are skipped
+        let entry = match self.entries.get(&id) {
+            Some(entry) => entry.clone(),
+            None => return Ok(0),
+        };
+        
+        let mut derived = Vec::new();
+        for processor in self.processors.iter_mut().filter(|p| p.is_enabled()) {
+            match processor.process(&entry) {
+                Ok(entries) => derived.extend(entries),
+                Err(e) => {
+                    let error = ReflectionError::ProcessingError(format!(
+                        "Processor {} failed on entry {}: {}", processor.name(), id, e
+                    ));
+                    if let Some(entry) = self.entries.get_mut(&id) {
+                        entry.status = ReflectionEntryStatus::Failed;
+                    }
+                    return Err(error);
+                },
+            }
+        }
+        
+        let count = derived.len();
+        for new_entry in derived {
+            self.add_entry(new_entry)?;
+        }
+        
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.status = ReflectionEntryStatus::Processed;
+        }
+        
+        Ok(count)
+    }
+    
+    /// Process queued entries until the queue is empty
+    ///
+    /// Fails once `max_iterations` entries have been processed with entries
+    /// still queued, which guards against processors feeding each other
+    /// forever. Returns the total number of derived entries.
+    pub fn process_all(&mut self, max_iterations: usize) -> Result<usize, ReflectionError> {
+        let mut total = 0;
+        
+        for _ in 0..max_iterations {
+            if self.entry_queue.is_empty() {
+                return Ok(total);
+            }
+            total += self.process_next()?;
+        }
+        
+        if self.entry_queue.is_empty() {
+            Ok(total)
+        } else {
+            Err(ReflectionError::ProcessingError(format!(
+                "Entry queue not drained after {} iterations: {} entries remaining",
+                max_iterations,
+                self.entry_queue.len()
+            )))
+        }
+    }
+    
     /// Get entry
     pub fn get_entry(&self, id: &str) -> Option<&ReflectionEntry> {
         self.entries.get(id)
@@ -150,6 +627,131 @@ impl ReflectionSystem {
         self.handlers.push(Box::new(handler));
     }
     
+    /// Set knowledge consistency check interval
+    pub fn set_knowledge_check_interval(&mut self, interval: Duration) {
+        self.knowledge_check_interval = interval;
+    }
+    
+    /// Check knowledge consistency, emitting one entry per finding
+    pub fn check_knowledge_consistency(&mut self, knowledge: &KnowledgeSystem, now: SystemTime) -> Result<Vec<String>, ReflectionError> {
+        let now_secs = now.duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| ReflectionError::ProcessingError(format!("Invalid check time: {}", e)))?
+            .as_secs();
+        
+        self.last_knowledge_check = Some(now);
+        
+        let mut entry_ids = Vec::new();
+        for issue in knowledge.validate_integrity(now_secs) {
+            let mut entry = ReflectionEntry::new(ReflectionType::Consistency, "knowledge", &issue.to_string());
+            let issue_type = match &issue {
+                IntegrityIssue::Contradiction { .. } => "contradiction",
+                IntegrityIssue::DanglingReference { .. } => "dangling_reference",
+                IntegrityIssue::ExpiredActive { .. } => "expired_active",
+            };
+            entry.add_metadata("issue", issue_type);
+            entry.add_metadata("entry_ids", &issue.entry_ids().join(","));
+            
+            entry_ids.push(entry.id.clone());
+            self.add_entry(entry)?;
+        }
+        
+        Ok(entry_ids)
+    }
+    
+    /// Run the knowledge consistency check if the interval has elapsed
+    pub fn run_periodic_checks(&mut self, knowledge: &KnowledgeSystem, now: SystemTime) -> Result<Option<Vec<String>>, ReflectionError> {
+        if let Some(last) = self.last_knowledge_check {
+            let elapsed = now.duration_since(last).unwrap_or_default();
+            if elapsed < self.knowledge_check_interval {
+                return Ok(None);
+            }
+        }
+        
+        self.check_knowledge_consistency(knowledge, now).map(Some)
+    }
+    
+    /// Enable or disable automatic application of low-risk recommendations
+    pub fn set_auto_apply(&mut self, enabled: bool) {
+        self.auto_apply_enabled = enabled;
+    }
+    
+    /// Submit recommendation, applying it if auto-apply allows
+    ///
+    /// A recommendation whose action fails stays pending, so it can be
+    /// confirmed once the cause is fixed.
+    pub fn submit_recommendation(&mut self, recommendation: Recommendation) -> Result<RecommendationOutcome, ReflectionError> {
+        if !(self.auto_apply_enabled && recommendation.is_auto_applicable()) {
+            self.add_entry(Self::recommendation_entry(&recommendation, "pending", None))?;
+            self.pending_recommendations.push(recommendation);
+            return Ok(RecommendationOutcome::PendingConfirmation);
+        }
+        
+        if let Err(e) = Self::apply(&recommendation) {
+            self.add_entry(Self::recommendation_entry(&recommendation, "failed", Some(&e)))?;
+            self.pending_recommendations.push(recommendation);
+            return Err(e);
+        }
+        
+        self.add_entry(Self::recommendation_entry(&recommendation, "applied", None))?;
+        Ok(RecommendationOutcome::Applied)
+    }
+    
+    /// Confirm pending recommendation, applying its action if any
+    ///
+    /// The recommendation stays pending if its action fails.
+    pub fn confirm_recommendation(&mut self, id: &str) -> Result<Recommendation, ReflectionError> {
+        let index = self.pending_recommendations.iter()
+            .position(|r| r.id == id)
+            .ok_or_else(|| ReflectionError::ProcessingError(format!("Recommendation not pending: {}", id)))?;
+        
+        if let Err(e) = Self::apply(&self.pending_recommendations[index]) {
+            let entry = Self::recommendation_entry(&self.pending_recommendations[index], "failed", Some(&e));
+            self.add_entry(entry)?;
+            return Err(e);
+        }
+        
+        let recommendation = self.pending_recommendations.remove(index);
+        self.add_entry(Self::recommendation_entry(&recommendation, "applied", None))?;
+        
+        Ok(recommendation)
+    }
+    
+    /// Create the decision entry recording what happened to a recommendation
+    fn recommendation_entry(recommendation: &Recommendation, outcome: &str, error: Option<&ReflectionError>) -> ReflectionEntry {
+        let mut entry = ReflectionEntry::new(ReflectionType::Decision, "recommendation", &recommendation.description);
+        entry.add_metadata("recommendation_id", &recommendation.id);
+        entry.add_metadata("severity", &recommendation.severity.to_string());
+        entry.add_metadata("metric", &recommendation.metric);
+        entry.add_metadata("metric_value", &recommendation.metric_value.to_string());
+        entry.add_metadata("outcome", outcome);
+        if let Some(error) = error {
+            entry.add_metadata("error", &error.to_string());
+        }
+        entry
+    }
+    
+    /// Dismiss pending recommendation without applying it
+    pub fn dismiss_recommendation(&mut self, id: &str) -> Option<Recommendation> {
+        let index = self.pending_recommendations.iter().position(|r| r.id == id)?;
+        Some(self.pending_recommendations.remove(index))
+    }
+    
+    /// Get recommendations waiting for confirmation
+    pub fn get_pending_recommendations(&self) -> &[Recommendation] {
+        &self.pending_recommendations
+    }
+    
+    /// Run recommendation action
+    fn apply(recommendation: &Recommendation) -> Result<(), ReflectionError> {
+        match &recommendation.auto_apply {
+            Some(action) => action().map_err(|e| ReflectionError::ProcessingError(format!(
+                "Failed to apply recommendation {}: {}",
+                recommendation.id, e
+            ))),
+            None => Ok(()),
+        }
+    }
+    
     /// Process reflection
     pub fn process_reflection(&mut self, reflection_type: ReflectionType, source: &str, content: &str) -> Result<String, ReflectionError> {
         let entry = ReflectionEntry::new(reflection_type, source, content);
@@ -0,0 +1,355 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neuro_symbolic::{KnowledgeEntry, KnowledgeSourceType, KnowledgeSystem};
+    use crate::reflection::reflection::{ReflectionSystem, ReflectionType};
+    use crate::reflection::reflection::{ReflectionEntry, ReflectionEntryStatus, ReflectionError, ReflectionProcessor, DEFAULT_MAX_PROCESSING_ITERATIONS};
+    use crate::reflection::reflection::{ReflectionTrigger, ReflectionTriggerType};
+    use crate::reflection::{Recommendation, RecommendationOutcome, RecommendationSeverity};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn test_knowledge_consistency_check_reports_findings() {
+        let mut knowledge = KnowledgeSystem::new().unwrap();
+        
+        let capital = KnowledgeEntry::new("capital_of_france", "Paris", KnowledgeSourceType::Internal, 0.9);
+        let wrong_capital = KnowledgeEntry::new("capital_of_france", "Lyon", KnowledgeSourceType::External, 0.4);
+        let mut dangling = KnowledgeEntry::new("paris_population", "2.1M", KnowledgeSourceType::Learned, 0.8);
+        dangling.add_reference("capital_of_france");
+        dangling.add_reference("paris_mayor");
+        let mut expired = KnowledgeEntry::new("weather", "sunny", KnowledgeSourceType::External, 0.7);
+        expired.set_expires_at(1_000);
+        
+        let conflicting_ids = {
+            let mut ids = vec![capital.id.clone(), wrong_capital.id.clone()];
+            ids.sort();
+            ids
+        };
+        let dangling_id = dangling.id.clone();
+        let expired_id = expired.id.clone();
+        
+        knowledge.add_entry(capital).unwrap();
+        knowledge.add_entry(wrong_capital).unwrap();
+        knowledge.add_entry(dangling).unwrap();
+        knowledge.add_entry(expired).unwrap();
+        
+        let mut reflection = ReflectionSystem::new().unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(2_000);
+        let findings = reflection.run_periodic_checks(&knowledge, now).unwrap().unwrap();
+        assert_eq!(findings.len(), 3);
+        
+        let entries = reflection.get_entries_by_type(ReflectionType::Consistency);
+        let find = |issue: &str| {
+            entries.iter()
+                .find(|e| e.get_metadata("issue").map(|s| s.as_str()) == Some(issue))
+                .unwrap()
+                .get_metadata("entry_ids")
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(find("contradiction"), conflicting_ids.join(","));
+        assert_eq!(find("dangling_reference"), dangling_id);
+        assert_eq!(find("expired_active"), expired_id);
+        
+        // Not due again until the interval elapses
+        assert!(reflection.run_periodic_checks(&knowledge, now + Duration::from_secs(1)).unwrap().is_none());
+        let later = now + reflection.knowledge_check_interval;
+        assert!(reflection.run_periodic_checks(&knowledge, later).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_knowledge_consistency_check_clean() {
+        let mut knowledge = KnowledgeSystem::new().unwrap();
+        knowledge.add_entry(KnowledgeEntry::new("a", "1", KnowledgeSourceType::Internal, 0.9)).unwrap();
+        
+        let mut reflection = ReflectionSystem::new().unwrap();
+        let findings = reflection.check_knowledge_consistency(&knowledge, SystemTime::now()).unwrap();
+        
+        assert!(findings.is_empty());
+        assert!(reflection.last_knowledge_check.is_some());
+    }
+
+    fn reduce_batch_size(batch_size: &Arc<AtomicUsize>) -> Recommendation {
+        let batch_size = batch_size.clone();
+        Recommendation::new(RecommendationSeverity::Low, "memory_usage", 0.93, "Reduce batch size")
+            .with_auto_apply(move || {
+                batch_size.store(16, Ordering::SeqCst);
+                Ok(())
+            })
+    }
+
+    #[test]
+    fn test_low_risk_recommendation_auto_applies_when_enabled() {
+        let batch_size = Arc::new(AtomicUsize::new(32));
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.set_auto_apply(true);
+        
+        let outcome = reflection.submit_recommendation(reduce_batch_size(&batch_size)).unwrap();
+        
+        assert_eq!(outcome, RecommendationOutcome::Applied);
+        assert_eq!(batch_size.load(Ordering::SeqCst), 16);
+        assert!(reflection.get_pending_recommendations().is_empty());
+        
+        let entries = reflection.get_entries_by_type(ReflectionType::Decision);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].get_metadata("metric").unwrap(), "memory_usage");
+        assert_eq!(entries[0].get_metadata("outcome").unwrap(), "applied");
+    }
+
+    #[test]
+    fn test_recommendation_skipped_when_auto_apply_disabled() {
+        let batch_size = Arc::new(AtomicUsize::new(32));
+        let mut reflection = ReflectionSystem::new().unwrap();
+        
+        let outcome = reflection.submit_recommendation(reduce_batch_size(&batch_size)).unwrap();
+        
+        assert_eq!(outcome, RecommendationOutcome::PendingConfirmation);
+        assert_eq!(batch_size.load(Ordering::SeqCst), 32);
+        
+        let id = reflection.get_pending_recommendations()[0].id.clone();
+        reflection.confirm_recommendation(&id).unwrap();
+        assert_eq!(batch_size.load(Ordering::SeqCst), 16);
+        assert!(reflection.get_pending_recommendations().is_empty());
+    }
+
+    #[test]
+    fn test_risky_recommendation_requires_confirmation() {
+        let applied = Arc::new(AtomicUsize::new(0));
+        let counter = applied.clone();
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.set_auto_apply(true);
+        
+        let recommendation = Recommendation::new(RecommendationSeverity::High, "error_rate", 0.4, "Retrain model")
+            .with_auto_apply(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+        
+        assert_eq!(reflection.submit_recommendation(recommendation).unwrap(), RecommendationOutcome::PendingConfirmation);
+        assert_eq!(applied.load(Ordering::SeqCst), 0);
+        
+        let id = reflection.get_pending_recommendations()[0].id.clone();
+        assert!(reflection.dismiss_recommendation(&id).is_some());
+        assert!(reflection.confirm_recommendation(&id).is_err());
+        assert_eq!(applied.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_failed_recommendation_stays_pending_and_is_logged() {
+        let healthy = Arc::new(AtomicUsize::new(0));
+        let flag = healthy.clone();
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.set_auto_apply(true);
+        
+        let recommendation = Recommendation::new(RecommendationSeverity::Low, "memory_usage", 0.95, "Flush caches")
+            .with_auto_apply(move || match flag.load(Ordering::SeqCst) {
+                0 => Err(ReflectionError::ProcessingError("cache locked".to_string())),
+                _ => Ok(()),
+            });
+        let id = recommendation.id.clone();
+        
+        assert!(reflection.submit_recommendation(recommendation).is_err());
+        assert_eq!(reflection.get_pending_recommendations()[0].id, id);
+        assert!(reflection.confirm_recommendation(&id).is_err());
+        assert_eq!(reflection.get_pending_recommendations().len(), 1);
+        
+        healthy.store(1, Ordering::SeqCst);
+        reflection.confirm_recommendation(&id).unwrap();
+        assert!(reflection.get_pending_recommendations().is_empty());
+        
+        let entries = reflection.get_entries_by_type(ReflectionType::Decision);
+        let mut outcomes: Vec<&str> = entries.iter().map(|e| e.get_metadata("outcome").unwrap().as_str()).collect();
+        outcomes.sort();
+        assert_eq!(outcomes, vec!["applied", "failed", "failed"]);
+        assert!(entries.iter()
+            .filter(|e| e.get_metadata("outcome").unwrap() == "failed")
+            .all(|e| e.get_metadata("error").unwrap().contains("cache locked")));
+    }
+
+    /// Turns each performance observation into one learning insight
+    struct InsightProcessor {
+        enabled: bool,
+    }
+
+    impl ReflectionProcessor for InsightProcessor {
+        fn name(&self) -> &str {
+            "insight"
+        }
+
+        fn is_enabled(&self) -> bool {
+            self.enabled
+        }
+
+        fn process(&mut self, entry: &ReflectionEntry) -> Result<Vec<ReflectionEntry>, ReflectionError> {
+            if entry.reflection_type != ReflectionType::Performance {
+                return Ok(Vec::new());
+            }
+            let mut insight = ReflectionEntry::new(ReflectionType::Learning, "insight", &format!("Investigate: {}", entry.content));
+            insight.add_metadata("derived_from", &entry.id);
+            Ok(vec![insight])
+        }
+    }
+
+    #[test]
+    fn test_process_next_turns_observation_into_insight() {
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.add_processor(InsightProcessor { enabled: true });
+        let observation = reflection.process_reflection(ReflectionType::Performance, "planner", "latency spike").unwrap();
+        assert_eq!(reflection.queued_count(), 1);
+
+        assert_eq!(reflection.process_next().unwrap(), 1);
+        assert_eq!(reflection.get_entry(&observation).unwrap().status, ReflectionEntryStatus::Processed);
+
+        let insights = reflection.get_entries_by_type(ReflectionType::Learning);
+        assert_eq!(insights.len(), 1);
+        assert_eq!(insights[0].content, "Investigate: latency spike");
+        assert_eq!(insights[0].get_metadata("derived_from"), Some(&observation));
+        assert_eq!(insights[0].status, ReflectionEntryStatus::Pending);
+
+        // The insight is queued too but produces nothing further
+        assert_eq!(reflection.process_all(DEFAULT_MAX_PROCESSING_ITERATIONS).unwrap(), 0);
+        assert_eq!(reflection.queued_count(), 0);
+        assert_eq!(reflection.process_next().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_process_all_skips_disabled_processors_and_guards_iterations() {
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.add_processor(InsightProcessor { enabled: false });
+        for i in 0..3 {
+            reflection.process_reflection(ReflectionType::Performance, "planner", &format!("spike {}", i)).unwrap();
+        }
+
+        assert!(reflection.process_all(2).is_err());
+        assert_eq!(reflection.queued_count(), 1);
+        assert_eq!(reflection.process_all(2).unwrap(), 0);
+        assert!(reflection.get_entries_by_type(ReflectionType::Learning).is_empty());
+    }
+
+    /// Fails on entries mentioning a crash
+    struct CrashProcessor;
+
+    impl ReflectionProcessor for CrashProcessor {
+        fn name(&self) -> &str {
+            "crash"
+        }
+
+        fn process(&mut self, entry: &ReflectionEntry) -> Result<Vec<ReflectionEntry>, ReflectionError> {
+            if entry.content.contains("crash") {
+                return Err(ReflectionError::ProcessingError("unparseable".to_string()));
+            }
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn test_failed_entry_is_marked_and_queue_continues() {
+        let mut reflection = ReflectionSystem::new().unwrap();
+        reflection.add_processor(InsightProcessor { enabled: true });
+        reflection.add_processor(CrashProcessor);
+        let failing = reflection.process_reflection(ReflectionType::Performance, "planner", "crash loop").unwrap();
+        let healthy = reflection.process_reflection(ReflectionType::Performance, "planner", "latency spike").unwrap();
+
+        assert!(reflection.process_next().is_err());
+        assert_eq!(reflection.get_entry(&failing).unwrap().status, ReflectionEntryStatus::Failed);
+        // The insight derived before the failure is discarded
+        assert!(reflection.get_entries_by_type(ReflectionType::Learning).is_empty());
+
+        assert_eq!(reflection.process_all(DEFAULT_MAX_PROCESSING_ITERATIONS).unwrap(), 1);
+        assert_eq!(reflection.get_entry(&healthy).unwrap().status, ReflectionEntryStatus::Processed);
+        assert_eq!(reflection.get_entry(&failing).unwrap().status, ReflectionEntryStatus::Failed);
+    }
+
+    #[test]
+    fn test_replacing_queued_entry_does_not_queue_it_twice() {
+        let mut reflection = ReflectionSystem::new().unwrap();
+        let mut entry = ReflectionEntry::new(ReflectionType::Performance, "planner", "latency spike");
+        reflection.add_entry(entry.clone()).unwrap();
+        entry.content = "latency spike (updated)".to_string();
+        reflection.add_entry(entry).unwrap();
+
+        assert_eq!(reflection.queued_count(), 1);
+    }
+
+    fn assert_tag_index_matches_scan(reflection: &ReflectionSystem, tags: &[&str]) {
+        for tag in tags {
+            let mut indexed: Vec<&str> = reflection.get_entries_by_tag(tag).iter().map(|e| e.id.as_str()).collect();
+            let mut scanned: Vec<&str> = reflection.entries.values()
+                .filter(|e| e.has_tag(tag))
+                .map(|e| e.id.as_str())
+                .collect();
+            indexed.sort();
+            scanned.sort();
+            assert_eq!(indexed, scanned, "tag {}", tag);
+        }
+    }
+
+    #[test]
+    fn test_tag_index_matches_linear_scan() {
+        let tags = ["latency", "memory", "planner"];
+        let mut reflection = ReflectionSystem::new().unwrap();
+        let mut ids = Vec::new();
+        for i in 0..12 {
+            let mut entry = ReflectionEntry::new(ReflectionType::Performance, "monitor", &format!("sample {}", i));
+            entry.add_tag(tags[i % 3]);
+            if i % 4 == 0 {
+                entry.add_tag("planner");
+            }
+            ids.push(entry.id.clone());
+            reflection.add_entry(entry).unwrap();
+        }
+        assert_tag_index_matches_scan(&reflection, &tags);
+
+        reflection.add_tag_to_entry(&ids[1], "memory").unwrap();
+        reflection.add_tag_to_entry(&ids[5], "latency").unwrap();
+        assert!(reflection.remove_tag_from_entry(&ids[0], "latency").unwrap());
+        assert!(!reflection.remove_tag_from_entry(&ids[0], "latency").unwrap());
+        assert!(reflection.remove_entry(&ids[4]).is_some());
+        assert!(reflection.remove_entry(&ids[8]).is_some());
+        assert_tag_index_matches_scan(&reflection, &tags);
+
+        // Replacing an entry re-indexes its tags
+        let mut replacement = reflection.get_entry(&ids[2]).unwrap().clone();
+        replacement.tags.clear();
+        replacement.add_tag("memory");
+        reflection.add_entry(replacement).unwrap();
+        assert_tag_index_matches_scan(&reflection, &tags);
+
+        assert!(reflection.add_tag_to_entry(&ids[4], "latency").is_err());
+        assert!(reflection.get_entries_by_tag("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_tick_fires_periodic_trigger_once_per_interval() {
+        let mut reflection = ReflectionSystem::new().unwrap();
+        let periodic = reflection.add_trigger(ReflectionTrigger::periodic("self_review", Duration::from_secs(10), |system| {
+            system.process_reflection(ReflectionType::Performance, "self_review", "periodic review").map(|_| ())
+        }));
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = fired.clone();
+        reflection.add_trigger(ReflectionTrigger::new("on_demand", ReflectionTriggerType::Manual, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }));
+
+        // Mock clock advancing in 5 second steps
+        let start = Instant::now();
+        let firings: Vec<usize> = (0..=6)
+            .map(|step| reflection.tick(start + Duration::from_secs(step * 5)).unwrap().len())
+            .collect();
+        assert_eq!(firings, vec![1, 0, 1, 0, 1, 0, 1]);
+
+        let trigger = reflection.get_trigger(&periodic).unwrap();
+        assert_eq!(trigger.fire_count, 4);
+        assert_eq!(trigger.last_triggered, Some(start + Duration::from_secs(30)));
+        assert_eq!(reflection.get_entries_by_source("self_review").len(), 4);
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        // Disabled triggers are skipped
+        reflection.set_trigger_enabled(&periodic, false).unwrap();
+        assert!(reflection.tick(start + Duration::from_secs(60)).unwrap().is_empty());
+        assert_eq!(reflection.triggers.len(), 2);
+    }
+}
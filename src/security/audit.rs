@@ -0,0 +1,274 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::{BTreeMap, VecDeque};
+
+use regex::Regex;
+
+use crate::security::ThreatLevel;
+
+/// Default maximum number of stored audit events
+pub const DEFAULT_MAX_AUDIT_EVENTS: usize = 10_000;
+
+/// Default replacement for redacted text
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Audit error
+#[derive(Debug)]
+pub enum AuditError {
+    /// Invalid redaction rule
+    InvalidRule(String),
+    /// Invalid config
+    InvalidConfig(String),
+    /// Other error
+    Other(String),
+}
+
+impl Error for AuditError {}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::InvalidRule(msg) => write!(f, "Invalid rule: {}", msg),
+            AuditError::InvalidConfig(msg) => write!(f, "Invalid config: {}", msg),
+            AuditError::Other(msg) => write!(f, "Other error: {}", msg),
+        }
+    }
+}
+
+/// Audit event
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    /// Event ID
+    pub id: String,
+    /// Event severity
+    pub severity: ThreatLevel,
+    /// Subsystem that emitted the event
+    pub source: String,
+    /// Audited action
+    pub action: String,
+    /// Event fields, e.g. user text or file paths
+    pub fields: BTreeMap<String, String>,
+    /// Event timestamp
+    pub timestamp: std::time::SystemTime,
+}
+
+impl AuditEvent {
+    /// Create a new audit event
+    pub fn new(severity: ThreatLevel, source: &str, action: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            severity,
+            source: source.to_string(),
+            action: action.to_string(),
+            fields: BTreeMap::new(),
+            timestamp: std::time::SystemTime::now(),
+        }
+    }
+
+    /// Add a field
+    pub fn with_field(mut self, name: &str, value: &str) -> Self {
+        self.fields.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+/// Regex-based field masking rule
+#[derive(Debug, Clone)]
+pub struct RedactionRule {
+    /// Field the rule applies to; `None` applies to every field
+    pub field: Option<String>,
+    /// Pattern to mask
+    pub pattern: Regex,
+    /// Replacement for each match
+    pub replacement: String,
+}
+
+impl RedactionRule {
+    /// Create a rule masking `pattern` in every field
+    pub fn new(pattern: &str) -> Result<Self, AuditError> {
+        let pattern = Regex::new(pattern)
+            .map_err(|e| AuditError::InvalidRule(format!("{}: {}", pattern, e)))?;
+
+        Ok(Self {
+            field: None,
+            pattern,
+            replacement: REDACTED.to_string(),
+        })
+    }
+
+    /// Restrict the rule to a single field
+    pub fn for_field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// Set replacement text
+    pub fn with_replacement(mut self, replacement: &str) -> Self {
+        self.replacement = replacement.to_string();
+        self
+    }
+
+    /// Check if the rule applies to a field
+    pub fn applies_to(&self, field: &str) -> bool {
+        self.field.as_deref().is_none_or(|f| f == field)
+    }
+}
+
+/// Audit log config
+#[derive(Debug, Clone)]
+pub struct AuditConfig {
+    /// Redaction rules, applied in order
+    pub redaction_rules: Vec<RedactionRule>,
+    /// Store every Nth event below `sampling_threshold`; 1 stores all
+    pub low_severity_sample_rate: usize,
+    /// Events at or above this severity are always stored
+    pub sampling_threshold: ThreatLevel,
+    /// Maximum number of stored events; the oldest event below
+    /// `sampling_threshold` is evicted first, then the oldest of any severity
+    pub max_events: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            redaction_rules: Vec::new(),
+            low_severity_sample_rate: 1,
+            sampling_threshold: ThreatLevel::High,
+            max_events: DEFAULT_MAX_AUDIT_EVENTS,
+        }
+    }
+}
+
+impl AuditConfig {
+    /// Validate config
+    pub fn validate(&self) -> Result<(), AuditError> {
+        if self.low_severity_sample_rate == 0 {
+            return Err(AuditError::InvalidConfig("Sample rate must be at least 1".to_string()));
+        }
+        if self.max_events == 0 {
+            return Err(AuditError::InvalidConfig("Max events must be at least 1".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Security audit log
+///
+/// Events are sampled and redacted before they are stored, so dropped
+/// events and masked text never reach the log. Low-severity events are
+/// sampled deterministically: the first of every `low_severity_sample_rate`
+/// is kept.
+pub struct AuditLog {
+    /// Audit config
+    pub config: AuditConfig,
+    /// Stored events, oldest first
+    events: VecDeque<AuditEvent>,
+    /// Low-severity events seen, including sampled out ones
+    low_severity_seen: u64,
+    /// Events dropped by sampling
+    sampled_out: u64,
+    /// Events evicted to stay within `max_events`
+    evicted: u64,
+}
+
+impl AuditLog {
+    /// Create a new audit log
+    pub fn new(config: AuditConfig) -> Result<Self, AuditError> {
+        config.validate()?;
+
+        Ok(Self {
+            config,
+            events: VecDeque::new(),
+            low_severity_seen: 0,
+            sampled_out: 0,
+            evicted: 0,
+        })
+    }
+
+    /// Add redaction rule
+    pub fn add_redaction_rule(&mut self, rule: RedactionRule) {
+        self.config.redaction_rules.push(rule);
+    }
+
+    /// Record an event, returning whether it was stored
+    pub fn record(&mut self, mut event: AuditEvent) -> bool {
+        if event.severity < self.config.sampling_threshold {
+            let seen = self.low_severity_seen;
+            self.low_severity_seen += 1;
+            if !seen.is_multiple_of(self.config.low_severity_sample_rate as u64) {
+                self.sampled_out += 1;
+                return false;
+            }
+        }
+
+        self.redact(&mut event);
+
+        if self.events.len() >= self.config.max_events {
+            let threshold = self.config.sampling_threshold;
+            let oldest_low = self.events.iter().position(|e| e.severity < threshold).unwrap_or(0);
+            self.events.remove(oldest_low);
+            self.evicted += 1;
+        }
+        self.events.push_back(event);
+        true
+    }
+
+    /// Apply redaction rules to an event's fields
+    fn redact(&self, event: &mut AuditEvent) {
+        for (name, value) in event.fields.iter_mut() {
+            for rule in self.config.redaction_rules.iter().filter(|r| r.applies_to(name)) {
+                if rule.pattern.is_match(value) {
+                    *value = rule.pattern.replace_all(value, rule.replacement.as_str()).into_owned();
+                }
+            }
+        }
+    }
+
+    /// Get stored events, oldest first
+    pub fn events(&self) -> impl Iterator<Item = &AuditEvent> {
+        self.events.iter()
+    }
+
+    /// Get stored events at or above a severity
+    pub fn events_at_least(&self, severity: ThreatLevel) -> Vec<&AuditEvent> {
+        self.events.iter().filter(|e| e.severity >= severity).collect()
+    }
+
+    /// Get number of stored events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check if no events are stored
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Get number of events dropped by sampling
+    pub fn sampled_out(&self) -> u64 {
+        self.sampled_out
+    }
+
+    /// Get number of events evicted to stay within `max_events`
+    pub fn evicted(&self) -> u64 {
+        self.evicted
+    }
+}
+
+/// Initialize audit module
+pub fn init() -> Result<(), AuditError> {
+    // Initialize audit module
+    Ok(())
+}
+
+/// Start audit module
+pub fn start() -> Result<(), AuditError> {
+    // Start audit module
+    Ok(())
+}
+
+/// Stop audit module
+pub fn stop() -> Result<(), AuditError> {
+    // Stop audit module
+    Ok(())
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::audit::{AuditConfig, AuditError, AuditEvent, AuditLog, RedactionRule, REDACTED};
+    use crate::security::ThreatLevel;
+
+    #[test]
+    fn test_redaction_masks_sensitive_fields_before_storing() {
+        let mut log = AuditLog::new(AuditConfig::default()).unwrap();
+        log.add_redaction_rule(RedactionRule::new(r"/home/[^/\s]+").unwrap().with_replacement("/home/<user>"));
+        log.add_redaction_rule(RedactionRule::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap().for_field("user_text"));
+
+        let event = AuditEvent::new(ThreatLevel::Medium, "interaction", "file_open")
+            .with_field("path", "/home/alice/notes.txt")
+            .with_field("user_text", "my ssn is 123-45-6789")
+            .with_field("session", "123-45-6789");
+        assert!(log.record(event));
+
+        let stored = log.events().next().unwrap();
+        assert_eq!(stored.fields["path"], "/home/<user>/notes.txt");
+        assert_eq!(stored.fields["user_text"], format!("my ssn is {}", REDACTED));
+        // Field-scoped rules leave other fields alone
+        assert_eq!(stored.fields["session"], "123-45-6789");
+
+        assert!(matches!(RedactionRule::new("("), Err(AuditError::InvalidRule(_))));
+    }
+
+    #[test]
+    fn test_sampling_keeps_all_high_severity_events() {
+        let config = AuditConfig {
+            low_severity_sample_rate: 10,
+            ..AuditConfig::default()
+        };
+        let mut log = AuditLog::new(config).unwrap();
+
+        for i in 0..100 {
+            let severity = match i % 4 {
+                0 => ThreatLevel::Low,
+                1 => ThreatLevel::Medium,
+                2 => ThreatLevel::High,
+                _ => ThreatLevel::Critical,
+            };
+            log.record(AuditEvent::new(severity, "kernel", "syscall"));
+        }
+
+        // 50 low-severity events sampled down to 5, all 50 high-severity kept
+        assert_eq!(log.events_at_least(ThreatLevel::High).len(), 50);
+        assert_eq!(log.len(), 55);
+        assert_eq!(log.sampled_out(), 45);
+    }
+
+    #[test]
+    fn test_audit_log_is_bounded() {
+        let config = AuditConfig {
+            max_events: 3,
+            ..AuditConfig::default()
+        };
+        let mut log = AuditLog::new(config).unwrap();
+
+        for action in ["a", "b", "c", "d"] {
+            log.record(AuditEvent::new(ThreatLevel::High, "kernel", action));
+        }
+
+        let actions: Vec<&str> = log.events().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["b", "c", "d"]);
+        assert_eq!(log.evicted(), 1);
+
+        // Low-severity events are evicted before older high-severity ones
+        log.record(AuditEvent::new(ThreatLevel::Low, "kernel", "e"));
+        log.record(AuditEvent::new(ThreatLevel::Critical, "kernel", "f"));
+        let actions: Vec<&str> = log.events().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["c", "d", "f"]);
+        assert_eq!(log.evicted(), 3);
+
+        let invalid = AuditConfig { low_severity_sample_rate: 0, ..AuditConfig::default() };
+        assert!(matches!(AuditLog::new(invalid), Err(AuditError::InvalidConfig(_))));
+    }
+}
@@ -2,12 +2,14 @@ mod sandbox;
 mod verification;
 mod threat_detection;
 mod access_control;
+mod audit;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use sandbox::{Sandbox, SandboxError, SandboxPolicy, SandboxPermission};
-pub use verification::{VerificationSystem, VerificationError};
-pub use threat_detection::{ThreatDetectionSystem, ThreatDetectionError};
+pub use sandbox::{Sandbox, SandboxError, SandboxPolicy, SandboxPermission, PolicyEngine, PolicyContext, SandboxResource, StaticPolicyEngine, ThreatAwarePolicyEngine};
+pub use verification::{VerificationSystem, VerificationError, VerificationResult, FormalSpecification, SpecProperty, VerificationConfig, Counterexample, State};
+pub use threat_detection::{ThreatDetectionSystem, ThreatDetectionError, ThreatLevel};
 pub use access_control::{AccessControlSystem, AccessControlError};
+pub use audit::{AuditLog, AuditError, AuditEvent, AuditConfig, RedactionRule};
 
 // 导出特定函数，避免冲突
 pub use sandbox::init as sandbox_init;
@@ -25,3 +27,7 @@ pub use threat_detection::stop as threat_detection_stop;
 pub use access_control::init as access_control_init;
 pub use access_control::start as access_control_start;
 pub use access_control::stop as access_control_stop;
+
+pub use audit::init as audit_init;
+pub use audit::start as audit_start;
+pub use audit::stop as audit_stop;
@@ -1,6 +1,9 @@
 use std::fmt;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::security::{ThreatDetectionSystem, ThreatLevel};
 
 /// Sandbox error
 #[derive(Debug)]
@@ -135,6 +138,90 @@ impl SandboxPolicy {
     pub fn check_system_call(&self, system_call: &str) -> bool {
         self.allowed_system_calls.contains(&system_call.to_string())
     }
+    
+    /// Check a request against the allow lists: the resource if one is named, otherwise the permission
+    pub fn check_request(&self, context: &PolicyContext) -> bool {
+        match &context.resource {
+            Some(SandboxResource::Path(path)) => self.check_path(path),
+            Some(SandboxResource::NetworkAddress(address)) => self.check_network_address(address),
+            Some(SandboxResource::SystemCall(system_call)) => self.check_system_call(system_call),
+            None => self.check_permission(context.permission),
+        }
+    }
+}
+
+/// Resource named in a permission request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SandboxResource {
+    /// File path
+    Path(String),
+    /// Network address
+    NetworkAddress(String),
+    /// System call
+    SystemCall(String),
+}
+
+/// Context of a permission decision
+#[derive(Debug, Clone)]
+pub struct PolicyContext {
+    /// Requested permission
+    pub permission: SandboxPermission,
+    /// Requested resource, or `None` for a plain permission check
+    pub resource: Option<SandboxResource>,
+    /// Highest unresolved threat level, if any
+    pub threat_level: Option<ThreatLevel>,
+    /// Subject requesting the permission, e.g. an access control username
+    pub subject: Option<String>,
+    /// Time of the request
+    pub time: SystemTime,
+}
+
+/// Policy engine deciding permission checks against the active policy
+pub trait PolicyEngine: Send + Sync {
+    /// Decide whether the permission in `context` is granted
+    fn check_permission(&self, policy: &SandboxPolicy, context: &PolicyContext) -> bool;
+}
+
+/// Policy engine granting exactly the policy's allowed permissions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StaticPolicyEngine;
+
+impl PolicyEngine for StaticPolicyEngine {
+    fn check_permission(&self, policy: &SandboxPolicy, context: &PolicyContext) -> bool {
+        policy.check_request(context)
+    }
+}
+
+/// Policy engine revoking restricted permissions while a threat is active
+///
+/// Below the threshold it behaves like `StaticPolicyEngine`.
+#[derive(Debug, Clone)]
+pub struct ThreatAwarePolicyEngine {
+    /// Threat level at which restricted permissions are revoked
+    pub threshold: ThreatLevel,
+    /// Permissions revoked at or above the threshold
+    pub restricted_permissions: Vec<SandboxPermission>,
+}
+
+impl ThreatAwarePolicyEngine {
+    /// Create a new engine revoking `restricted_permissions` at `threshold`
+    pub fn new(threshold: ThreatLevel, restricted_permissions: Vec<SandboxPermission>) -> Self {
+        Self {
+            threshold,
+            restricted_permissions,
+        }
+    }
+}
+
+impl PolicyEngine for ThreatAwarePolicyEngine {
+    fn check_permission(&self, policy: &SandboxPolicy, context: &PolicyContext) -> bool {
+        let threatened = context.threat_level.is_some_and(|level| level >= self.threshold);
+        if threatened && self.restricted_permissions.contains(&context.permission) {
+            return false;
+        }
+        
+        policy.check_request(context)
+    }
 }
 
 /// Sandbox
@@ -143,6 +230,12 @@ pub struct Sandbox {
     pub policies: std::collections::HashMap<String, SandboxPolicy>,
     /// Active policy
     pub active_policy: Option<String>,
+    /// Policy engine
+    pub policy_engine: Box<dyn PolicyEngine>,
+    /// Current threat level
+    pub threat_level: Option<ThreatLevel>,
+    /// Current subject
+    pub subject: Option<String>,
 }
 
 impl Sandbox {
@@ -151,9 +244,32 @@ impl Sandbox {
         Ok(Self {
             policies: std::collections::HashMap::new(),
             active_policy: None,
+            policy_engine: Box::new(StaticPolicyEngine),
+            threat_level: None,
+            subject: None,
         })
     }
     
+    /// Set policy engine
+    pub fn set_policy_engine<E: PolicyEngine + 'static>(&mut self, engine: E) {
+        self.policy_engine = Box::new(engine);
+    }
+    
+    /// Set current threat level
+    pub fn set_threat_level(&mut self, level: Option<ThreatLevel>) {
+        self.threat_level = level;
+    }
+    
+    /// Update current threat level from a threat detection system
+    pub fn update_threat_level(&mut self, detector: &ThreatDetectionSystem) {
+        self.threat_level = detector.current_threat_level();
+    }
+    
+    /// Set current subject
+    pub fn set_subject(&mut self, subject: Option<&str>) {
+        self.subject = subject.map(|s| s.to_string());
+    }
+    
     /// Add policy
     pub fn add_policy(&mut self, policy: SandboxPolicy) -> Result<(), SandboxError> {
         if self.policies.contains_key(&policy.id) {
@@ -196,38 +312,39 @@ impl Sandbox {
     
     /// Check permission
     pub fn check_permission(&self, permission: SandboxPermission) -> Result<bool, SandboxError> {
-        let policy = self.get_active_policy().ok_or_else(|| {
-            SandboxError::SecurityError("No active policy".to_string())
-        })?;
-        
-        Ok(policy.check_permission(permission))
+        self.check_request(permission, None)
     }
     
-    /// Check path
+    /// Check path, as a `FileRead` request
     pub fn check_path(&self, path: &str) -> Result<bool, SandboxError> {
-        let policy = self.get_active_policy().ok_or_else(|| {
-            SandboxError::SecurityError("No active policy".to_string())
-        })?;
-        
-        Ok(policy.check_path(path))
+        self.check_request(SandboxPermission::FileRead, Some(SandboxResource::Path(path.to_string())))
     }
     
-    /// Check network address
+    /// Check network address, as a `NetworkAccess` request
     pub fn check_network_address(&self, address: &str) -> Result<bool, SandboxError> {
-        let policy = self.get_active_policy().ok_or_else(|| {
-            SandboxError::SecurityError("No active policy".to_string())
-        })?;
-        
-        Ok(policy.check_network_address(address))
+        self.check_request(SandboxPermission::NetworkAccess, Some(SandboxResource::NetworkAddress(address.to_string())))
     }
     
-    /// Check system call
+    /// Check system call, as a `SystemCall` request
     pub fn check_system_call(&self, system_call: &str) -> Result<bool, SandboxError> {
+        self.check_request(SandboxPermission::SystemCall, Some(SandboxResource::SystemCall(system_call.to_string())))
+    }
+    
+    /// Decide a request with the policy engine against the active policy
+    fn check_request(&self, permission: SandboxPermission, resource: Option<SandboxResource>) -> Result<bool, SandboxError> {
         let policy = self.get_active_policy().ok_or_else(|| {
             SandboxError::SecurityError("No active policy".to_string())
         })?;
         
-        Ok(policy.check_system_call(system_call))
+        let context = PolicyContext {
+            permission,
+            resource,
+            threat_level: self.threat_level,
+            subject: self.subject.clone(),
+            time: SystemTime::now(),
+        };
+        
+        Ok(self.policy_engine.check_permission(policy, &context))
     }
     
     /// Execute in sandbox
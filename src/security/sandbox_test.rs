@@ -2,6 +2,9 @@
 mod tests {
     use super::*;
     use crate::security::sandbox::{Sandbox, MemoryRegion, ProtectionFlags, SandboxError};
+    use crate::security::sandbox::{SandboxPermission, SandboxPolicy, ThreatAwarePolicyEngine};
+    use crate::security::sandbox::{PolicyContext, PolicyEngine, SandboxResource};
+    use crate::security::threat_detection::{Threat, ThreatDetectionSystem, ThreatLevel, ThreatType};
 
     #[test]
     fn test_protection_flags() {
@@ -122,4 +125,90 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_policy_engine_revokes_permission_under_high_threat() {
+        let mut sandbox = Sandbox::new().unwrap();
+        let mut policy = SandboxPolicy::new("agent");
+        policy.add_permission(SandboxPermission::FileRead);
+        policy.add_permission(SandboxPermission::NetworkAccess);
+        let policy_id = policy.id.clone();
+        sandbox.add_policy(policy).unwrap();
+        sandbox.set_active_policy(&policy_id).unwrap();
+
+        // Default engine keeps the static behavior
+        assert!(sandbox.check_permission(SandboxPermission::NetworkAccess).unwrap());
+        assert!(!sandbox.check_permission(SandboxPermission::FileWrite).unwrap());
+
+        sandbox.set_policy_engine(ThreatAwarePolicyEngine::new(
+            ThreatLevel::High,
+            vec![SandboxPermission::NetworkAccess],
+        ));
+        assert!(sandbox.check_permission(SandboxPermission::NetworkAccess).unwrap());
+
+        let mut detector = ThreatDetectionSystem::new().unwrap();
+        let threat = Threat::new("exfil", "Outbound data spike", ThreatLevel::High, ThreatType::DataLeak, "net");
+        let threat_id = threat.id.clone();
+        detector.threats.insert(threat_id.clone(), threat);
+        sandbox.update_threat_level(&detector);
+
+        assert!(!sandbox.check_permission(SandboxPermission::NetworkAccess).unwrap());
+        assert!(sandbox.check_permission(SandboxPermission::FileRead).unwrap());
+
+        // Permissions come back once the threat is resolved
+        detector.resolve_threat(&threat_id).unwrap();
+        sandbox.update_threat_level(&detector);
+        assert!(sandbox.check_permission(SandboxPermission::NetworkAccess).unwrap());
+    }
+
+    #[test]
+    fn test_resource_checks_go_through_policy_engine() {
+        let mut sandbox = Sandbox::new().unwrap();
+        let mut policy = SandboxPolicy::new("agent");
+        policy.add_path("/var/lib/agi");
+        policy.add_network_address("10.0.");
+        policy.add_system_call("read");
+        let policy_id = policy.id.clone();
+        sandbox.add_policy(policy).unwrap();
+        sandbox.set_active_policy(&policy_id).unwrap();
+
+        assert!(sandbox.check_path("/var/lib/agi/model.bin").unwrap());
+        assert!(!sandbox.check_path("/etc/shadow").unwrap());
+        assert!(sandbox.check_network_address("10.0.0.7").unwrap());
+        assert!(sandbox.check_system_call("read").unwrap());
+
+        // A high threat revokes network access for address checks too
+        sandbox.set_policy_engine(ThreatAwarePolicyEngine::new(
+            ThreatLevel::High,
+            vec![SandboxPermission::NetworkAccess],
+        ));
+        sandbox.set_threat_level(Some(ThreatLevel::Critical));
+        assert!(!sandbox.check_network_address("10.0.0.7").unwrap());
+        assert!(sandbox.check_path("/var/lib/agi/model.bin").unwrap());
+        assert!(sandbox.check_system_call("read").unwrap());
+    }
+
+    /// Engine denying every system call regardless of policy
+    struct NoSystemCalls;
+
+    impl PolicyEngine for NoSystemCalls {
+        fn check_permission(&self, policy: &SandboxPolicy, context: &PolicyContext) -> bool {
+            !matches!(context.resource, Some(SandboxResource::SystemCall(_))) && policy.check_request(context)
+        }
+    }
+
+    #[test]
+    fn test_custom_engine_sees_requested_resource() {
+        let mut sandbox = Sandbox::new().unwrap();
+        let mut policy = SandboxPolicy::new("agent");
+        policy.add_path("/tmp");
+        policy.add_system_call("read");
+        let policy_id = policy.id.clone();
+        sandbox.add_policy(policy).unwrap();
+        sandbox.set_active_policy(&policy_id).unwrap();
+        sandbox.set_policy_engine(NoSystemCalls);
+
+        assert!(!sandbox.check_system_call("read").unwrap());
+        assert!(sandbox.check_path("/tmp/scratch").unwrap());
+    }
 }
@@ -219,6 +219,14 @@ impl ThreatDetectionSystem {
             .collect()
     }
     
+    /// Get highest level among unresolved threats, or `None` if there are none
+    pub fn current_threat_level(&self) -> Option<ThreatLevel> {
+        self.threats.values()
+            .filter(|t| !t.resolved)
+            .map(|t| t.level)
+            .max()
+    }
+    
     /// Resolve threat
     pub fn resolve_threat(&mut self, id: &str) -> Result<(), ThreatDetectionError> {
         let threat = self.threats.get_mut(id).ok_or_else(|| {
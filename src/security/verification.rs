@@ -1,6 +1,9 @@
 use std::fmt;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
+use std::collections::{BTreeMap, HashMap};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 /// Verification error
 #[derive(Debug)]
@@ -25,6 +28,86 @@ impl fmt::Display for VerificationError {
     }
 }
 
+/// State in a counterexample trace
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct State {
+    /// Action that led to this state, `None` for the initial state
+    pub action: Option<String>,
+    /// Variable assignments
+    pub variables: BTreeMap<String, String>,
+}
+
+impl State {
+    /// Create an initial state
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Create a state reached by an action
+    pub fn after(action: &str) -> Self {
+        Self {
+            action: Some(action.to_string()),
+            variables: BTreeMap::new(),
+        }
+    }
+    
+    /// Set variable
+    pub fn with_variable(mut self, name: &str, value: &str) -> Self {
+        self.variables.insert(name.to_string(), value.to_string());
+        self
+    }
+    
+    /// Get variable
+    pub fn get_variable(&self, name: &str) -> Option<&String> {
+        self.variables.get(name)
+    }
+}
+
+/// Execution trace leading to a property violation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Name of the violated property
+    pub violated_property: String,
+    /// States from the initial state to the violating state
+    pub trace: Vec<State>,
+}
+
+impl Counterexample {
+    /// Create a new counterexample
+    pub fn new(violated_property: &str, trace: Vec<State>) -> Self {
+        Self {
+            violated_property: violated_property.to_string(),
+            trace,
+        }
+    }
+    
+    /// Get number of steps from the initial state to the violation
+    pub fn steps(&self) -> usize {
+        self.trace.len().saturating_sub(1)
+    }
+    
+    /// Get the state in which the property is violated
+    pub fn violating_state(&self) -> Option<&State> {
+        self.trace.last()
+    }
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Property {} violated after {} step(s)", self.violated_property, self.steps())?;
+        
+        for (index, state) in self.trace.iter().enumerate() {
+            let variables: Vec<String> = state.variables.iter()
+                .map(|(name, value)| format!("{} = {}", name, value))
+                .collect();
+            write!(f, "\n  State {} ({}): {}",
+                index, state.action.as_deref().unwrap_or("initial"), variables.join(", "))?;
+        }
+        
+        Ok(())
+    }
+}
+
 /// Verification result
 #[derive(Debug, Clone)]
 pub struct VerificationResult {
@@ -36,6 +119,8 @@ pub struct VerificationResult {
     pub success: bool,
     /// Verification message
     pub message: Option<String>,
+    /// Counterexample for a failed verification
+    pub counterexample: Option<Counterexample>,
     /// Verification timestamp
     pub timestamp: std::time::SystemTime,
 }
@@ -48,6 +133,7 @@ impl VerificationResult {
             entity_id: entity_id.to_string(),
             success,
             message: None,
+            counterexample: None,
             timestamp: std::time::SystemTime::now(),
         }
     }
@@ -56,6 +142,12 @@ impl VerificationResult {
     pub fn set_message(&mut self, message: &str) {
         self.message = Some(message.to_string());
     }
+    
+    /// Set counterexample, marking the result as failed
+    pub fn set_counterexample(&mut self, counterexample: Counterexample) {
+        self.success = false;
+        self.counterexample = Some(counterexample);
+    }
 }
 
 /// Verification system
@@ -127,6 +219,179 @@ impl VerificationSystem {
     }
 }
 
+/// Specification property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecProperty {
+    /// Property name
+    pub name: String,
+    /// Property content
+    pub content: String,
+}
+
+impl SpecProperty {
+    /// Create a new specification property
+    pub fn new(name: &str, content: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            content: content.to_string(),
+        }
+    }
+}
+
+/// Verification config
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VerificationConfig {
+    /// Maximum exploration depth
+    pub max_depth: usize,
+    /// Treat warnings as failures
+    pub strict: bool,
+}
+
+impl Default for VerificationConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 32,
+            strict: false,
+        }
+    }
+}
+
+/// Cached verification result
+#[derive(Debug, Clone)]
+struct CachedVerification {
+    /// Hash of property content and config
+    hash: u64,
+    /// Result
+    result: VerificationResult,
+}
+
+/// Formal specification with incremental verification
+///
+/// Results are cached by a hash of each property's content and the config,
+/// so `verify_all` only re-checks properties that changed since the last run.
+pub struct FormalSpecification {
+    /// Specification name
+    pub name: String,
+    /// Properties, in declaration order
+    pub properties: Vec<SpecProperty>,
+    /// Verification config
+    pub config: VerificationConfig,
+    /// Property verifier
+    verifier: Box<dyn Fn(&SpecProperty, &VerificationConfig) -> Result<VerificationResult, VerificationError> + Send + Sync>,
+    /// Cached results by property name
+    cache: HashMap<String, CachedVerification>,
+    /// Properties re-verified by the last `verify_all`
+    last_verified: Vec<String>,
+}
+
+impl FormalSpecification {
+    /// Create a new formal specification
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            properties: Vec::new(),
+            config: VerificationConfig::default(),
+            verifier: Box::new(|property, _| {
+                let success = !property.content.trim().is_empty();
+                let mut result = VerificationResult::new(&property.name, success);
+                if !success {
+                    result.set_message("Property is empty");
+                }
+                Ok(result)
+            }),
+            cache: HashMap::new(),
+            last_verified: Vec::new(),
+        }
+    }
+    
+    /// Set property verifier, invalidating all cached results
+    pub fn set_verifier<F>(&mut self, verifier: F)
+    where
+        F: Fn(&SpecProperty, &VerificationConfig) -> Result<VerificationResult, VerificationError> + Send + Sync + 'static,
+    {
+        self.verifier = Box::new(verifier);
+        self.cache.clear();
+    }
+    
+    /// Set verification config
+    pub fn set_config(&mut self, config: VerificationConfig) {
+        self.config = config;
+    }
+    
+    /// Add or replace property
+    pub fn set_property(&mut self, name: &str, content: &str) {
+        match self.properties.iter_mut().find(|p| p.name == name) {
+            Some(property) => property.content = content.to_string(),
+            None => self.properties.push(SpecProperty::new(name, content)),
+        }
+    }
+    
+    /// Remove property
+    pub fn remove_property(&mut self, name: &str) -> Result<SpecProperty, VerificationError> {
+        let index = self.properties.iter().position(|p| p.name == name).ok_or_else(|| {
+            VerificationError::Other(format!("Property not found: {}", name))
+        })?;
+        
+        self.cache.remove(name);
+        Ok(self.properties.remove(index))
+    }
+    
+    /// Get property
+    pub fn get_property(&self, name: &str) -> Option<&SpecProperty> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+    
+    /// Hash a property together with the config
+    fn property_hash(&self, property: &SpecProperty) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        property.content.hash(&mut hasher);
+        self.config.hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    /// Verify all properties, reusing cached results for unchanged ones
+    pub fn verify_all(&mut self) -> Result<Vec<VerificationResult>, VerificationError> {
+        let mut results = Vec::with_capacity(self.properties.len());
+        let mut verified = Vec::new();
+        
+        for property in &self.properties {
+            let hash = self.property_hash(property);
+            
+            if let Some(cached) = self.cache.get(&property.name) {
+                if cached.hash == hash {
+                    results.push(cached.result.clone());
+                    continue;
+                }
+            }
+            
+            let result = (self.verifier)(property, &self.config)?;
+            self.cache.insert(property.name.clone(), CachedVerification {
+                hash,
+                result: result.clone(),
+            });
+            verified.push(property.name.clone());
+            results.push(result);
+        }
+        
+        // Drop results for properties that no longer exist
+        let names: Vec<&String> = self.properties.iter().map(|p| &p.name).collect();
+        self.cache.retain(|name, _| names.contains(&name));
+        
+        self.last_verified = verified;
+        Ok(results)
+    }
+    
+    /// Get properties re-verified by the last `verify_all`
+    pub fn get_last_verified(&self) -> &[String] {
+        &self.last_verified
+    }
+    
+    /// Clear cached results
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
 /// Initialize verification module
 pub fn init() -> Result<(), VerificationError> {
     // Initialize verification module
@@ -2,6 +2,8 @@
 mod tests {
     use super::*;
     use crate::security::verification::{VerificationError};
+    use crate::security::{Counterexample, FormalSpecification, State, VerificationConfig, VerificationResult};
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_verification_error() {
@@ -35,4 +37,81 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    fn recording_spec() -> (FormalSpecification, Arc<Mutex<Vec<String>>>) {
+        let runs = Arc::new(Mutex::new(Vec::new()));
+        let recorded = runs.clone();
+        
+        let mut spec = FormalSpecification::new("scheduler");
+        spec.set_verifier(move |property, _| {
+            recorded.lock().unwrap().push(property.name.clone());
+            Ok(VerificationResult::new(&property.name, !property.content.contains("false")))
+        });
+        spec.set_property("no_starvation", "always eventually scheduled(p)");
+        spec.set_property("mutual_exclusion", "always running <= 1");
+        spec.set_property("liveness", "eventually idle");
+        
+        (spec, runs)
+    }
+    
+    #[test]
+    fn test_verify_all_only_reruns_changed_properties() {
+        let (mut spec, runs) = recording_spec();
+        
+        let results = spec.verify_all().unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.success));
+        assert_eq!(runs.lock().unwrap().len(), 3);
+        
+        runs.lock().unwrap().clear();
+        spec.verify_all().unwrap();
+        assert!(runs.lock().unwrap().is_empty());
+        assert!(spec.get_last_verified().is_empty());
+        
+        spec.set_property("mutual_exclusion", "false");
+        let results = spec.verify_all().unwrap();
+        
+        assert_eq!(*runs.lock().unwrap(), vec!["mutual_exclusion".to_string()]);
+        assert_eq!(spec.get_last_verified(), &["mutual_exclusion".to_string()]);
+        assert!(!results[1].success);
+        assert!(results[0].success && results[2].success);
+    }
+    
+    #[test]
+    fn test_verify_all_reruns_everything_on_config_change() {
+        let (mut spec, runs) = recording_spec();
+        spec.verify_all().unwrap();
+        runs.lock().unwrap().clear();
+        
+        spec.set_config(VerificationConfig {
+            max_depth: 64,
+            strict: true,
+        });
+        spec.verify_all().unwrap();
+        
+        assert_eq!(runs.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_structured_counterexample() {
+        let trace = vec![
+            State::new().with_variable("a", "idle").with_variable("b", "idle"),
+            State::after("a.enter").with_variable("a", "critical").with_variable("b", "idle"),
+            State::after("b.enter").with_variable("a", "critical").with_variable("b", "critical"),
+        ];
+        let mut result = VerificationResult::new("mutex", true);
+        result.set_counterexample(Counterexample::new("mutual_exclusion", trace));
+        
+        assert!(!result.success);
+        let counterexample = result.counterexample.as_ref().unwrap();
+        assert_eq!(counterexample.violated_property, "mutual_exclusion");
+        assert_eq!(counterexample.steps(), 2);
+        assert_eq!(counterexample.trace[1].action.as_deref(), Some("a.enter"));
+        assert_eq!(counterexample.violating_state().unwrap().get_variable("b").unwrap(), "critical");
+        
+        assert_eq!(counterexample.to_string(), "Property mutual_exclusion violated after 2 step(s)\n  \
+            State 0 (initial): a = idle, b = idle\n  \
+            State 1 (a.enter): a = critical, b = idle\n  \
+            State 2 (b.enter): a = critical, b = critical");
+    }
 }
@@ -1,6 +1,28 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// Default maximum config file size in bytes
+pub const DEFAULT_MAX_CONFIG_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Prefix of environment variables that override config values
+///
+/// `AGI_OS_SYSTEM__DEBUG=false` overrides `system.debug`: the prefix is
+/// stripped, `__` separates key parts and names are lowercased.
+pub const CONFIG_ENV_PREFIX: &str = "AGI_OS_";
+
+/// Default interval between config file modification checks
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(500);
 
 /// Config error
 #[derive(Debug)]
@@ -41,7 +63,7 @@ pub struct SystemConfig {
 }
 
 /// Config value
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfigValue {
     /// String value
     String(String),
@@ -59,6 +81,331 @@ pub enum ConfigValue {
     Null,
 }
 
+impl<'de> Deserialize<'de> for ConfigValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ConfigValueVisitor)
+    }
+}
+
+impl Serialize for ConfigValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ConfigValue::String(value) => serializer.serialize_str(value),
+            ConfigValue::Integer(value) => serializer.serialize_i64(*value),
+            ConfigValue::Float(value) => serializer.serialize_f64(*value),
+            ConfigValue::Boolean(value) => serializer.serialize_bool(*value),
+            ConfigValue::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            },
+            ConfigValue::Object(values) => {
+                // Sorted keys keep diagnostics output stable
+                let sorted: BTreeMap<&String, &ConfigValue> = values.iter().collect();
+                let mut map = serializer.serialize_map(Some(sorted.len()))?;
+                for (key, value) in sorted {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            },
+            ConfigValue::Null => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl ConfigValue {
+    /// Parse an environment variable value as a boolean, number or string
+    pub fn parse_env(value: &str) -> Self {
+        if let Ok(value) = value.parse::<bool>() {
+            ConfigValue::Boolean(value)
+        } else if let Ok(value) = value.parse::<i64>() {
+            ConfigValue::Integer(value)
+        } else if let Ok(value) = value.parse::<f64>() {
+            ConfigValue::Float(value)
+        } else {
+            ConfigValue::String(value.to_string())
+        }
+    }
+}
+
+/// Config value type expected by a schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigValueType {
+    /// String value
+    String,
+    /// Integer value
+    Integer,
+    /// Float value; integers are accepted too
+    Float,
+    /// Boolean value
+    Boolean,
+    /// Array value
+    Array,
+    /// Object value
+    Object,
+    /// Any value
+    Any,
+}
+
+impl fmt::Display for ConfigValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigValueType::String => write!(f, "string"),
+            ConfigValueType::Integer => write!(f, "integer"),
+            ConfigValueType::Float => write!(f, "float"),
+            ConfigValueType::Boolean => write!(f, "boolean"),
+            ConfigValueType::Array => write!(f, "array"),
+            ConfigValueType::Object => write!(f, "object"),
+            ConfigValueType::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl ConfigValueType {
+    /// Check if a value has this type
+    pub fn matches(&self, value: &ConfigValue) -> bool {
+        matches!(
+            (self, value),
+            (ConfigValueType::Any, _)
+                | (ConfigValueType::String, ConfigValue::String(_))
+                | (ConfigValueType::Integer, ConfigValue::Integer(_))
+                | (ConfigValueType::Float, ConfigValue::Float(_) | ConfigValue::Integer(_))
+                | (ConfigValueType::Boolean, ConfigValue::Boolean(_))
+                | (ConfigValueType::Array, ConfigValue::Array(_))
+                | (ConfigValueType::Object, ConfigValue::Object(_))
+        )
+    }
+}
+
+/// Schema of a config property
+#[derive(Debug, Clone)]
+pub struct ConfigPropertySchema {
+    /// Expected value type
+    pub value_type: ConfigValueType,
+    /// Object properties that must be present
+    pub required: Vec<String>,
+    /// Object property schemas
+    pub properties: BTreeMap<String, ConfigPropertySchema>,
+    /// Array item schema
+    pub items: Option<Box<ConfigPropertySchema>>,
+    /// Minimum numeric value, inclusive
+    pub minimum: Option<f64>,
+    /// Maximum numeric value, inclusive
+    pub maximum: Option<f64>,
+}
+
+impl ConfigPropertySchema {
+    /// Create a new schema accepting any value of a type
+    pub fn new(value_type: ConfigValueType) -> Self {
+        Self {
+            value_type,
+            required: Vec::new(),
+            properties: BTreeMap::new(),
+            items: None,
+            minimum: None,
+            maximum: None,
+        }
+    }
+    
+    /// Add an optional object property
+    pub fn with_property(mut self, name: &str, schema: ConfigPropertySchema) -> Self {
+        self.properties.insert(name.to_string(), schema);
+        self
+    }
+    
+    /// Add a required object property
+    pub fn with_required_property(mut self, name: &str, schema: ConfigPropertySchema) -> Self {
+        self.required.push(name.to_string());
+        self.with_property(name, schema)
+    }
+    
+    /// Set array item schema
+    pub fn with_items(mut self, schema: ConfigPropertySchema) -> Self {
+        self.items = Some(Box::new(schema));
+        self
+    }
+    
+    /// Set inclusive numeric range
+    pub fn with_range(mut self, minimum: Option<f64>, maximum: Option<f64>) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+    
+    /// Validate a value, returning the first violation
+    pub fn validate(&self, value: &ConfigValue) -> Result<(), ConfigError> {
+        match self.validate_all(value).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+    
+    /// Validate a value, returning every violation in nested objects and arrays
+    ///
+    /// Each error names the offending key path, e.g. `workers[1].threads`.
+    pub fn validate_all(&self, value: &ConfigValue) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        self.collect_errors("", value, &mut errors);
+        errors
+    }
+    
+    /// Collect violations of a value at `path`
+    fn collect_errors(&self, path: &str, value: &ConfigValue, errors: &mut Vec<ConfigError>) {
+        let location = if path.is_empty() { "<root>" } else { path };
+        
+        if !self.value_type.matches(value) {
+            errors.push(ConfigError::ValidationError(format!(
+                "{}: expected {}, found {}",
+                location,
+                self.value_type,
+                describe_value(value)
+            )));
+            return;
+        }
+        
+        match value {
+            ConfigValue::Integer(n) => self.check_range(location, *n as f64, errors),
+            ConfigValue::Float(n) => self.check_range(location, *n, errors),
+            ConfigValue::Object(values) => {
+                for name in &self.required {
+                    if !values.contains_key(name) {
+                        errors.push(ConfigError::ValidationError(format!(
+                            "{}: missing required property {}",
+                            location,
+                            name
+                        )));
+                    }
+                }
+                for (name, schema) in &self.properties {
+                    if let Some(child) = values.get(name) {
+                        let child_path = if path.is_empty() { name.clone() } else { format!("{}.{}", path, name) };
+                        schema.collect_errors(&child_path, child, errors);
+                    }
+                }
+            },
+            ConfigValue::Array(values) => {
+                if let Some(schema) = &self.items {
+                    for (i, item) in values.iter().enumerate() {
+                        schema.collect_errors(&format!("{}[{}]", path, i), item, errors);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    
+    /// Collect a range violation of a number at `location`
+    fn check_range(&self, location: &str, number: f64, errors: &mut Vec<ConfigError>) {
+        if self.minimum.is_some_and(|min| number < min) || self.maximum.is_some_and(|max| number > max) {
+            errors.push(ConfigError::ValidationError(format!(
+                "{}: {} is outside [{}, {}]",
+                location,
+                number,
+                self.minimum.map_or("-inf".to_string(), |m| m.to_string()),
+                self.maximum.map_or("inf".to_string(), |m| m.to_string())
+            )));
+        }
+    }
+}
+
+/// Describe a value's type for validation messages
+fn describe_value(value: &ConfigValue) -> &'static str {
+    match value {
+        ConfigValue::String(_) => "string",
+        ConfigValue::Integer(_) => "integer",
+        ConfigValue::Float(_) => "float",
+        ConfigValue::Boolean(_) => "boolean",
+        ConfigValue::Array(_) => "array",
+        ConfigValue::Object(_) => "object",
+        ConfigValue::Null => "null",
+    }
+}
+
+/// Builds config values directly from parser events, without an intermediate document
+struct ConfigValueVisitor;
+
+impl<'de> Visitor<'de> for ConfigValueVisitor {
+    type Value = ConfigValue;
+    
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a config value")
+    }
+    
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Boolean(value))
+    }
+    
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Integer(value))
+    }
+    
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<ConfigValue, E> {
+        Ok(i64::try_from(value).map_or(ConfigValue::Float(value as f64), ConfigValue::Integer))
+    }
+    
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Float(value))
+    }
+    
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::String(value.to_string()))
+    }
+    
+    fn visit_string<E: de::Error>(self, value: String) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::String(value))
+    }
+    
+    fn visit_unit<E: de::Error>(self) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Null)
+    }
+    
+    fn visit_none<E: de::Error>(self) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Null)
+    }
+    
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ConfigValue, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(ConfigValue::Array(values))
+    }
+    
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ConfigValue, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some((key, value)) = map.next_entry::<String, ConfigValue>()? {
+            values.insert(key, value);
+        }
+        Ok(ConfigValue::Object(values))
+    }
+}
+
+/// Reader that fails once more than `limit` bytes have been read
+struct SizeGuard<R> {
+    inner: R,
+    read: u64,
+    limit: u64,
+}
+
+impl<R: Read> Read for SizeGuard<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.read += n as u64;
+        if self.read > self.limit {
+            return Err(io::Error::other(format!("config exceeds the limit of {} bytes", self.limit)));
+        }
+        Ok(n)
+    }
+}
+
 impl SystemConfig {
     /// Create a new system config
     pub fn new() -> Self {
@@ -85,6 +432,75 @@ impl SystemConfig {
         Ok(config)
     }
     
+    /// Load config from a JSON file, rejecting files over `DEFAULT_MAX_CONFIG_SIZE`
+    pub fn load_from_file(file_path: &str) -> Result<Self, ConfigError> {
+        Self::load_from_file_with_limit(file_path, DEFAULT_MAX_CONFIG_SIZE)
+    }
+    
+    /// Load config from a JSON file, rejecting files over `max_size` bytes
+    ///
+    /// The file is parsed as a stream and values are built directly from
+    /// parser events, so the raw text is never held in memory. The size is
+    /// checked up front and enforced again while reading, in case the file
+    /// grows or reports no size. YAML is not supported.
+    pub fn load_from_file_with_limit(file_path: &str, max_size: u64) -> Result<Self, ConfigError> {
+        let extension = Path::new(file_path).extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+        match extension.as_deref() {
+            None | Some("json") => {},
+            Some(other) => {
+                return Err(ConfigError::ParsingError(format!(
+                    "Unsupported config format: .{} (only JSON is supported)",
+                    other
+                )));
+            },
+        }
+        
+        let file = File::open(file_path)
+            .map_err(|e| ConfigError::LoadingError(format!("Failed to open {}: {}", file_path, e)))?;
+        let size = file.metadata()
+            .map_err(|e| ConfigError::LoadingError(format!("Failed to stat {}: {}", file_path, e)))?
+            .len();
+        if size > max_size {
+            return Err(ConfigError::LoadingError(format!(
+                "Config {} is {} bytes, which exceeds the limit of {} bytes",
+                file_path, size, max_size
+            )));
+        }
+        
+        let reader = SizeGuard {
+            inner: BufReader::new(file),
+            read: 0,
+            limit: max_size,
+        };
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let value = ConfigValue::deserialize(&mut deserializer)
+            .and_then(|value| deserializer.end().map(|_| value))
+            .map_err(|e| {
+                if e.is_io() {
+                    ConfigError::LoadingError(format!("Failed to read {}: {}", file_path, e))
+                } else {
+                    ConfigError::ParsingError(format!("Failed to parse {}: {}", file_path, e))
+                }
+            })?;
+        
+        let values = match value {
+            ConfigValue::Object(values) => values,
+            _ => {
+                return Err(ConfigError::ParsingError(format!(
+                    "Config {} must contain an object at the top level",
+                    file_path
+                )));
+            },
+        };
+        
+        let mut config = Self::new();
+        config.values = values;
+        config.file_path = Some(file_path.to_string());
+        Ok(config)
+    }
+    
     /// Save config to file
     pub fn save(&self) -> Result<(), ConfigError> {
         if let Some(file_path) = &self.file_path {
@@ -199,6 +615,502 @@ impl SystemConfig {
     }
 }
 
+/// Layer a config value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigSource {
+    /// Built-in default
+    Default,
+    /// Config file
+    File,
+    /// Environment variable
+    Env,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::File => write!(f, "file"),
+            ConfigSource::Env => write!(f, "env"),
+        }
+    }
+}
+
+/// Layered system config: defaults, then a config file, then environment overrides
+pub struct SystemConfigManager {
+    /// Default values
+    pub defaults: SystemConfig,
+    /// Values loaded from the config file
+    pub file: SystemConfig,
+    /// Values from environment variables
+    pub env: SystemConfig,
+}
+
+impl SystemConfigManager {
+    /// Create a new config manager with empty layers
+    pub fn new() -> Self {
+        Self {
+            defaults: SystemConfig::new(),
+            file: SystemConfig::new(),
+            env: SystemConfig::new(),
+        }
+    }
+    
+    /// Set default value
+    pub fn set_default(&mut self, key: &str, value: ConfigValue) {
+        self.defaults.set(key, value);
+    }
+    
+    /// Load the file layer from a JSON config file
+    pub fn load_file(&mut self, file_path: &str) -> Result<(), ConfigError> {
+        self.file = SystemConfig::load_from_file(file_path)?;
+        Ok(())
+    }
+    
+    /// Apply overrides from the process environment
+    pub fn apply_env(&mut self) -> Result<(), ConfigError> {
+        self.apply_env_vars(std::env::vars())
+    }
+    
+    /// Apply overrides from `CONFIG_ENV_PREFIX` variables, ignoring others
+    ///
+    /// Keys and values follow `apply_env_overrides_from`, coercing each value
+    /// to the type it has in the lower layers. On error the env layer is
+    /// left unchanged.
+    pub fn apply_env_vars<I>(&mut self, vars: I) -> Result<(), ConfigError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut effective = ConfigValue::Object(self.resolve()?.values);
+        let keys = override_env_leaves(&mut effective, CONFIG_ENV_PREFIX, vars)?;
+        
+        let mut overrides = SystemConfig::new();
+        if let ConfigValue::Object(values) = effective {
+            overrides.values = values;
+        }
+        for key in keys {
+            if let Some(value) = overrides.get(&key) {
+                self.env.set(&key, value.clone());
+            }
+        }
+        Ok(())
+    }
+    
+    /// Resolve every leaf value and the layer it came from
+    ///
+    /// A later layer replaces a value wholesale: a scalar hides an object
+    /// below it in an earlier layer and vice versa.
+    pub fn resolved_values(&self) -> BTreeMap<String, (ConfigValue, ConfigSource)> {
+        let mut resolved = BTreeMap::new();
+        let layers = [
+            (&self.defaults, ConfigSource::Default),
+            (&self.file, ConfigSource::File),
+            (&self.env, ConfigSource::Env),
+        ];
+        
+        for (layer, source) in layers {
+            let mut leaves = Vec::new();
+            flatten_values("", &layer.values, &mut leaves);
+            
+            for (key, value) in leaves {
+                let nested = format!("{}.", key);
+                resolved.retain(|existing: &String, _| {
+                    !existing.starts_with(&nested) && !key.starts_with(&format!("{}.", existing))
+                });
+                resolved.insert(key, (value, source));
+            }
+        }
+        
+        resolved
+    }
+    
+    /// Get the effective value of a key and the layer it came from
+    pub fn get_with_source(&self, key: &str) -> Option<(ConfigValue, ConfigSource)> {
+        self.resolved_values().remove(key)
+    }
+    
+    /// Resolve all layers into a validated config
+    pub fn resolve(&self) -> Result<SystemConfig, ConfigError> {
+        let mut config = SystemConfig::new();
+        for (key, (value, _)) in self.resolved_values() {
+            config.set(&key, value);
+        }
+        config.file_path = self.file.file_path.clone();
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Get the effective config tree with provenance
+    ///
+    /// Each leaf is an object with the resolved `value` and the `source`
+    /// layer it came from (`default`, `file` or `env`).
+    pub fn effective_config(&self) -> Result<ConfigValue, ConfigError> {
+        self.resolve()?;
+        
+        let mut tree = SystemConfig::new();
+        for (key, (value, source)) in self.resolved_values() {
+            let mut annotated = std::collections::HashMap::new();
+            annotated.insert("value".to_string(), value);
+            annotated.insert("source".to_string(), ConfigValue::String(source.to_string()));
+            tree.set(&key, ConfigValue::Object(annotated));
+        }
+        
+        Ok(ConfigValue::Object(tree.values))
+    }
+}
+
+impl Default for SystemConfigManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collect leaf values under dotted keys; empty objects count as leaves
+fn flatten_values(prefix: &str, values: &std::collections::HashMap<String, ConfigValue>, out: &mut Vec<(String, ConfigValue)>) {
+    for (key, value) in values {
+        let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            ConfigValue::Object(children) if !children.is_empty() => flatten_values(&path, children, out),
+            _ => out.push((path, value.clone())),
+        }
+    }
+}
+
+/// Change between two config trees
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigChange {
+    /// Value present only in the new tree
+    Added {
+        /// Dotted path
+        path: String,
+        /// New value
+        value: ConfigValue,
+    },
+    /// Value present only in the old tree
+    Removed {
+        /// Dotted path
+        path: String,
+        /// Old value
+        value: ConfigValue,
+    },
+    /// Value present in both trees with different contents
+    Changed {
+        /// Dotted path
+        path: String,
+        /// Old value
+        old: ConfigValue,
+        /// New value
+        new: ConfigValue,
+    },
+}
+
+impl ConfigChange {
+    /// Get the dotted path of the changed value
+    pub fn path(&self) -> &str {
+        match self {
+            ConfigChange::Added { path, .. } => path,
+            ConfigChange::Removed { path, .. } => path,
+            ConfigChange::Changed { path, .. } => path,
+        }
+    }
+}
+
+/// Diff two config trees
+///
+/// Objects and arrays are compared element-wise, so changes are reported at
+/// the deepest differing path, e.g. `kernel.scheduler` or `workers[1].threads`.
+/// A value whose type changed is reported as a single `Changed`. Object keys
+/// are visited in sorted order; a change of the root itself has an empty path.
+pub fn diff(old: &ConfigValue, new: &ConfigValue) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+    diff_into("", old, new, &mut changes);
+    changes
+}
+
+/// Collect changes between two values at `path`
+fn diff_into(path: &str, old: &ConfigValue, new: &ConfigValue, changes: &mut Vec<ConfigChange>) {
+    match (old, new) {
+        (ConfigValue::Object(old_values), ConfigValue::Object(new_values)) => {
+            let keys: std::collections::BTreeSet<&String> = old_values.keys().chain(new_values.keys()).collect();
+            for key in keys {
+                let key_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match (old_values.get(key), new_values.get(key)) {
+                    (Some(old), Some(new)) => diff_into(&key_path, old, new, changes),
+                    (Some(old), None) => changes.push(ConfigChange::Removed { path: key_path, value: old.clone() }),
+                    (None, Some(new)) => changes.push(ConfigChange::Added { path: key_path, value: new.clone() }),
+                    (None, None) => {},
+                }
+            }
+        },
+        (ConfigValue::Array(old_items), ConfigValue::Array(new_items)) => {
+            for i in 0..old_items.len().max(new_items.len()) {
+                let item_path = format!("{}[{}]", path, i);
+                match (old_items.get(i), new_items.get(i)) {
+                    (Some(old), Some(new)) => diff_into(&item_path, old, new, changes),
+                    (Some(old), None) => changes.push(ConfigChange::Removed { path: item_path, value: old.clone() }),
+                    (None, Some(new)) => changes.push(ConfigChange::Added { path: item_path, value: new.clone() }),
+                    (None, None) => {},
+                }
+            }
+        },
+        _ if old != new => changes.push(ConfigChange::Changed {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {},
+    }
+}
+
+/// Handle to a config file watcher; dropping it stops the watcher thread
+pub struct WatchHandle {
+    /// Stop signal, dropped to stop the watcher
+    stop: Option<mpsc::Sender<()>>,
+    /// Watcher thread
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.stop.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Load a JSON or TOML config file as a value tree
+fn load_value(path: &Path) -> Result<ConfigValue, ConfigError> {
+    let is_toml = path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("toml"));
+    if is_toml {
+        return load_toml(path);
+    }
+    
+    let file_path = path.to_str()
+        .ok_or_else(|| ConfigError::LoadingError(format!("Invalid config path: {}", path.display())))?;
+    Ok(ConfigValue::Object(SystemConfig::load_from_file(file_path)?.values))
+}
+
+/// Get a file's modification time and size, used to detect edits
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Watch a JSON or TOML config file, calling `callback` with the changes on each edit
+pub fn watch<F>(path: &Path, callback: F) -> Result<WatchHandle, ConfigError>
+where
+    F: Fn(&[ConfigChange]) + Send + 'static,
+{
+    watch_with_interval(path, DEFAULT_WATCH_INTERVAL, callback)
+}
+
+/// Watch a config file, checking its modification time every `interval`
+///
+/// The file is loaded up front so a broken config fails here. Edits that
+/// fail to load are skipped and retried on the next check, so a half-written
+/// file never reaches the callback. Edits that change no values are ignored.
+pub fn watch_with_interval<F>(path: &Path, interval: Duration, callback: F) -> Result<WatchHandle, ConfigError>
+where
+    F: Fn(&[ConfigChange]) + Send + 'static,
+{
+    let path = path.to_path_buf();
+    let mut stamp = file_stamp(&path);
+    let mut current = load_value(&path)?;
+    let (stop, stopped) = mpsc::channel::<()>();
+    
+    let worker = thread::spawn(move || {
+        while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+            let latest = file_stamp(&path);
+            if latest.is_none() || latest == stamp {
+                continue;
+            }
+            
+            let Ok(value) = load_value(&path) else {
+                continue;
+            };
+            stamp = latest;
+            let changes = diff(&current, &value);
+            current = value;
+            if !changes.is_empty() {
+                callback(&changes);
+            }
+        }
+    });
+    
+    Ok(WatchHandle {
+        stop: Some(stop),
+        worker: Some(worker),
+    })
+}
+
+/// Coerce an environment override to the type of the value it replaces
+fn coerce_env(existing: Option<&ConfigValue>, raw: &str, name: &str) -> Result<ConfigValue, ConfigError> {
+    let invalid = |expected: &str| ConfigError::ValidationError(format!("{}: expected {}, found {:?}", name, expected, raw));
+    match existing {
+        Some(ConfigValue::Integer(_)) => raw.trim().parse().map(ConfigValue::Integer).map_err(|_| invalid("integer")),
+        Some(ConfigValue::Float(_)) => raw.trim().parse().map(ConfigValue::Float).map_err(|_| invalid("float")),
+        Some(ConfigValue::Boolean(_)) => raw.trim().parse().map(ConfigValue::Boolean).map_err(|_| invalid("boolean")),
+        Some(ConfigValue::Array(_)) | Some(ConfigValue::Object(_)) => Err(ConfigError::ValidationError(format!(
+            "{}: cannot override {} with a single value",
+            name,
+            describe_value(existing.expect("value is present"))
+        ))),
+        Some(ConfigValue::String(_)) | Some(ConfigValue::Null) | None => Ok(ConfigValue::String(raw.to_string())),
+    }
+}
+
+/// Override config leaves from environment variables starting with `prefix`
+///
+/// `PREFIX_MEMORY__LIMIT=8192` sets `memory.limit`, matching existing keys
+/// case-insensitively. The string is coerced to the type of the value it
+/// replaces; new keys are inserted as strings.
+pub fn apply_env_overrides(value: &mut ConfigValue, prefix: &str) -> Result<(), ConfigError> {
+    apply_env_overrides_from(value, prefix, std::env::vars())
+}
+
+/// Override config leaves from `prefix` variables, ignoring others
+///
+/// Overrides are applied to a copy, so `value` is unchanged on error.
+pub fn apply_env_overrides_from<I>(value: &mut ConfigValue, prefix: &str, vars: I) -> Result<(), ConfigError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut updated = value.clone();
+    override_env_leaves(&mut updated, prefix, vars)?;
+    *value = updated;
+    Ok(())
+}
+
+/// Apply `prefix` variables to `value` in place, returning the dotted keys set
+fn override_env_leaves<I>(value: &mut ConfigValue, prefix: &str, vars: I) -> Result<Vec<String>, ConfigError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut keys = Vec::new();
+    let mut overrides: Vec<(String, String)> = vars.into_iter()
+        .filter(|(name, _)| name.len() > prefix.len() && name.starts_with(prefix))
+        .collect();
+    overrides.sort();
+    
+    for (name, raw) in overrides {
+        let path: Vec<&str> = name[prefix.len()..].split("__").collect();
+        if path.iter().any(|segment| segment.is_empty()) {
+            return Err(ConfigError::ValidationError(format!("{}: empty key segment", name)));
+        }
+        
+        let mut current = &mut *value;
+        let mut dotted = Vec::with_capacity(path.len());
+        for (i, segment) in path.iter().enumerate() {
+            let values = match current {
+                ConfigValue::Object(values) => values,
+                other => return Err(ConfigError::ValidationError(format!(
+                    "{}: cannot set a key inside {}",
+                    name,
+                    describe_value(other)
+                ))),
+            };
+            let key = values.keys()
+                .find(|key| key.eq_ignore_ascii_case(segment))
+                .cloned()
+                .unwrap_or_else(|| segment.to_ascii_lowercase());
+            dotted.push(key.clone());
+            
+            if i + 1 == path.len() {
+                let coerced = coerce_env(values.get(&key), &raw, &name)?;
+                values.insert(key, coerced);
+                break;
+            }
+            current = values.entry(key).or_insert_with(|| ConfigValue::Object(std::collections::HashMap::new()));
+        }
+        keys.push(dotted.join("."));
+    }
+    
+    Ok(keys)
+}
+
+/// Convert a TOML value to a config value; datetimes become strings
+fn from_toml(value: toml::Value) -> ConfigValue {
+    match value {
+        toml::Value::String(value) => ConfigValue::String(value),
+        toml::Value::Integer(value) => ConfigValue::Integer(value),
+        toml::Value::Float(value) => ConfigValue::Float(value),
+        toml::Value::Boolean(value) => ConfigValue::Boolean(value),
+        toml::Value::Datetime(value) => ConfigValue::String(value.to_string()),
+        toml::Value::Array(values) => ConfigValue::Array(values.into_iter().map(from_toml).collect()),
+        toml::Value::Table(values) => ConfigValue::Object(
+            values.into_iter().map(|(key, value)| (key, from_toml(value))).collect()
+        ),
+    }
+}
+
+/// Convert a config value to a TOML value, or `None` for null
+///
+/// TOML has no null: null object properties are omitted, and a null array
+/// item is an error since dropping it would shift the other items.
+fn to_toml(value: &ConfigValue, path: &str) -> Result<Option<toml::Value>, ConfigError> {
+    let value = match value {
+        ConfigValue::String(value) => toml::Value::String(value.clone()),
+        ConfigValue::Integer(value) => toml::Value::Integer(*value),
+        ConfigValue::Float(value) => toml::Value::Float(*value),
+        ConfigValue::Boolean(value) => toml::Value::Boolean(*value),
+        ConfigValue::Array(values) => {
+            let mut items = Vec::with_capacity(values.len());
+            for (i, value) in values.iter().enumerate() {
+                let item_path = format!("{}[{}]", path, i);
+                match to_toml(value, &item_path)? {
+                    Some(item) => items.push(item),
+                    None => return Err(ConfigError::Other(format!("{}: TOML arrays cannot hold null", item_path))),
+                }
+            }
+            toml::Value::Array(items)
+        },
+        ConfigValue::Object(values) => {
+            let mut table = toml::value::Table::new();
+            for (key, value) in values {
+                let key_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                if let Some(value) = to_toml(value, &key_path)? {
+                    table.insert(key.clone(), value);
+                }
+            }
+            toml::Value::Table(table)
+        },
+        ConfigValue::Null => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+/// Load a config value from a TOML file
+///
+/// TOML has no null, so keys saved from null values are simply missing.
+pub fn load_toml(path: &Path) -> Result<ConfigValue, ConfigError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| ConfigError::LoadingError(format!("Failed to read {}: {}", path.display(), e)))?;
+    let value: toml::Value = toml::from_str(&content)
+        .map_err(|e| ConfigError::ParsingError(format!("Failed to parse {}: {}", path.display(), e)))?;
+    
+    Ok(from_toml(value))
+}
+
+/// Save a config object to a TOML file, omitting null properties
+pub fn save_toml(value: &ConfigValue, path: &Path) -> Result<(), ConfigError> {
+    if !matches!(value, ConfigValue::Object(_)) {
+        return Err(ConfigError::Other(format!(
+            "TOML documents must be objects, found {}",
+            describe_value(value)
+        )));
+    }
+    
+    let table = to_toml(value, "")?.unwrap_or_else(|| toml::Value::Table(toml::value::Table::new()));
+    let content = toml::to_string(&table)
+        .map_err(|e| ConfigError::Other(format!("Failed to serialize TOML: {}", e)))?;
+    
+    std::fs::write(path, content)
+        .map_err(|e| ConfigError::Other(format!("Failed to write {}: {}", path.display(), e)))
+}
+
 /// Initialize config module
 pub fn init() -> Result<(), ConfigError> {
     // Initialize config module
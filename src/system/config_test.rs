@@ -1,7 +1,13 @@
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::system::config::{SystemConfig, ConfigError};
+    use crate::system::config::{SystemConfig, ConfigError, ConfigValue};
+    use crate::system::{ConfigSource, SystemConfigManager};
+    use crate::system::{ConfigPropertySchema, ConfigValueType};
+    use crate::system::{load_toml, save_toml};
+    use crate::system::{apply_env_overrides, apply_env_overrides_from};
+    use crate::system::{diff, ConfigChange};
+    use crate::system::watch_with_interval;
     use std::collections::HashMap;
 
     #[test]
@@ -97,4 +103,322 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    fn write_temp_config(name: &str, contents: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("config_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_from_file_parses_json() {
+        let path = write_temp_config(
+            "system.json",
+            r#"{ "system": { "name": "AGI OS", "workers": 8, "ratio": 0.5, "debug": false },
+                 "modules": ["vision", "speech"], "idle_timeout_secs": null }"#,
+        );
+
+        let config = SystemConfig::load_from_file(path.to_str().unwrap()).unwrap();
+
+        assert!(matches!(config.get("system.name"), Some(ConfigValue::String(name)) if name == "AGI OS"));
+        assert!(matches!(config.get("system.workers"), Some(ConfigValue::Integer(8))));
+        assert!(matches!(config.get("system.ratio"), Some(ConfigValue::Float(r)) if *r == 0.5));
+        assert!(matches!(config.get("system.debug"), Some(ConfigValue::Boolean(false))));
+        assert!(matches!(config.get("modules"), Some(ConfigValue::Array(modules)) if modules.len() == 2));
+        assert!(matches!(config.get("idle_timeout_secs"), Some(ConfigValue::Null)));
+        assert_eq!(config.file_path.as_deref(), path.to_str());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_load_from_file_rejects_oversize_config() {
+        let padding = "x".repeat(4096);
+        let path = write_temp_config("huge.json", &format!(r#"{{ "padding": "{}" }}"#, padding));
+
+        match SystemConfig::load_from_file_with_limit(path.to_str().unwrap(), 1024) {
+            Err(ConfigError::LoadingError(msg)) => assert!(msg.contains("exceeds the limit of 1024 bytes")),
+            other => panic!("expected loading error, got {:?}", other.map(|c| c.values.len())),
+        }
+        assert!(SystemConfig::load_from_file_with_limit(path.to_str().unwrap(), 8192).is_ok());
+
+        let yaml = write_temp_config("system.yaml", "system:\n  name: AGI OS\n");
+        assert!(matches!(
+            SystemConfig::load_from_file(yaml.to_str().unwrap()),
+            Err(ConfigError::ParsingError(_))
+        ));
+
+        let array = write_temp_config("array.json", "[1, 2, 3]");
+        assert!(matches!(
+            SystemConfig::load_from_file(array.to_str().unwrap()),
+            Err(ConfigError::ParsingError(_))
+        ));
+
+        for file in [path, yaml, array] {
+            std::fs::remove_dir_all(file.parent().unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_effective_config_reports_provenance() {
+        let mut manager = SystemConfigManager::new();
+        manager.set_default("system.name", ConfigValue::String("AGI OS".to_string()));
+        manager.set_default("system.debug", ConfigValue::Boolean(true));
+        manager.apply_env_vars(vec![
+            ("AGI_OS_SYSTEM__DEBUG".to_string(), "false".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+        ]).unwrap();
+
+        assert!(matches!(
+            manager.get_with_source("system.debug"),
+            Some((ConfigValue::Boolean(false), ConfigSource::Env))
+        ));
+        assert!(matches!(
+            manager.get_with_source("system.name"),
+            Some((ConfigValue::String(ref name), ConfigSource::Default)) if name == "AGI OS"
+        ));
+        assert!(manager.get_with_source("path").is_none());
+
+        let effective = manager.effective_config().unwrap();
+        assert_eq!(
+            serde_json::to_string(&effective).unwrap(),
+            r#"{"system":{"debug":{"source":"env","value":false},"name":{"source":"default","value":"AGI OS"}}}"#
+        );
+
+        let resolved = manager.resolve().unwrap();
+        assert!(matches!(resolved.get("system.debug"), Some(ConfigValue::Boolean(false))));
+    }
+
+    #[test]
+    fn test_schema_validate_all_reports_every_violation() {
+        let worker = ConfigPropertySchema::new(ConfigValueType::Object)
+            .with_required_property("name", ConfigPropertySchema::new(ConfigValueType::String))
+            .with_property("threads", ConfigPropertySchema::new(ConfigValueType::Integer).with_range(Some(1.0), Some(64.0)));
+        let schema = ConfigPropertySchema::new(ConfigValueType::Object)
+            .with_required_property("version", ConfigPropertySchema::new(ConfigValueType::String))
+            .with_property("timeout", ConfigPropertySchema::new(ConfigValueType::Float).with_range(Some(0.0), None))
+            .with_property("workers", ConfigPropertySchema::new(ConfigValueType::Array).with_items(worker));
+
+        let value: ConfigValue = serde_json::from_str(r#"{
+            "timeout": 2.5,
+            "workers": [
+                {"name": "planner", "threads": 4},
+                {"threads": 128},
+                "oops"
+            ]
+        }"#).unwrap();
+
+        let errors: Vec<String> = schema.validate_all(&value).iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, vec![
+            "Validation error: <root>: missing required property version",
+            "Validation error: workers[1]: missing required property name",
+            "Validation error: workers[1].threads: 128 is outside [1, 64]",
+            "Validation error: workers[2]: expected object, found string",
+        ]);
+
+        // validate stops at the first violation
+        let first = schema.validate(&value).unwrap_err().to_string();
+        assert_eq!(first, errors[0]);
+    }
+
+    #[test]
+    fn test_schema_accepts_valid_config() {
+        let schema = ConfigPropertySchema::new(ConfigValueType::Object)
+            .with_required_property("retries", ConfigPropertySchema::new(ConfigValueType::Integer).with_range(Some(0.0), Some(10.0)))
+            .with_property("ratio", ConfigPropertySchema::new(ConfigValueType::Float).with_range(Some(0.0), Some(1.0)));
+
+        let value: ConfigValue = serde_json::from_str(r#"{"retries": 3, "ratio": 1, "extra": null}"#).unwrap();
+        assert!(schema.validate_all(&value).is_empty());
+        assert!(schema.validate(&value).is_ok());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let value: ConfigValue = serde_json::from_str(r#"{
+            "name": "agi-os",
+            "debug": false,
+            "scale": 1.5,
+            "retries": 3,
+            "unset": null,
+            "ports": [8080, 8081],
+            "kernel": {
+                "scheduler": "priority",
+                "limits": {"memory_mb": 512, "tags": ["a", "b"]},
+                "parent": null
+            },
+            "workers": [{"name": "planner"}, {"name": "vision", "threads": 2}]
+        }"#).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("config_toml_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        save_toml(&value, &path).unwrap();
+        let loaded = load_toml(&path);
+        let _ = std::fs::remove_dir_all(&dir);
+        let loaded = loaded.unwrap();
+
+        // Null keys are dropped; everything else survives unchanged
+        let expected: ConfigValue = serde_json::from_str(r#"{
+            "name": "agi-os",
+            "debug": false,
+            "scale": 1.5,
+            "retries": 3,
+            "ports": [8080, 8081],
+            "kernel": {
+                "scheduler": "priority",
+                "limits": {"memory_mb": 512, "tags": ["a", "b"]}
+            },
+            "workers": [{"name": "planner"}, {"name": "vision", "threads": 2}]
+        }"#).unwrap();
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), serde_json::to_string(&expected).unwrap());
+
+        assert!(save_toml(&ConfigValue::Integer(1), &path).is_err());
+        assert!(save_toml(&serde_json::from_str(r#"{"items": [1, null]}"#).unwrap(), &path).is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_coerce_to_existing_types() {
+        let mut value: ConfigValue = serde_json::from_str(
+            r#"{"memory": {"limit": 1024, "ratio": 0.5}, "debug": true}"#
+        ).unwrap();
+
+        std::env::set_var("AGIOS_MEMORY__LIMIT", "8192");
+        let result = apply_env_overrides(&mut value, "AGIOS_");
+        std::env::remove_var("AGIOS_MEMORY__LIMIT");
+        result.unwrap();
+
+        apply_env_overrides_from(&mut value, "AGIOS_", vec![
+            ("AGIOS_MEMORY__RATIO".to_string(), "0.75".to_string()),
+            ("AGIOS_DEBUG".to_string(), "false".to_string()),
+            ("AGIOS_KERNEL__SCHEDULER".to_string(), "42".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+        ]).unwrap();
+
+        let expected: ConfigValue = serde_json::from_str(
+            r#"{"memory": {"limit": 8192, "ratio": 0.75}, "debug": false, "kernel": {"scheduler": "42"}}"#
+        ).unwrap();
+        let sorted = |v: &ConfigValue| serde_json::to_value(v).unwrap();
+        assert_eq!(sorted(&value), sorted(&expected));
+
+        let result = apply_env_overrides_from(&mut value, "AGIOS_", vec![
+            ("AGIOS_DEBUG".to_string(), "true".to_string()),
+            ("AGIOS_MEMORY__LIMIT".to_string(), "lots".to_string()),
+        ]);
+        assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+        assert_eq!(sorted(&value), sorted(&expected));
+    }
+
+    #[test]
+    fn test_manager_env_overrides_share_coercion_and_are_atomic() {
+        let mut manager = SystemConfigManager::new();
+        manager.set_default("memory.limit", ConfigValue::Integer(1024));
+        manager.set_default("system.Name", ConfigValue::String("AGI OS".to_string()));
+
+        manager.apply_env_vars(vec![
+            ("AGI_OS_MEMORY__LIMIT".to_string(), "8192".to_string()),
+            ("AGI_OS_SYSTEM__NAME".to_string(), "42".to_string()),
+        ]).unwrap();
+        assert!(matches!(
+            manager.get_with_source("memory.limit"),
+            Some((ConfigValue::Integer(8192), ConfigSource::Env))
+        ));
+        assert!(matches!(
+            manager.get_with_source("system.Name"),
+            Some((ConfigValue::String(ref name), ConfigSource::Env)) if name == "42"
+        ));
+
+        let result = manager.apply_env_vars(vec![
+            ("AGI_OS_MEMORY__LIMIT".to_string(), "lots".to_string()),
+            ("AGI_OS_SYSTEM__NAME".to_string(), "changed".to_string()),
+        ]);
+        assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+        assert!(matches!(
+            manager.get_with_source("system.Name"),
+            Some((ConfigValue::String(ref name), ConfigSource::Env)) if name == "42"
+        ));
+        assert!(matches!(
+            manager.get_with_source("memory.limit"),
+            Some((ConfigValue::Integer(8192), ConfigSource::Env))
+        ));
+    }
+
+    #[test]
+    fn test_diff_reports_changes_by_path() {
+        let old: ConfigValue = serde_json::from_str(r#"{
+            "debug": true,
+            "log_level": "info",
+            "kernel": {"scheduler": "round_robin", "limits": {"memory_mb": 512}},
+            "workers": [{"name": "planner", "threads": 1}]
+        }"#).unwrap();
+        let new: ConfigValue = serde_json::from_str(r#"{
+            "debug": false,
+            "kernel": {"scheduler": "round_robin", "limits": {"memory_mb": 1024}},
+            "workers": [{"name": "planner", "threads": 2}, {"name": "vision"}],
+            "metrics": {"enabled": true}
+        }"#).unwrap();
+
+        let changes = diff(&old, &new);
+        assert_eq!(changes, vec![
+            ConfigChange::Changed {
+                path: "debug".to_string(),
+                old: ConfigValue::Boolean(true),
+                new: ConfigValue::Boolean(false),
+            },
+            ConfigChange::Changed {
+                path: "kernel.limits.memory_mb".to_string(),
+                old: ConfigValue::Integer(512),
+                new: ConfigValue::Integer(1024),
+            },
+            ConfigChange::Removed {
+                path: "log_level".to_string(),
+                value: ConfigValue::String("info".to_string()),
+            },
+            ConfigChange::Added {
+                path: "metrics".to_string(),
+                value: serde_json::from_str(r#"{"enabled": true}"#).unwrap(),
+            },
+            ConfigChange::Changed {
+                path: "workers[0].threads".to_string(),
+                old: ConfigValue::Integer(1),
+                new: ConfigValue::Integer(2),
+            },
+            ConfigChange::Added {
+                path: "workers[1]".to_string(),
+                value: serde_json::from_str(r#"{"name": "vision"}"#).unwrap(),
+            },
+        ]);
+
+        assert!(diff(&old, &old).is_empty());
+        assert_eq!(diff(&ConfigValue::Integer(1), &ConfigValue::String("1".to_string()))[0].path(), "");
+    }
+
+    #[test]
+    fn test_watch_reports_file_edits() {
+        let dir = std::env::temp_dir().join(format!("config_watch_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        std::fs::write(&path, r#"{"kernel": {"scheduler": "round_robin"}, "debug": true}"#).unwrap();
+
+        let (sender, received) = std::sync::mpsc::channel();
+        let handle = watch_with_interval(&path, std::time::Duration::from_millis(10), move |changes| {
+            let _ = sender.send(changes.to_vec());
+        }).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::fs::write(&path, r#"{"kernel": {"scheduler": "priority"}, "debug": true}"#).unwrap();
+        let changes = received.recv_timeout(std::time::Duration::from_secs(5));
+
+        drop(handle);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(changes.unwrap(), vec![ConfigChange::Changed {
+            path: "kernel.scheduler".to_string(),
+            old: ConfigValue::String("round_robin".to_string()),
+            new: ConfigValue::String("priority".to_string()),
+        }]);
+
+        // Dropping the handle stops the watcher, which drops the callback
+        assert!(received.recv().is_err());
+    }
 }
@@ -1,6 +1,15 @@
 use std::fmt;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
+use std::collections::{HashSet, VecDeque};
+
+use crate::system::ActivitySignal;
+
+/// Default dead-letter queue capacity
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 1024;
+
+/// Default number of processed message IDs remembered per idempotent destination
+pub const DEFAULT_DEDUP_WINDOW: usize = 1024;
 
 /// Integration error
 #[derive(Debug)]
@@ -126,6 +135,75 @@ impl IntegrationMessage {
     }
 }
 
+/// Dead-letter reason
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// Destination is not a known endpoint
+    UnknownDestination,
+    /// No handler is registered for the destination
+    NoSubscriber,
+    /// Handler returned an error
+    HandlerError(String),
+}
+
+impl fmt::Display for DeadLetterReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeadLetterReason::UnknownDestination => write!(f, "Unknown destination"),
+            DeadLetterReason::NoSubscriber => write!(f, "No subscriber"),
+            DeadLetterReason::HandlerError(msg) => write!(f, "Handler error: {}", msg),
+        }
+    }
+}
+
+/// Undeliverable message
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    /// Message
+    pub message: IntegrationMessage,
+    /// Failure reason
+    pub reason: DeadLetterReason,
+    /// Delivery attempts
+    pub attempts: u32,
+    /// Whether the message is already in the message history
+    pub recorded: bool,
+    /// Last failure timestamp
+    pub failed_at: std::time::SystemTime,
+}
+
+/// Bounded set of processed message IDs, oldest evicted first
+#[derive(Debug, Default)]
+struct ProcessedIds {
+    /// IDs in processing order
+    order: VecDeque<String>,
+    /// IDs for lookup
+    ids: HashSet<String>,
+}
+
+impl ProcessedIds {
+    /// Check if an ID was processed
+    fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+    
+    /// Record a processed ID, keeping at most `window` IDs
+    fn insert(&mut self, id: &str, window: usize) {
+        if self.ids.insert(id.to_string()) {
+            self.order.push_back(id.to_string());
+        }
+        self.trim(window);
+    }
+    
+    /// Drop the oldest IDs beyond `window`
+    fn trim(&mut self, window: usize) {
+        while self.order.len() > window {
+            if let Some(id) = self.order.pop_front() {
+                self.ids.remove(&id);
+            }
+        }
+    }
+}
+
 /// Integration interface
 pub struct IntegrationInterface {
     /// Endpoints
@@ -134,6 +212,18 @@ pub struct IntegrationInterface {
     pub messages: Vec<IntegrationMessage>,
     /// Message handlers
     pub message_handlers: std::collections::HashMap<String, Box<dyn Fn(&IntegrationMessage) -> Result<(), IntegrationError> + Send + Sync>>,
+    /// Dead-letter queue
+    pub dead_letters: VecDeque<DeadLetter>,
+    /// Dead-letter queue capacity, 0 disables the queue
+    pub dead_letter_capacity: usize,
+    /// Processed message IDs of destinations with idempotent handlers
+    processed_ids: std::collections::HashMap<String, ProcessedIds>,
+    /// Number of processed message IDs remembered per idempotent destination
+    pub dedup_window: usize,
+    /// Duplicate messages skipped by idempotent destinations
+    pub duplicates_skipped: u64,
+    /// Activity signal notified of sent messages
+    activity: Option<ActivitySignal>,
 }
 
 impl IntegrationInterface {
@@ -143,6 +233,12 @@ impl IntegrationInterface {
             endpoints: std::collections::HashMap::new(),
             messages: Vec::new(),
             message_handlers: std::collections::HashMap::new(),
+            dead_letters: VecDeque::new(),
+            dead_letter_capacity: DEFAULT_DEAD_LETTER_CAPACITY,
+            processed_ids: std::collections::HashMap::new(),
+            dedup_window: DEFAULT_DEDUP_WINDOW,
+            duplicates_skipped: 0,
+            activity: None,
         })
     }
     
@@ -163,22 +259,123 @@ impl IntegrationInterface {
     }
     
     /// Send message
+    ///
+    /// Messages that cannot be delivered are routed to the dead-letter queue.
+    /// A message without a subscriber is not an error for the sender.
     pub fn send_message(&mut self, message: IntegrationMessage) -> Result<(), IntegrationError> {
+        if let Some(activity) = &self.activity {
+            activity.notify(std::time::SystemTime::now());
+        }
+        self.deliver(message, 1, false).map(|_| ())
+    }
+    
+    /// Report sent messages as activity, e.g. to an idle detector
+    pub fn set_activity_signal(&mut self, activity: ActivitySignal) {
+        self.activity = Some(activity);
+    }
+    
+    /// Deliver message, dead-lettering it on failure; returns true if a handler received it
+    ///
+    /// Messages already processed by an idempotent destination are skipped.
+    /// Delivered messages and messages without a subscriber are recorded in
+    /// the message history once, unless `recorded` says they already are.
+    fn deliver(&mut self, message: IntegrationMessage, attempts: u32, recorded: bool) -> Result<bool, IntegrationError> {
         // Check if destination exists
         if !self.endpoints.values().any(|e| e.name == message.destination) {
-            return Err(IntegrationError::CommunicationError(format!(
+            let error = IntegrationError::CommunicationError(format!(
                 "Destination not found: {}",
                 message.destination
-            )));
+            ));
+            self.dead_letter(message, DeadLetterReason::UnknownDestination, attempts, recorded);
+            return Err(error);
+        }
+        
+        // Skip messages an idempotent destination has already processed
+        let duplicate = self.processed_ids.get(&message.destination)
+            .is_some_and(|processed| processed.contains(&message.id));
+        if duplicate {
+            self.duplicates_skipped += 1;
+            return Ok(false);
         }
         
         // Handle message
-        if let Some(handler) = self.message_handlers.get(&message.destination) {
-            handler(&message)?;
+        let result = match self.message_handlers.get(&message.destination) {
+            Some(handler) => handler(&message),
+            None => {
+                if !recorded {
+                    self.messages.push(message.clone());
+                }
+                self.dead_letter(message, DeadLetterReason::NoSubscriber, attempts, true);
+                return Ok(false);
+            }
+        };
+        
+        if let Err(e) = result {
+            self.dead_letter(message, DeadLetterReason::HandlerError(e.to_string()), attempts, recorded);
+            return Err(e);
         }
         
-        self.messages.push(message);
-        Ok(())
+        // Failed deliveries are not recorded, so retries still reach the handler
+        if let Some(processed) = self.processed_ids.get_mut(&message.destination) {
+            processed.insert(&message.id, self.dedup_window);
+        }
+        
+        if !recorded {
+            self.messages.push(message);
+        }
+        Ok(true)
+    }
+    
+    /// Add message to the dead-letter queue, dropping the oldest when full
+    fn dead_letter(&mut self, message: IntegrationMessage, reason: DeadLetterReason, attempts: u32, recorded: bool) {
+        if self.dead_letter_capacity == 0 {
+            return;
+        }
+        
+        while self.dead_letters.len() >= self.dead_letter_capacity {
+            self.dead_letters.pop_front();
+        }
+        
+        self.dead_letters.push_back(DeadLetter {
+            message,
+            reason,
+            attempts,
+            recorded,
+            failed_at: std::time::SystemTime::now(),
+        });
+    }
+    
+    /// Set dead-letter queue capacity, 0 disables the queue
+    pub fn set_dead_letter_capacity(&mut self, capacity: usize) {
+        self.dead_letter_capacity = capacity;
+        while self.dead_letters.len() > capacity {
+            self.dead_letters.pop_front();
+        }
+    }
+    
+    /// Get dead letters, oldest first
+    pub fn get_dead_letters(&self) -> Vec<&DeadLetter> {
+        self.dead_letters.iter().collect()
+    }
+    
+    /// Remove and return all dead letters
+    pub fn take_dead_letters(&mut self) -> Vec<DeadLetter> {
+        self.dead_letters.drain(..).collect()
+    }
+    
+    /// Retry delivery of all dead letters, returns the number delivered
+    ///
+    /// Messages that fail again are put back with their attempt count increased.
+    pub fn reprocess_dead_letters(&mut self) -> usize {
+        let mut delivered = 0;
+        
+        for dead_letter in self.take_dead_letters() {
+            if let Ok(true) = self.deliver(dead_letter.message, dead_letter.attempts + 1, dead_letter.recorded) {
+                delivered += 1;
+            }
+        }
+        
+        delivered
     }
     
     /// Register message handler
@@ -187,9 +384,32 @@ impl IntegrationInterface {
         F: Fn(&IntegrationMessage) -> Result<(), IntegrationError> + Send + Sync + 'static,
     {
         self.message_handlers.insert(destination.to_string(), Box::new(handler));
+        self.processed_ids.remove(destination);
+        Ok(())
+    }
+    
+    /// Register an idempotent message handler
+    ///
+    /// The handler runs at most once per message ID among the last
+    /// `dedup_window` messages it processed, so replays and retries of
+    /// already-handled messages are skipped.
+    pub fn register_idempotent_handler<F>(&mut self, destination: &str, handler: F) -> Result<(), IntegrationError>
+    where
+        F: Fn(&IntegrationMessage) -> Result<(), IntegrationError> + Send + Sync + 'static,
+    {
+        self.message_handlers.insert(destination.to_string(), Box::new(handler));
+        self.processed_ids.insert(destination.to_string(), ProcessedIds::default());
         Ok(())
     }
     
+    /// Set number of processed message IDs remembered per idempotent destination
+    pub fn set_dedup_window(&mut self, window: usize) {
+        self.dedup_window = window;
+        for processed in self.processed_ids.values_mut() {
+            processed.trim(window);
+        }
+    }
+    
     /// Get messages by source
     pub fn get_messages_by_source(&self, source: &str) -> Vec<&IntegrationMessage> {
         self.messages.iter()
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        DeadLetterReason, IntegrationEndpoint, IntegrationError, IntegrationInterface,
+        IntegrationMessage, IntegrationProtocol, IdleDetector, IdleTransition
+    };
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, SystemTime};
+
+    fn interface_with_endpoint(name: &str) -> IntegrationInterface {
+        let mut interface = IntegrationInterface::new().unwrap();
+        interface.add_endpoint(IntegrationEndpoint::new(name, "local://bus", IntegrationProtocol::Custom)).unwrap();
+        interface
+    }
+
+    #[test]
+    fn test_message_without_subscriber_is_dead_lettered() {
+        let mut interface = interface_with_endpoint("planning");
+        let message = IntegrationMessage::new("reasoning", "planning", b"goal".to_vec());
+        let message_id = message.id.clone();
+
+        interface.send_message(message).unwrap();
+
+        let dead_letters = interface.get_dead_letters();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].message.id, message_id);
+        assert_eq!(dead_letters[0].reason, DeadLetterReason::NoSubscriber);
+        assert_eq!(dead_letters[0].attempts, 1);
+        // Still recorded in history, as before dead-lettering existed
+        let history = interface.get_messages_by_destination("planning");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, message_id);
+    }
+
+    #[test]
+    fn test_handler_error_is_dead_lettered() {
+        let mut interface = interface_with_endpoint("planning");
+        interface.register_message_handler("planning", |_| {
+            Err(IntegrationError::CommunicationError("planner busy".to_string()))
+        }).unwrap();
+
+        let result = interface.send_message(IntegrationMessage::new("reasoning", "planning", Vec::new()));
+
+        assert!(result.is_err());
+        match &interface.get_dead_letters()[0].reason {
+            DeadLetterReason::HandlerError(msg) => assert!(msg.contains("planner busy")),
+            other => panic!("expected handler error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_reprocess_dead_letters_after_subscribing() {
+        let mut interface = interface_with_endpoint("planning");
+        interface.send_message(IntegrationMessage::new("reasoning", "planning", Vec::new())).unwrap();
+        assert!(interface.send_message(IntegrationMessage::new("reasoning", "unknown", Vec::new())).is_err());
+        assert_eq!(interface.get_dead_letters().len(), 2);
+
+        interface.register_message_handler("planning", |_| Ok(())).unwrap();
+
+        // Recorded once when sent, not again when redelivered
+        assert_eq!(interface.get_messages_by_destination("planning").len(), 1);
+        assert_eq!(interface.reprocess_dead_letters(), 1);
+        assert_eq!(interface.get_messages_by_destination("planning").len(), 1);
+
+        let remaining = interface.get_dead_letters();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].reason, DeadLetterReason::UnknownDestination);
+        assert_eq!(remaining[0].attempts, 2);
+    }
+
+    #[test]
+    fn test_dead_letter_capacity_drops_oldest() {
+        let mut interface = interface_with_endpoint("planning");
+        interface.set_dead_letter_capacity(2);
+
+        let ids: Vec<String> = (0..3)
+            .map(|_| {
+                let message = IntegrationMessage::new("reasoning", "planning", Vec::new());
+                let id = message.id.clone();
+                interface.send_message(message).unwrap();
+                id
+            })
+            .collect();
+
+        let dead_letters: Vec<&str> = interface.get_dead_letters().iter().map(|d| d.message.id.as_str()).collect();
+        assert_eq!(dead_letters, vec![ids[1].as_str(), ids[2].as_str()]);
+
+        interface.set_dead_letter_capacity(0);
+        interface.send_message(IntegrationMessage::new("reasoning", "planning", Vec::new())).unwrap();
+        assert!(interface.get_dead_letters().is_empty());
+    }
+
+    #[test]
+    fn test_idempotent_handler_runs_once_per_message_id() {
+        let mut interface = interface_with_endpoint("planning");
+        let runs = Arc::new(Mutex::new(0));
+        let counter = runs.clone();
+        interface.register_idempotent_handler("planning", move |_| {
+            *counter.lock().unwrap() += 1;
+            Ok(())
+        }).unwrap();
+
+        let message = IntegrationMessage::new("reasoning", "planning", b"goal".to_vec());
+        interface.send_message(message.clone()).unwrap();
+        interface.send_message(message.clone()).unwrap();
+
+        assert_eq!(*runs.lock().unwrap(), 1);
+        assert_eq!(interface.duplicates_skipped, 1);
+        assert_eq!(interface.get_messages_by_destination("planning").len(), 1);
+
+        // IDs fall out of the bounded window
+        interface.set_dedup_window(1);
+        interface.send_message(IntegrationMessage::new("reasoning", "planning", Vec::new())).unwrap();
+        interface.send_message(message).unwrap();
+        assert_eq!(*runs.lock().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_bus_message_resumes_suspended_detector() {
+        let mut detector = IdleDetector::new(Some(Duration::from_secs(60)), SystemTime::now());
+        let mut interface = interface_with_endpoint("planning");
+        interface.set_activity_signal(detector.activity_signal());
+
+        let later = SystemTime::now() + Duration::from_secs(120);
+        assert_eq!(detector.check(later), IdleTransition::Suspend);
+
+        interface.send_message(IntegrationMessage::new("reasoning", "planning", Vec::new())).unwrap();
+        assert_eq!(detector.poll_activity(), IdleTransition::Resume);
+        assert!(!detector.is_suspended());
+    }
+}
@@ -0,0 +1,402 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+use crate::system::{Metric, MetricType, MonitoringError, MonitoringSystem};
+
+/// End-to-end perception to action latency metric
+pub const METRIC_E2E_LATENCY: &str = "e2e_latency_ms";
+
+/// Default number of completed traces kept for reporting
+pub const DEFAULT_MAX_COMPLETED_TRACES: usize = 1024;
+
+/// Default number of open traces kept; the oldest is dropped beyond this
+pub const DEFAULT_MAX_OPEN_TRACES: usize = 1024;
+
+/// Default age after which an open trace is expired
+pub const DEFAULT_TRACE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Latency error
+#[derive(Debug)]
+pub enum LatencyError {
+    /// No open trace with the correlation ID
+    UnknownRequest(String),
+    /// Stage marked out of pipeline order
+    OutOfOrder(String),
+    /// Other error
+    Other(String),
+}
+
+impl Error for LatencyError {}
+
+impl fmt::Display for LatencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LatencyError::UnknownRequest(msg) => write!(f, "Unknown request: {}", msg),
+            LatencyError::OutOfOrder(msg) => write!(f, "Out of order: {}", msg),
+            LatencyError::Other(msg) => write!(f, "Other error: {}", msg),
+        }
+    }
+}
+
+/// Request ingress modality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ingress {
+    /// Speech input
+    Speech,
+    /// Vision input
+    Vision,
+    /// Text input
+    Text,
+}
+
+impl fmt::Display for Ingress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ingress::Speech => write!(f, "speech"),
+            Ingress::Vision => write!(f, "vision"),
+            Ingress::Text => write!(f, "text"),
+        }
+    }
+}
+
+/// Pipeline stage, in pipeline order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatencyStage {
+    /// Natural language processing
+    Nlp,
+    /// Reasoning
+    Reasoning,
+    /// Planning
+    Planning,
+    /// First executed action
+    Action,
+}
+
+impl LatencyStage {
+    /// All stages in pipeline order
+    pub const ALL: [LatencyStage; 4] = [
+        LatencyStage::Nlp,
+        LatencyStage::Reasoning,
+        LatencyStage::Planning,
+        LatencyStage::Action,
+    ];
+}
+
+impl fmt::Display for LatencyStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LatencyStage::Nlp => write!(f, "nlp"),
+            LatencyStage::Reasoning => write!(f, "reasoning"),
+            LatencyStage::Planning => write!(f, "planning"),
+            LatencyStage::Action => write!(f, "action"),
+        }
+    }
+}
+
+/// Open request trace
+#[derive(Debug, Clone)]
+struct OpenTrace {
+    /// Ingress modality
+    ingress: Ingress,
+    /// Ingress time
+    started_at: Instant,
+    /// Stage completion times, in pipeline order
+    marks: Vec<(LatencyStage, Instant)>,
+}
+
+/// Completed request trace
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletedTrace {
+    /// Correlation ID
+    pub correlation_id: String,
+    /// Ingress modality
+    pub ingress: Ingress,
+    /// Time spent in each stage, from the previous mark to this one
+    pub stages: Vec<(LatencyStage, Duration)>,
+    /// Time from ingress to the first executed action
+    pub total: Duration,
+}
+
+impl CompletedTrace {
+    /// Get time spent in a stage, if the request passed through it
+    pub fn stage(&self, stage: LatencyStage) -> Option<Duration> {
+        self.stages.iter().find(|(s, _)| *s == stage).map(|(_, d)| *d)
+    }
+}
+
+/// Latency distribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatencyDistribution {
+    /// Number of samples
+    pub count: usize,
+    /// Mean latency
+    pub mean: Duration,
+    /// Median latency
+    pub p50: Duration,
+    /// 95th percentile latency
+    pub p95: Duration,
+    /// Maximum latency
+    pub max: Duration,
+}
+
+impl LatencyDistribution {
+    /// Compute distribution of samples
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let percentile = |p: usize| sorted[((sorted.len() * p).div_ceil(100)).saturating_sub(1)];
+        let sum: Duration = sorted.iter().sum();
+
+        Self {
+            count: sorted.len(),
+            mean: sum / sorted.len() as u32,
+            p50: percentile(50),
+            p95: percentile(95),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// End-to-end latency report
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyReport {
+    /// Latency per stage
+    pub stages: BTreeMap<LatencyStage, LatencyDistribution>,
+    /// Ingress to first action latency
+    pub total: LatencyDistribution,
+}
+
+impl LatencyReport {
+    /// Render the report as Markdown
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# End-to-end latency");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "| stage | count | mean | p50 | p95 | max |");
+        let _ = writeln!(out, "|---|---|---|---|---|---|");
+        let rows = self.stages.iter()
+            .map(|(stage, dist)| (stage.to_string(), dist))
+            .chain(std::iter::once(("total".to_string(), &self.total)));
+        for (name, dist) in rows {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {:?} | {:?} | {:?} | {:?} |",
+                name, dist.count, dist.mean, dist.p50, dist.p95, dist.max
+            );
+        }
+        out
+    }
+}
+
+/// Perception to action latency tracer
+///
+/// A request is traced from ingress through NLP, reasoning and planning to
+/// its first executed action under a correlation ID that each subsystem
+/// passes along. Each stage is timed from the previous mark, so stage
+/// timings of a completed trace always sum to its total. Stages a request
+/// skips are simply absent from its trace.
+///
+/// Requests that never reach an action would otherwise stay open forever,
+/// so open traces are capped at `max_open` and dropped by `expire` once
+/// older than `trace_timeout`.
+pub struct LatencyTracer {
+    /// Open traces by correlation ID
+    open: HashMap<String, OpenTrace>,
+    /// Completed traces, oldest first
+    completed: VecDeque<CompletedTrace>,
+    /// Maximum number of completed traces kept
+    max_completed: usize,
+    /// Maximum number of open traces kept
+    max_open: usize,
+    /// Age after which an open trace is expired
+    trace_timeout: Duration,
+}
+
+impl Default for LatencyTracer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_COMPLETED_TRACES)
+    }
+}
+
+impl LatencyTracer {
+    /// Create a new tracer keeping up to `max_completed` completed traces
+    pub fn new(max_completed: usize) -> Self {
+        Self {
+            open: HashMap::new(),
+            completed: VecDeque::new(),
+            max_completed: max_completed.max(1),
+            max_open: DEFAULT_MAX_OPEN_TRACES,
+            trace_timeout: DEFAULT_TRACE_TIMEOUT,
+        }
+    }
+
+    /// Set maximum number of open traces
+    pub fn set_max_open(&mut self, max_open: usize) {
+        self.max_open = max_open.max(1);
+    }
+
+    /// Set age after which an open trace is expired
+    pub fn set_trace_timeout(&mut self, timeout: Duration) {
+        self.trace_timeout = timeout;
+    }
+
+    /// Start tracing a request, returning its correlation ID
+    pub fn begin(&mut self, ingress: Ingress) -> String {
+        self.begin_at(ingress, Instant::now())
+    }
+
+    /// Start tracing a request that arrived at `at`
+    ///
+    /// At `max_open` open traces, the one that started first is dropped.
+    pub fn begin_at(&mut self, ingress: Ingress, at: Instant) -> String {
+        if self.open.len() >= self.max_open {
+            let oldest = self.open.iter()
+                .min_by_key(|(_, trace)| trace.started_at)
+                .map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                self.open.remove(&oldest);
+            }
+        }
+
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        self.open.insert(correlation_id.clone(), OpenTrace {
+            ingress,
+            started_at: at,
+            marks: Vec::new(),
+        });
+        correlation_id
+    }
+
+    /// Mark a stage as completed now
+    pub fn mark(&mut self, correlation_id: &str, stage: LatencyStage) -> Result<Option<CompletedTrace>, LatencyError> {
+        self.mark_at(correlation_id, stage, Instant::now())
+    }
+
+    /// Mark a stage as completed at `at`
+    ///
+    /// Marking `Action` completes the trace and returns it.
+    pub fn mark_at(&mut self, correlation_id: &str, stage: LatencyStage, at: Instant) -> Result<Option<CompletedTrace>, LatencyError> {
+        let trace = self.open.get_mut(correlation_id).ok_or_else(|| {
+            LatencyError::UnknownRequest(correlation_id.to_string())
+        })?;
+
+        let (previous_stage, previous_at) = match trace.marks.last() {
+            Some((s, t)) => (Some(*s), *t),
+            None => (None, trace.started_at),
+        };
+        if previous_stage.is_some_and(|s| s >= stage) || at < previous_at {
+            return Err(LatencyError::OutOfOrder(format!(
+                "{} marked after {} for request {}",
+                stage,
+                previous_stage.map_or("ingress".to_string(), |s| s.to_string()),
+                correlation_id
+            )));
+        }
+
+        trace.marks.push((stage, at));
+        if stage != LatencyStage::Action {
+            return Ok(None);
+        }
+
+        let trace = self.open.remove(correlation_id).expect("trace is open");
+        let mut stages = Vec::with_capacity(trace.marks.len());
+        let mut previous_at = trace.started_at;
+        for (stage, at) in &trace.marks {
+            stages.push((*stage, at.duration_since(previous_at)));
+            previous_at = *at;
+        }
+
+        let completed = CompletedTrace {
+            correlation_id: correlation_id.to_string(),
+            ingress: trace.ingress,
+            stages,
+            total: previous_at.duration_since(trace.started_at),
+        };
+
+        if self.completed.len() >= self.max_completed {
+            self.completed.pop_front();
+        }
+        self.completed.push_back(completed.clone());
+        Ok(Some(completed))
+    }
+
+    /// Abandon a request that will never reach an action
+    pub fn abandon(&mut self, correlation_id: &str) -> bool {
+        self.open.remove(correlation_id).is_some()
+    }
+
+    /// Drop open traces older than `trace_timeout`, returning how many were dropped
+    pub fn expire(&mut self) -> usize {
+        self.expire_at(Instant::now())
+    }
+
+    /// Drop open traces older than `trace_timeout` at `now`
+    pub fn expire_at(&mut self, now: Instant) -> usize {
+        let before = self.open.len();
+        let timeout = self.trace_timeout;
+        self.open.retain(|_, trace| now.saturating_duration_since(trace.started_at) < timeout);
+        before - self.open.len()
+    }
+
+    /// Get number of open traces
+    pub fn open_count(&self) -> usize {
+        self.open.len()
+    }
+
+    /// Get completed traces, oldest first
+    pub fn completed(&self) -> impl Iterator<Item = &CompletedTrace> {
+        self.completed.iter()
+    }
+
+    /// Compute per-stage and total latency distributions of completed traces
+    pub fn report(&self) -> LatencyReport {
+        let mut stages = BTreeMap::new();
+        for stage in LatencyStage::ALL {
+            let samples: Vec<Duration> = self.completed.iter().filter_map(|t| t.stage(stage)).collect();
+            if !samples.is_empty() {
+                stages.insert(stage, LatencyDistribution::from_samples(&samples));
+            }
+        }
+
+        let totals: Vec<Duration> = self.completed.iter().map(|t| t.total).collect();
+        LatencyReport {
+            stages,
+            total: LatencyDistribution::from_samples(&totals),
+        }
+    }
+
+    /// Record a completed trace's total latency to the monitoring system
+    pub fn record_metric(trace: &CompletedTrace, monitoring: &mut MonitoringSystem) -> Result<(), MonitoringError> {
+        monitoring.add_metric(Metric::new(
+            METRIC_E2E_LATENCY,
+            MetricType::Custom,
+            trace.total.as_secs_f64() * 1000.0,
+            "ms",
+        ))
+    }
+}
+
+/// Initialize latency module
+pub fn init() -> Result<(), LatencyError> {
+    // Initialize latency module
+    Ok(())
+}
+
+/// Start latency module
+pub fn start() -> Result<(), LatencyError> {
+    // Start latency module
+    Ok(())
+}
+
+/// Stop latency module
+pub fn stop() -> Result<(), LatencyError> {
+    // Stop latency module
+    Ok(())
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::latency::{Ingress, LatencyError, LatencyStage, LatencyTracer};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_synthetic_request_stage_timings_sum_to_total() {
+        let mut tracer = LatencyTracer::default();
+        let id = tracer.begin(Ingress::Speech);
+
+        // Drive the request through each stage
+        let mut completed = None;
+        for stage in LatencyStage::ALL {
+            std::thread::sleep(Duration::from_millis(2));
+            completed = tracer.mark(&id, stage).unwrap();
+        }
+
+        let trace = completed.expect("action completes the trace");
+        assert_eq!(trace.correlation_id, id);
+        assert_eq!(trace.ingress, Ingress::Speech);
+        assert_eq!(trace.stages.iter().map(|(s, _)| *s).collect::<Vec<_>>(), LatencyStage::ALL.to_vec());
+        assert!(trace.stages.iter().all(|(_, d)| *d >= Duration::from_millis(2)));
+        assert_eq!(trace.stages.iter().map(|(_, d)| *d).sum::<Duration>(), trace.total);
+        assert_eq!(tracer.open_count(), 0);
+
+        let report = tracer.report();
+        assert_eq!(report.total.count, 1);
+        assert_eq!(report.total.max, trace.total);
+        assert_eq!(report.stages.values().map(|d| d.mean).sum::<Duration>(), report.total.mean);
+        assert!(report.render().contains("| planning | 1 |"));
+    }
+
+    #[test]
+    fn test_latency_report_distribution() {
+        let mut tracer = LatencyTracer::default();
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+
+        for i in 1..=20u64 {
+            let id = tracer.begin_at(Ingress::Text, start);
+            // Requests that need no reasoning skip that stage
+            tracer.mark_at(&id, LatencyStage::Nlp, start + ms(i)).unwrap();
+            tracer.mark_at(&id, LatencyStage::Planning, start + ms(i + 10)).unwrap();
+            tracer.mark_at(&id, LatencyStage::Action, start + ms(i + 15)).unwrap();
+        }
+
+        let report = tracer.report();
+        assert!(!report.stages.contains_key(&LatencyStage::Reasoning));
+        assert_eq!(report.stages[&LatencyStage::Planning].mean, ms(10));
+        assert_eq!(report.stages[&LatencyStage::Action].max, ms(5));
+        assert_eq!(report.stages[&LatencyStage::Nlp].p50, ms(10));
+        assert_eq!(report.stages[&LatencyStage::Nlp].p95, ms(19));
+        assert_eq!(report.total.count, 20);
+        assert_eq!(report.total.max, ms(35));
+    }
+
+    #[test]
+    fn test_latency_tracer_rejects_out_of_order_marks() {
+        let mut tracer = LatencyTracer::default();
+        let id = tracer.begin(Ingress::Vision);
+
+        tracer.mark(&id, LatencyStage::Reasoning).unwrap();
+        assert!(matches!(tracer.mark(&id, LatencyStage::Nlp), Err(LatencyError::OutOfOrder(_))));
+        assert!(matches!(tracer.mark("missing", LatencyStage::Nlp), Err(LatencyError::UnknownRequest(_))));
+
+        assert!(tracer.abandon(&id));
+        assert_eq!(tracer.open_count(), 0);
+        assert_eq!(tracer.report().total.count, 0);
+    }
+
+    #[test]
+    fn test_open_traces_expire_after_timeout() {
+        let mut tracer = LatencyTracer::default();
+        tracer.set_trace_timeout(Duration::from_secs(30));
+        let start = Instant::now();
+
+        let stale = tracer.begin_at(Ingress::Vision, start);
+        let fresh = tracer.begin_at(Ingress::Text, start + Duration::from_secs(20));
+        assert_eq!(tracer.expire_at(start + Duration::from_secs(29)), 0);
+
+        assert_eq!(tracer.expire_at(start + Duration::from_secs(30)), 1);
+        assert_eq!(tracer.open_count(), 1);
+        assert!(matches!(tracer.mark(&stale, LatencyStage::Nlp), Err(LatencyError::UnknownRequest(_))));
+        assert!(tracer.mark_at(&fresh, LatencyStage::Nlp, start + Duration::from_secs(31)).is_ok());
+    }
+
+    #[test]
+    fn test_open_traces_are_capped() {
+        let mut tracer = LatencyTracer::default();
+        tracer.set_max_open(2);
+        let start = Instant::now();
+
+        let first = tracer.begin_at(Ingress::Speech, start);
+        let second = tracer.begin_at(Ingress::Speech, start + Duration::from_millis(1));
+        let third = tracer.begin_at(Ingress::Speech, start + Duration::from_millis(2));
+
+        assert_eq!(tracer.open_count(), 2);
+        assert!(!tracer.abandon(&first));
+        assert!(tracer.abandon(&second));
+        assert!(tracer.abandon(&third));
+    }
+}
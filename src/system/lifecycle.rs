@@ -1,5 +1,38 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Default drain grace period
+pub const DEFAULT_DRAIN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Delay before polling a busy component again during drain
+const DRAIN_POLL_INITIAL_BACKOFF: Duration = Duration::from_millis(1);
+
+/// Upper bound on the delay between drain polls
+const DRAIN_POLL_MAX_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Default maximum number of watchdog restarts per component
+pub const DEFAULT_MAX_RESTARTS: u32 = 5;
+
+/// Default delay before the first watchdog restart
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Default upper bound on the delay between watchdog restarts
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Default time a restarted component must stay healthy before its restart count resets
+pub const DEFAULT_RESTART_RESET_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Default interval between health check polls
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default number of re-initializations of poisoned critical state before halting
+pub const DEFAULT_MAX_REINIT_ATTEMPTS: u32 = 3;
 
 /// Lifecycle error
 #[derive(Debug)]
@@ -10,6 +43,12 @@ pub enum LifecycleError {
     StartError(String),
     /// Stop error
     StopError(String),
+    /// Wiring error
+    WiringError(String),
+    /// Critical state is corrupted and could not be re-initialized
+    StateCorrupted(String),
+    /// Supervised component kept failing after its allowed restarts
+    RestartsExhausted(String),
     /// Other error
     Other(String),
 }
@@ -22,6 +61,9 @@ impl fmt::Display for LifecycleError {
             LifecycleError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             LifecycleError::StartError(msg) => write!(f, "Start error: {}", msg),
             LifecycleError::StopError(msg) => write!(f, "Stop error: {}", msg),
+            LifecycleError::WiringError(msg) => write!(f, "Wiring error: {}", msg),
+            LifecycleError::StateCorrupted(msg) => write!(f, "State corrupted: {}", msg),
+            LifecycleError::RestartsExhausted(msg) => write!(f, "Restarts exhausted: {}", msg),
             LifecycleError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -40,12 +82,18 @@ pub enum LifecycleState {
     Starting,
     /// Running
     Running,
+    /// Running without some non-critical components
+    Degraded,
+    /// Draining
+    Draining,
     /// Stopping
     Stopping,
     /// Stopped
     Stopped,
     /// Error
     Error,
+    /// Halted after critical state was found corrupted
+    Halted,
 }
 
 impl fmt::Display for LifecycleState {
@@ -56,9 +104,12 @@ impl fmt::Display for LifecycleState {
             LifecycleState::Initialized => write!(f, "Initialized"),
             LifecycleState::Starting => write!(f, "Starting"),
             LifecycleState::Running => write!(f, "Running"),
+            LifecycleState::Degraded => write!(f, "Degraded"),
+            LifecycleState::Draining => write!(f, "Draining"),
             LifecycleState::Stopping => write!(f, "Stopping"),
             LifecycleState::Stopped => write!(f, "Stopped"),
             LifecycleState::Error => write!(f, "Error"),
+            LifecycleState::Halted => write!(f, "Halted"),
         }
     }
 }
@@ -79,6 +130,307 @@ pub trait LifecycleComponent: Send + Sync {
     
     /// Get component state
     fn state(&self) -> LifecycleState;
+    
+    /// Get the number of pending work items
+    fn pending_work(&self) -> usize {
+        0
+    }
+    
+    /// Finish or checkpoint pending work, returns true once nothing is left
+    fn drain(&mut self) -> Result<bool, LifecycleError> {
+        Ok(self.pending_work() == 0)
+    }
+    
+    /// Flush buffered logs and metrics
+    fn flush(&mut self) -> Result<(), LifecycleError> {
+        Ok(())
+    }
+    
+    /// Do periodic work
+    fn update(&mut self) -> Result<(), LifecycleError> {
+        Ok(())
+    }
+}
+
+/// Component wiring
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentWiring {
+    /// Component name
+    pub name: String,
+    /// Names of components this component depends on
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Whether a failure of this component aborts startup
+    #[serde(default = "default_critical")]
+    pub critical: bool,
+}
+
+fn default_critical() -> bool {
+    true
+}
+
+/// Component that failed and is unavailable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnavailableComponent {
+    /// Component name
+    pub name: String,
+    /// Failure reason
+    pub reason: String,
+}
+
+/// Watchdog restart policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Restarts allowed before the component is given up on
+    pub max_restarts: u32,
+    /// Delay before the first restart
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between restarts
+    pub max_backoff: Duration,
+    /// Time a restarted component must stay healthy before its record is reset
+    pub reset_after: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            reset_after: DEFAULT_RESTART_RESET_INTERVAL,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Get the delay before a restart, doubling with each previous restart
+    pub fn backoff(&self, restarts: u32) -> Duration {
+        let factor = 1u32.checked_shl(restarts).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// Supervision policy for a component whose update fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SupervisionPolicy {
+    /// Propagate the failure
+    #[default]
+    Never,
+    /// Restart after `backoff` on every failure
+    Always {
+        /// Delay before each restart
+        backoff: Duration,
+    },
+    /// Restart after `backoff` up to `retries` consecutive times
+    MaxRetries {
+        /// Consecutive restarts allowed
+        retries: u32,
+        /// Delay before each restart
+        backoff: Duration,
+    },
+}
+
+/// Watchdog or supervisor restart record of a component
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RestartRecord {
+    /// Number of restarts attempted
+    pub restarts: u32,
+    /// Backoff waited before each restart
+    pub backoffs: Vec<Duration>,
+    /// Time the component first failed since the last restart
+    pub failed_at: Option<Instant>,
+    /// Time of the last restart
+    pub restarted_at: Option<Instant>,
+    /// Time a stopped component is due to be restarted by the supervisor
+    pub next_restart_at: Option<Instant>,
+    /// Whether the watchdog gave up on the component
+    pub given_up: bool,
+}
+
+/// Watchdog finding severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FindingSeverity {
+    /// Warning
+    Warning,
+    /// Critical
+    Critical,
+}
+
+impl fmt::Display for FindingSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FindingSeverity::Warning => write!(f, "Warning"),
+            FindingSeverity::Critical => write!(f, "Critical"),
+        }
+    }
+}
+
+/// Watchdog finding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchdogFinding {
+    /// Component name
+    pub component: String,
+    /// Finding severity
+    pub severity: FindingSeverity,
+    /// Finding message
+    pub message: String,
+}
+
+/// Health status reported by a health check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// Working normally
+    Healthy,
+    /// Working with reduced capability
+    Degraded(String),
+    /// Not working, e.g. wedged or disconnected
+    Unhealthy(String),
+}
+
+impl HealthStatus {
+    /// Check if healthy
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, HealthStatus::Healthy)
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Healthy => write!(f, "Healthy"),
+            HealthStatus::Degraded(reason) => write!(f, "Degraded: {}", reason),
+            HealthStatus::Unhealthy(reason) => write!(f, "Unhealthy: {}", reason),
+        }
+    }
+}
+
+/// Health check of a subsystem
+pub trait HealthCheck: Send + Sync {
+    /// Check subsystem health
+    fn check(&self) -> HealthStatus;
+}
+
+/// Declarative wiring of the subsystem dependency graph
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WiringConfig {
+    /// Components
+    pub components: Vec<ComponentWiring>,
+}
+
+impl WiringConfig {
+    /// Create a new wiring config
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+    
+    /// Add component
+    pub fn add_component(&mut self, name: &str, dependencies: &[&str]) {
+        self.components.push(ComponentWiring {
+            name: name.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            critical: true,
+        });
+    }
+    
+    /// Mark a component as critical or non-critical
+    pub fn set_critical(&mut self, name: &str, critical: bool) -> Result<(), LifecycleError> {
+        let component = self.components.iter_mut().find(|c| c.name == name).ok_or_else(|| {
+            LifecycleError::WiringError(format!("Unknown component {}", name))
+        })?;
+        
+        component.critical = critical;
+        Ok(())
+    }
+    
+    /// Parse wiring config from JSON
+    pub fn from_json(json: &str) -> Result<Self, LifecycleError> {
+        let config: WiringConfig = serde_json::from_str(json).map_err(|e| {
+            LifecycleError::WiringError(format!("Failed to parse wiring config: {}", e))
+        })?;
+        
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Load wiring config from file
+    pub fn load(path: &str) -> Result<Self, LifecycleError> {
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            LifecycleError::WiringError(format!("Failed to read wiring config {}: {}", path, e))
+        })?;
+        
+        Self::from_json(&json)
+    }
+    
+    /// Get dependencies of a component
+    pub fn get_dependencies(&self, name: &str) -> Option<&[String]> {
+        self.components.iter()
+            .find(|c| c.name == name)
+            .map(|c| c.dependencies.as_slice())
+    }
+    
+    /// Validate wiring, rejecting duplicate, missing and circular dependencies
+    pub fn validate(&self) -> Result<(), LifecycleError> {
+        self.start_order().map(|_| ())
+    }
+    
+    /// Get component start order, dependencies first
+    pub fn start_order(&self) -> Result<Vec<String>, LifecycleError> {
+        let mut names = HashSet::new();
+        for component in &self.components {
+            if !names.insert(component.name.as_str()) {
+                return Err(LifecycleError::WiringError(format!(
+                    "Duplicate component {}",
+                    component.name
+                )));
+            }
+        }
+        
+        for component in &self.components {
+            for dependency in &component.dependencies {
+                if !names.contains(dependency.as_str()) {
+                    return Err(LifecycleError::WiringError(format!(
+                        "Component {} depends on missing component {}",
+                        component.name,
+                        dependency
+                    )));
+                }
+            }
+        }
+        
+        // Repeatedly take components whose dependencies are all placed,
+        // keeping declaration order so the result is deterministic
+        let mut order: Vec<String> = Vec::new();
+        let mut placed: HashSet<&str> = HashSet::new();
+        
+        while order.len() < self.components.len() {
+            let ready: Vec<&ComponentWiring> = self.components.iter()
+                .filter(|c| !placed.contains(c.name.as_str()))
+                .filter(|c| c.dependencies.iter().all(|d| placed.contains(d.as_str())))
+                .collect();
+            
+            if ready.is_empty() {
+                let cycle: Vec<&str> = self.components.iter()
+                    .filter(|c| !placed.contains(c.name.as_str()))
+                    .map(|c| c.name.as_str())
+                    .collect();
+                return Err(LifecycleError::WiringError(format!(
+                    "Circular dependency involving components: {}",
+                    cycle.join(", ")
+                )));
+            }
+            
+            for component in ready {
+                placed.insert(component.name.as_str());
+                order.push(component.name.clone());
+            }
+        }
+        
+        Ok(order)
+    }
 }
 
 /// Component reference wrapper
@@ -93,6 +445,137 @@ impl<'a> ComponentRef<'a> {
     }
 }
 
+/// Critical state re-initializer
+type Reinitializer<T> = Box<dyn Fn() -> Result<T, LifecycleError> + Send + Sync>;
+
+/// Mutex-guarded state of a critical subsystem
+///
+/// A panic while the lock is held leaves the state unknown. Instead of
+/// reading through the poisoned lock, `with` replaces the state with a
+/// freshly initialized value, trying at most `max_reinit_attempts` times
+/// with no delay in between. If every attempt fails the state is marked
+/// halted and all further access fails with `StateCorrupted`.
+pub struct CriticalState<T> {
+    /// Subsystem name
+    name: String,
+    /// Guarded state
+    state: Mutex<T>,
+    /// Builds a fresh state
+    reinit: Reinitializer<T>,
+    /// Maximum re-initializations per poisoning
+    max_reinit_attempts: u32,
+    /// Total re-initializations attempted
+    reinit_attempts: AtomicU32,
+    /// Whether recovery failed
+    halted: AtomicBool,
+}
+
+impl<T> CriticalState<T> {
+    /// Create guarded critical state
+    pub fn new<F>(name: &str, initial: T, reinit: F) -> Self
+    where
+        F: Fn() -> Result<T, LifecycleError> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            state: Mutex::new(initial),
+            reinit: Box::new(reinit),
+            max_reinit_attempts: DEFAULT_MAX_REINIT_ATTEMPTS,
+            reinit_attempts: AtomicU32::new(0),
+            halted: AtomicBool::new(false),
+        }
+    }
+    
+    /// Set maximum re-initializations per poisoning
+    pub fn with_max_reinit_attempts(mut self, attempts: u32) -> Self {
+        self.max_reinit_attempts = attempts;
+        self
+    }
+    
+    /// Get subsystem name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    /// Check if the lock is poisoned and awaiting re-initialization
+    pub fn is_poisoned(&self) -> bool {
+        self.state.is_poisoned()
+    }
+    
+    /// Get total re-initializations attempted
+    pub fn reinit_attempts(&self) -> u32 {
+        self.reinit_attempts.load(Ordering::SeqCst)
+    }
+    
+    /// Check if recovery failed
+    pub fn is_halted(&self) -> bool {
+        self.halted.load(Ordering::SeqCst)
+    }
+    
+    /// Run `f` on the state, re-initializing it first if the lock is poisoned
+    pub fn with<R, F>(&self, f: F) -> Result<R, LifecycleError>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        if self.is_halted() {
+            return Err(self.corrupted());
+        }
+        
+        let mut guard = match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => self.recover(poisoned.into_inner())?,
+        };
+        Ok(f(&mut guard))
+    }
+    
+    /// Replace poisoned state with a fresh value, or halt
+    fn recover<'a>(&'a self, mut guard: MutexGuard<'a, T>) -> Result<MutexGuard<'a, T>, LifecycleError> {
+        for attempt in 1..=self.max_reinit_attempts {
+            self.reinit_attempts.fetch_add(1, Ordering::SeqCst);
+            match (self.reinit)() {
+                Ok(fresh) => {
+                    *guard = fresh;
+                    self.state.clear_poison();
+                    println!("[LIFECYCLE] Re-initialized poisoned state of {} (attempt {})", self.name, attempt);
+                    return Ok(guard);
+                },
+                Err(e) => {
+                    println!("[LIFECYCLE] Re-initializing {} failed (attempt {}): {}", self.name, attempt, e);
+                },
+            }
+        }
+        
+        self.halted.store(true, Ordering::SeqCst);
+        Err(self.corrupted())
+    }
+    
+    /// Error for corrupted state
+    fn corrupted(&self) -> LifecycleError {
+        LifecycleError::StateCorrupted(format!(
+            "{} lock poisoned and {} re-initialization attempts failed",
+            self.name, self.max_reinit_attempts
+        ))
+    }
+}
+
+impl<T: Send> HealthCheck for CriticalState<T> {
+    fn check(&self) -> HealthStatus {
+        if self.is_halted() {
+            HealthStatus::Unhealthy(self.corrupted().to_string())
+        } else if self.is_poisoned() {
+            HealthStatus::Degraded(format!("{} lock poisoned, re-initializing on next access", self.name))
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+}
+
+impl<H: HealthCheck + ?Sized> HealthCheck for Arc<H> {
+    fn check(&self) -> HealthStatus {
+        (**self).check()
+    }
+}
+
 /// Lifecycle manager
 pub struct LifecycleManager {
     /// Components
@@ -101,6 +584,34 @@ pub struct LifecycleManager {
     pub state: LifecycleState,
     /// State change handlers
     pub state_change_handlers: Vec<Box<dyn Fn(LifecycleState, LifecycleState) -> Result<(), LifecycleError> + Send + Sync>>,
+    /// Drain grace period
+    pub drain_grace_period: Duration,
+    /// Whether new work is accepted
+    pub accepting_work: bool,
+    /// Components force-stopped during the last drain
+    pub force_stopped: Vec<String>,
+    /// Component dependencies
+    pub dependencies: HashMap<String, Vec<String>>,
+    /// Components allowed to fail without aborting startup
+    pub non_critical: HashSet<String>,
+    /// Non-critical components that failed and are unavailable
+    pub unavailable: Vec<UnavailableComponent>,
+    /// Watchdog restart policy
+    pub restart_policy: RestartPolicy,
+    /// Watchdog restart records by component
+    pub restart_records: HashMap<String, RestartRecord>,
+    /// Components in the order they were started
+    pub started_order: Vec<String>,
+    /// Registered health checks by name
+    pub health_checks: Vec<(String, Box<dyn HealthCheck>)>,
+    /// Interval between health check polls
+    pub health_check_interval: Duration,
+    /// Time of the last health check poll
+    pub last_health_check: Option<Instant>,
+    /// Supervision policies by component
+    pub supervision_policies: HashMap<String, SupervisionPolicy>,
+    /// Supervised restarts since the last successful update, by component
+    pub supervision_records: HashMap<String, RestartRecord>,
 }
 
 impl LifecycleManager {
@@ -110,6 +621,20 @@ impl LifecycleManager {
             components: Vec::new(),
             state: LifecycleState::Uninitialized,
             state_change_handlers: Vec::new(),
+            drain_grace_period: DEFAULT_DRAIN_GRACE_PERIOD,
+            accepting_work: false,
+            force_stopped: Vec::new(),
+            dependencies: HashMap::new(),
+            non_critical: HashSet::new(),
+            unavailable: Vec::new(),
+            restart_policy: RestartPolicy::default(),
+            restart_records: HashMap::new(),
+            started_order: Vec::new(),
+            health_checks: Vec::new(),
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            last_health_check: None,
+            supervision_policies: HashMap::new(),
+            supervision_records: HashMap::new(),
         })
     }
     
@@ -122,19 +647,343 @@ impl LifecycleManager {
         Ok(())
     }
     
+    /// Add component supervised by `policy`
+    pub fn add_supervised_component<T>(&mut self, component: T, policy: SupervisionPolicy) -> Result<(), LifecycleError>
+    where
+        T: LifecycleComponent + 'static,
+    {
+        self.set_supervision_policy(component.name(), policy);
+        self.add_component(component)
+    }
+    
+    /// Set supervision policy of a component
+    pub fn set_supervision_policy(&mut self, name: &str, policy: SupervisionPolicy) {
+        self.supervision_policies.insert(name.to_string(), policy);
+    }
+    
+    /// Get consecutive supervised restarts of a component
+    pub fn get_supervision_retries(&self, name: &str) -> u32 {
+        self.supervision_records.get(name).map_or(0, |record| record.restarts)
+    }
+    
+    /// Get supervision record of a component
+    pub fn get_supervision_record(&self, name: &str) -> Option<&RestartRecord> {
+        self.supervision_records.get(name)
+    }
+    
+    /// Update started components, restarting failed ones per their supervision policy
+    ///
+    /// See `update_at`.
+    pub fn update(&mut self) -> Result<(), LifecycleError> {
+        self.update_at(Instant::now())
+    }
+    
+    /// Update started components at `now`
+    ///
+    /// A failing component under `Never` reports its error and is left to
+    /// the watchdog if it entered the error state. Otherwise it is stopped
+    /// and restarted by a later update once the policy's backoff has
+    /// elapsed; a successful update resets its retry count. Once
+    /// `MaxRetries` is used up the component is marked unavailable and
+    /// `RestartsExhausted` is returned. A failure never keeps the remaining
+    /// components from being updated, and components in the error state are
+    /// skipped.
+    pub fn update_at(&mut self, now: Instant) -> Result<(), LifecycleError> {
+        if self.state != LifecycleState::Running && self.state != LifecycleState::Degraded {
+            return Ok(());
+        }
+        
+        let mut errors = Vec::new();
+        for index in 0..self.components.len() {
+            let name = self.components[index].name().to_string();
+            if !self.started_order.contains(&name) || self.is_unavailable(&name) {
+                continue;
+            }
+            
+            let restart_due = self.supervision_records.get(&name).and_then(|record| record.next_restart_at);
+            let result = match restart_due {
+                Some(due) if now < due => continue,
+                Some(_) => self.restart_supervised(index, now),
+                None if self.components[index].state() == LifecycleState::Error => continue,
+                None => Ok(()),
+            };
+            
+            match result.and_then(|_| self.components[index].update()) {
+                Ok(()) => {
+                    self.supervision_records.remove(&name);
+                },
+                Err(LifecycleError::StateCorrupted(reason)) => {
+                    // Never restart on top of unknown state
+                    self.halt(&reason)?;
+                    return Err(LifecycleError::StateCorrupted(reason));
+                },
+                Err(e) => {
+                    if let Err(e) = self.supervise(index, e, now) {
+                        errors.push(e);
+                    }
+                },
+            }
+        }
+        
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(LifecycleError::Other(
+                errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; ")
+            )),
+        }
+    }
+    
+    /// Stop a component whose update failed and schedule its restart, or give up on it
+    fn supervise(&mut self, index: usize, error: LifecycleError, now: Instant) -> Result<(), LifecycleError> {
+        let name = self.components[index].name().to_string();
+        let (limit, backoff) = match self.supervision_policies.get(&name).copied().unwrap_or_default() {
+            SupervisionPolicy::Never => return Err(error),
+            SupervisionPolicy::Always { backoff } => (None, backoff),
+            SupervisionPolicy::MaxRetries { retries, backoff } => (Some(retries), backoff),
+        };
+        
+        let record = self.supervision_records.entry(name.clone()).or_default();
+        if limit.is_some_and(|limit| record.restarts >= limit) {
+            let message = format!("{} failed after {} restarts: {}", name, record.restarts, error);
+            record.given_up = true;
+            record.next_restart_at = None;
+            self.mark_unavailable(&name, message.clone());
+            if self.state == LifecycleState::Running {
+                self.set_state(LifecycleState::Degraded)?;
+            }
+            return Err(LifecycleError::RestartsExhausted(message));
+        }
+        record.restarts += 1;
+        record.failed_at = Some(now);
+        record.next_restart_at = Some(now + backoff);
+        record.backoffs.push(backoff);
+        
+        if let Err(e) = self.components[index].stop() {
+            println!("[LIFECYCLE] Supervisor: {}: stop before restart failed: {}", name, e);
+        }
+        println!("[LIFECYCLE] Supervisor: {}: restarting in {:?} after update failure: {}", name, backoff, error);
+        
+        Ok(())
+    }
+    
+    /// Restart a component whose scheduled restart is due
+    fn restart_supervised(&mut self, index: usize, now: Instant) -> Result<(), LifecycleError> {
+        let name = self.components[index].name().to_string();
+        let record = self.supervision_records.entry(name.clone()).or_default();
+        record.next_restart_at = None;
+        record.restarted_at = Some(now);
+        let restarts = record.restarts;
+        
+        let component = &mut self.components[index];
+        let result = component.initialize().and_then(|_| component.start());
+        match &result {
+            Ok(()) => println!("[LIFECYCLE] Supervisor: {}: restarted (restart {})", name, restarts),
+            Err(e) => println!("[LIFECYCLE] Supervisor: {}: restart {} failed: {}", name, restarts, e),
+        }
+        result
+    }
+    
+    /// Apply wiring, ordering components so dependencies start first
+    pub fn apply_wiring(&mut self, wiring: &WiringConfig) -> Result<(), LifecycleError> {
+        let order = wiring.start_order()?;
+        
+        for component in &self.components {
+            if wiring.get_dependencies(component.name()).is_none() {
+                return Err(LifecycleError::WiringError(format!(
+                    "Component {} is not declared in wiring config",
+                    component.name()
+                )));
+            }
+        }
+        
+        for name in &order {
+            if !self.components.iter().any(|c| c.name() == name) {
+                return Err(LifecycleError::WiringError(format!(
+                    "Wired component {} is not registered",
+                    name
+                )));
+            }
+        }
+        
+        self.components.sort_by_key(|c| order.iter().position(|n| n == c.name()));
+        self.dependencies = wiring.components.iter()
+            .map(|c| (c.name.clone(), c.dependencies.clone()))
+            .collect();
+        self.non_critical = wiring.components.iter()
+            .filter(|c| !c.critical)
+            .map(|c| c.name.clone())
+            .collect();
+        
+        Ok(())
+    }
+    
+    /// Load wiring config from file and apply it
+    pub fn load_wiring(&mut self, path: &str) -> Result<(), LifecycleError> {
+        let wiring = WiringConfig::load(path)?;
+        self.apply_wiring(&wiring)
+    }
+    
+    /// Get dependencies of a component
+    pub fn get_dependencies(&self, name: &str) -> Vec<&str> {
+        self.dependencies.get(name)
+            .map(|d| d.iter().map(|s| s.as_str()).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Add a dependency between components without a wiring config
+    pub fn add_dependency(&mut self, name: &str, dependency: &str) {
+        let dependencies = self.dependencies.entry(name.to_string()).or_insert_with(Vec::new);
+        if !dependencies.iter().any(|d| d == dependency) {
+            dependencies.push(dependency.to_string());
+        }
+    }
+    
+    /// Check if `to` is reachable from `from` along dependency edges
+    fn depends_on(&self, from: &str, to: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![from];
+        
+        while let Some(name) = stack.pop() {
+            if name == to {
+                return true;
+            }
+            if visited.insert(name) {
+                stack.extend(self.get_dependencies(name));
+            }
+        }
+        
+        false
+    }
+    
+    /// Render the component dependency graph in Graphviz DOT format
+    ///
+    /// Edges point from a component to its dependency. Edges and components
+    /// on a dependency cycle are drawn in red.
+    pub fn dependency_graph_dot(&self) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        
+        // Registered components first, then dependencies that are not registered
+        let mut nodes: Vec<&str> = self.components.iter().map(|c| c.name()).collect();
+        let mut undeclared: Vec<&str> = self.dependencies.iter()
+            .flat_map(|(name, deps)| std::iter::once(name.as_str()).chain(deps.iter().map(|d| d.as_str())))
+            .filter(|name| !nodes.contains(name))
+            .collect();
+        undeclared.sort();
+        undeclared.dedup();
+        nodes.extend(undeclared);
+        
+        let mut dot = String::from("digraph subsystems {\n    rankdir=LR;\n");
+        
+        for node in &nodes {
+            let mut attributes = Vec::new();
+            if self.get_component(node).is_none() {
+                attributes.push("style=dashed");
+            }
+            if self.get_dependencies(node).iter().any(|d| self.depends_on(d, node)) {
+                attributes.push("color=red");
+            }
+            
+            if attributes.is_empty() {
+                dot.push_str(&format!("    {};\n", quote(node)));
+            } else {
+                dot.push_str(&format!("    {} [{}];\n", quote(node), attributes.join(", ")));
+            }
+        }
+        
+        for node in &nodes {
+            for dependency in self.get_dependencies(node) {
+                if self.depends_on(dependency, node) {
+                    dot.push_str(&format!("    {} -> {} [color=red];\n", quote(node), quote(dependency)));
+                } else {
+                    dot.push_str(&format!("    {} -> {};\n", quote(node), quote(dependency)));
+                }
+            }
+        }
+        
+        dot.push_str("}\n");
+        dot
+    }
+    
+    /// Mark a component as critical or non-critical
+    pub fn set_critical(&mut self, name: &str, critical: bool) {
+        if critical {
+            self.non_critical.remove(name);
+        } else {
+            self.non_critical.insert(name.to_string());
+        }
+    }
+    
+    /// Check if a component is critical
+    pub fn is_critical(&self, name: &str) -> bool {
+        !self.non_critical.contains(name)
+    }
+    
+    /// Check if a component is unavailable
+    pub fn is_unavailable(&self, name: &str) -> bool {
+        self.unavailable.iter().any(|u| u.name == name)
+    }
+    
+    /// Get unavailable components
+    pub fn get_unavailable(&self) -> &[UnavailableComponent] {
+        &self.unavailable
+    }
+    
+    /// Check if running without some non-critical components
+    pub fn is_degraded(&self) -> bool {
+        self.state == LifecycleState::Degraded
+    }
+    
+    /// Check if a component can run, given the components already unavailable
+    fn check_dependencies(&self, name: &str) -> Result<(), LifecycleError> {
+        let missing: Vec<&str> = self.get_dependencies(name).into_iter()
+            .filter(|d| self.is_unavailable(d))
+            .collect();
+        
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(LifecycleError::Other(format!(
+                "Unavailable dependencies: {}",
+                missing.join(", ")
+            )))
+        }
+    }
+    
+    /// Record a non-critical component failure
+    fn mark_unavailable(&mut self, name: &str, reason: String) {
+        println!("[LIFECYCLE] Non-critical component {} unavailable: {}", name, reason);
+        self.unavailable.push(UnavailableComponent {
+            name: name.to_string(),
+            reason,
+        });
+    }
+    
     /// Initialize
+    ///
+    /// A failing non-critical component is recorded as unavailable and the
+    /// remaining components are still initialized; a failing critical
+    /// component aborts initialization.
     pub fn initialize(&mut self) -> Result<(), LifecycleError> {
         self.set_state(LifecycleState::Initializing)?;
+        self.unavailable.clear();
         
         // Collect errors during initialization
         let mut init_error = None;
         let mut failed_component = String::new();
         
-        for component in &mut self.components {
-            if let Err(e) = component.initialize() {
-                init_error = Some(e);
-                failed_component = component.name().to_string();
-                break;
+        for index in 0..self.components.len() {
+            let name = self.components[index].name().to_string();
+            let result = self.check_dependencies(&name)
+                .and_then(|_| self.components[index].initialize());
+            
+            if let Err(e) = result {
+                if self.is_critical(&name) {
+                    init_error = Some(e);
+                    failed_component = name;
+                    break;
+                }
+                self.mark_unavailable(&name, e.to_string());
             }
         }
         
@@ -162,16 +1011,29 @@ impl LifecycleManager {
         }
         
         self.set_state(LifecycleState::Starting)?;
+        self.started_order.clear();
         
         // Collect errors during start
         let mut start_error = None;
         let mut failed_component = String::new();
         
-        for component in &mut self.components {
-            if let Err(e) = component.start() {
-                start_error = Some(e);
-                failed_component = component.name().to_string();
-                break;
+        for index in 0..self.components.len() {
+            let name = self.components[index].name().to_string();
+            if self.is_unavailable(&name) {
+                continue;
+            }
+            
+            let result = self.check_dependencies(&name)
+                .and_then(|_| self.components[index].start());
+            
+            match result {
+                Ok(()) => self.started_order.push(name),
+                Err(e) if self.is_critical(&name) => {
+                    start_error = Some(e);
+                    failed_component = name;
+                    break;
+                },
+                Err(e) => self.mark_unavailable(&name, e.to_string()),
             }
         }
         
@@ -185,41 +1047,311 @@ impl LifecycleManager {
             )));
         }
         
-        self.set_state(LifecycleState::Running)?;
+        self.accepting_work = true;
+        if self.unavailable.is_empty() {
+            self.set_state(LifecycleState::Running)?;
+        } else {
+            self.set_state(LifecycleState::Degraded)?;
+        }
+        Ok(())
+    }
+    
+    /// Set watchdog restart policy
+    pub fn set_restart_policy(&mut self, policy: RestartPolicy) {
+        self.restart_policy = policy;
+    }
+    
+    /// Get watchdog restart record of a component
+    pub fn get_restart_record(&self, name: &str) -> Option<&RestartRecord> {
+        self.restart_records.get(name)
+    }
+    
+    /// Run one watchdog pass
+    ///
+    /// Components in the error state are restarted once their backoff has
+    /// elapsed; the backoff doubles with each restart. After
+    /// `max_restarts` restarts a component that fails again is given up on:
+    /// it is left unavailable, the manager switches to degraded mode and a
+    /// critical finding is emitted. A component that stays healthy for the
+    /// policy's `reset_after` after a restart has its record cleared, so a
+    /// later failure starts again from the initial backoff.
+    pub fn watchdog_check(&mut self, now: Instant) -> Result<Vec<WatchdogFinding>, LifecycleError> {
+        if self.state != LifecycleState::Running && self.state != LifecycleState::Degraded {
+            return Ok(Vec::new());
+        }
+        
+        let mut findings = Vec::new();
+        
+        for index in 0..self.components.len() {
+            let name = self.components[index].name().to_string();
+            if self.is_unavailable(&name) {
+                continue;
+            }
+            
+            let policy = self.restart_policy;
+            if self.components[index].state() != LifecycleState::Error {
+                let recovered = self.restart_records.get(&name)
+                    .and_then(|record| record.restarted_at)
+                    .is_some_and(|restarted_at| now.duration_since(restarted_at) >= policy.reset_after);
+                if recovered {
+                    self.restart_records.remove(&name);
+                }
+                continue;
+            }
+            
+            let record = self.restart_records.entry(name.clone()).or_default();
+            let failed_at = *record.failed_at.get_or_insert(now);
+            
+            if record.restarts >= policy.max_restarts {
+                record.given_up = true;
+                let message = format!("Gave up after {} restarts", record.restarts);
+                self.mark_unavailable(&name, message.clone());
+                findings.push(WatchdogFinding {
+                    component: name,
+                    severity: FindingSeverity::Critical,
+                    message,
+                });
+                continue;
+            }
+            
+            let backoff = policy.backoff(record.restarts);
+            if now.duration_since(failed_at) < backoff {
+                continue;
+            }
+            
+            record.restarts += 1;
+            record.backoffs.push(backoff);
+            record.failed_at = None;
+            record.restarted_at = Some(now);
+            let restarts = record.restarts;
+            
+            let result = self.check_dependencies(&name)
+                .and_then(|_| self.components[index].initialize())
+                .and_then(|_| self.components[index].start());
+            
+            let message = match result {
+                Ok(()) => format!("Restarted after {:?} (restart {})", backoff, restarts),
+                Err(e) => format!("Restart {} failed: {}", restarts, e),
+            };
+            println!("[LIFECYCLE] Watchdog: {}: {}", name, message);
+            findings.push(WatchdogFinding {
+                component: name,
+                severity: FindingSeverity::Warning,
+                message,
+            });
+        }
+        
+        if !self.unavailable.is_empty() && self.state == LifecycleState::Running {
+            self.set_state(LifecycleState::Degraded)?;
+        }
+        
+        Ok(findings)
+    }
+    
+    /// Register a health check, replacing any check with the same name
+    pub fn add_health_check<T>(&mut self, name: &str, check: T)
+    where
+        T: HealthCheck + 'static,
+    {
+        self.health_checks.retain(|(n, _)| n != name);
+        self.health_checks.push((name.to_string(), Box::new(check)));
+    }
+    
+    /// Set interval between health check polls
+    pub fn set_health_check_interval(&mut self, interval: Duration) {
+        self.health_check_interval = interval;
+    }
+    
+    /// Run every registered health check
+    pub fn run_health_checks(&self) -> HashMap<String, HealthStatus> {
+        self.health_checks.iter()
+            .map(|(name, check)| (name.clone(), check.check()))
+            .collect()
+    }
+    
+    /// Run health checks if `health_check_interval` has elapsed since the last poll
+    ///
+    /// Non-healthy results are logged. Returns `None` if the checks were not due.
+    pub fn poll_health(&mut self, now: Instant) -> Option<HashMap<String, HealthStatus>> {
+        if self.last_health_check.is_some_and(|last| now.duration_since(last) < self.health_check_interval) {
+            return None;
+        }
+        self.last_health_check = Some(now);
+        
+        let statuses = self.run_health_checks();
+        let mut unhealthy: Vec<_> = statuses.iter().filter(|(_, status)| !status.is_healthy()).collect();
+        unhealthy.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, status) in unhealthy {
+            println!("[LIFECYCLE] Health check {}: {}", name, status);
+        }
+        
+        Some(statuses)
+    }
+    
+    /// Run `f` on critical state, halting the system if the state is corrupted
+    ///
+    /// See `CriticalState::with`. Once halted, no work is accepted and the
+    /// manager stays in `Halted` rather than operating on unknown state.
+    pub fn with_critical<T, R, F>(&mut self, state: &CriticalState<T>, f: F) -> Result<R, LifecycleError>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let result = state.with(f);
+        if let Err(LifecycleError::StateCorrupted(reason)) = &result {
+            if self.state != LifecycleState::Halted {
+                self.halt(reason)?;
+            }
+        }
+        result
+    }
+    
+    /// Halt: stop accepting work and enter the `Halted` state
+    pub fn halt(&mut self, reason: &str) -> Result<(), LifecycleError> {
+        println!("[LIFECYCLE] Halting: {}", reason);
+        self.accepting_work = false;
+        self.set_state(LifecycleState::Halted)
+    }
+    
+    /// Set drain grace period
+    pub fn set_drain_grace_period(&mut self, grace_period: Duration) {
+        self.drain_grace_period = grace_period;
+    }
+    
+    /// Check if new work is accepted
+    pub fn is_accepting_work(&self) -> bool {
+        self.accepting_work
+    }
+    
+    /// Drain pending work before teardown
+    ///
+    /// Components are polled until they report no pending work, backing off
+    /// between polls; a component still busy at the end of the grace period
+    /// is force-stopped. A failing component does not stop the others from
+    /// draining, and every component is flushed even if draining did not
+    /// complete. All failures are reported in one `StopError`.
+    pub fn drain(&mut self) -> Result<(), LifecycleError> {
+        if self.state != LifecycleState::Running && self.state != LifecycleState::Degraded {
+            return Err(LifecycleError::StopError(format!(
+                "Cannot drain from state {}",
+                self.state
+            )));
+        }
+        
+        // Stop accepting new work
+        self.accepting_work = false;
+        self.force_stopped.clear();
+        self.set_state(LifecycleState::Draining)?;
+        
+        let deadline = Instant::now() + self.drain_grace_period;
+        let mut failures = Vec::new();
+        
+        let unavailable: HashSet<String> = self.unavailable.iter().map(|u| u.name.clone()).collect();
+        
+        for component in self.components.iter_mut().rev() {
+            if unavailable.contains(component.name()) {
+                continue;
+            }
+            
+            let mut backoff = DRAIN_POLL_INITIAL_BACKOFF;
+            loop {
+                match component.drain() {
+                    Ok(true) => break,
+                    Ok(false) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            self.force_stopped.push(component.name().to_string());
+                            break;
+                        }
+                        std::thread::sleep(backoff.min(deadline - now));
+                        backoff = (backoff * 2).min(DRAIN_POLL_MAX_BACKOFF);
+                    }
+                    Err(e) => {
+                        failures.push(format!("{}: {}", component.name(), e));
+                        break;
+                    }
+                }
+            }
+        }
+        
+        // Flush logs and metrics even if draining did not complete
+        for component in self.components.iter_mut().rev() {
+            if unavailable.contains(component.name()) {
+                continue;
+            }
+            
+            if let Err(e) = component.flush() {
+                failures.push(format!("{} (flush): {}", component.name(), e));
+            }
+        }
+        
+        if !failures.is_empty() {
+            self.set_state(LifecycleState::Error)?;
+            return Err(LifecycleError::StopError(format!(
+                "Failed to drain components: {}",
+                failures.join("; ")
+            )));
+        }
+        
         Ok(())
     }
     
-    /// Stop
+    /// Get components force-stopped during the last drain
+    pub fn get_force_stopped(&self) -> &[String] {
+        &self.force_stopped
+    }
+    
+    /// Get components in the order they were started
+    pub fn get_started_order(&self) -> &[String] {
+        &self.started_order
+    }
+    
+    /// Drain pending work, then stop started components in reverse start order
+    ///
+    /// Components are stopped even if draining fails; if both fail, both
+    /// failures are reported.
     pub fn stop(&mut self) -> Result<(), LifecycleError> {
-        if self.state != LifecycleState::Running {
+        if self.state != LifecycleState::Running && self.state != LifecycleState::Degraded {
             return Err(LifecycleError::StopError(format!(
                 "Cannot stop from state {}",
                 self.state
             )));
         }
         
-        self.set_state(LifecycleState::Stopping)?;
+        match (self.drain(), self.stop_system()) {
+            (Ok(()), stopped) => stopped,
+            (Err(e), Ok(())) => Err(e),
+            (Err(drain), Err(stop)) => Err(LifecycleError::StopError(format!("{}; {}", drain, stop))),
+        }
+    }
+    
+    /// Stop started components in strict reverse start order
+    ///
+    /// A failing component does not abort shutdown: the remaining components
+    /// are still stopped and every failure is reported in one `StopError`.
+    /// This also tears down the components started before a failed start.
+    pub fn stop_system(&mut self) -> Result<(), LifecycleError> {
+        if self.started_order.is_empty() {
+            return self.set_state(LifecycleState::Stopped);
+        }
         
-        // Collect errors during stop
-        let mut stop_error = None;
-        let mut failed_component = String::new();
+        self.set_state(LifecycleState::Stopping)?;
         
-        // Stop components in reverse order
-        for component in self.components.iter_mut().rev() {
+        let mut failures = Vec::new();
+        for name in std::mem::take(&mut self.started_order).into_iter().rev() {
+            let Some(component) = self.components.iter_mut().find(|c| c.name() == name) else {
+                continue;
+            };
+            
             if let Err(e) = component.stop() {
-                stop_error = Some(e);
-                failed_component = component.name().to_string();
-                break;
+                failures.push(format!("{}: {}", name, e));
             }
         }
         
-        // Handle errors after the loop
-        if let Some(e) = stop_error {
+        if !failures.is_empty() {
             self.set_state(LifecycleState::Error)?;
             return Err(LifecycleError::StopError(format!(
-                "Failed to stop component {}: {}",
-                failed_component,
-                e
+                "Failed to stop components: {}",
+                failures.join("; ")
             )));
         }
         
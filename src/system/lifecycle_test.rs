@@ -0,0 +1,875 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::lifecycle::{
+        CriticalState, FindingSeverity, HealthCheck, HealthStatus, LifecycleComponent, LifecycleError, LifecycleManager,
+        LifecycleState, RestartPolicy, SupervisionPolicy, WiringConfig,
+    };
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    struct WorkerComponent {
+        name: String,
+        state: LifecycleState,
+        pending: usize,
+        stuck: bool,
+        flushed: bool,
+        fail_init: bool,
+    }
+
+    impl WorkerComponent {
+        fn new(name: &str, pending: usize, stuck: bool) -> Self {
+            Self {
+                name: name.to_string(),
+                state: LifecycleState::Uninitialized,
+                pending,
+                stuck,
+                flushed: false,
+                fail_init: false,
+            }
+        }
+
+        fn failing(name: &str) -> Self {
+            let mut component = Self::new(name, 0, false);
+            component.fail_init = true;
+            component
+        }
+    }
+
+    impl LifecycleComponent for WorkerComponent {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            if self.fail_init {
+                self.state = LifecycleState::Error;
+                return Err(LifecycleError::InitializationError("device not found".to_string()));
+            }
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+
+        fn pending_work(&self) -> usize {
+            self.pending
+        }
+
+        fn drain(&mut self) -> Result<bool, LifecycleError> {
+            if !self.stuck && self.pending > 0 {
+                self.pending -= 1;
+            }
+            Ok(self.pending == 0)
+        }
+
+        fn flush(&mut self) -> Result<(), LifecycleError> {
+            self.flushed = true;
+            Ok(())
+        }
+    }
+
+    /// Component that crashes right after every start
+    struct CrashingComponent {
+        state: LifecycleState,
+    }
+
+    impl LifecycleComponent for CrashingComponent {
+        fn name(&self) -> &str {
+            "speech"
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Error;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+    }
+
+    /// Component that logs its starts and stops to a shared log
+    struct LoggingComponent {
+        name: String,
+        state: LifecycleState,
+        log: Arc<std::sync::Mutex<Vec<String>>>,
+        fail_stop: bool,
+    }
+
+    impl LifecycleComponent for LoggingComponent {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.log.lock().unwrap().push(format!("start {}", self.name));
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.log.lock().unwrap().push(format!("stop {}", self.name));
+            if self.fail_stop {
+                return Err(LifecycleError::StopError("socket busy".to_string()));
+            }
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+    }
+
+    #[test]
+    fn test_drain_pending_work_within_grace_period() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("planner", 10, false)).unwrap();
+        manager.set_drain_grace_period(Duration::from_secs(1));
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        assert!(manager.is_accepting_work());
+
+        let started = Instant::now();
+        manager.stop().unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(!manager.is_accepting_work());
+        assert!(manager.get_force_stopped().is_empty());
+        assert_eq!(manager.state, LifecycleState::Stopped);
+
+        let component = manager.get_component("planner").unwrap();
+        assert_eq!(component.pending_work(), 0);
+        assert_eq!(component.state(), LifecycleState::Stopped);
+    }
+
+    #[test]
+    fn test_drain_force_stops_after_grace_period() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("bus", 0, false)).unwrap();
+        manager.add_component(WorkerComponent::new("stuck", 3, true)).unwrap();
+        manager.set_drain_grace_period(Duration::from_millis(50));
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        manager.stop().unwrap();
+
+        assert_eq!(manager.get_force_stopped(), &["stuck".to_string()]);
+        assert_eq!(manager.state, LifecycleState::Stopped);
+        assert_eq!(manager.get_component("stuck").unwrap().pending_work(), 3);
+    }
+
+    /// Component whose drain fails
+    struct BrokenDrainComponent {
+        state: LifecycleState,
+    }
+
+    impl LifecycleComponent for BrokenDrainComponent {
+        fn name(&self) -> &str {
+            "bus"
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+
+        fn drain(&mut self) -> Result<bool, LifecycleError> {
+            Err(LifecycleError::Other("queue unreachable".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_failed_drain_still_drains_others_and_stops() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("planner", 2, false)).unwrap();
+        manager.add_component(BrokenDrainComponent { state: LifecycleState::Uninitialized }).unwrap();
+        manager.add_component(WorkerComponent::new("cache", 0, false)).unwrap();
+        manager.set_drain_grace_period(Duration::from_secs(1));
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        let result = manager.stop();
+        assert!(matches!(&result, Err(LifecycleError::StopError(msg)) if msg.contains("bus: Other error: queue unreachable")));
+
+        // "planner" drains after "bus" in reverse order
+        assert_eq!(manager.get_component("planner").unwrap().pending_work(), 0);
+        for name in ["planner", "bus", "cache"] {
+            assert_eq!(manager.get_component(name).unwrap().state(), LifecycleState::Stopped);
+        }
+        assert_eq!(manager.state, LifecycleState::Stopped);
+    }
+
+    #[test]
+    fn test_wiring_config_builds_graph() {
+        let json = r#"{
+            "components": [
+                { "name": "reasoning", "dependencies": ["symbolic", "learning"] },
+                { "name": "symbolic" },
+                { "name": "learning", "dependencies": ["neural"] },
+                { "name": "neural" }
+            ]
+        }"#;
+        let wiring = WiringConfig::from_json(json).unwrap();
+        
+        let mut manager = LifecycleManager::new().unwrap();
+        for name in &["reasoning", "symbolic", "learning", "neural"] {
+            manager.add_component(WorkerComponent::new(name, 0, false)).unwrap();
+        }
+        manager.apply_wiring(&wiring).unwrap();
+        
+        let order: Vec<&str> = manager.components.iter().map(|c| c.name()).collect();
+        assert_eq!(order, vec!["symbolic", "neural", "learning", "reasoning"]);
+        assert_eq!(manager.get_dependencies("reasoning"), vec!["symbolic", "learning"]);
+        assert!(manager.get_dependencies("neural").is_empty());
+    }
+
+    #[test]
+    fn test_shipped_wiring_config_is_valid() {
+        let wiring = WiringConfig::load(concat!(env!("CARGO_MANIFEST_DIR"), "/wiring.json")).unwrap();
+        let order = wiring.start_order().unwrap();
+        let position = |name: &str| order.iter().position(|n| n == name).unwrap();
+
+        assert_eq!(order.len(), wiring.components.len());
+        assert!(position("memory_manager") < position("process_manager"));
+        assert!(position("process_manager") < position("sandbox_manager"));
+        assert!(position("desktop_environment") < position("input_manager"));
+    }
+
+    #[test]
+    fn test_wiring_config_rejects_missing_dependency() {
+        let json = r#"{ "components": [ { "name": "reasoning", "dependencies": ["symbolic"] } ] }"#;
+        
+        match WiringConfig::from_json(json) {
+            Err(LifecycleError::WiringError(msg)) => assert!(msg.contains("missing component symbolic")),
+            other => panic!("expected wiring error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_wiring_config_rejects_circular_dependency() {
+        let mut wiring = WiringConfig::new();
+        wiring.add_component("planning", &["reasoning"]);
+        wiring.add_component("reasoning", &["planning"]);
+        wiring.add_component("neural", &[]);
+        
+        match wiring.validate() {
+            Err(LifecycleError::WiringError(msg)) => {
+                assert!(msg.contains("Circular dependency"));
+                assert!(msg.contains("planning"));
+                assert!(!msg.contains("neural"));
+            }
+            other => panic!("expected wiring error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_critical_init_failure_degrades() {
+        let json = r#"{
+            "components": [
+                { "name": "kernel" },
+                { "name": "speech", "critical": false },
+                { "name": "dialogue", "dependencies": ["speech"], "critical": false },
+                { "name": "planner", "dependencies": ["kernel"] }
+            ]
+        }"#;
+        let wiring = WiringConfig::from_json(json).unwrap();
+
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("kernel", 0, false)).unwrap();
+        manager.add_component(WorkerComponent::failing("speech")).unwrap();
+        manager.add_component(WorkerComponent::new("dialogue", 0, false)).unwrap();
+        manager.add_component(WorkerComponent::new("planner", 0, false)).unwrap();
+        manager.apply_wiring(&wiring).unwrap();
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        assert_eq!(manager.state, LifecycleState::Degraded);
+        assert!(manager.is_degraded());
+        assert!(manager.is_accepting_work());
+
+        let unavailable: Vec<&str> = manager.get_unavailable().iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(unavailable, vec!["speech", "dialogue"]);
+        assert!(manager.get_unavailable()[0].reason.contains("device not found"));
+        assert_eq!(manager.get_component("planner").unwrap().state(), LifecycleState::Running);
+        assert_eq!(manager.get_component("dialogue").unwrap().state(), LifecycleState::Uninitialized);
+
+        manager.stop().unwrap();
+        assert_eq!(manager.state, LifecycleState::Stopped);
+    }
+
+    #[test]
+    fn test_critical_init_failure_aborts() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::failing("kernel")).unwrap();
+        manager.add_component(WorkerComponent::new("planner", 0, false)).unwrap();
+        manager.set_critical("planner", false);
+
+        match manager.initialize() {
+            Err(LifecycleError::InitializationError(msg)) => assert!(msg.contains("kernel")),
+            other => panic!("expected initialization error, got {:?}", other),
+        }
+        assert_eq!(manager.state, LifecycleState::Error);
+        assert_eq!(manager.get_component("planner").unwrap().state(), LifecycleState::Uninitialized);
+    }
+
+    #[test]
+    fn test_dependency_graph_dot_lists_edges() {
+        let mut wiring = WiringConfig::new();
+        wiring.add_component("memory", &[]);
+        wiring.add_component("process", &["memory"]);
+        wiring.add_component("planner", &["process", "memory"]);
+
+        let mut manager = LifecycleManager::new().unwrap();
+        for name in &["planner", "process", "memory"] {
+            manager.add_component(WorkerComponent::new(name, 0, false)).unwrap();
+        }
+        manager.apply_wiring(&wiring).unwrap();
+
+        let dot = manager.dependency_graph_dot();
+
+        assert!(dot.starts_with("digraph subsystems {"));
+        assert!(dot.contains("    \"memory\";\n"));
+        assert!(dot.contains("    \"process\" -> \"memory\";\n"));
+        assert!(dot.contains("    \"planner\" -> \"process\";\n"));
+        assert!(dot.contains("    \"planner\" -> \"memory\";\n"));
+        assert!(!dot.contains("red"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_dependency_graph_dot_marks_cycles() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("reasoning", 0, false)).unwrap();
+        manager.add_component(WorkerComponent::new("planning", 0, false)).unwrap();
+        manager.add_dependency("reasoning", "planning");
+        manager.add_dependency("planning", "reasoning");
+        manager.add_dependency("planning", "knowledge");
+
+        let dot = manager.dependency_graph_dot();
+
+        assert!(dot.contains("    \"reasoning\" [color=red];\n"));
+        assert!(dot.contains("    \"reasoning\" -> \"planning\" [color=red];\n"));
+        assert!(dot.contains("    \"planning\" -> \"reasoning\" [color=red];\n"));
+        assert!(dot.contains("    \"planning\" -> \"knowledge\";\n"));
+        assert!(dot.contains("    \"knowledge\" [style=dashed];\n"));
+    }
+
+    #[test]
+    fn test_watchdog_gives_up_after_max_restarts() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("planner", 0, false)).unwrap();
+        manager.add_component(CrashingComponent { state: LifecycleState::Uninitialized }).unwrap();
+        manager.set_restart_policy(RestartPolicy {
+            max_restarts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            ..RestartPolicy::default()
+        });
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        assert_eq!(manager.state, LifecycleState::Running);
+
+        let base = Instant::now();
+        let mut findings = Vec::new();
+        for second in 0..30 {
+            findings.extend(manager.watchdog_check(base + Duration::from_secs(second)).unwrap());
+        }
+
+        let record = manager.get_restart_record("speech").unwrap();
+        assert_eq!(record.restarts, 3);
+        assert_eq!(record.backoffs, vec![Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(4)]);
+        assert!(record.given_up);
+
+        let critical: Vec<_> = findings.iter().filter(|f| f.severity == FindingSeverity::Critical).collect();
+        assert_eq!(critical.len(), 1);
+        assert_eq!(critical[0].component, "speech");
+        assert_eq!(findings.last().unwrap().severity, FindingSeverity::Critical);
+
+        assert!(manager.is_unavailable("speech"));
+        assert!(manager.is_degraded());
+        assert!(manager.get_restart_record("planner").is_none());
+        assert_eq!(manager.get_component("planner").unwrap().state(), LifecycleState::Running);
+    }
+
+    /// Component that is in the error state while `broken` is set
+    struct ToggleComponent {
+        state: LifecycleState,
+        broken: Arc<AtomicBool>,
+    }
+
+    impl LifecycleComponent for ToggleComponent {
+        fn name(&self) -> &str {
+            "vision"
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            if self.broken.load(Ordering::SeqCst) {
+                LifecycleState::Error
+            } else {
+                self.state
+            }
+        }
+    }
+
+    #[test]
+    fn test_watchdog_resets_record_after_healthy_interval() {
+        let broken = Arc::new(AtomicBool::new(false));
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(ToggleComponent { state: LifecycleState::Uninitialized, broken: broken.clone() }).unwrap();
+        manager.set_restart_policy(RestartPolicy {
+            max_restarts: 2,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            reset_after: Duration::from_secs(30),
+        });
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        let base = Instant::now();
+        let at = |seconds: u64| base + Duration::from_secs(seconds);
+
+        broken.store(true, Ordering::SeqCst);
+        manager.watchdog_check(at(0)).unwrap();
+        assert_eq!(manager.watchdog_check(at(1)).unwrap().len(), 1);
+        broken.store(false, Ordering::SeqCst);
+        assert_eq!(manager.get_restart_record("vision").unwrap().restarts, 1);
+
+        // Healthy, but not for long enough yet
+        manager.watchdog_check(at(30)).unwrap();
+        assert_eq!(manager.get_restart_record("vision").unwrap().restarts, 1);
+        manager.watchdog_check(at(31)).unwrap();
+        assert!(manager.get_restart_record("vision").is_none());
+
+        // A later failure starts over from the initial backoff instead of
+        // counting towards giving up
+        for failure in 0..3 {
+            broken.store(true, Ordering::SeqCst);
+            let start = 100 * (failure + 1);
+            manager.watchdog_check(at(start)).unwrap();
+            manager.watchdog_check(at(start + 1)).unwrap();
+            broken.store(false, Ordering::SeqCst);
+            let record = manager.get_restart_record("vision").unwrap();
+            assert_eq!(record.backoffs, vec![Duration::from_secs(1)]);
+            manager.watchdog_check(at(start + 50)).unwrap();
+        }
+        assert!(!manager.is_unavailable("vision"));
+        assert_eq!(manager.state, LifecycleState::Running);
+    }
+
+    #[test]
+    fn test_restart_policy_backoff_is_capped() {
+        let policy = RestartPolicy {
+            max_restarts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(10),
+            ..RestartPolicy::default()
+        };
+
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(8));
+        assert_eq!(policy.backoff(4), Duration::from_secs(10));
+        assert_eq!(policy.backoff(40), Duration::from_secs(10));
+    }
+
+    /// Access control table guarded as critical state
+    #[derive(Debug, PartialEq)]
+    struct Permissions {
+        granted: Vec<String>,
+    }
+
+    /// Poison the lock by panicking while holding it
+    fn poison<T>(state: &CriticalState<T>) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            state.with(|_| panic!("corrupting critical state"))
+        }));
+        assert!(result.is_err());
+        assert!(state.is_poisoned());
+    }
+
+    #[test]
+    fn test_poisoned_critical_state_is_reinitialized() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let state = CriticalState::new("access_control", Permissions { granted: vec!["root".to_string()] }, move || {
+            // First attempt fails, second succeeds
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(LifecycleError::InitializationError("policy store busy".to_string())),
+                _ => Ok(Permissions { granted: Vec::new() }),
+            }
+        });
+
+        poison(&state);
+        let granted = state.with(|p| p.granted.clone()).unwrap();
+
+        assert!(granted.is_empty());
+        assert_eq!(state.reinit_attempts(), 2);
+        assert!(!state.is_halted());
+        assert!(!state.is_poisoned());
+    }
+
+    #[test]
+    fn test_unrecoverable_critical_state_halts() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::new("planner", 0, false)).unwrap();
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        let state = CriticalState::new("access_control", Permissions { granted: Vec::new() }, || {
+            Err(LifecycleError::InitializationError("policy store unreachable".to_string()))
+        }).with_max_reinit_attempts(3);
+
+        assert!(manager.with_critical(&state, |p| p.granted.len()).is_ok());
+        poison(&state);
+
+        match manager.with_critical(&state, |p| p.granted.len()) {
+            Err(LifecycleError::StateCorrupted(msg)) => assert!(msg.contains("access_control"), "{}", msg),
+            other => panic!("expected corrupted state, got {:?}", other),
+        }
+        assert_eq!(state.reinit_attempts(), 3);
+        assert!(state.is_halted());
+        assert_eq!(manager.state, LifecycleState::Halted);
+        assert!(!manager.is_accepting_work());
+        assert!(manager.start().is_err());
+
+        // Halted state is never touched again, and no further retries run
+        assert!(matches!(state.with(|p| p.granted.len()), Err(LifecycleError::StateCorrupted(_))));
+        assert_eq!(state.reinit_attempts(), 3);
+    }
+
+    #[test]
+    fn test_stop_system_reverses_start_order_despite_errors() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut manager = LifecycleManager::new().unwrap();
+        for name in ["A", "B", "C"] {
+            manager.add_component(LoggingComponent {
+                name: name.to_string(),
+                state: LifecycleState::Uninitialized,
+                log: log.clone(),
+                fail_stop: name == "B",
+            }).unwrap();
+        }
+
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        assert_eq!(manager.get_started_order(), &["A".to_string(), "B".to_string(), "C".to_string()]);
+
+        let result = manager.stop_system();
+        assert!(matches!(&result, Err(LifecycleError::StopError(msg)) if msg.contains("B: Stop error: socket busy")));
+        assert_eq!(*log.lock().unwrap(), vec!["start A", "start B", "start C", "stop C", "stop B", "stop A"]);
+        assert_eq!(manager.get_component("A").unwrap().state(), LifecycleState::Stopped);
+        assert_eq!(manager.state, LifecycleState::Error);
+        assert!(manager.get_started_order().is_empty());
+    }
+
+    #[test]
+    fn test_stop_system_without_started_components_stops() {
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_component(WorkerComponent::failing("camera")).unwrap();
+        assert!(manager.initialize().is_err());
+        assert_eq!(manager.state, LifecycleState::Error);
+
+        manager.stop_system().unwrap();
+        assert_eq!(manager.state, LifecycleState::Stopped);
+    }
+
+    /// Health check of a component that can wedge
+    struct WedgeCheck {
+        wedged: Arc<AtomicBool>,
+    }
+
+    impl HealthCheck for WedgeCheck {
+        fn check(&self) -> HealthStatus {
+            if self.wedged.load(Ordering::SeqCst) {
+                HealthStatus::Unhealthy("event loop stalled".to_string())
+            } else {
+                HealthStatus::Healthy
+            }
+        }
+    }
+
+    #[test]
+    fn test_health_checks_report_unhealthy_component() {
+        let wedged = Arc::new(AtomicBool::new(false));
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_health_check("planner", WedgeCheck { wedged: wedged.clone() });
+        manager.add_health_check("speech", WedgeCheck { wedged: Arc::new(AtomicBool::new(false)) });
+        manager.set_health_check_interval(Duration::from_secs(10));
+
+        let start = Instant::now();
+        let statuses = manager.poll_health(start).unwrap();
+        assert!(statuses.values().all(HealthStatus::is_healthy));
+
+        wedged.store(true, Ordering::SeqCst);
+        // Not due yet
+        assert!(manager.poll_health(start + Duration::from_secs(1)).is_none());
+
+        let statuses = manager.poll_health(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses["planner"], HealthStatus::Unhealthy("event loop stalled".to_string()));
+        assert_eq!(statuses["speech"], HealthStatus::Healthy);
+        assert_eq!(manager.run_health_checks(), statuses);
+    }
+
+    /// Component whose first `failures` updates fail
+    struct FlakyComponent {
+        name: String,
+        state: LifecycleState,
+        failures: u32,
+        starts: Arc<AtomicU32>,
+    }
+
+    impl LifecycleComponent for FlakyComponent {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.starts.fetch_add(1, Ordering::SeqCst);
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+
+        fn update(&mut self) -> Result<(), LifecycleError> {
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(LifecycleError::Other("camera disconnected".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_supervisor_restarts_failed_component() {
+        let starts = Arc::new(AtomicU32::new(0));
+        let policy = SupervisionPolicy::MaxRetries { retries: 3, backoff: Duration::ZERO };
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_supervised_component(FlakyComponent {
+            name: "vision".to_string(),
+            state: LifecycleState::Uninitialized,
+            failures: 2,
+            starts: starts.clone(),
+        }, policy).unwrap();
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        manager.update().unwrap();
+        manager.update().unwrap();
+        assert_eq!(manager.get_supervision_retries("vision"), 2);
+        manager.update().unwrap();
+
+        // Started once, then restarted after each of the two failures
+        assert_eq!(starts.load(Ordering::SeqCst), 3);
+        assert_eq!(manager.get_supervision_retries("vision"), 0);
+        assert_eq!(manager.state, LifecycleState::Running);
+
+        // A component that keeps failing is given up on
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_supervised_component(FlakyComponent {
+            name: "speech".to_string(),
+            state: LifecycleState::Uninitialized,
+            failures: u32::MAX,
+            starts: Arc::new(AtomicU32::new(0)),
+        }, SupervisionPolicy::MaxRetries { retries: 1, backoff: Duration::ZERO }).unwrap();
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        manager.update().unwrap();
+        assert!(matches!(manager.update(), Err(LifecycleError::RestartsExhausted(_))));
+        assert!(manager.is_unavailable("speech"));
+        assert_eq!(manager.state, LifecycleState::Degraded);
+        manager.update().unwrap();
+    }
+
+    #[test]
+    fn test_supervisor_restarts_after_backoff_without_blocking() {
+        let starts = Arc::new(AtomicU32::new(0));
+        let backoff = Duration::from_secs(10);
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_supervised_component(FlakyComponent {
+            name: "vision".to_string(),
+            state: LifecycleState::Uninitialized,
+            failures: 1,
+            starts: starts.clone(),
+        }, SupervisionPolicy::Always { backoff }).unwrap();
+        manager.add_component(WorkerComponent::new("planner", 0, false)).unwrap();
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        let base = Instant::now();
+        let started = Instant::now();
+        manager.update_at(base).unwrap();
+        assert!(started.elapsed() < backoff);
+
+        // Stopped and waiting for its backoff
+        let record = manager.get_supervision_record("vision").unwrap();
+        assert_eq!(record.next_restart_at, Some(base + backoff));
+        assert_eq!(manager.get_component("vision").unwrap().state(), LifecycleState::Stopped);
+        manager.update_at(base + Duration::from_secs(5)).unwrap();
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+
+        manager.update_at(base + backoff).unwrap();
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+        assert_eq!(manager.get_component("vision").unwrap().state(), LifecycleState::Running);
+        assert!(manager.get_supervision_record("vision").is_none());
+    }
+
+    #[test]
+    fn test_unsupervised_failure_does_not_skip_other_components() {
+        let starts = Arc::new(AtomicU32::new(0));
+        let mut manager = LifecycleManager::new().unwrap();
+        for name in ["vision", "speech"] {
+            manager.add_component(FlakyComponent {
+                name: name.to_string(),
+                state: LifecycleState::Uninitialized,
+                failures: 1,
+                starts: starts.clone(),
+            }).unwrap();
+        }
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+
+        // Both components fail once; both failures are reported together
+        let result = manager.update();
+        assert!(matches!(&result, Err(LifecycleError::Other(msg)) if msg.matches("camera disconnected").count() == 2));
+        manager.update().unwrap();
+    }
+
+    /// Component whose update runs on critical state
+    struct GuardedComponent {
+        state: LifecycleState,
+        permissions: Arc<CriticalState<Permissions>>,
+    }
+
+    impl LifecycleComponent for GuardedComponent {
+        fn name(&self) -> &str {
+            "access_control"
+        }
+
+        fn initialize(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Initialized;
+            Ok(())
+        }
+
+        fn start(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Running;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), LifecycleError> {
+            self.state = LifecycleState::Stopped;
+            Ok(())
+        }
+
+        fn state(&self) -> LifecycleState {
+            self.state
+        }
+
+        fn update(&mut self) -> Result<(), LifecycleError> {
+            self.permissions.with(|p| p.granted.len()).map(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_corrupted_state_in_update_halts_without_restart() {
+        let permissions = Arc::new(CriticalState::new("access_control", Permissions { granted: Vec::new() }, || {
+            Err(LifecycleError::InitializationError("policy store unreachable".to_string()))
+        }).with_max_reinit_attempts(2));
+        let mut manager = LifecycleManager::new().unwrap();
+        manager.add_supervised_component(GuardedComponent {
+            state: LifecycleState::Uninitialized,
+            permissions: permissions.clone(),
+        }, SupervisionPolicy::Always { backoff: Duration::ZERO }).unwrap();
+        manager.add_health_check("access_control", permissions.clone());
+        manager.initialize().unwrap();
+        manager.start().unwrap();
+        manager.update().unwrap();
+
+        poison(&permissions);
+        assert!(matches!(manager.run_health_checks().get("access_control"), Some(HealthStatus::Degraded(_))));
+
+        assert!(matches!(manager.update(), Err(LifecycleError::StateCorrupted(_))));
+        assert_eq!(manager.state, LifecycleState::Halted);
+        assert!(manager.get_supervision_record("access_control").is_none());
+        assert!(matches!(manager.run_health_checks().get("access_control"), Some(HealthStatus::Unhealthy(_))));
+
+        // No further updates run once halted
+        manager.update().unwrap();
+        assert_eq!(permissions.reinit_attempts(), 2);
+    }
+}
@@ -3,13 +3,26 @@ mod resource;
 mod lifecycle;
 mod monitoring;
 mod integration;
+mod store;
+mod thread_pool;
+mod latency;
 
 // 使用具体的模块导出，避免glob导出冲突
-pub use config::{SystemConfig, SystemConfigManager, ConfigError};
-pub use resource::{Resource, ResourceManager, ResourceError};
-pub use lifecycle::{SystemLifecycle, SystemLifecycleManager, LifecycleError};
-pub use monitoring::{Monitoring, MonitoringManager, MonitoringError, MetricType};
-pub use integration::{SystemIntegration, SystemIntegrationManager, IntegrationError};
+pub use config::{SystemConfig, SystemConfigManager, ConfigError, ConfigValue, DEFAULT_MAX_CONFIG_SIZE, ConfigSource, CONFIG_ENV_PREFIX, ConfigPropertySchema, ConfigValueType};
+pub use config::{load_toml, save_toml};
+pub use config::{apply_env_overrides, apply_env_overrides_from};
+pub use config::{diff, ConfigChange};
+pub use config::{watch, watch_with_interval, WatchHandle};
+pub use resource::{Resource, ResourceManager, ResourceError, ByteSize, ModelPrecision, PrecisionPolicy, PrecisionChange};
+pub use lifecycle::{SystemLifecycle, SystemLifecycleManager, LifecycleError, WiringConfig, ComponentWiring, UnavailableComponent, RestartPolicy, RestartRecord, WatchdogFinding, FindingSeverity, CriticalState, DEFAULT_MAX_REINIT_ATTEMPTS};
+pub use lifecycle::{HealthCheck, HealthStatus, DEFAULT_HEALTH_CHECK_INTERVAL};
+pub use lifecycle::SupervisionPolicy;
+pub use lifecycle::{LifecycleComponent, LifecycleManager, LifecycleState};
+pub use monitoring::{Monitoring, MonitoringManager, MonitoringError, MetricType, Metric, IdleDetector, IdleTransition, ActivitySignal, DEFAULT_IDLE_TIMEOUT, SessionRecorder, SessionReport, SubsystemTiming, LeakedRegion, COUNTER_PROCESSES_RUN, COUNTER_PLANS_COMPLETED, COUNTER_THREATS_DETECTED, COUNTER_VERIFICATIONS_PASSED, COUNTER_VERIFICATIONS_FAILED, MonitoringSystem, MetricDescriptor, MetricStats, ReasoningSample, METRIC_REASONING_LATENCY, METRIC_REASONING_CACHE_HIT_RATE, METRIC_REASONING_FACTS_DERIVED, METRIC_REASONING_RULE_FIRINGS, METRIC_PROCESS_CPU_USAGE, AlertRule, Alert, AlertPolicy, Comparator, AlertAction, suspend_process_action, process_cpu_metrics, DEFAULT_MAX_METRIC_HISTORY};
+pub use integration::{SystemIntegration, SystemIntegrationManager, IntegrationError, IntegrationInterface, IntegrationEndpoint, IntegrationMessage, IntegrationProtocol, DeadLetter, DeadLetterReason, DEFAULT_DEDUP_WINDOW};
+pub use store::{Store, StoreConfig, StoreError};
+pub use thread_pool::{ThreadPool, ThreadPoolError, AutoscalePolicy, ScalingDecision, METRIC_POOL_QUEUE_DEPTH, METRIC_POOL_UTILIZATION, METRIC_POOL_WORKERS};
+pub use latency::{LatencyTracer, LatencyError, LatencyStage, LatencyReport, LatencyDistribution, CompletedTrace, Ingress, METRIC_E2E_LATENCY, DEFAULT_MAX_OPEN_TRACES, DEFAULT_TRACE_TIMEOUT};
 
 // 导出特定函数，避免冲突
 pub use config::init as config_init;
@@ -31,3 +44,15 @@ pub use monitoring::stop as monitoring_stop;
 pub use integration::init as integration_init;
 pub use integration::start as integration_start;
 pub use integration::stop as integration_stop;
+
+pub use store::init as store_init;
+pub use store::start as store_start;
+pub use store::stop as store_stop;
+
+pub use thread_pool::init as thread_pool_init;
+pub use thread_pool::start as thread_pool_start;
+pub use thread_pool::stop as thread_pool_stop;
+
+pub use latency::init as latency_init;
+pub use latency::start as latency_start;
+pub use latency::stop as latency_stop;
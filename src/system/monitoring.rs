@@ -1,6 +1,50 @@
 use std::fmt;
 use std::error::Error;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt::Write as _;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use crate::kernel::{ProcessManager, ProcessState};
+
+/// Default idle timeout before auto-suspend
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(900);
+
+/// Name of the idle time metric
+pub const IDLE_METRIC: &str = "idle_seconds";
+
+/// Reasoning query wall time metric
+pub const METRIC_REASONING_LATENCY: &str = "reasoning_latency_ms";
+
+/// Reasoning cache hit rate metric
+pub const METRIC_REASONING_CACHE_HIT_RATE: &str = "reasoning_cache_hit_rate";
+
+/// Facts derived per reasoning query metric
+pub const METRIC_REASONING_FACTS_DERIVED: &str = "reasoning_facts_derived";
+
+/// Rule firings per reasoning query metric
+pub const METRIC_REASONING_RULE_FIRINGS: &str = "reasoning_rule_firings";
+
+/// Per-process CPU usage metric
+pub const METRIC_PROCESS_CPU_USAGE: &str = "process_cpu_percent";
+
+/// Session counter: processes run
+pub const COUNTER_PROCESSES_RUN: &str = "processes_run";
+
+/// Session counter: plans completed
+pub const COUNTER_PLANS_COMPLETED: &str = "plans_completed";
+
+/// Session counter: threats detected
+pub const COUNTER_THREATS_DETECTED: &str = "threats_detected";
+
+/// Session counter: verifications passed
+pub const COUNTER_VERIFICATIONS_PASSED: &str = "verifications_passed";
+
+/// Session counter: verifications failed
+pub const COUNTER_VERIFICATIONS_FAILED: &str = "verifications_failed";
+
+/// Default number of samples kept per metric
+pub const DEFAULT_MAX_METRIC_HISTORY: usize = 10_000;
 
 /// Monitoring error
 #[derive(Debug)]
@@ -34,6 +78,8 @@ pub enum MetricType {
     Process,
     /// Network
     Network,
+    /// Reasoning
+    Reasoning,
     /// Custom
     Custom,
 }
@@ -44,6 +90,7 @@ impl fmt::Display for MetricType {
             MetricType::System => write!(f, "System"),
             MetricType::Process => write!(f, "Process"),
             MetricType::Network => write!(f, "Network"),
+            MetricType::Reasoning => write!(f, "Reasoning"),
             MetricType::Custom => write!(f, "Custom"),
         }
     }
@@ -64,6 +111,8 @@ pub struct Metric {
     pub unit: String,
     /// Metric timestamp
     pub timestamp: std::time::SystemTime,
+    /// Process the metric was measured for
+    pub process_id: Option<String>,
 }
 
 impl Metric {
@@ -76,16 +125,239 @@ impl Metric {
             value,
             unit: unit.to_string(),
             timestamp: std::time::SystemTime::now(),
+            process_id: None,
         }
     }
+    
+    /// Attribute the metric to a process
+    pub fn for_process(mut self, process_id: &str) -> Self {
+        self.process_id = Some(process_id.to_string());
+        self
+    }
+}
+
+/// Registered metric description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricDescriptor {
+    /// Metric name
+    pub name: String,
+    /// Metric type
+    pub metric_type: MetricType,
+    /// Metric unit
+    pub unit: String,
+    /// Metric help text
+    pub help: String,
+}
+
+impl MetricDescriptor {
+    /// Create a new metric descriptor
+    pub fn new(name: &str, metric_type: MetricType, unit: &str, help: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            metric_type,
+            unit: unit.to_string(),
+            help: help.to_string(),
+        }
+    }
+}
+
+/// Rolling statistics over recent metric samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricStats {
+    /// Number of samples
+    pub count: usize,
+    /// Mean value
+    pub mean: f64,
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    pub max: f64,
+    /// 95th percentile value
+    pub p95: f64,
+    /// Latest value
+    pub last: f64,
+}
+
+impl MetricStats {
+    /// Compute statistics of samples, oldest first
+    fn from_values(values: &[f64]) -> Option<Self> {
+        let last = *values.last()?;
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let sum: f64 = sorted.iter().sum();
+        
+        Some(Self {
+            count: sorted.len(),
+            mean: sum / sorted.len() as f64,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            p95: sorted[((sorted.len() * 95).div_ceil(100)).saturating_sub(1)],
+            last,
+        })
+    }
+}
+
+/// Measurements of a single reasoning query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReasoningSample {
+    /// Query wall time
+    pub latency: Duration,
+    /// Whether the result was served from cache
+    pub cache_hit: bool,
+    /// Number of facts derived
+    pub facts_derived: usize,
+    /// Number of rule firings
+    pub rule_firings: usize,
+}
+
+/// How a metric value is compared against an alert threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Comparator {
+    /// Breached when the value is above the threshold
+    #[default]
+    Above,
+    /// Breached when the value is below the threshold
+    Below,
+}
+
+impl Comparator {
+    /// Check if a value breaches a threshold
+    pub fn breached(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparator::Above => value > threshold,
+            Comparator::Below => value < threshold,
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparator::Above => write!(f, ">"),
+            Comparator::Below => write!(f, "<"),
+        }
+    }
+}
+
+/// Alert rule, fired when a metric breaches a threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    /// Rule ID
+    pub id: String,
+    /// Rule name
+    pub name: String,
+    /// Metric name
+    ///
+    /// A name rather than a `MetricType`: many metrics share a type, so the
+    /// type alone cannot pick the samples to compare.
+    pub metric: String,
+    /// Comparison against the threshold
+    pub comparator: Comparator,
+    /// Threshold the metric must breach
+    pub threshold: f64,
+    /// How long the breach must last; zero fires on the breaching sample
+    pub duration: Duration,
+}
+
+/// Fired alert
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    /// Alert ID
+    pub id: String,
+    /// Rule ID
+    pub rule_id: String,
+    /// Rule name
+    pub rule_name: String,
+    /// Metric name
+    pub metric: String,
+    /// Metric value that crossed the threshold
+    pub value: f64,
+    /// Rule comparison
+    pub comparator: Comparator,
+    /// Rule threshold
+    pub threshold: f64,
+    /// Offending process, if the metric was measured for one
+    pub process_id: Option<String>,
+    /// Alert timestamp
+    pub timestamp: SystemTime,
+}
+
+/// Policy for acting on fired alerts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AlertPolicy {
+    /// Whether alert actions run automatically; alerts are recorded either way
+    pub auto_action: bool,
+}
+
+/// Alert action
+pub type AlertAction = Box<dyn Fn(&Alert) -> Result<(), MonitoringError> + Send + Sync>;
+
+/// Create an alert action that suspends the offending process
+///
+/// Alerts without a process, and processes that are already suspended or no
+/// longer running, are ignored.
+pub fn suspend_process_action(processes: Arc<Mutex<ProcessManager>>) -> AlertAction {
+    Box::new(move |alert: &Alert| {
+        let process_id = match &alert.process_id {
+            Some(process_id) => process_id,
+            None => return Ok(()),
+        };
+        
+        let mut processes = processes.lock()
+            .map_err(|_| MonitoringError::Other("Process manager lock poisoned".to_string()))?;
+        let suspendable = processes.get_process(process_id)
+            .is_some_and(|p| matches!(p.state, ProcessState::Running | ProcessState::Waiting));
+        if !suspendable {
+            return Ok(());
+        }
+        
+        processes.suspend_process(process_id).map_err(|e| {
+            MonitoringError::MonitoringError(format!("Failed to suspend process {}: {}", process_id, e))
+        })?;
+        Ok(())
+    })
+}
+
+/// Get the CPU usage of every running or waiting process as `METRIC_PROCESS_CPU_USAGE`
+///
+/// Feeds per-process CPU quota alerts. Metrics are sorted by process ID.
+/// Add them after releasing the process manager lock, since alert actions
+/// such as `suspend_process_action` take it.
+pub fn process_cpu_metrics(processes: &ProcessManager) -> Vec<Metric> {
+    let mut metrics: Vec<Metric> = processes.processes.values()
+        .filter(|p| matches!(p.state, ProcessState::Running | ProcessState::Waiting))
+        .map(|p| {
+            Metric::new(METRIC_PROCESS_CPU_USAGE, MetricType::Process, f64::from(p.cpu_usage), "%").for_process(&p.id)
+        })
+        .collect();
+    metrics.sort_by(|a, b| a.process_id.cmp(&b.process_id));
+    metrics
 }
 
 /// Monitoring system
 pub struct MonitoringSystem {
-    /// Metrics
-    pub metrics: std::collections::HashMap<String, Vec<Metric>>,
+    /// Metrics, oldest first
+    pub metrics: std::collections::HashMap<String, VecDeque<Metric>>,
     /// Metric handlers
     pub metric_handlers: Vec<Box<dyn Fn(&Metric) -> Result<(), MonitoringError> + Send + Sync>>,
+    /// Registered metrics by name
+    pub descriptors: BTreeMap<String, MetricDescriptor>,
+    /// Reasoning queries recorded
+    pub reasoning_queries: u64,
+    /// Reasoning queries served from cache
+    pub reasoning_cache_hits: u64,
+    /// Alert rules
+    pub alert_rules: Vec<AlertRule>,
+    /// Alert actions
+    pub alert_actions: Vec<AlertAction>,
+    /// Alert policy
+    pub alert_policy: AlertPolicy,
+    /// Fired alerts
+    pub alerts: Vec<Alert>,
+    /// Sustained rules currently in breach, by rule ID and process
+    sustained_breaches: HashSet<(String, Option<String>)>,
+    /// Number of samples kept per metric; the oldest are dropped first
+    pub max_history: usize,
 }
 
 impl MonitoringSystem {
@@ -94,9 +366,271 @@ impl MonitoringSystem {
         Ok(Self {
             metrics: std::collections::HashMap::new(),
             metric_handlers: Vec::new(),
+            descriptors: BTreeMap::new(),
+            reasoning_queries: 0,
+            reasoning_cache_hits: 0,
+            alert_rules: Vec::new(),
+            alert_actions: Vec::new(),
+            alert_policy: AlertPolicy::default(),
+            alerts: Vec::new(),
+            sustained_breaches: HashSet::new(),
+            max_history: DEFAULT_MAX_METRIC_HISTORY,
         })
     }
     
+    /// Set number of samples kept per metric, dropping older samples
+    pub fn set_max_history(&mut self, max_history: usize) {
+        self.max_history = max_history.max(1);
+        for history in self.metrics.values_mut() {
+            let excess = history.len().saturating_sub(self.max_history);
+            history.drain(..excess);
+        }
+    }
+    
+    /// Add alert rule firing when a metric rises above a threshold, returning its ID
+    pub fn add_alert_rule(&mut self, name: &str, metric: &str, threshold: f64) -> String {
+        self.add_sustained_alert_rule(name, metric, Comparator::Above, threshold, Duration::ZERO)
+    }
+    
+    /// Add alert rule firing when a metric breaches a threshold for `duration`, returning its ID
+    ///
+    /// Rules with a non-zero duration are checked by `evaluate_alerts`.
+    pub fn add_sustained_alert_rule(
+        &mut self,
+        name: &str,
+        metric: &str,
+        comparator: Comparator,
+        threshold: f64,
+        duration: Duration,
+    ) -> String {
+        let rule = AlertRule {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            metric: metric.to_string(),
+            comparator,
+            threshold,
+            duration,
+        };
+        let rule_id = rule.id.clone();
+        self.alert_rules.push(rule);
+        rule_id
+    }
+    
+    /// Add alert action
+    pub fn add_alert_action<F>(&mut self, action: F)
+    where
+        F: Fn(&Alert) -> Result<(), MonitoringError> + Send + Sync + 'static,
+    {
+        self.alert_actions.push(Box::new(action));
+    }
+    
+    /// Set alert policy
+    pub fn set_alert_policy(&mut self, policy: AlertPolicy) {
+        self.alert_policy = policy;
+    }
+    
+    /// Get fired alerts
+    pub fn get_alerts(&self) -> &[Alert] {
+        &self.alerts
+    }
+    
+    /// Fire alerts for instant rules the metric crosses
+    ///
+    /// A rule fires when the value breaches its threshold and the previous
+    /// sample of the same metric for the same process did not.
+    fn evaluate_crossings(&mut self, metric: &Metric) -> Vec<Alert> {
+        let previous = self.metrics.get(&metric.name)
+            .and_then(|history| history.iter().rev().find(|m| m.process_id == metric.process_id))
+            .map(|m| m.value);
+        
+        let fired: Vec<Alert> = self.alert_rules.iter()
+            .filter(|rule| rule.duration.is_zero() && rule.metric == metric.name)
+            .filter(|rule| rule.comparator.breached(metric.value, rule.threshold))
+            .filter(|rule| previous.is_none_or(|value| !rule.comparator.breached(value, rule.threshold)))
+            .map(|rule| Alert {
+                id: uuid::Uuid::new_v4().to_string(),
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                metric: metric.name.clone(),
+                value: metric.value,
+                comparator: rule.comparator,
+                threshold: rule.threshold,
+                process_id: metric.process_id.clone(),
+                timestamp: metric.timestamp,
+            })
+            .collect();
+        
+        self.alerts.extend(fired.iter().cloned());
+        fired
+    }
+    
+    /// Fire alerts for sustained rules whose metric has been in breach for their duration
+    ///
+    /// Samples are grouped by process, as for instant rules. A rule is in
+    /// breach for a process when every sample of that process in the
+    /// rolling window since `now - duration`, plus its last sample before
+    /// it, breaches the threshold. Each breach fires once; the rule re-arms
+    /// for the process when one of its samples no longer breaches.
+    pub fn evaluate_alerts(&mut self, now: SystemTime) -> Result<Vec<Alert>, MonitoringError> {
+        let mut fired = Vec::new();
+        for rule in self.alert_rules.iter().filter(|rule| !rule.duration.is_zero()) {
+            let history = match self.metrics.get(&rule.metric) {
+                Some(history) => history,
+                None => continue,
+            };
+            let mut by_process: BTreeMap<Option<&str>, Vec<&Metric>> = BTreeMap::new();
+            for metric in history {
+                by_process.entry(metric.process_id.as_deref()).or_default().push(metric);
+            }
+            
+            let since = now.checked_sub(rule.duration).unwrap_or(SystemTime::UNIX_EPOCH);
+            for (process_id, samples) in by_process {
+                let key = (rule.id.clone(), process_id.map(str::to_string));
+                let window = samples.iter().rev().take_while(|m| m.timestamp > since).count();
+                
+                // Without a sample at or before the window start the breach may be shorter
+                let recent: Vec<f64> = samples[samples.len().saturating_sub(window + 1)..]
+                    .iter()
+                    .map(|m| m.value)
+                    .collect();
+                let stats = match MetricStats::from_values(&recent) {
+                    Some(stats) if stats.count > window => stats,
+                    _ => {
+                        self.sustained_breaches.remove(&key);
+                        continue;
+                    }
+                };
+                let worst = match rule.comparator {
+                    Comparator::Above => stats.min,
+                    Comparator::Below => stats.max,
+                };
+                if !rule.comparator.breached(worst, rule.threshold) {
+                    self.sustained_breaches.remove(&key);
+                    continue;
+                }
+                if !self.sustained_breaches.insert(key) {
+                    continue;
+                }
+                
+                fired.push(Alert {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    rule_id: rule.id.clone(),
+                    rule_name: rule.name.clone(),
+                    metric: rule.metric.clone(),
+                    value: stats.last,
+                    comparator: rule.comparator,
+                    threshold: rule.threshold,
+                    process_id: process_id.map(str::to_string),
+                    timestamp: now,
+                });
+            }
+        }
+        
+        self.alerts.extend(fired.iter().cloned());
+        self.run_alert_actions(&fired)?;
+        Ok(fired)
+    }
+    
+    /// Run alert actions for fired alerts if the policy allows it
+    fn run_alert_actions(&self, fired: &[Alert]) -> Result<(), MonitoringError> {
+        if self.alert_policy.auto_action {
+            for alert in fired {
+                for action in &self.alert_actions {
+                    action(alert)?;
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    /// Register metric
+    pub fn register_metric(&mut self, descriptor: MetricDescriptor) -> Result<(), MonitoringError> {
+        if let Some(existing) = self.descriptors.get(&descriptor.name) {
+            if existing.metric_type != descriptor.metric_type || existing.unit != descriptor.unit {
+                return Err(MonitoringError::MonitoringError(format!(
+                    "Metric {} already registered as {} in {}",
+                    descriptor.name, existing.metric_type, existing.unit
+                )));
+            }
+        }
+        
+        self.descriptors.insert(descriptor.name.clone(), descriptor);
+        Ok(())
+    }
+    
+    /// Get registered metric
+    pub fn get_descriptor(&self, name: &str) -> Option<&MetricDescriptor> {
+        self.descriptors.get(name)
+    }
+    
+    /// Register reasoning latency and cache metrics
+    pub fn register_reasoning_metrics(&mut self) -> Result<(), MonitoringError> {
+        self.register_metric(MetricDescriptor::new(
+            METRIC_REASONING_LATENCY, MetricType::Reasoning, "ms", "Reasoning query wall time",
+        ))?;
+        self.register_metric(MetricDescriptor::new(
+            METRIC_REASONING_CACHE_HIT_RATE, MetricType::Reasoning, "ratio", "Share of reasoning queries served from cache",
+        ))?;
+        self.register_metric(MetricDescriptor::new(
+            METRIC_REASONING_FACTS_DERIVED, MetricType::Reasoning, "facts", "Facts derived per reasoning query",
+        ))?;
+        self.register_metric(MetricDescriptor::new(
+            METRIC_REASONING_RULE_FIRINGS, MetricType::Reasoning, "firings", "Rule firings per reasoning query",
+        ))
+    }
+    
+    /// Record a reasoning query
+    pub fn record_reasoning(&mut self, sample: &ReasoningSample) -> Result<(), MonitoringError> {
+        if !self.descriptors.contains_key(METRIC_REASONING_LATENCY) {
+            self.register_reasoning_metrics()?;
+        }
+        
+        self.reasoning_queries += 1;
+        if sample.cache_hit {
+            self.reasoning_cache_hits += 1;
+        }
+        let hit_rate = self.reasoning_cache_hits as f64 / self.reasoning_queries as f64;
+        
+        self.record_registered(METRIC_REASONING_LATENCY, sample.latency.as_secs_f64() * 1000.0)?;
+        self.record_registered(METRIC_REASONING_CACHE_HIT_RATE, hit_rate)?;
+        self.record_registered(METRIC_REASONING_FACTS_DERIVED, sample.facts_derived as f64)?;
+        self.record_registered(METRIC_REASONING_RULE_FIRINGS, sample.rule_firings as f64)
+    }
+    
+    /// Record a value for a registered metric
+    pub fn record_registered(&mut self, name: &str, value: f64) -> Result<(), MonitoringError> {
+        let descriptor = self.descriptors.get(name)
+            .ok_or_else(|| MonitoringError::MonitoringError(format!("Metric {} is not registered", name)))?;
+        let metric = Metric::new(name, descriptor.metric_type, value, &descriptor.unit);
+        self.add_metric(metric)
+    }
+    
+    /// Get rolling statistics over the last `window` samples of a metric
+    ///
+    /// The window is capped by the samples kept, see `max_history`. The
+    /// 95th percentile is the nearest-rank sample.
+    pub fn metric_stats(&self, name: &str, window: usize) -> Option<MetricStats> {
+        let history = self.metrics.get(name)?;
+        let recent: Vec<f64> = history.iter()
+            .skip(history.len().saturating_sub(window))
+            .map(|m| m.value)
+            .collect();
+        MetricStats::from_values(&recent)
+    }
+    
+    /// Export latest values of registered metrics in Prometheus text format
+    pub fn export_prometheus(&self) -> String {
+        let mut out = String::new();
+        for descriptor in self.descriptors.values() {
+            if let Some(metric) = self.get_latest_metric(&descriptor.name) {
+                let _ = writeln!(out, "# HELP {} {} ({})", descriptor.name, descriptor.help, descriptor.unit);
+                let _ = writeln!(out, "# TYPE {} gauge", descriptor.name);
+                let _ = writeln!(out, "{} {}", descriptor.name, metric.value);
+            }
+        }
+        out
+    }
+    
     /// Add metric
     pub fn add_metric(&mut self, metric: Metric) -> Result<(), MonitoringError> {
         // Notify metric handlers
@@ -106,27 +640,33 @@ impl MonitoringSystem {
             }
         }
         
-        // Add metric to history
-        let metrics = self.metrics.entry(metric.name.clone()).or_insert_with(Vec::new);
-        metrics.push(metric);
+        let fired = self.evaluate_crossings(&metric);
         
-        Ok(())
+        // Add metric to history, keeping at most `max_history` samples
+        let metrics = self.metrics.entry(metric.name.clone()).or_default();
+        metrics.push_back(metric);
+        while metrics.len() > self.max_history {
+            metrics.pop_front();
+        }
+        
+        // Run alert actions
+        self.run_alert_actions(&fired)
     }
     
     /// Get latest metric
     pub fn get_latest_metric(&self, name: &str) -> Option<&Metric> {
-        self.metrics.get(name).and_then(|metrics| metrics.last())
+        self.metrics.get(name).and_then(|metrics| metrics.back())
     }
     
-    /// Get metric history
-    pub fn get_metric_history(&self, name: &str) -> Option<&Vec<Metric>> {
+    /// Get metric history, oldest first
+    pub fn get_metric_history(&self, name: &str) -> Option<&VecDeque<Metric>> {
         self.metrics.get(name)
     }
     
     /// Get metrics by type
     pub fn get_metrics_by_type(&self, metric_type: MetricType) -> Vec<&Metric> {
         self.metrics.values()
-            .filter_map(|metrics| metrics.last())
+            .filter_map(|metrics| metrics.back())
             .filter(|m| m.metric_type == metric_type)
             .collect()
     }
@@ -140,6 +680,270 @@ impl MonitoringSystem {
     }
 }
 
+/// Idle state transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleTransition {
+    /// No change
+    None,
+    /// Idle timeout elapsed, the system should suspend
+    Suspend,
+    /// Activity arrived while suspended, the system should resume
+    Resume,
+}
+
+/// Shared handle for reporting input or bus activity from other threads
+#[derive(Debug, Clone, Default)]
+pub struct ActivitySignal {
+    /// Latest activity not yet seen by the idle detector
+    pending: Arc<Mutex<Option<SystemTime>>>,
+}
+
+impl ActivitySignal {
+    /// Create a new activity signal
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Report activity at `now`
+    pub fn notify(&self, now: SystemTime) {
+        if let Ok(mut pending) = self.pending.lock() {
+            if pending.is_none_or(|t| now > t) {
+                *pending = Some(now);
+            }
+        }
+    }
+    
+    /// Take the latest activity reported since the last call
+    pub fn take(&self) -> Option<SystemTime> {
+        self.pending.lock().ok().and_then(|mut pending| pending.take())
+    }
+}
+
+/// Idle detector tracking input and bus activity
+#[derive(Debug, Clone)]
+pub struct IdleDetector {
+    /// Idle timeout; `None` disables auto-suspend
+    pub idle_timeout: Option<Duration>,
+    /// Last activity time
+    pub last_activity: SystemTime,
+    /// Whether the system is suspended
+    pub suspended: bool,
+    /// Activity reported by input and bus producers
+    activity: ActivitySignal,
+}
+
+impl IdleDetector {
+    /// Create a new idle detector
+    pub fn new(idle_timeout: Option<Duration>, now: SystemTime) -> Self {
+        Self {
+            idle_timeout,
+            last_activity: now,
+            suspended: false,
+            activity: ActivitySignal::new(),
+        }
+    }
+    
+    /// Set idle timeout
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.idle_timeout = idle_timeout;
+    }
+    
+    /// Record input or bus activity
+    pub fn record_activity(&mut self, now: SystemTime) -> IdleTransition {
+        if now > self.last_activity {
+            self.last_activity = now;
+        }
+        
+        if self.suspended {
+            self.suspended = false;
+            IdleTransition::Resume
+        } else {
+            IdleTransition::None
+        }
+    }
+    
+    /// Get a handle input and bus producers use to report activity
+    pub fn activity_signal(&self) -> ActivitySignal {
+        self.activity.clone()
+    }
+    
+    /// Record activity reported through the activity signal since the last poll
+    pub fn poll_activity(&mut self) -> IdleTransition {
+        match self.activity.take() {
+            Some(at) => self.record_activity(at),
+            None => IdleTransition::None,
+        }
+    }
+    
+    /// Check whether the idle timeout has elapsed
+    pub fn check(&mut self, now: SystemTime) -> IdleTransition {
+        let timeout = match self.idle_timeout {
+            Some(timeout) => timeout,
+            None => return IdleTransition::None,
+        };
+        
+        if !self.suspended && self.idle_duration(now) >= timeout {
+            self.suspended = true;
+            IdleTransition::Suspend
+        } else {
+            IdleTransition::None
+        }
+    }
+    
+    /// Get time since the last activity
+    pub fn idle_duration(&self, now: SystemTime) -> Duration {
+        now.duration_since(self.last_activity).unwrap_or_default()
+    }
+    
+    /// Check if suspended
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+    
+    /// Get idle time metric
+    pub fn idle_metric(&self, now: SystemTime) -> Metric {
+        Metric::new(IDLE_METRIC, MetricType::System, self.idle_duration(now).as_secs_f64(), "s")
+    }
+}
+
+/// Update time spent in a subsystem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubsystemTiming {
+    /// Total update time
+    pub total: Duration,
+    /// Number of updates
+    pub updates: u64,
+}
+
+/// Memory region still allocated at shutdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakedRegion {
+    /// Region ID
+    pub id: String,
+    /// Region owner
+    pub owner: Option<String>,
+    /// Region size in bytes
+    pub size: usize,
+}
+
+/// Session summary produced on shutdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionReport {
+    /// Session uptime
+    pub uptime: Duration,
+    /// Update time per subsystem
+    pub subsystem_timings: BTreeMap<String, SubsystemTiming>,
+    /// Session counters
+    pub counters: BTreeMap<String, u64>,
+    /// Memory regions still allocated
+    pub leaked_regions: Vec<LeakedRegion>,
+}
+
+impl SessionReport {
+    /// Get counter value
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters.get(name).copied().unwrap_or(0)
+    }
+    
+    /// Render the report as Markdown
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# Session report");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "- Uptime: {:.3}s", self.uptime.as_secs_f64());
+        let _ = writeln!(out);
+        
+        let _ = writeln!(out, "## Subsystem update time");
+        let _ = writeln!(out);
+        for (name, timing) in &self.subsystem_timings {
+            let _ = writeln!(out, "- {}: {:.3}s over {} updates", name, timing.total.as_secs_f64(), timing.updates);
+        }
+        let _ = writeln!(out);
+        
+        let _ = writeln!(out, "## Counters");
+        let _ = writeln!(out);
+        for (name, value) in &self.counters {
+            let _ = writeln!(out, "- {}: {}", name, value);
+        }
+        let _ = writeln!(out);
+        
+        let _ = writeln!(out, "## Leaked memory regions");
+        let _ = writeln!(out);
+        if self.leaked_regions.is_empty() {
+            let _ = writeln!(out, "None");
+        }
+        for region in &self.leaked_regions {
+            let _ = writeln!(
+                out,
+                "- {} ({} bytes, owner {})",
+                region.id,
+                region.size,
+                region.owner.as_deref().unwrap_or("<none>")
+            );
+        }
+        
+        out
+    }
+    
+    /// Write the rendered report to a file
+    pub fn write_to(&self, path: &str) -> Result<(), MonitoringError> {
+        std::fs::write(path, self.render())
+            .map_err(|e| MonitoringError::Other(format!("Failed to write session report {}: {}", path, e)))
+    }
+}
+
+/// Session statistics recorder
+#[derive(Debug, Clone)]
+pub struct SessionRecorder {
+    /// Session start time
+    pub started_at: SystemTime,
+    /// Update time per subsystem
+    pub subsystem_timings: BTreeMap<String, SubsystemTiming>,
+    /// Session counters
+    pub counters: BTreeMap<String, u64>,
+}
+
+impl SessionRecorder {
+    /// Create a new session recorder
+    pub fn new(started_at: SystemTime) -> Self {
+        Self {
+            started_at,
+            subsystem_timings: BTreeMap::new(),
+            counters: BTreeMap::new(),
+        }
+    }
+    
+    /// Record a subsystem update
+    pub fn record_update(&mut self, subsystem: &str, elapsed: Duration) {
+        let timing = self.subsystem_timings.entry(subsystem.to_string()).or_default();
+        timing.total += elapsed;
+        timing.updates += 1;
+    }
+    
+    /// Increase a counter
+    pub fn increment(&mut self, counter: &str, by: u64) {
+        *self.counters.entry(counter.to_string()).or_insert(0) += by;
+    }
+    
+    /// Set a counter
+    pub fn set_counter(&mut self, counter: &str, value: u64) {
+        self.counters.insert(counter.to_string(), value);
+    }
+    
+    /// Finish the session
+    pub fn finish(&self, now: SystemTime, leaked_regions: Vec<LeakedRegion>) -> SessionReport {
+        let mut leaked_regions = leaked_regions;
+        leaked_regions.sort_by(|a, b| a.id.cmp(&b.id));
+        
+        SessionReport {
+            uptime: now.duration_since(self.started_at).unwrap_or_default(),
+            subsystem_timings: self.subsystem_timings.clone(),
+            counters: self.counters.clone(),
+            leaked_regions,
+        }
+    }
+}
+
 /// Initialize monitoring module
 pub fn init() -> Result<(), MonitoringError> {
     // Initialize monitoring module
@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        IdleDetector, IdleTransition, LeakedRegion, SessionRecorder,
+        COUNTER_PLANS_COMPLETED, COUNTER_PROCESSES_RUN, COUNTER_THREATS_DETECTED,
+    };
+    use crate::system::{suspend_process_action, AlertPolicy, Comparator, Metric, MetricType, MonitoringSystem, METRIC_PROCESS_CPU_USAGE};
+    use crate::system::process_cpu_metrics;
+    use crate::kernel::{ProcessManager, ProcessPriority, ProcessState};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_idle_detector_suspends_and_resumes() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut detector = IdleDetector::new(Some(Duration::from_secs(60)), start);
+
+        assert_eq!(detector.check(start + Duration::from_secs(30)), IdleTransition::None);
+        assert!(!detector.is_suspended());
+
+        assert_eq!(detector.check(start + Duration::from_secs(61)), IdleTransition::Suspend);
+        assert!(detector.is_suspended());
+        assert_eq!(detector.check(start + Duration::from_secs(120)), IdleTransition::None);
+
+        let metric = detector.idle_metric(start + Duration::from_secs(120));
+        assert_eq!(metric.value, 120.0);
+
+        assert_eq!(detector.record_activity(start + Duration::from_secs(125)), IdleTransition::Resume);
+        assert!(!detector.is_suspended());
+        assert_eq!(detector.idle_duration(start + Duration::from_secs(130)), Duration::from_secs(5));
+        assert_eq!(detector.check(start + Duration::from_secs(130)), IdleTransition::None);
+    }
+
+    #[test]
+    fn test_idle_detector_activity_defers_suspend() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut detector = IdleDetector::new(Some(Duration::from_secs(60)), start);
+
+        assert_eq!(detector.record_activity(start + Duration::from_secs(50)), IdleTransition::None);
+        assert_eq!(detector.check(start + Duration::from_secs(100)), IdleTransition::None);
+        assert_eq!(detector.check(start + Duration::from_secs(110)), IdleTransition::Suspend);
+    }
+
+    #[test]
+    fn test_idle_detector_resumes_on_signalled_activity() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut detector = IdleDetector::new(Some(Duration::from_secs(60)), start);
+        let signal = detector.activity_signal();
+
+        assert_eq!(detector.check(start + Duration::from_secs(61)), IdleTransition::Suspend);
+        assert_eq!(detector.poll_activity(), IdleTransition::None);
+
+        // Input arrives on another thread while suspended
+        let producer = signal.clone();
+        std::thread::spawn(move || producer.notify(start + Duration::from_secs(90)))
+            .join()
+            .unwrap();
+        signal.notify(start + Duration::from_secs(80));
+
+        assert_eq!(detector.poll_activity(), IdleTransition::Resume);
+        assert!(!detector.is_suspended());
+        assert_eq!(detector.idle_duration(start + Duration::from_secs(100)), Duration::from_secs(10));
+        assert_eq!(detector.poll_activity(), IdleTransition::None);
+    }
+
+    #[test]
+    fn test_idle_detector_disabled() {
+        let start = SystemTime::UNIX_EPOCH;
+        let mut detector = IdleDetector::new(None, start);
+
+        assert_eq!(detector.check(start + Duration::from_secs(86_400)), IdleTransition::None);
+        assert!(!detector.is_suspended());
+    }
+
+    #[test]
+    fn test_session_report_summarizes_mock_session() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let mut session = SessionRecorder::new(start);
+
+        for _ in 0..3 {
+            session.record_update("planning_system", Duration::from_millis(20));
+            session.record_update("memory_manager", Duration::from_millis(5));
+        }
+        session.increment(COUNTER_PROCESSES_RUN, 2);
+        session.increment(COUNTER_PROCESSES_RUN, 1);
+        session.set_counter(COUNTER_PLANS_COMPLETED, 1);
+
+        let leaked = vec![LeakedRegion { id: "block-1".to_string(), owner: Some("planner".to_string()), size: 4096 }];
+        let report = session.finish(start + Duration::from_secs(90), leaked);
+
+        assert_eq!(report.uptime, Duration::from_secs(90));
+        assert_eq!(report.counter(COUNTER_PROCESSES_RUN), 3);
+        assert_eq!(report.counter(COUNTER_THREATS_DETECTED), 0);
+        let planning = report.subsystem_timings["planning_system"];
+        assert_eq!(planning.total, Duration::from_millis(60));
+        assert_eq!(planning.updates, 3);
+
+        let path = std::env::temp_dir().join(format!("session_report_{}.md", uuid::Uuid::new_v4()));
+        let path = path.to_str().unwrap();
+        report.write_to(path).unwrap();
+        let written = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(written, report.render());
+        assert!(written.contains("- Uptime: 90.000s"));
+        assert!(written.contains("- planning_system: 0.060s over 3 updates"));
+        assert!(written.contains("- processes_run: 3"));
+        assert!(written.contains("- block-1 (4096 bytes, owner planner)"));
+    }
+
+    #[test]
+    fn test_cpu_quota_alert_suspends_process() {
+        let processes = Arc::new(Mutex::new(ProcessManager::new().unwrap()));
+        let (hog, quiet) = {
+            let mut manager = processes.lock().unwrap();
+            let hog = manager.create_process("hog", ProcessPriority::Normal).unwrap();
+            let quiet = manager.create_process("quiet", ProcessPriority::Normal).unwrap();
+            manager.start_process(&hog).unwrap();
+            manager.start_process(&quiet).unwrap();
+            (hog, quiet)
+        };
+
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        monitoring.add_alert_rule("cpu_quota", METRIC_PROCESS_CPU_USAGE, 80.0);
+        monitoring.add_alert_action(suspend_process_action(processes.clone()));
+        let cpu = |value: f64, process_id: &str| {
+            Metric::new(METRIC_PROCESS_CPU_USAGE, MetricType::Process, value, "%").for_process(process_id)
+        };
+
+        // Alerts are recorded but not acted on until the policy allows it
+        monitoring.add_metric(cpu(95.0, &hog)).unwrap();
+        assert_eq!(monitoring.get_alerts().len(), 1);
+        assert_eq!(processes.lock().unwrap().get_process(&hog).unwrap().state, ProcessState::Running);
+
+        monitoring.set_alert_policy(AlertPolicy { auto_action: true });
+        monitoring.add_metric(cpu(40.0, &hog)).unwrap();
+        monitoring.add_metric(cpu(50.0, &quiet)).unwrap();
+        monitoring.add_metric(cpu(120.0, &hog)).unwrap();
+
+        let alerts = monitoring.get_alerts();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[1].process_id.as_deref(), Some(hog.as_str()));
+        assert_eq!(alerts[1].value, 120.0);
+
+        let mut manager = processes.lock().unwrap();
+        assert_eq!(manager.get_process(&hog).unwrap().state, ProcessState::Suspended);
+        assert_eq!(manager.get_process(&quiet).unwrap().state, ProcessState::Running);
+
+        manager.resume_process(&hog).unwrap();
+        assert_eq!(manager.get_process(&hog).unwrap().state, ProcessState::Running);
+    }
+
+    #[test]
+    fn test_process_cpu_metrics_feed_quota_alert() {
+        let processes = Arc::new(Mutex::new(ProcessManager::new().unwrap()));
+        let (hog, quiet, idle) = {
+            let mut manager = processes.lock().unwrap();
+            let hog = manager.create_process("hog", ProcessPriority::Normal).unwrap();
+            let quiet = manager.create_process("quiet", ProcessPriority::Normal).unwrap();
+            let idle = manager.create_process("idle", ProcessPriority::Normal).unwrap();
+            manager.start_process(&hog).unwrap();
+            manager.start_process(&quiet).unwrap();
+            manager.get_process_mut(&hog).unwrap().set_usage(0, 95.0);
+            manager.get_process_mut(&quiet).unwrap().set_usage(0, 20.0);
+            manager.get_process_mut(&idle).unwrap().set_usage(0, 99.0);
+            (hog, quiet, idle)
+        };
+
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        monitoring.add_alert_rule("cpu_quota", METRIC_PROCESS_CPU_USAGE, 80.0);
+        monitoring.set_alert_policy(AlertPolicy { auto_action: true });
+        monitoring.add_alert_action(suspend_process_action(processes.clone()));
+
+        // Processes that are not running report nothing
+        let metrics = process_cpu_metrics(&processes.lock().unwrap());
+        assert_eq!(metrics.len(), 2);
+        assert!(metrics.iter().all(|m| m.name == METRIC_PROCESS_CPU_USAGE && m.process_id.as_deref() != Some(idle.as_str())));
+        for metric in metrics {
+            monitoring.add_metric(metric).unwrap();
+        }
+
+        let manager = processes.lock().unwrap();
+        assert_eq!(manager.get_process(&hog).unwrap().state, ProcessState::Suspended);
+        assert_eq!(manager.get_process(&quiet).unwrap().state, ProcessState::Running);
+    }
+
+    #[test]
+    fn test_metric_stats_rolling_window() {
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        let cpu = |value: f64| Metric::new(METRIC_PROCESS_CPU_USAGE, MetricType::System, value, "%");
+
+        // Samples 1 to 20, out of order, ending with 6, 13, 20, 7, 14
+        for i in 0..20 {
+            monitoring.add_metric(cpu(((i * 7) % 20 + 1) as f64)).unwrap();
+        }
+
+        let stats = monitoring.metric_stats(METRIC_PROCESS_CPU_USAGE, 20).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max), (20, 1.0, 20.0));
+        assert_eq!(stats.mean, 10.5);
+        assert_eq!(stats.p95, 19.0);
+        assert_eq!(stats.last, 14.0);
+
+        let stats = monitoring.metric_stats(METRIC_PROCESS_CPU_USAGE, 5).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max), (5, 6.0, 20.0));
+        assert_eq!(stats.mean, 12.0);
+        assert_eq!(stats.p95, 20.0);
+
+        // Only the last `max_history` samples are kept
+        monitoring.set_max_history(4);
+        assert_eq!(monitoring.get_metric_history(METRIC_PROCESS_CPU_USAGE).unwrap().len(), 4);
+        monitoring.add_metric(cpu(50.0)).unwrap();
+        let stats = monitoring.metric_stats(METRIC_PROCESS_CPU_USAGE, 100).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max, stats.last), (4, 7.0, 50.0, 50.0));
+        assert!(monitoring.metric_stats("missing", 10).is_none());
+    }
+
+    #[test]
+    fn test_sustained_alert_fires_once_after_duration() {
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        let rule_id = monitoring.add_sustained_alert_rule(
+            "cpu_sustained", METRIC_PROCESS_CPU_USAGE, Comparator::Above, 80.0, Duration::from_secs(60),
+        );
+        let start = SystemTime::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let cpu = |value: f64, secs: u64| Metric {
+            timestamp: at(secs),
+            ..Metric::new(METRIC_PROCESS_CPU_USAGE, MetricType::System, value, "%")
+        };
+
+        // A sample every 10 seconds, above threshold from the start
+        let mut fired = Vec::new();
+        for i in 0..=9 {
+            monitoring.add_metric(cpu(90.0, i * 10)).unwrap();
+            fired.extend(monitoring.evaluate_alerts(at(i * 10)).unwrap());
+        }
+
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].rule_id, rule_id);
+        assert_eq!(fired[0].timestamp, at(60));
+        assert_eq!(fired[0].comparator, Comparator::Above);
+        assert_eq!(monitoring.get_alerts().len(), 1);
+
+        // Dropping below the threshold re-arms the rule
+        monitoring.add_metric(cpu(50.0, 100)).unwrap();
+        assert!(monitoring.evaluate_alerts(at(100)).unwrap().is_empty());
+        monitoring.add_metric(cpu(95.0, 110)).unwrap();
+        assert!(monitoring.evaluate_alerts(at(160)).unwrap().is_empty());
+        assert_eq!(monitoring.evaluate_alerts(at(170)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_sustained_alert_is_evaluated_per_process() {
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        monitoring.add_sustained_alert_rule(
+            "cpu_sustained", METRIC_PROCESS_CPU_USAGE, Comparator::Above, 80.0, Duration::from_secs(30),
+        );
+        let start = SystemTime::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let cpu = |value: f64, process_id: &str, secs: u64| Metric {
+            timestamp: at(secs),
+            ..Metric::new(METRIC_PROCESS_CPU_USAGE, MetricType::Process, value, "%").for_process(process_id)
+        };
+
+        // A quiet process reporting after the hot one does not mask its breach
+        let mut fired = Vec::new();
+        for i in 0..=4 {
+            monitoring.add_metric(cpu(95.0, "hot", i * 10)).unwrap();
+            monitoring.add_metric(cpu(10.0, "quiet", i * 10)).unwrap();
+            fired.extend(monitoring.evaluate_alerts(at(i * 10)).unwrap());
+        }
+
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].process_id.as_deref(), Some("hot"));
+        assert_eq!(fired[0].value, 95.0);
+        assert_eq!(fired[0].timestamp, at(30));
+    }
+}
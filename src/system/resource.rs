@@ -11,6 +11,8 @@ pub enum ResourceError {
     AllocationError(String),
     /// Deallocation error
     DeallocationError(String),
+    /// Parse error
+    ParseError(String),
     /// Other error
     Other(String),
 }
@@ -23,6 +25,7 @@ impl fmt::Display for ResourceError {
             ResourceError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
             ResourceError::AllocationError(msg) => write!(f, "Allocation error: {}", msg),
             ResourceError::DeallocationError(msg) => write!(f, "Deallocation error: {}", msg),
+            ResourceError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             ResourceError::Other(msg) => write!(f, "Other error: {}", msg),
         }
     }
@@ -58,6 +61,265 @@ impl fmt::Display for ResourceType {
     }
 }
 
+/// Byte size with explicit binary units (1 KB = 1024 bytes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Bytes per kilobyte
+    pub const KB: u64 = 1024;
+    /// Bytes per megabyte
+    pub const MB: u64 = 1024 * 1024;
+    /// Bytes per gigabyte
+    pub const GB: u64 = 1024 * 1024 * 1024;
+    /// Bytes per terabyte
+    pub const TB: u64 = 1024 * 1024 * 1024 * 1024;
+    
+    /// Create from bytes
+    pub const fn bytes(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+    
+    /// Create from kilobytes
+    pub const fn kb(kb: u64) -> Self {
+        ByteSize(kb * Self::KB)
+    }
+    
+    /// Create from megabytes
+    pub const fn mb(mb: u64) -> Self {
+        ByteSize(mb * Self::MB)
+    }
+    
+    /// Create from gigabytes
+    pub const fn gb(gb: u64) -> Self {
+        ByteSize(gb * Self::GB)
+    }
+    
+    /// Get size in bytes
+    pub fn as_bytes(&self) -> u64 {
+        self.0
+    }
+    
+    /// Get size in whole megabytes, rounding down
+    pub fn as_mb(&self) -> u64 {
+        self.0 / Self::MB
+    }
+    
+    /// Get size in whole gigabytes, rounding down
+    pub fn as_gb(&self) -> u64 {
+        self.0 / Self::GB
+    }
+    
+    /// Parse a size such as "4GB", "4096MB", "512 KiB" or "1.5G".
+    /// A unit is required so values cannot be misread by a factor of 1024.
+    pub fn parse(value: &str) -> Result<Self, ResourceError> {
+        let trimmed = value.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .ok_or_else(|| ResourceError::ParseError(format!("Missing unit in size: {}", value)))?;
+        let (number, unit) = trimmed.split_at(split);
+        
+        let multiplier = match unit.trim().to_ascii_uppercase().as_str() {
+            "B" => 1,
+            "K" | "KB" | "KIB" => Self::KB,
+            "M" | "MB" | "MIB" => Self::MB,
+            "G" | "GB" | "GIB" => Self::GB,
+            "T" | "TB" | "TIB" => Self::TB,
+            other => {
+                return Err(ResourceError::ParseError(format!(
+                    "Unknown size unit '{}' in: {}",
+                    other, value
+                )));
+            }
+        };
+        
+        let bytes = if number.contains('.') {
+            let number: f64 = number.parse().map_err(|_| {
+                ResourceError::ParseError(format!("Invalid size: {}", value))
+            })?;
+            let bytes = number * multiplier as f64;
+            if bytes.fract() != 0.0 || bytes > u64::MAX as f64 {
+                return Err(ResourceError::ParseError(format!(
+                    "Size is not a whole number of bytes: {}",
+                    value
+                )));
+            }
+            bytes as u64
+        } else {
+            let number: u64 = number.parse().map_err(|_| {
+                ResourceError::ParseError(format!("Invalid size: {}", value))
+            })?;
+            number.checked_mul(multiplier).ok_or_else(|| {
+                ResourceError::ParseError(format!("Size overflows: {}", value))
+            })?
+        };
+        
+        Ok(ByteSize(bytes))
+    }
+}
+
+impl std::str::FromStr for ByteSize {
+    type Err = ResourceError;
+    
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ByteSize::parse(value)
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Use the largest unit that represents the size exactly
+        let units = [(Self::TB, "TB"), (Self::GB, "GB"), (Self::MB, "MB"), (Self::KB, "KB")];
+        for (size, unit) in units.iter() {
+            if self.0 >= *size && self.0.is_multiple_of(*size) {
+                return write!(f, "{}{}", self.0 / size, unit);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+/// Model precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ModelPrecision {
+    /// 32-bit floating point
+    FP32,
+    /// 16-bit floating point
+    FP16,
+    /// 8-bit integer
+    INT8,
+}
+
+impl ModelPrecision {
+    /// Get bytes per parameter
+    pub fn bytes_per_parameter(&self) -> usize {
+        match self {
+            ModelPrecision::FP32 => 4,
+            ModelPrecision::FP16 => 2,
+            ModelPrecision::INT8 => 1,
+        }
+    }
+}
+
+impl fmt::Display for ModelPrecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelPrecision::FP32 => write!(f, "FP32"),
+            ModelPrecision::FP16 => write!(f, "FP16"),
+            ModelPrecision::INT8 => write!(f, "INT8"),
+        }
+    }
+}
+
+/// Precision change
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrecisionChange {
+    /// Previous precision
+    pub from: ModelPrecision,
+    /// New precision
+    pub to: ModelPrecision,
+    /// Memory pressure that triggered the change
+    pub memory_pressure: f64,
+    /// Change timestamp
+    pub timestamp: std::time::SystemTime,
+}
+
+impl PrecisionChange {
+    /// Check if this change is a downgrade
+    pub fn is_downgrade(&self) -> bool {
+        self.to > self.from
+    }
+}
+
+/// Precision policy driven by memory pressure
+#[derive(Debug, Clone)]
+pub struct PrecisionPolicy {
+    /// Memory pressure at which models switch to FP16
+    pub fp16_threshold: f64,
+    /// Memory pressure at which models switch to INT8
+    pub int8_threshold: f64,
+    /// Pressure drop below a threshold required before switching back
+    pub recovery_margin: f64,
+    /// Current precision
+    pub current: ModelPrecision,
+}
+
+impl PrecisionPolicy {
+    /// Create a new precision policy
+    pub fn new(fp16_threshold: f64, int8_threshold: f64, recovery_margin: f64) -> Result<Self, ResourceError> {
+        if !(0.0..=1.0).contains(&fp16_threshold) || !(0.0..=1.0).contains(&int8_threshold) || fp16_threshold > int8_threshold {
+            return Err(ResourceError::InitializationError(format!(
+                "Invalid precision thresholds: fp16={}, int8={}",
+                fp16_threshold, int8_threshold
+            )));
+        }
+        
+        Ok(Self {
+            fp16_threshold,
+            int8_threshold,
+            recovery_margin: recovery_margin.max(0.0),
+            current: ModelPrecision::FP32,
+        })
+    }
+    
+    /// Get target precision for the given memory pressure
+    pub fn target_precision(&self, memory_pressure: f64) -> ModelPrecision {
+        if memory_pressure >= self.int8_threshold {
+            ModelPrecision::INT8
+        } else if memory_pressure >= self.fp16_threshold {
+            // Stay at INT8 until pressure clearly eases
+            if self.current == ModelPrecision::INT8 && memory_pressure >= self.int8_threshold - self.recovery_margin {
+                ModelPrecision::INT8
+            } else {
+                ModelPrecision::FP16
+            }
+        } else if self.current != ModelPrecision::FP32 && memory_pressure >= self.fp16_threshold - self.recovery_margin {
+            ModelPrecision::FP16
+        } else {
+            ModelPrecision::FP32
+        }
+    }
+    
+    /// Evaluate memory pressure, returning a change if the precision switched
+    pub fn evaluate(&mut self, memory_pressure: f64) -> Option<PrecisionChange> {
+        let change = self.propose(memory_pressure)?;
+        self.commit(&change);
+        Some(change)
+    }
+    
+    /// Get the change memory pressure calls for, without switching precision
+    pub fn propose(&self, memory_pressure: f64) -> Option<PrecisionChange> {
+        let target = self.target_precision(memory_pressure);
+        
+        if target == self.current {
+            return None;
+        }
+        
+        Some(PrecisionChange {
+            from: self.current,
+            to: target,
+            memory_pressure,
+            timestamp: std::time::SystemTime::now(),
+        })
+    }
+    
+    /// Switch to the precision of an accepted change
+    pub fn commit(&mut self, change: &PrecisionChange) {
+        self.current = change.to;
+    }
+}
+
+impl Default for PrecisionPolicy {
+    fn default() -> Self {
+        Self {
+            fp16_threshold: 0.8,
+            int8_threshold: 0.9,
+            recovery_margin: 0.1,
+            current: ModelPrecision::FP32,
+        }
+    }
+}
+
 /// Resource
 #[derive(Debug, Clone)]
 pub struct Resource {
@@ -94,6 +356,11 @@ impl Resource {
         }
     }
     
+    /// Create a new resource measured in bytes
+    pub fn with_byte_size(name: &str, resource_type: ResourceType, capacity: ByteSize) -> Self {
+        Self::new(name, resource_type, capacity.as_bytes() as f64, "B")
+    }
+    
     /// Set owner
     pub fn set_owner(&mut self, owner: &str) {
         self.owner = Some(owner.to_string());
@@ -137,6 +404,12 @@ pub struct ResourceManager {
     pub resources: std::collections::HashMap<String, Resource>,
     /// Resource handlers
     pub resource_handlers: Vec<Box<dyn Fn(&Resource) -> Result<(), ResourceError> + Send + Sync>>,
+    /// Precision policy
+    pub precision_policy: PrecisionPolicy,
+    /// Precision change handlers
+    pub precision_handlers: Vec<Box<dyn Fn(&PrecisionChange) -> Result<(), ResourceError> + Send + Sync>>,
+    /// Precision change history
+    pub precision_history: Vec<PrecisionChange>,
 }
 
 impl ResourceManager {
@@ -145,6 +418,9 @@ impl ResourceManager {
         Ok(Self {
             resources: std::collections::HashMap::new(),
             resource_handlers: Vec::new(),
+            precision_policy: PrecisionPolicy::default(),
+            precision_handlers: Vec::new(),
+            precision_history: Vec::new(),
         })
     }
     
@@ -172,39 +448,51 @@ impl ResourceManager {
     }
     
     /// Allocate resource
+    ///
+    /// Handler errors are logged rather than returned, since the allocation
+    /// has already been made and must not be retried.
     pub fn allocate_resource(&mut self, id: &str, amount: f64) -> Result<(), ResourceError> {
         let resource = self.resources.get_mut(id).ok_or_else(|| {
             ResourceError::AllocationError(format!("Resource not found: id={}", id))
         })?;
         
         resource.allocate(amount)?;
-        
-        // Notify resource handlers
-        for handler in &self.resource_handlers {
-            if let Err(e) = handler(resource) {
-                return Err(e);
-            }
-        }
+        self.resource_changed(id);
         
         Ok(())
     }
     
     /// Deallocate resource
+    ///
+    /// Handler errors are logged rather than returned, as for allocation.
     pub fn deallocate_resource(&mut self, id: &str, amount: f64) -> Result<(), ResourceError> {
         let resource = self.resources.get_mut(id).ok_or_else(|| {
             ResourceError::DeallocationError(format!("Resource not found: id={}", id))
         })?;
         
         resource.deallocate(amount)?;
+        self.resource_changed(id);
+        
+        Ok(())
+    }
+    
+    /// Notify handlers of a changed resource, re-checking memory pressure for memory
+    fn resource_changed(&mut self, id: &str) {
+        let Some(resource) = self.resources.get(id) else {
+            return;
+        };
         
-        // Notify resource handlers
         for handler in &self.resource_handlers {
             if let Err(e) = handler(resource) {
-                return Err(e);
+                println!("[RESOURCE] Handler rejected change to {}: {}", resource.name, e);
             }
         }
         
-        Ok(())
+        if resource.resource_type == ResourceType::Memory {
+            if let Err(e) = self.check_memory_pressure() {
+                println!("[RESOURCE] Precision change rejected: {}", e);
+            }
+        }
     }
     
     /// Get resources by type
@@ -221,6 +509,60 @@ impl ResourceManager {
             .collect()
     }
     
+    /// Get memory pressure across all memory resources
+    pub fn get_memory_pressure(&self) -> f64 {
+        let (used, capacity) = self.get_resources_by_type(ResourceType::Memory)
+            .iter()
+            .fold((0.0, 0.0), |(used, capacity), r| (used + r.used, capacity + r.capacity));
+        
+        if capacity > 0.0 {
+            used / capacity
+        } else {
+            0.0
+        }
+    }
+    
+    /// Set precision policy
+    pub fn set_precision_policy(&mut self, policy: PrecisionPolicy) {
+        self.precision_policy = policy;
+    }
+    
+    /// Get current model precision
+    pub fn get_model_precision(&self) -> ModelPrecision {
+        self.precision_policy.current
+    }
+    
+    /// Check memory pressure and switch model precision if needed
+    ///
+    /// The switch is committed and recorded only once every precision
+    /// handler accepts it; otherwise the precision is left unchanged and
+    /// the change is proposed again on the next check.
+    pub fn check_memory_pressure(&mut self) -> Result<Option<PrecisionChange>, ResourceError> {
+        let memory_pressure = self.get_memory_pressure();
+        
+        let change = match self.precision_policy.propose(memory_pressure) {
+            Some(change) => change,
+            None => return Ok(None),
+        };
+        
+        // Notify precision handlers
+        for handler in &self.precision_handlers {
+            handler(&change)?;
+        }
+        
+        self.precision_policy.commit(&change);
+        self.precision_history.push(change.clone());
+        Ok(Some(change))
+    }
+    
+    /// Add precision change handler
+    pub fn add_precision_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&PrecisionChange) -> Result<(), ResourceError> + Send + Sync + 'static,
+    {
+        self.precision_handlers.push(Box::new(handler));
+    }
+    
     /// Add resource handler
     pub fn add_resource_handler<F>(&mut self, handler: F)
     where
@@ -89,4 +89,95 @@ mod tests {
         assert!(start().is_ok());
         assert!(stop().is_ok());
     }
+
+    #[test]
+    fn test_precision_downgrade_under_memory_pressure() {
+        use crate::system::resource::{ModelPrecision, Resource};
+        use std::sync::{Arc, Mutex};
+        
+        let mut manager = ResourceManager::new().unwrap();
+        let memory = Resource::new("system_memory", ResourceType::Memory, 100.0, "MB");
+        let memory_id = memory.id.clone();
+        manager.add_resource(memory).unwrap();
+        
+        // Subsystem precision follows the reported changes
+        let model_precision = Arc::new(Mutex::new(ModelPrecision::FP32));
+        let handler_precision = model_precision.clone();
+        manager.add_precision_handler(move |change| {
+            *handler_precision.lock().unwrap() = change.to;
+            Ok(())
+        });
+        
+        manager.allocate_resource(&memory_id, 50.0).unwrap();
+        assert_eq!(*model_precision.lock().unwrap(), ModelPrecision::FP32);
+        
+        manager.allocate_resource(&memory_id, 35.0).unwrap();
+        assert_eq!(*model_precision.lock().unwrap(), ModelPrecision::FP16);
+        
+        manager.allocate_resource(&memory_id, 10.0).unwrap();
+        assert_eq!(*model_precision.lock().unwrap(), ModelPrecision::INT8);
+        assert!(manager.precision_history.iter().all(|c| c.is_downgrade()));
+        
+        // Pressure eases, precision switches back
+        manager.deallocate_resource(&memory_id, 60.0).unwrap();
+        assert_eq!(*model_precision.lock().unwrap(), ModelPrecision::FP32);
+        assert_eq!(manager.get_model_precision(), ModelPrecision::FP32);
+        assert_eq!(manager.precision_history.len(), 3);
+    }
+
+    #[test]
+    fn test_rejected_precision_change_is_not_committed() {
+        use crate::system::resource::{ModelPrecision, Resource};
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        
+        let mut manager = ResourceManager::new().unwrap();
+        let memory = Resource::new("system_memory", ResourceType::Memory, 100.0, "MB");
+        let memory_id = memory.id.clone();
+        manager.add_resource(memory).unwrap();
+        
+        let accept = Arc::new(AtomicBool::new(false));
+        let handler_accept = accept.clone();
+        manager.add_precision_handler(move |_| match handler_accept.load(Ordering::SeqCst) {
+            true => Ok(()),
+            false => Err(ResourceError::Other("model busy".to_string())),
+        });
+        manager.add_resource_handler(|_| Err(ResourceError::Other("audit offline".to_string())));
+        
+        // The allocation stands even though both handlers fail
+        manager.allocate_resource(&memory_id, 85.0).unwrap();
+        assert_eq!(manager.get_resource(&memory_id).unwrap().used, 85.0);
+        assert_eq!(manager.get_model_precision(), ModelPrecision::FP32);
+        assert!(manager.precision_history.is_empty());
+        assert!(manager.check_memory_pressure().is_err());
+        
+        accept.store(true, Ordering::SeqCst);
+        let change = manager.check_memory_pressure().unwrap().unwrap();
+        assert_eq!((change.from, change.to), (ModelPrecision::FP32, ModelPrecision::FP16));
+        assert_eq!(manager.get_model_precision(), ModelPrecision::FP16);
+        assert_eq!(manager.precision_history, vec![change]);
+    }
+
+    #[test]
+    fn test_byte_size_parsing() {
+        use crate::system::resource::ByteSize;
+        
+        assert_eq!(ByteSize::parse("4GB").unwrap(), ByteSize::gb(4));
+        assert_eq!(ByteSize::parse("4096MB").unwrap(), ByteSize::gb(4));
+        assert_eq!(ByteSize::parse("4G").unwrap(), ByteSize::parse("4096 MiB").unwrap());
+        assert_eq!(ByteSize::parse(" 512 kb ").unwrap(), ByteSize::kb(512));
+        assert_eq!(ByteSize::parse("1.5GB").unwrap(), ByteSize::mb(1536));
+        assert_eq!(ByteSize::parse("1024B").unwrap(), ByteSize::kb(1));
+        assert_eq!("2TB".parse::<ByteSize>().unwrap().as_bytes(), 2 * ByteSize::TB);
+        
+        assert_eq!(ByteSize::gb(4).as_mb(), 4096);
+        assert_eq!(ByteSize::mb(4096).to_string(), "4GB");
+        assert_eq!(ByteSize::mb(1536).to_string(), "1536MB");
+        
+        // A bare number is ambiguous and rejected
+        assert!(ByteSize::parse("4096").is_err());
+        assert!(ByteSize::parse("4XB").is_err());
+        assert!(ByteSize::parse("GB").is_err());
+        assert!(ByteSize::parse("0.3B").is_err());
+    }
 }
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Default maximum number of buffered writes before a flush
+pub const DEFAULT_MAX_PENDING_WRITES: usize = 64;
+
+/// Default interval between automatic flushes
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Store error
+#[derive(Debug)]
+pub enum StoreError {
+    /// Initialization error
+    InitializationError(String),
+    /// Invalid key error
+    InvalidKey(String),
+    /// IO error
+    IoError(String),
+    /// Other error
+    Other(String),
+}
+
+impl Error for StoreError {}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::InitializationError(msg) => write!(f, "Initialization error: {}", msg),
+            StoreError::InvalidKey(msg) => write!(f, "Invalid key: {}", msg),
+            StoreError::IoError(msg) => write!(f, "IO error: {}", msg),
+            StoreError::Other(msg) => write!(f, "Other error: {}", msg),
+        }
+    }
+}
+
+/// Store config
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoreConfig {
+    /// Maximum number of buffered writes before a flush; 0 writes through
+    pub max_pending_writes: usize,
+    /// Interval after which buffered writes are flushed on the next write
+    pub flush_interval: Duration,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            max_pending_writes: DEFAULT_MAX_PENDING_WRITES,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+        }
+    }
+}
+
+/// File-backed key-value store with a write batcher
+///
+/// Each key is stored in its own file under the root directory. Writes and
+/// deletes are buffered in memory and coalesced per key; reads see buffered
+/// values immediately. Buffered writes are flushed when the pending count
+/// reaches `max_pending_writes`, when `flush_interval` has elapsed since the
+/// last flush, on `sync()`, and on a best-effort basis when the store is
+/// dropped.
+///
+/// Durability: only writes flushed by a successful `sync()` are guaranteed to
+/// survive a crash. Each file is written to a temporary path, fsynced and
+/// renamed into place, so a crash never leaves a partially written value.
+pub struct Store {
+    /// Root directory
+    pub root: PathBuf,
+    /// Store config
+    pub config: StoreConfig,
+    /// Buffered writes; `None` marks a delete
+    pending: BTreeMap<String, Option<Vec<u8>>>,
+    /// Last flush time
+    last_flush: Instant,
+}
+
+impl Store {
+    /// Open a store rooted at a directory, creating it if needed
+    pub fn open<P: AsRef<Path>>(root: P, config: StoreConfig) -> Result<Self, StoreError> {
+        let root = root.as_ref().to_path_buf();
+        fs::create_dir_all(&root).map_err(|e| {
+            StoreError::InitializationError(format!("Failed to create {}: {}", root.display(), e))
+        })?;
+
+        Ok(Self {
+            root,
+            config,
+            pending: BTreeMap::new(),
+            last_flush: Instant::now(),
+        })
+    }
+
+    /// Put value
+    pub fn put(&mut self, key: &str, value: &[u8]) -> Result<(), StoreError> {
+        Self::validate_key(key)?;
+        self.pending.insert(key.to_string(), Some(value.to_vec()));
+        self.flush_if_due()
+    }
+
+    /// Get value, including buffered writes
+    pub fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        Self::validate_key(key)?;
+
+        if let Some(pending) = self.pending.get(key) {
+            return Ok(pending.clone());
+        }
+
+        match fs::read(self.path_for(key)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(StoreError::IoError(format!("Failed to read {}: {}", key, e))),
+        }
+    }
+
+    /// Delete value
+    pub fn delete(&mut self, key: &str) -> Result<(), StoreError> {
+        Self::validate_key(key)?;
+        self.pending.insert(key.to_string(), None);
+        self.flush_if_due()
+    }
+
+    /// Get stored keys, including buffered writes
+    pub fn keys(&self) -> Result<Vec<String>, StoreError> {
+        let entries = fs::read_dir(&self.root)
+            .map_err(|e| StoreError::IoError(format!("Failed to list {}: {}", self.root.display(), e)))?;
+
+        let mut keys = std::collections::BTreeSet::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| StoreError::IoError(e.to_string()))?;
+            if let Some(name) = entry.file_name().to_str() {
+                if Self::validate_key(name).is_ok() {
+                    keys.insert(name.to_string());
+                }
+            }
+        }
+
+        for (key, value) in &self.pending {
+            match value {
+                Some(_) => keys.insert(key.clone()),
+                None => keys.remove(key),
+            };
+        }
+
+        Ok(keys.into_iter().collect())
+    }
+
+    /// Get number of buffered writes
+    pub fn pending_writes(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Flush all buffered writes to disk, returning the number flushed
+    pub fn sync(&mut self) -> Result<usize, StoreError> {
+        let pending = std::mem::take(&mut self.pending);
+        let count = pending.len();
+
+        let mut iter = pending.into_iter();
+        while let Some((key, value)) = iter.next() {
+            if let Err(e) = self.write_through(&key, value.as_deref()) {
+                // Keep the failed write and everything after it for the next sync
+                self.pending.insert(key, value);
+                self.pending.extend(iter);
+                return Err(e);
+            }
+        }
+
+        if count > 0 {
+            if let Ok(dir) = File::open(&self.root) {
+                let _ = dir.sync_all();
+            }
+        }
+
+        self.last_flush = Instant::now();
+        Ok(count)
+    }
+
+    /// Flush if the batch is full or the flush interval has elapsed
+    fn flush_if_due(&mut self) -> Result<(), StoreError> {
+        if self.pending.len() >= self.config.max_pending_writes
+            || self.last_flush.elapsed() >= self.config.flush_interval
+        {
+            self.sync()?;
+        }
+        Ok(())
+    }
+
+    /// Write a single value atomically
+    fn write_through(&self, key: &str, value: Option<&[u8]>) -> Result<(), StoreError> {
+        let path = self.path_for(key);
+
+        match value {
+            Some(bytes) => {
+                let tmp = self.root.join(format!(".{}.tmp", key));
+                let mut file = File::create(&tmp)
+                    .map_err(|e| StoreError::IoError(format!("Failed to create {}: {}", tmp.display(), e)))?;
+                file.write_all(bytes)
+                    .and_then(|_| file.sync_all())
+                    .map_err(|e| StoreError::IoError(format!("Failed to write {}: {}", key, e)))?;
+                fs::rename(&tmp, &path)
+                    .map_err(|e| StoreError::IoError(format!("Failed to replace {}: {}", key, e)))
+            },
+            None => match fs::remove_file(&path) {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(StoreError::IoError(format!("Failed to delete {}: {}", key, e))),
+            },
+        }
+    }
+
+    /// Get file path for a key
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+
+    /// Validate key, allowing only characters safe in file names
+    fn validate_key(key: &str) -> Result<(), StoreError> {
+        let valid = !key.is_empty()
+            && !key.starts_with('.')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(StoreError::InvalidKey(key.to_string()))
+        }
+    }
+}
+
+impl Drop for Store {
+    fn drop(&mut self) {
+        if let Err(e) = self.sync() {
+            eprintln!("[STORE] Failed to flush pending writes on drop: {}", e);
+        }
+    }
+}
+
+/// Initialize store module
+pub fn init() -> Result<(), StoreError> {
+    // Initialize store module
+    Ok(())
+}
+
+/// Start store module
+pub fn start() -> Result<(), StoreError> {
+    // Start store module
+    Ok(())
+}
+
+/// Stop store module
+pub fn stop() -> Result<(), StoreError> {
+    // Stop store module
+    Ok(())
+}
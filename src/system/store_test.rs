@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{Store, StoreConfig, StoreError};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("store-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn batching_config() -> StoreConfig {
+        StoreConfig {
+            max_pending_writes: 100,
+            flush_interval: Duration::from_secs(3600),
+        }
+    }
+
+    #[test]
+    fn test_batched_writes_readable_after_sync_and_reopen() {
+        let root = temp_root();
+        let mut store = Store::open(&root, batching_config()).unwrap();
+
+        for i in 0..10 {
+            store.put(&format!("session-{}", i), format!("state {}", i).as_bytes()).unwrap();
+        }
+        store.put("session-3", b"state 3 updated").unwrap();
+        store.delete("session-9").unwrap();
+
+        // Coalesced per key and visible before reaching disk
+        assert_eq!(store.pending_writes(), 10);
+        assert_eq!(store.get("session-3").unwrap().unwrap(), b"state 3 updated");
+        assert!(!root.join("session-0").exists());
+
+        assert_eq!(store.sync().unwrap(), 10);
+        assert_eq!(store.pending_writes(), 0);
+        drop(store);
+
+        let reopened = Store::open(&root, batching_config()).unwrap();
+        for i in 0..9 {
+            let expected = if i == 3 { "state 3 updated".to_string() } else { format!("state {}", i) };
+            assert_eq!(reopened.get(&format!("session-{}", i)).unwrap().unwrap(), expected.as_bytes());
+        }
+        assert!(reopened.get("session-9").unwrap().is_none());
+        assert_eq!(reopened.keys().unwrap().len(), 9);
+
+        drop(reopened);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_unsynced_writes_lost_on_crash() {
+        let root = temp_root();
+        let mut store = Store::open(&root, batching_config()).unwrap();
+        store.put("checkpoint", b"v1").unwrap();
+        store.sync().unwrap();
+        store.put("checkpoint", b"v2").unwrap();
+
+        // Simulate a crash: skip the flush on drop
+        std::mem::forget(store);
+
+        let reopened = Store::open(&root, batching_config()).unwrap();
+        assert_eq!(reopened.get("checkpoint").unwrap().unwrap(), b"v1");
+
+        drop(reopened);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_batch_flushes_when_full() {
+        let root = temp_root();
+        let config = StoreConfig {
+            max_pending_writes: 3,
+            flush_interval: Duration::from_secs(3600),
+        };
+        let mut store = Store::open(&root, config).unwrap();
+
+        store.put("a", b"1").unwrap();
+        store.put("b", b"2").unwrap();
+        assert_eq!(store.pending_writes(), 2);
+        store.put("c", b"3").unwrap();
+        assert_eq!(store.pending_writes(), 0);
+        assert_eq!(std::fs::read(root.join("c")).unwrap(), b"3");
+
+        match store.put("../escape", b"x") {
+            Err(StoreError::InvalidKey(key)) => assert_eq!(key, "../escape"),
+            other => panic!("expected invalid key, got {:?}", other),
+        }
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
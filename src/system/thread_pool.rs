@@ -0,0 +1,343 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::system::{MetricDescriptor, MetricType, MonitoringError, MonitoringSystem};
+
+/// Thread pool queue depth metric
+pub const METRIC_POOL_QUEUE_DEPTH: &str = "thread_pool_queue_depth";
+
+/// Thread pool utilization metric, the share of workers running a job
+pub const METRIC_POOL_UTILIZATION: &str = "thread_pool_utilization";
+
+/// Thread pool worker count metric
+pub const METRIC_POOL_WORKERS: &str = "thread_pool_workers";
+
+/// Thread pool error
+#[derive(Debug)]
+pub enum ThreadPoolError {
+    /// Invalid configuration
+    InvalidConfig(String),
+    /// Worker thread could not be spawned
+    SpawnError(String),
+    /// Monitoring error
+    MonitoringError(String),
+    /// Other error
+    Other(String),
+}
+
+impl Error for ThreadPoolError {}
+
+impl fmt::Display for ThreadPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThreadPoolError::InvalidConfig(msg) => write!(f, "Invalid config: {}", msg),
+            ThreadPoolError::SpawnError(msg) => write!(f, "Spawn error: {}", msg),
+            ThreadPoolError::MonitoringError(msg) => write!(f, "Monitoring error: {}", msg),
+            ThreadPoolError::Other(msg) => write!(f, "Other error: {}", msg),
+        }
+    }
+}
+
+impl From<MonitoringError> for ThreadPoolError {
+    fn from(e: MonitoringError) -> Self {
+        ThreadPoolError::MonitoringError(e.to_string())
+    }
+}
+
+/// Autoscaling policy
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoscalePolicy {
+    /// Minimum number of workers
+    pub min_workers: usize,
+    /// Maximum number of workers
+    pub max_workers: usize,
+    /// Number of samples a decision is based on
+    pub window: usize,
+    /// Mean queued jobs per worker at or above which the pool grows
+    pub grow_queue_per_worker: f64,
+    /// Mean utilization at or below which an empty-queued pool shrinks
+    pub shrink_utilization: f64,
+}
+
+impl Default for AutoscalePolicy {
+    fn default() -> Self {
+        Self {
+            min_workers: 1,
+            max_workers: thread::available_parallelism().map(|n| n.get()).unwrap_or(4),
+            window: 5,
+            grow_queue_per_worker: 1.0,
+            shrink_utilization: 0.25,
+        }
+    }
+}
+
+impl AutoscalePolicy {
+    /// Validate policy
+    pub fn validate(&self) -> Result<(), ThreadPoolError> {
+        if self.min_workers == 0 || self.min_workers > self.max_workers {
+            return Err(ThreadPoolError::InvalidConfig(format!(
+                "Worker bounds must satisfy 1 <= min <= max, got min={} max={}",
+                self.min_workers, self.max_workers
+            )));
+        }
+        if self.window == 0 {
+            return Err(ThreadPoolError::InvalidConfig("Window must be at least one sample".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Autoscaling decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalingDecision {
+    /// Keep the current worker count
+    Hold,
+    /// Grew to the given worker count
+    Grow(usize),
+    /// Shrank to the given worker count
+    Shrink(usize),
+}
+
+/// Queued job
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Job queue shared with workers
+struct PoolQueue {
+    /// Pending jobs
+    jobs: VecDeque<Job>,
+    /// Number of idle workers asked to exit
+    retiring: usize,
+    /// Whether the pool is shutting down
+    shutdown: bool,
+}
+
+/// State shared with workers
+struct PoolShared {
+    /// Job queue
+    queue: Mutex<PoolQueue>,
+    /// Signalled when jobs are queued, workers retire or the pool shuts down
+    available: Condvar,
+    /// Number of workers running a job
+    busy: AtomicUsize,
+}
+
+/// Thread pool that grows and shrinks between policy bounds
+///
+/// The pool does not scale on its own. Call `autoscale` periodically: it
+/// records queue depth and utilization to the monitoring system and adjusts
+/// the worker count by one when the last `window` samples show sustained
+/// backlog or idleness. Shrinking retires idle workers only.
+pub struct ThreadPool {
+    /// Pool name
+    pub name: String,
+    /// Autoscaling policy
+    pub policy: AutoscalePolicy,
+    /// State shared with workers
+    shared: Arc<PoolShared>,
+    /// Worker handles, including retired workers not yet joined
+    handles: Vec<JoinHandle<()>>,
+    /// Current worker count
+    workers: usize,
+    /// Samples recorded since the last scaling decision
+    samples_since_scale: usize,
+}
+
+impl ThreadPool {
+    /// Create a new thread pool with `policy.min_workers` workers
+    pub fn new(name: &str, policy: AutoscalePolicy) -> Result<Self, ThreadPoolError> {
+        policy.validate()?;
+
+        let mut pool = Self {
+            name: name.to_string(),
+            shared: Arc::new(PoolShared {
+                queue: Mutex::new(PoolQueue {
+                    jobs: VecDeque::new(),
+                    retiring: 0,
+                    shutdown: false,
+                }),
+                available: Condvar::new(),
+                busy: AtomicUsize::new(0),
+            }),
+            handles: Vec::new(),
+            workers: 0,
+            samples_since_scale: 0,
+            policy,
+        };
+
+        for _ in 0..pool.policy.min_workers {
+            pool.spawn_worker()?;
+        }
+
+        Ok(pool)
+    }
+
+    /// Queue a job
+    pub fn submit<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.shared.queue.lock().unwrap().jobs.push_back(Box::new(job));
+        self.shared.available.notify_one();
+    }
+
+    /// Get current worker count
+    pub fn worker_count(&self) -> usize {
+        self.workers
+    }
+
+    /// Get number of queued jobs
+    pub fn queue_depth(&self) -> usize {
+        self.shared.queue.lock().unwrap().jobs.len()
+    }
+
+    /// Get number of workers running a job
+    pub fn busy_workers(&self) -> usize {
+        self.shared.busy.load(Ordering::SeqCst)
+    }
+
+    /// Register pool metrics
+    pub fn register_metrics(monitoring: &mut MonitoringSystem) -> Result<(), MonitoringError> {
+        monitoring.register_metric(MetricDescriptor::new(
+            METRIC_POOL_QUEUE_DEPTH, MetricType::System, "jobs", "Jobs waiting for a thread pool worker",
+        ))?;
+        monitoring.register_metric(MetricDescriptor::new(
+            METRIC_POOL_UTILIZATION, MetricType::System, "ratio", "Share of thread pool workers running a job",
+        ))?;
+        monitoring.register_metric(MetricDescriptor::new(
+            METRIC_POOL_WORKERS, MetricType::System, "workers", "Thread pool worker count",
+        ))
+    }
+
+    /// Record current queue depth, utilization and worker count
+    pub fn record_metrics(&mut self, monitoring: &mut MonitoringSystem) -> Result<(), MonitoringError> {
+        if monitoring.get_descriptor(METRIC_POOL_QUEUE_DEPTH).is_none() {
+            Self::register_metrics(monitoring)?;
+        }
+
+        let utilization = self.busy_workers() as f64 / self.workers as f64;
+        monitoring.record_registered(METRIC_POOL_QUEUE_DEPTH, self.queue_depth() as f64)?;
+        monitoring.record_registered(METRIC_POOL_UTILIZATION, utilization)?;
+        monitoring.record_registered(METRIC_POOL_WORKERS, self.workers as f64)?;
+        self.samples_since_scale += 1;
+        Ok(())
+    }
+
+    /// Record metrics and adjust the worker count from the last `window` samples
+    ///
+    /// Samples taken before the previous adjustment are ignored, so each
+    /// decision is based on a full window at the current size.
+    pub fn autoscale(&mut self, monitoring: &mut MonitoringSystem) -> Result<ScalingDecision, ThreadPoolError> {
+        self.record_metrics(monitoring)?;
+        if self.samples_since_scale < self.policy.window {
+            return Ok(ScalingDecision::Hold);
+        }
+
+        let (queue, utilization) = match (
+            monitoring.metric_stats(METRIC_POOL_QUEUE_DEPTH, self.policy.window),
+            monitoring.metric_stats(METRIC_POOL_UTILIZATION, self.policy.window),
+        ) {
+            (Some(queue), Some(utilization)) => (queue, utilization),
+            _ => return Ok(ScalingDecision::Hold),
+        };
+
+        let backlog = queue.min / self.workers as f64 >= self.policy.grow_queue_per_worker;
+        let idle = queue.max == 0.0 && utilization.mean <= self.policy.shrink_utilization;
+
+        let decision = if backlog && self.workers < self.policy.max_workers {
+            self.spawn_worker()?;
+            ScalingDecision::Grow(self.workers)
+        } else if idle && self.workers > self.policy.min_workers {
+            self.retire_worker();
+            ScalingDecision::Shrink(self.workers)
+        } else {
+            ScalingDecision::Hold
+        };
+
+        if decision != ScalingDecision::Hold {
+            self.samples_since_scale = 0;
+        }
+        Ok(decision)
+    }
+
+    /// Spawn a worker
+    fn spawn_worker(&mut self) -> Result<(), ThreadPoolError> {
+        let shared = self.shared.clone();
+        let handle = thread::Builder::new()
+            .name(format!("{}-worker-{}", self.name, self.handles.len()))
+            .spawn(move || Self::worker_loop(&shared))
+            .map_err(|e| ThreadPoolError::SpawnError(e.to_string()))?;
+
+        // Drop handles of retired workers that have exited
+        self.handles.retain(|h| !h.is_finished());
+        self.handles.push(handle);
+        self.workers += 1;
+        Ok(())
+    }
+
+    /// Ask an idle worker to exit
+    fn retire_worker(&mut self) {
+        self.shared.queue.lock().unwrap().retiring += 1;
+        self.shared.available.notify_one();
+        self.workers -= 1;
+    }
+
+    /// Run jobs until retired or the pool shuts down with an empty queue
+    fn worker_loop(shared: &PoolShared) {
+        loop {
+            let job = {
+                let mut queue = shared.queue.lock().unwrap();
+                loop {
+                    if queue.retiring > 0 {
+                        queue.retiring -= 1;
+                        return;
+                    }
+                    if let Some(job) = queue.jobs.pop_front() {
+                        break job;
+                    }
+                    if queue.shutdown {
+                        return;
+                    }
+                    queue = shared.available.wait(queue).unwrap();
+                }
+            };
+
+            // A panicking job must not kill the worker or leave it counted as busy
+            shared.busy.fetch_add(1, Ordering::SeqCst);
+            let _ = panic::catch_unwind(AssertUnwindSafe(job));
+            shared.busy.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shared.queue.lock().unwrap().shutdown = true;
+        self.shared.available.notify_all();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Initialize thread pool module
+pub fn init() -> Result<(), ThreadPoolError> {
+    // Initialize thread pool module
+    Ok(())
+}
+
+/// Start thread pool module
+pub fn start() -> Result<(), ThreadPoolError> {
+    // Start thread pool module
+    Ok(())
+}
+
+/// Stop thread pool module
+pub fn stop() -> Result<(), ThreadPoolError> {
+    // Stop thread pool module
+    Ok(())
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        AutoscalePolicy, MonitoringSystem, ScalingDecision, ThreadPool, ThreadPoolError,
+        METRIC_POOL_QUEUE_DEPTH, METRIC_POOL_WORKERS,
+    };
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn policy() -> AutoscalePolicy {
+        AutoscalePolicy {
+            min_workers: 1,
+            max_workers: 3,
+            window: 3,
+            grow_queue_per_worker: 1.0,
+            shrink_utilization: 0.25,
+        }
+    }
+
+    /// Run `autoscale` for a full window and return the last decision
+    fn autoscale_window(pool: &mut ThreadPool, monitoring: &mut MonitoringSystem) -> ScalingDecision {
+        let mut decision = ScalingDecision::Hold;
+        for _ in 0..pool.policy.window {
+            decision = pool.autoscale(monitoring).unwrap();
+        }
+        decision
+    }
+
+    fn wait_until_idle(pool: &ThreadPool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while pool.queue_depth() > 0 || pool.busy_workers() > 0 {
+            assert!(Instant::now() < deadline, "pool did not drain");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_pool_grows_under_backlog_and_shrinks_when_idle() {
+        let mut monitoring = MonitoringSystem::new().unwrap();
+        let mut pool = ThreadPool::new("test", policy()).unwrap();
+        assert_eq!(pool.worker_count(), 1);
+
+        // Jobs block until the gate opens, keeping the queue full
+        let gate = Arc::new((Mutex::new(false), Condvar::new()));
+        let done = Arc::new(Mutex::new(0));
+        for _ in 0..8 {
+            let (gate, done) = (gate.clone(), done.clone());
+            pool.submit(move || {
+                let (open, opened) = &*gate;
+                let _open = opened.wait_while(open.lock().unwrap(), |open| !*open).unwrap();
+                *done.lock().unwrap() += 1;
+            });
+        }
+
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Grow(2));
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Grow(3));
+        // Bounded by max_workers
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Hold);
+        assert!(monitoring.get_latest_metric(METRIC_POOL_QUEUE_DEPTH).unwrap().value >= 5.0);
+        assert_eq!(monitoring.get_latest_metric(METRIC_POOL_WORKERS).unwrap().value, 3.0);
+
+        *gate.0.lock().unwrap() = true;
+        gate.1.notify_all();
+        wait_until_idle(&pool);
+        assert_eq!(*done.lock().unwrap(), 8);
+
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Shrink(2));
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Shrink(1));
+        // Bounded by min_workers
+        assert_eq!(autoscale_window(&mut pool, &mut monitoring), ScalingDecision::Hold);
+
+        // Remaining worker still runs jobs
+        let (sender, receiver) = std::sync::mpsc::channel();
+        pool.submit(move || sender.send(42).unwrap());
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_pool_rejects_invalid_bounds() {
+        let inverted = AutoscalePolicy { min_workers: 4, max_workers: 2, ..policy() };
+        assert!(matches!(ThreadPool::new("bad", inverted), Err(ThreadPoolError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_panicking_job_does_not_kill_worker() {
+        let pool = ThreadPool::new("panic", policy()).unwrap();
+        pool.submit(|| panic!("job failed"));
+        wait_until_idle(&pool);
+        assert_eq!(pool.busy_workers(), 0);
+
+        // The single worker survives and keeps running jobs
+        let (sender, receiver) = std::sync::mpsc::channel();
+        pool.submit(move || sender.send(42).unwrap());
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+    }
+}